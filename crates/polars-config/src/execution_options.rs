@@ -0,0 +1,25 @@
+use crate::SpillPolicy;
+
+/// Options for executing a single streaming query.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StreamingExecutionOptions {
+    /// Budget for the spillable data held by this query. Once exceeded, the nodes of this query
+    /// spill their data, regardless of the memory usage of other queries. `None` only applies
+    /// the global memory budget.
+    pub memory_budget_bytes: Option<u64>,
+
+    /// Overrides the order in which the nodes of this query spill their data.
+    pub spill_policy: SpillPolicy,
+}
+
+impl Default for StreamingExecutionOptions {
+    /// The options set by `POLARS_OOC_QUERY_MEMORY_BUDGET_MB` and `POLARS_OOC_SPILL_POLICY`.
+    fn default() -> Self {
+        let config = crate::config();
+        let budget = config.ooc_query_memory_budget_bytes();
+        Self {
+            memory_budget_bytes: (budget != u64::MAX).then_some(budget),
+            spill_policy: config.ooc_spill_policy(),
+        }
+    }
+}
@@ -2,15 +2,19 @@ use std::sync::LazyLock;
 use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU64, Ordering};
 
 mod engine;
+mod execution_options;
 mod parse;
 mod resolve_mode;
 mod spill_format;
 pub mod spill_path;
+mod spill_policy;
 
 pub use engine::Engine;
+pub use execution_options::StreamingExecutionOptions;
 use polars_error::polars_warn;
 pub use resolve_mode::ResolveMode;
 pub use spill_format::SpillFormat;
+pub use spill_policy::SpillPolicy;
 
 // Public.
 const VERBOSE: &str = "POLARS_VERBOSE";
@@ -60,7 +64,6 @@ const DEFAULT_IMPORT_INTERVAL_AS_STRUCT: bool = false;
 const OOC_DRIFT_THRESHOLD: &str = "POLARS_OOC_DRIFT_THRESHOLD";
 const DEFAULT_OOC_DRIFT_THRESHOLD: u64 = 4 * 1024 * 1024;
 
-// Unused at the moment, always IPC.
 const OOC_SPILL_FORMAT: &str = "POLARS_OOC_SPILL_FORMAT";
 const DEFAULT_OOC_SPILL_FORMAT: SpillFormat = SpillFormat::Ipc;
 
@@ -74,6 +77,15 @@ const DEFAULT_OOC_MEMORY_BUDGET_FRACTION: f64 = 0.8;
 const OOC_MEMORY_BUDGET_MB: &str = "POLARS_OOC_MEMORY_BUDGET_MB";
 const DEFAULT_OOC_MEMORY_BUDGET_MB: u64 = u64::MAX;
 
+/// Budget for the spillable buffers held by a single streaming query. Each query spills its own
+/// buffers once it exceeds this, independent of the global memory budget.
+const OOC_QUERY_MEMORY_BUDGET_MB: &str = "POLARS_OOC_QUERY_MEMORY_BUDGET_MB";
+const DEFAULT_OOC_QUERY_MEMORY_BUDGET_MB: u64 = u64::MAX;
+
+/// Overrides the order in which the spill contexts of a streaming query spill their buffers.
+const OOC_SPILL_POLICY: &str = "POLARS_OOC_SPILL_POLICY";
+const DEFAULT_OOC_SPILL_POLICY: SpillPolicy = SpillPolicy::Auto;
+
 const OOC_SPILL_MIN_BYTES: &str = "POLARS_OOC_SPILL_MIN_BYTES";
 const DEFAULT_OOC_SPILL_MIN_BYTES: u64 = 64 * 1024; // 64 KB
 
@@ -83,6 +95,20 @@ const DEFAULT_OOC_LOG_METRICS: bool = false;
 const JOIN_SAMPLE_LIMIT: &str = "POLARS_JOIN_SAMPLE_LIMIT";
 const DEFAULT_JOIN_SAMPLE_LIMIT: u64 = 10_000_000;
 
+/// Number of partitions both sides of an equi-join are split into when it falls back to a grace
+/// hash join.
+const JOIN_GRACE_PARTITIONS: &str = "POLARS_JOIN_GRACE_PARTITIONS";
+const DEFAULT_JOIN_GRACE_PARTITIONS: u64 = 64;
+
+/// Always use a grace hash join for unordered equi-joins, even without memory pressure.
+const FORCE_GRACE_JOIN: &str = "POLARS_FORCE_GRACE_JOIN";
+const DEFAULT_FORCE_GRACE_JOIN: bool = false;
+
+/// Always spill the evicted pre-aggregates of a streaming group-by and repartition each of its
+/// partitions at least once, even without memory pressure.
+const FORCE_GROUP_BY_SPILL: &str = "POLARS_FORCE_GROUP_BY_SPILL";
+const DEFAULT_FORCE_GROUP_BY_SPILL: bool = false;
+
 /// Allows pruning of strict hconcat inputs in projection pushdown. This can reduce data loading
 /// but may discard shape errors.
 const PROJECTION_PUSHDOWN_PRUNE_STRICT_HCONCAT_INPUTS: &str =
@@ -92,6 +118,11 @@ const DEFAULT_PROJECTION_PUSHDOWN_PRUNE_STRICT_HCONCAT_INPUTS: bool = false;
 const ALLOW_NESTED_CSPE: &str = "POLARS_ALLOW_NESTED_CSPE";
 const DEFAULT_ALLOW_NESTED_CSPE: bool = false;
 
+/// Maximum number of chunks of a fixed-width file being prefetched at once. 0 uses twice the
+/// number of pipelines.
+const FWF_CHUNK_PREFETCH_LIMIT: &str = "POLARS_FWF_CHUNK_PREFETCH_LIMIT";
+const DEFAULT_FWF_CHUNK_PREFETCH_LIMIT: u64 = 0;
+
 static KNOWN_OPTIONS: &[&str] = &[
     // Public.
     VERBOSE,
@@ -136,10 +167,16 @@ static KNOWN_OPTIONS: &[&str] = &[
     OOC_SPILL_COMPRESSION_LEVEL,
     OOC_MEMORY_BUDGET_FRACTION,
     OOC_MEMORY_BUDGET_MB,
+    OOC_QUERY_MEMORY_BUDGET_MB,
+    OOC_SPILL_POLICY,
     OOC_SPILL_MIN_BYTES,
     OOC_LOG_METRICS,
     JOIN_SAMPLE_LIMIT,
+    JOIN_GRACE_PARTITIONS,
+    FORCE_GRACE_JOIN,
+    FORCE_GROUP_BY_SPILL,
     PROJECTION_PUSHDOWN_PRUNE_STRICT_HCONCAT_INPUTS,
+    FWF_CHUNK_PREFETCH_LIMIT,
 ];
 
 pub struct Config {
@@ -163,10 +200,16 @@ pub struct Config {
     ooc_spill_compression_level: AtomicU64,
     ooc_memory_budget_fraction: AtomicU64,
     ooc_memory_budget_bytes: AtomicU64,
+    ooc_query_memory_budget_bytes: AtomicU64,
+    ooc_spill_policy: AtomicU8,
     ooc_spill_min_bytes: AtomicU64,
     ooc_log_metrics: AtomicBool,
     join_sample_limit: AtomicU64,
+    join_grace_partitions: AtomicU64,
+    force_grace_join: AtomicBool,
+    force_group_by_spill: AtomicBool,
     projection_pushdown_prune_strict_hconcat_inputs: AtomicBool,
+    fwf_chunk_prefetch_limit: AtomicU64,
 }
 
 impl Config {
@@ -197,13 +240,21 @@ impl Config {
             ooc_memory_budget_bytes: AtomicU64::new(
                 DEFAULT_OOC_MEMORY_BUDGET_MB.saturating_mul(1_000_000),
             ),
+            ooc_query_memory_budget_bytes: AtomicU64::new(
+                DEFAULT_OOC_QUERY_MEMORY_BUDGET_MB.saturating_mul(1_000_000),
+            ),
+            ooc_spill_policy: AtomicU8::new(DEFAULT_OOC_SPILL_POLICY as u8),
             ooc_spill_min_bytes: AtomicU64::new(DEFAULT_OOC_SPILL_MIN_BYTES),
             ooc_log_metrics: AtomicBool::new(false),
             join_sample_limit: AtomicU64::new(DEFAULT_JOIN_SAMPLE_LIMIT),
+            join_grace_partitions: AtomicU64::new(DEFAULT_JOIN_GRACE_PARTITIONS),
+            force_grace_join: AtomicBool::new(DEFAULT_FORCE_GRACE_JOIN),
+            force_group_by_spill: AtomicBool::new(DEFAULT_FORCE_GROUP_BY_SPILL),
             projection_pushdown_prune_strict_hconcat_inputs: AtomicBool::new(
                 DEFAULT_PROJECTION_PUSHDOWN_PRUNE_STRICT_HCONCAT_INPUTS,
             ),
             allow_nested_cspe: AtomicBool::new(DEFAULT_ALLOW_NESTED_CSPE),
+            fwf_chunk_prefetch_limit: AtomicU64::new(DEFAULT_FWF_CHUNK_PREFETCH_LIMIT),
         };
         cfg.reload_env_vars();
         cfg
@@ -323,6 +374,17 @@ impl Config {
                     .saturating_mul(1_000_000),
                 Ordering::Relaxed,
             ),
+            OOC_QUERY_MEMORY_BUDGET_MB => self.ooc_query_memory_budget_bytes.store(
+                val.and_then(|x| parse::parse_u64(var, x))
+                    .unwrap_or(DEFAULT_OOC_QUERY_MEMORY_BUDGET_MB)
+                    .saturating_mul(1_000_000),
+                Ordering::Relaxed,
+            ),
+            OOC_SPILL_POLICY => self.ooc_spill_policy.store(
+                val.and_then(|x| parse::parse_spill_policy(var, x))
+                    .unwrap_or(DEFAULT_OOC_SPILL_POLICY) as u8,
+                Ordering::Relaxed,
+            ),
             OOC_SPILL_MIN_BYTES => self.ooc_spill_min_bytes.store(
                 val.and_then(|x| parse::parse_u64(var, x))
                     .unwrap_or(DEFAULT_OOC_SPILL_MIN_BYTES),
@@ -338,6 +400,21 @@ impl Config {
                     .unwrap_or(DEFAULT_JOIN_SAMPLE_LIMIT),
                 Ordering::Relaxed,
             ),
+            JOIN_GRACE_PARTITIONS => self.join_grace_partitions.store(
+                val.and_then(|x| parse::parse_u64(var, x))
+                    .unwrap_or(DEFAULT_JOIN_GRACE_PARTITIONS),
+                Ordering::Relaxed,
+            ),
+            FORCE_GRACE_JOIN => self.force_grace_join.store(
+                val.and_then(|x| parse::parse_bool(var, x))
+                    .unwrap_or(DEFAULT_FORCE_GRACE_JOIN),
+                Ordering::Relaxed,
+            ),
+            FORCE_GROUP_BY_SPILL => self.force_group_by_spill.store(
+                val.and_then(|x| parse::parse_bool(var, x))
+                    .unwrap_or(DEFAULT_FORCE_GROUP_BY_SPILL),
+                Ordering::Relaxed,
+            ),
             PROJECTION_PUSHDOWN_PRUNE_STRICT_HCONCAT_INPUTS => {
                 self.projection_pushdown_prune_strict_hconcat_inputs.store(
                     val.and_then(|x| parse::parse_bool(var, x))
@@ -345,6 +422,11 @@ impl Config {
                     Ordering::Relaxed,
                 )
             },
+            FWF_CHUNK_PREFETCH_LIMIT => self.fwf_chunk_prefetch_limit.store(
+                val.and_then(|x| parse::parse_u64(var, x))
+                    .unwrap_or(DEFAULT_FWF_CHUNK_PREFETCH_LIMIT),
+                Ordering::Relaxed,
+            ),
             _ => {
                 if var.starts_with("POLARS_") {
                     if self.warn_unknown_config.load(Ordering::Relaxed) {
@@ -456,6 +538,18 @@ impl Config {
         self.ooc_memory_budget_bytes.load(Ordering::Relaxed)
     }
 
+    /// The budget for the spillable buffers of a single streaming query, `u64::MAX` if
+    /// unlimited.
+    #[inline(always)]
+    pub fn ooc_query_memory_budget_bytes(&self) -> u64 {
+        self.ooc_query_memory_budget_bytes.load(Ordering::Relaxed)
+    }
+
+    #[inline(always)]
+    pub fn ooc_spill_policy(&self) -> SpillPolicy {
+        SpillPolicy::from_discriminant(self.ooc_spill_policy.load(Ordering::Relaxed))
+    }
+
     #[inline(always)]
     pub fn ooc_spill_min_bytes(&self) -> u64 {
         self.ooc_spill_min_bytes.load(Ordering::Relaxed)
@@ -466,11 +560,21 @@ impl Config {
         self.ooc_log_metrics.load(Ordering::Relaxed)
     }
 
-    pub fn ooc_spill_dir(&self) -> std::path::PathBuf {
-        if let Ok(dir) = std::env::var("POLARS_OOC_SPILL_DIR") {
-            std::path::PathBuf::from(dir)
+    /// The directories spill files are written to. `POLARS_OOC_SPILL_DIR` may list several
+    /// directories separated by the platform's path separator (`:` on Unix, `;` on Windows).
+    pub fn ooc_spill_dirs(&self) -> Vec<std::path::PathBuf> {
+        let dirs = std::env::var_os("POLARS_OOC_SPILL_DIR")
+            .map(|dirs| {
+                std::env::split_paths(&dirs)
+                    .filter(|dir| !dir.as_os_str().is_empty())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        if dirs.is_empty() {
+            vec![spill_path::default_ooc_spill_dir()]
         } else {
-            spill_path::default_ooc_spill_dir()
+            dirs
         }
     }
 
@@ -479,11 +583,36 @@ impl Config {
         self.join_sample_limit.load(Ordering::Relaxed)
     }
 
+    #[inline(always)]
+    pub fn join_grace_partitions(&self) -> u64 {
+        self.join_grace_partitions.load(Ordering::Relaxed)
+    }
+
+    #[inline(always)]
+    pub fn force_grace_join(&self) -> bool {
+        self.force_grace_join.load(Ordering::Relaxed)
+    }
+
+    #[inline(always)]
+    pub fn force_group_by_spill(&self) -> bool {
+        self.force_group_by_spill.load(Ordering::Relaxed)
+    }
+
     #[inline(always)]
     pub fn projection_pushdown_prune_strict_hconcat_inputs(&self) -> bool {
         self.projection_pushdown_prune_strict_hconcat_inputs
             .load(Ordering::Relaxed)
     }
+
+    /// The maximum number of chunks of a fixed-width file being prefetched at once, `None` if
+    /// it should be derived from the number of pipelines.
+    #[inline(always)]
+    pub fn fwf_chunk_prefetch_limit(&self) -> Option<usize> {
+        match self.fwf_chunk_prefetch_limit.load(Ordering::Relaxed) {
+            0 => None,
+            limit => Some(usize::try_from(limit).unwrap_or(usize::MAX)),
+        }
+    }
 }
 
 pub fn config() -> &'static Config {
@@ -1,6 +1,6 @@
 use polars_error::polars_warn;
 
-use crate::{Engine, ResolveMode, SpillFormat};
+use crate::{Engine, ResolveMode, SpillFormat, SpillPolicy};
 
 pub fn parse_bool(var: &str, val: &str) -> Option<bool> {
     match val.trim_ascii() {
@@ -49,6 +49,16 @@ pub fn parse_spill_format(var: &str, val: &str) -> Option<SpillFormat> {
     }
 }
 
+pub fn parse_spill_policy(var: &str, val: &str) -> Option<SpillPolicy> {
+    match val.trim_ascii().parse::<SpillPolicy>() {
+        Ok(x) => Some(x),
+        Err(e) => {
+            polars_warn!("illegal value '{val}' found while parsing option '{var}' ({e})");
+            None
+        },
+    }
+}
+
 pub fn parse_resolve_mode(var: &str, val: &str) -> Option<ResolveMode> {
     match val.trim_ascii().parse::<ResolveMode>() {
         Ok(x) => Some(x),
@@ -1,11 +1,17 @@
 use std::fmt;
 use std::str::FromStr;
 
+/// The on-disk encoding of spilled DataFrames.
 #[repr(u8)]
 #[derive(Clone, Debug, Copy, Default, Eq, PartialEq, Hash)]
 pub enum SpillFormat {
+    /// Arrow IPC, zstd-compressed if `POLARS_OOC_SPILL_COMPRESSION_LEVEL` is non-zero.
     #[default]
     Ipc = 0,
+    /// Arrow IPC with lz4 compression.
+    IpcLz4 = 1,
+    /// The raw row-encoded buffers of the frame.
+    Row = 2,
 }
 
 impl fmt::Display for SpillFormat {
@@ -20,15 +26,21 @@ impl FromStr for SpillFormat {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "ipc" => Ok(Self::Ipc),
-            v => Err(format!("`spill_format` must be one of {{'ipc'}}, got {v}",)),
+            "ipc_lz4" => Ok(Self::IpcLz4),
+            "row" => Ok(Self::Row),
+            v => Err(format!(
+                "`spill_format` must be one of {{'ipc', 'ipc_lz4', 'row'}}, got {v}",
+            )),
         }
     }
 }
 
 impl SpillFormat {
-    pub(crate) fn from_discriminant(d: u8) -> Self {
+    pub fn from_discriminant(d: u8) -> Self {
         match d {
             0 => Self::Ipc,
+            1 => Self::IpcLz4,
+            2 => Self::Row,
             _ => unreachable!(),
         }
     }
@@ -36,6 +48,8 @@ impl SpillFormat {
     pub fn as_static_str(&self) -> &'static str {
         match self {
             Self::Ipc => "ipc",
+            Self::IpcLz4 => "ipc_lz4",
+            Self::Row => "row",
         }
     }
 }
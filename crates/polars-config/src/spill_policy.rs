@@ -0,0 +1,60 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// The order in which a spill context picks its buffers to spill.
+#[repr(u8)]
+#[derive(Clone, Debug, Copy, Default, Eq, PartialEq, Hash)]
+pub enum SpillPolicy {
+    /// Every node uses the order best suited to its access pattern.
+    #[default]
+    Auto = 0,
+    /// Spill the most recently registered buffer first.
+    MostRecent = 1,
+    /// Spill the least recently registered buffer first.
+    LeastRecent = 2,
+    /// Spill a random registered buffer.
+    Random = 3,
+}
+
+impl fmt::Display for SpillPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_static_str())
+    }
+}
+
+impl FromStr for SpillPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "most_recent" => Ok(Self::MostRecent),
+            "least_recent" => Ok(Self::LeastRecent),
+            "random" => Ok(Self::Random),
+            v => Err(format!(
+                "`spill_policy` must be one of {{'auto', 'most_recent', 'least_recent', 'random'}}, got {v}",
+            )),
+        }
+    }
+}
+
+impl SpillPolicy {
+    pub fn from_discriminant(d: u8) -> Self {
+        match d {
+            0 => Self::Auto,
+            1 => Self::MostRecent,
+            2 => Self::LeastRecent,
+            3 => Self::Random,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn as_static_str(&self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::MostRecent => "most_recent",
+            Self::LeastRecent => "least_recent",
+            Self::Random => "random",
+        }
+    }
+}
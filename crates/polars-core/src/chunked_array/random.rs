@@ -8,7 +8,7 @@ use rand_distr::{Normal, StandardNormal, StandardUniform, Uniform};
 use crate::prelude::DataType::Float64;
 use crate::prelude::*;
 use crate::random::get_global_random_u64;
-use crate::utils::NoNull;
+use crate::utils::{NoNull, accumulate_dataframes_vertical_unchecked};
 
 fn create_rand_index_with_replacement(n: usize, len: usize, seed: Option<u64>) -> IdxCa {
     if len == 0 {
@@ -241,6 +241,36 @@ impl DataFrame {
             None => Ok(self.clear()),
         }
     }
+
+    /// Keep every row independently with probability `fraction` (Bernoulli sampling).
+    ///
+    /// The order of the rows is maintained.
+    pub fn sample_bernoulli(&self, fraction: f64, seed: Option<u64>) -> PolarsResult<Self> {
+        let dist = Bernoulli::new(fraction).map_err(to_compute_err)?;
+        let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
+        let mask: BooleanChunked = (0..self.height())
+            .map(|_| dist.sample(&mut rng))
+            .collect_trusted();
+        self.filter(&mask)
+    }
+
+    /// Keep every chunk independently with probability `fraction` (system or block sampling).
+    ///
+    /// This is cheaper than [`DataFrame::sample_bernoulli`] as no per-row work is done, at the
+    /// cost of the sampled rows being clustered. The order of the rows is maintained.
+    pub fn sample_system(&self, fraction: f64, seed: Option<u64>) -> PolarsResult<Self> {
+        let dist = Bernoulli::new(fraction).map_err(to_compute_err)?;
+        let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
+        let mut df = self.clone();
+        let chunks: Vec<_> = df
+            .split_chunks()
+            .filter(|_| dist.sample(&mut rng))
+            .collect();
+        if chunks.is_empty() {
+            return Ok(self.clear());
+        }
+        Ok(accumulate_dataframes_vertical_unchecked(chunks))
+    }
 }
 
 impl<T> ChunkedArray<T>
@@ -35,6 +35,32 @@ pub(super) fn approx_n_unique(s: &Column) -> PolarsResult<Column> {
         .map(|v| Column::new_scalar(s.name().clone(), Scalar::new(IDX_DTYPE, v.into()), 1))
 }
 
+#[cfg(feature = "approx_quantile")]
+pub(super) fn approx_quantile(s: &Column, quantile: f64) -> PolarsResult<Column> {
+    use polars_utils::quantile_sketch::QuantileSketch;
+
+    let dtype = s.dtype();
+    polars_ensure!(
+        dtype.is_primitive_numeric() || dtype.is_null(),
+        InvalidOperation: "`approx_quantile` operation not supported for dtype `{dtype}`"
+    );
+    let mut sketch = QuantileSketch::new();
+    let values = s.cast(&DataType::Float64)?;
+    for v in values.f64()?.iter().flatten() {
+        sketch.insert(v);
+    }
+    let value = match sketch.quantile(quantile) {
+        Some(v) => AnyValue::Float64(v),
+        None => AnyValue::Null,
+    };
+    let out = Column::new_scalar(s.name().clone(), Scalar::new(DataType::Float64, value), 1);
+    if dtype.is_float() {
+        out.cast(dtype)
+    } else {
+        Ok(out)
+    }
+}
+
 #[cfg(feature = "diff")]
 pub(super) fn diff(s: &[Column], null_behavior: NullBehavior) -> PolarsResult<Column> {
     let s1 = s[0].as_materialized_series();
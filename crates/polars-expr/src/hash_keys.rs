@@ -328,6 +328,125 @@ impl HashKeys {
             HashKeys::Binview(s) => Self::Binview(s.gather_unchecked(idxs)),
         }
     }
+
+    /// Converts these keys into columns, such that they can be stored in a
+    /// DataFrame (e.g. to spill them to disk). The keys can be restored with
+    /// [`HashKeys::from_columns`] using the returned layout.
+    pub fn to_columns(&self) -> (HashKeysLayout, Vec<Column>) {
+        let hashes_column = |hashes: &UInt64Array| {
+            UInt64Chunked::with_chunk(PlSmallStr::from_static(HASHES_COLUMN), hashes.clone())
+                .into_column()
+        };
+        let keys_column = |arr: Box<dyn Array>, dtype: &DataType| unsafe {
+            Series::from_chunks_and_dtype_unchecked(
+                PlSmallStr::from_static(KEYS_COLUMN),
+                vec![arr],
+                dtype,
+            )
+            .into_column()
+        };
+        match self {
+            HashKeys::RowEncoded(s) => (
+                HashKeysLayout::RowEncoded,
+                vec![
+                    hashes_column(&s.hashes),
+                    keys_column(s.keys.clone().boxed(), &DataType::BinaryOffset),
+                ],
+            ),
+            HashKeys::Binview(s) => (
+                HashKeysLayout::Binview {
+                    null_is_valid: s.null_is_valid,
+                },
+                vec![
+                    hashes_column(&s.hashes),
+                    keys_column(s.keys.clone().boxed(), &DataType::Binary),
+                ],
+            ),
+            HashKeys::Single(s) => (
+                HashKeysLayout::Single {
+                    random_state: s.random_state.clone(),
+                    null_is_valid: s.null_is_valid,
+                },
+                vec![
+                    s.keys
+                        .clone()
+                        .with_name(PlSmallStr::from_static(KEYS_COLUMN))
+                        .into_column(),
+                ],
+            ),
+        }
+    }
+
+    /// Restores keys converted with [`HashKeys::to_columns`].
+    pub fn from_columns(layout: &HashKeysLayout, columns: &[Column]) -> PolarsResult<Self> {
+        let hashes = |c: &Column| -> PolarsResult<UInt64Array> {
+            Ok(c.u64()?.rechunk().downcast_as_array().clone())
+        };
+        Ok(match layout {
+            HashKeysLayout::RowEncoded => {
+                let [hashes_col, keys_col] = columns else {
+                    polars_bail!(ComputeError: "expected 2 columns for row-encoded hash keys")
+                };
+                let keys = keys_col
+                    .as_materialized_series()
+                    .cast(&DataType::BinaryOffset)?;
+                Self::RowEncoded(RowEncodedKeys {
+                    hashes: hashes(hashes_col)?,
+                    keys: keys.binary_offset()?.rechunk().downcast_as_array().clone(),
+                })
+            },
+            HashKeysLayout::Binview { null_is_valid } => {
+                let [hashes_col, keys_col] = columns else {
+                    polars_bail!(ComputeError: "expected 2 columns for binview hash keys")
+                };
+                Self::Binview(BinviewKeys {
+                    hashes: hashes(hashes_col)?,
+                    keys: keys_col.binary()?.rechunk().downcast_as_array().clone(),
+                    null_is_valid: *null_is_valid,
+                })
+            },
+            HashKeysLayout::Single {
+                random_state,
+                null_is_valid,
+            } => {
+                let [keys_col] = columns else {
+                    polars_bail!(ComputeError: "expected 1 column for single hash keys")
+                };
+                Self::Single(SingleKeys {
+                    random_state: random_state.clone(),
+                    keys: keys_col.as_materialized_series().rechunk(),
+                    null_is_valid: *null_is_valid,
+                })
+            },
+        })
+    }
+}
+
+const HASHES_COLUMN: &str = "__POLARS_HASH_KEYS_HASHES";
+const KEYS_COLUMN: &str = "__POLARS_HASH_KEYS";
+
+/// The parts of [`HashKeys`] which aren't stored in the columns returned by
+/// [`HashKeys::to_columns`].
+#[derive(Clone, Debug)]
+pub enum HashKeysLayout {
+    RowEncoded,
+    Binview {
+        null_is_valid: bool,
+    },
+    Single {
+        random_state: PlRandomState,
+        null_is_valid: bool,
+    },
+}
+
+impl HashKeysLayout {
+    /// The number of columns the keys are stored in.
+    pub fn num_columns(&self) -> usize {
+        match self {
+            HashKeysLayout::RowEncoded | HashKeysLayout::Binview { .. } => 2,
+            HashKeysLayout::Single { .. } => 1,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -0,0 +1,105 @@
+use std::marker::PhantomData;
+
+use num_traits::AsPrimitive;
+use polars_core::with_match_physical_numeric_polars_type;
+use polars_utils::quantile_sketch::QuantileSketch;
+
+use super::*;
+
+pub fn new_approx_quantile_reduction(
+    dtype: DataType,
+    quantile: f64,
+) -> PolarsResult<Box<dyn GroupedReduction>> {
+    use VecGroupedReduction as VGR;
+    Ok(match dtype {
+        _ if dtype.is_primitive_numeric() => {
+            with_match_physical_numeric_polars_type!(dtype.to_physical(), |$T| {
+                Box::new(VGR::new(dtype, ApproxQuantileReducer::<$T>::new(quantile)))
+            })
+        },
+        DataType::Null => Box::new(super::NullGroupedReduction::new(Scalar::null(
+            DataType::Float64,
+        ))),
+        _ => {
+            polars_bail!(InvalidOperation: "`approx_quantile` operation not supported for dtype `{dtype}`")
+        },
+    })
+}
+
+struct ApproxQuantileReducer<T> {
+    quantile: f64,
+    marker: PhantomData<T>,
+}
+
+impl<T> ApproxQuantileReducer<T> {
+    fn new(quantile: f64) -> Self {
+        Self {
+            quantile,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Clone for ApproxQuantileReducer<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.quantile)
+    }
+}
+
+impl<T> Reducer for ApproxQuantileReducer<T>
+where
+    T: PolarsNumericType,
+{
+    type Dtype = T;
+    type Value = QuantileSketch;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        QuantileSketch::new()
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        s.to_physical_repr()
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.combine(b);
+    }
+
+    #[inline(always)]
+    fn reduce_one(
+        &self,
+        a: &mut Self::Value,
+        b: Option<<Self::Dtype as PolarsDataType>::Physical<'_>>,
+        _seq_id: u64,
+    ) {
+        if let Some(b) = b {
+            a.insert(b.as_());
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        for val in ca.iter().flatten() {
+            v.insert(val.as_());
+        }
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let ca: Float64Chunked = v
+            .into_iter()
+            .map(|mut sketch| sketch.quantile(self.quantile))
+            .collect_ca(PlSmallStr::EMPTY);
+        if dtype.is_float() {
+            ca.into_series().cast(dtype)
+        } else {
+            Ok(ca.into_series())
+        }
+    }
+}
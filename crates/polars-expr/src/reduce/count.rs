@@ -121,6 +121,27 @@ impl GroupedReduction for CountReduce {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn encode_state(&self) -> Option<BinaryViewArray> {
+        let mut out = MutableBinaryViewArray::<[u8]>::with_capacity(self.counts.len());
+        for c in &self.counts {
+            out.push_value(c.to_le_bytes());
+        }
+        Some(out.freeze())
+    }
+
+    fn decode_state(&self, state: &BinaryViewArray) -> PolarsResult<Box<dyn GroupedReduction>> {
+        polars_ensure!(
+            !state.has_nulls(),
+            ComputeError: "invalid encoded grouped reduction state"
+        );
+        let mut out = Self::new(self.include_nulls);
+        out.counts = state
+            .values_iter()
+            .map(|b| Ok(decode_native::<u64>(b)?.0))
+            .collect::<PolarsResult<_>>()?;
+        Ok(Box::new(out))
+    }
 }
 
 pub struct NullCountReduce {
@@ -250,6 +250,20 @@ impl Reducer for BinaryMinReducer {
         let ca: BinaryChunked = v.into_iter().collect_ca(PlSmallStr::EMPTY);
         ca.into_series().cast(dtype)
     }
+
+    #[inline(always)]
+    fn can_encode(&self) -> bool {
+        true
+    }
+
+    fn encode_value(&self, v: &Self::Value, buf: &mut Vec<u8>) -> PolarsResult<()> {
+        encode_opt_bytes(v.as_deref(), buf);
+        Ok(())
+    }
+
+    fn decode_value(&self, buf: &[u8]) -> PolarsResult<Self::Value> {
+        decode_opt_bytes(buf)
+    }
 }
 
 impl Reducer for BinaryMaxReducer {
@@ -301,6 +315,20 @@ impl Reducer for BinaryMaxReducer {
         let ca: BinaryChunked = v.into_iter().collect_ca(PlSmallStr::EMPTY);
         ca.into_series().cast(dtype)
     }
+
+    #[inline(always)]
+    fn can_encode(&self) -> bool {
+        true
+    }
+
+    fn encode_value(&self, v: &Self::Value, buf: &mut Vec<u8>) -> PolarsResult<()> {
+        encode_opt_bytes(v.as_deref(), buf);
+        Ok(())
+    }
+
+    fn decode_value(&self, buf: &[u8]) -> PolarsResult<Self::Value> {
+        decode_opt_bytes(buf)
+    }
 }
 
 #[derive(Default)]
@@ -600,6 +628,21 @@ impl<T: PolarsCategoricalType> Reducer for CatMinReducer<T> {
             )
         }
     }
+
+    #[inline(always)]
+    fn can_encode(&self) -> bool {
+        true
+    }
+
+    // The categories are shared by the mapping, so their ids can be stored.
+    fn encode_value(&self, v: &Self::Value, buf: &mut Vec<u8>) -> PolarsResult<()> {
+        encode_native(*v, buf);
+        Ok(())
+    }
+
+    fn decode_value(&self, buf: &[u8]) -> PolarsResult<Self::Value> {
+        Ok(decode_native(buf)?.0)
+    }
 }
 
 #[cfg(feature = "dtype-categorical")]
@@ -667,6 +710,21 @@ impl<T: PolarsCategoricalType> Reducer for CatMaxReducer<T> {
             )
         }
     }
+
+    #[inline(always)]
+    fn can_encode(&self) -> bool {
+        true
+    }
+
+    // The categories are shared by the mapping, so their ids can be stored.
+    fn encode_value(&self, v: &Self::Value, buf: &mut Vec<u8>) -> PolarsResult<()> {
+        encode_native(*v, buf);
+        Ok(())
+    }
+
+    fn decode_value(&self, buf: &[u8]) -> PolarsResult<Self::Value> {
+        Ok(decode_native(buf)?.0)
+    }
 }
 
 #[derive(Default)]
@@ -2,6 +2,8 @@
 mod any_all;
 #[cfg(feature = "approx_unique")]
 mod approx_n_unique;
+#[cfg(feature = "approx_quantile")]
+mod approx_quantile;
 #[cfg(feature = "bitwise")]
 mod bitwise;
 mod convert;
@@ -25,8 +27,9 @@ use std::any::Any;
 use std::borrow::Cow;
 use std::marker::PhantomData;
 
-use arrow::array::{Array, PrimitiveArray, StaticArray};
+use arrow::array::{Array, BinaryViewArray, MutableBinaryViewArray, PrimitiveArray, StaticArray};
 use arrow::bitmap::{Bitmap, BitmapBuilder, MutableBitmap};
+use arrow::types::NativeType;
 pub use convert::into_reduction;
 pub use min_max::{new_max_reduction, new_min_reduction};
 use polars_core::prelude::*;
@@ -123,6 +126,20 @@ pub trait GroupedReduction: Any + Send + Sync {
 
     /// Returns this GroupedReduction as a dyn Any.
     fn as_any(&self) -> &dyn Any;
+
+    /// Encodes the reduction state of each group as a binary value, such that
+    /// it can be spilled and later restored with [`GroupedReduction::decode_state`].
+    ///
+    /// Returns None if the state of this reduction can't be encoded.
+    fn encode_state(&self) -> Option<BinaryViewArray> {
+        None
+    }
+
+    /// Returns a new GroupedReduction with one group per value in the given
+    /// state, as encoded by [`GroupedReduction::encode_state`].
+    fn decode_state(&self, _state: &BinaryViewArray) -> PolarsResult<Box<dyn GroupedReduction>> {
+        polars_bail!(ComputeError: "decoding the state of this reduction is not supported")
+    }
 }
 
 // Helper traits used in the VecGroupedReduction and VecMaskGroupedReduction to
@@ -149,6 +166,85 @@ pub trait Reducer: Send + Sync + Clone + 'static {
         m: Option<Bitmap>,
         dtype: &DataType,
     ) -> PolarsResult<Series>;
+
+    /// Whether values can be encoded with [`Reducer::encode_value`].
+    #[inline(always)]
+    fn can_encode(&self) -> bool {
+        false
+    }
+
+    /// Appends the binary encoding of a value to buf. Only supported if
+    /// [`Reducer::can_encode`] returns true.
+    fn encode_value(&self, _v: &Self::Value, _buf: &mut Vec<u8>) -> PolarsResult<()> {
+        unsupported_encoding()
+    }
+
+    /// Decodes a value written by [`Reducer::encode_value`].
+    fn decode_value(&self, _buf: &[u8]) -> PolarsResult<Self::Value> {
+        unsupported_encoding()
+    }
+}
+
+fn unsupported_encoding<T>() -> PolarsResult<T> {
+    polars_bail!(ComputeError: "encoding the state of this reduction is not supported")
+}
+
+#[inline(always)]
+fn encode_native<T: NativeType>(v: T, buf: &mut Vec<u8>) {
+    buf.extend_from_slice(v.to_le_bytes().as_ref());
+}
+
+/// Decodes a value written by [`encode_native`] from the start of buf,
+/// returning the remaining bytes.
+#[inline(always)]
+fn decode_native<T: NativeType>(buf: &[u8]) -> PolarsResult<(T, &[u8])> {
+    polars_ensure!(
+        buf.len() >= size_of::<T>(),
+        ComputeError: "invalid encoded grouped reduction state"
+    );
+    let (bytes, rest) = buf.split_at(size_of::<T>());
+    let Ok(bytes) = bytes.try_into() else {
+        unreachable!()
+    };
+    Ok((T::from_le_bytes(bytes), rest))
+}
+
+/// Encodes an optional byte string as a presence flag followed by the bytes.
+fn encode_opt_bytes(v: Option<&[u8]>, buf: &mut Vec<u8>) {
+    buf.push(v.is_some() as u8);
+    buf.extend_from_slice(v.unwrap_or_default());
+}
+
+/// Decodes an optional byte string written by [`encode_opt_bytes`].
+fn decode_opt_bytes(buf: &[u8]) -> PolarsResult<Option<Vec<u8>>> {
+    match buf.split_first() {
+        Some((0, [])) => Ok(None),
+        Some((1, bytes)) => Ok(Some(bytes.to_vec())),
+        _ => polars_bail!(ComputeError: "invalid encoded grouped reduction state"),
+    }
+}
+
+fn encode_values<R: Reducer>(
+    reducer: &R,
+    values: &[R::Value],
+    mask: Option<&MutableBitmap>,
+) -> Option<BinaryViewArray> {
+    if !reducer.can_encode() {
+        return None;
+    }
+    let mut out = MutableBinaryViewArray::<[u8]>::with_capacity(values.len());
+    let mut buf = Vec::new();
+    for (i, v) in values.iter().enumerate() {
+        if mask.is_some_and(|m| !m.get(i)) {
+            out.push_null();
+        } else {
+            buf.clear();
+            // Keep the state in memory if a value can't be encoded after all.
+            reducer.encode_value(v, &mut buf).ok()?;
+            out.push_value(&buf);
+        }
+    }
+    Some(out.freeze())
 }
 
 pub trait NumericReduction: Send + Sync + 'static {
@@ -222,6 +318,20 @@ impl<R: NumericReduction> Reducer for NumReducer<R> {
         let arr = Box::new(PrimitiveArray::<Self::Value>::from_vec(v).with_validity(m));
         Ok(unsafe { Series::from_chunks_and_dtype_unchecked(PlSmallStr::EMPTY, vec![arr], dtype) })
     }
+
+    #[inline(always)]
+    fn can_encode(&self) -> bool {
+        true
+    }
+
+    fn encode_value(&self, v: &Self::Value, buf: &mut Vec<u8>) -> PolarsResult<()> {
+        encode_native(*v, buf);
+        Ok(())
+    }
+
+    fn decode_value(&self, buf: &[u8]) -> PolarsResult<Self::Value> {
+        Ok(decode_native(buf)?.0)
+    }
 }
 
 pub struct VecGroupedReduction<R: Reducer> {
@@ -360,6 +470,26 @@ where
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn encode_state(&self) -> Option<BinaryViewArray> {
+        encode_values(&self.reducer, &self.values, None)
+    }
+
+    fn decode_state(&self, state: &BinaryViewArray) -> PolarsResult<Box<dyn GroupedReduction>> {
+        polars_ensure!(
+            self.reducer.can_encode() && !state.has_nulls(),
+            ComputeError: "invalid encoded grouped reduction state"
+        );
+        Ok(Box::new(Self {
+            values: state
+                .values_iter()
+                .map(|b| self.reducer.decode_value(b))
+                .collect::<PolarsResult<_>>()?,
+            evicted_values: Vec::new(),
+            in_dtype: self.in_dtype.clone(),
+            reducer: self.reducer.clone(),
+        }))
+    }
 }
 
 pub struct VecMaskGroupedReduction<R: Reducer> {
@@ -501,6 +631,28 @@ where
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn encode_state(&self) -> Option<BinaryViewArray> {
+        // Groups without any valid value are encoded as null.
+        encode_values(&self.reducer, &self.values, Some(&self.mask))
+    }
+
+    fn decode_state(&self, state: &BinaryViewArray) -> PolarsResult<Box<dyn GroupedReduction>> {
+        polars_ensure!(
+            self.reducer.can_encode(),
+            ComputeError: "invalid encoded grouped reduction state"
+        );
+        let mut out = Self::new(self.in_dtype.clone(), self.reducer.clone());
+        out.reserve(state.len());
+        for opt_b in state.iter() {
+            out.values.push(match opt_b {
+                Some(b) => self.reducer.decode_value(b)?,
+                None => self.reducer.init(),
+            });
+            out.mask.push(opt_b.is_some());
+        }
+        Ok(Box::new(out))
+    }
 }
 
 #[derive(Clone)]
@@ -586,4 +738,17 @@ impl GroupedReduction for NullGroupedReduction {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn encode_state(&self) -> Option<BinaryViewArray> {
+        Some(BinaryViewArray::new_null(
+            ArrowDataType::BinaryView,
+            self.num_groups as usize,
+        ))
+    }
+
+    fn decode_state(&self, state: &BinaryViewArray) -> PolarsResult<Box<dyn GroupedReduction>> {
+        let mut out = Self::new(self.output.clone());
+        out.num_groups = state.len() as IdxSize;
+        Ok(Box::new(out))
+    }
 }
@@ -105,6 +105,20 @@ where
             )
         })
     }
+
+    #[inline(always)]
+    fn can_encode(&self) -> bool {
+        true
+    }
+
+    fn encode_value(&self, v: &Self::Value, buf: &mut Vec<u8>) -> PolarsResult<()> {
+        encode_native(*v, buf);
+        Ok(())
+    }
+
+    fn decode_value(&self, buf: &[u8]) -> PolarsResult<Self::Value> {
+        Ok(decode_native(buf)?.0)
+    }
 }
 
 #[derive(Clone)]
@@ -143,4 +157,18 @@ impl Reducer for BoolSumReducer {
         assert!(dtype == &DataType::Boolean);
         Ok(IdxCa::from_vec(PlSmallStr::EMPTY, v).into_series())
     }
+
+    #[inline(always)]
+    fn can_encode(&self) -> bool {
+        true
+    }
+
+    fn encode_value(&self, v: &Self::Value, buf: &mut Vec<u8>) -> PolarsResult<()> {
+        encode_native(*v, buf);
+        Ok(())
+    }
+
+    fn decode_value(&self, buf: &[u8]) -> PolarsResult<Self::Value> {
+        Ok(decode_native(buf)?.0)
+    }
 }
@@ -199,4 +199,21 @@ impl Reducer for BoolVarStdReducer {
             .collect_ca(PlSmallStr::EMPTY);
         Ok(ca.into_series())
     }
+
+    #[inline(always)]
+    fn can_encode(&self) -> bool {
+        true
+    }
+
+    fn encode_value(&self, v: &Self::Value, buf: &mut Vec<u8>) -> PolarsResult<()> {
+        encode_native(v.0 as u64, buf);
+        encode_native(v.1 as u64, buf);
+        Ok(())
+    }
+
+    fn decode_value(&self, buf: &[u8]) -> PolarsResult<Self::Value> {
+        let (num_true, buf) = decode_native::<u64>(buf)?;
+        let (count, _) = decode_native::<u64>(buf)?;
+        Ok((num_true as usize, count as usize))
+    }
 }
@@ -0,0 +1,284 @@
+//! Block level access to Avro object container files, used by the streaming scan to read the
+//! blocks of a file in parallel.
+
+use std::io::{Cursor, Read};
+use std::ops::Range;
+
+use arrow::datatypes::ArrowSchema;
+use arrow::io::avro::avro_schema::file::Block;
+use arrow::io::avro::avro_schema::read::block_iterator;
+use arrow::io::avro::avro_schema::read::fallible_streaming_iterator::FallibleStreamingIterator;
+use arrow::io::avro::avro_schema::schema::{Record, Schema as AvroSchema};
+use arrow::io::avro::read;
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+
+use super::{AvroCodec, AvroCompression};
+use crate::utils::byte_source::{ByteSource, DynByteSource};
+
+/// Size of the first fetch when reading the file header.
+const INITIAL_HEADER_FETCH_SIZE: usize = 64 * 1024;
+
+/// The maximum size of a block header, which consists of two zigzag encoded longs.
+pub const MAX_BLOCK_HEADER_SIZE: usize = 20;
+
+const SYNC_MARKER_SIZE: usize = 16;
+
+/// The file metadata and inferred schema of an Avro file.
+#[derive(Debug)]
+pub struct AvroFileMetadata {
+    pub record: Record,
+    pub codec: Option<AvroCodec>,
+    pub marker: [u8; 16],
+    pub schema: ArrowSchema,
+    /// The offset at which the first block starts.
+    pub data_offset: usize,
+}
+
+impl AvroFileMetadata {
+    /// Parse the header at the start of `bytes`.
+    pub fn try_from_bytes(bytes: &[u8]) -> PolarsResult<Self> {
+        polars_ensure!(
+            bytes.starts_with(b"Obj\x01"),
+            ComputeError: "not an avro file: missing magic bytes"
+        );
+        let mut pos = 4;
+
+        // The header metadata is a map of string keys to bytes values.
+        let mut record = None;
+        let mut codec = None;
+        loop {
+            let mut n_entries = read_long(bytes, &mut pos)?;
+            if n_entries == 0 {
+                break;
+            }
+            if n_entries < 0 {
+                // A negative count is followed by the byte size of the entries.
+                n_entries = -n_entries;
+                read_long(bytes, &mut pos)?;
+            }
+            for _ in 0..n_entries {
+                let key = read_bytes(bytes, &mut pos)?;
+                let value = read_bytes(bytes, &mut pos)?;
+                match key {
+                    b"avro.schema" => {
+                        let schema: AvroSchema =
+                            serde_json::from_slice(value).map_err(to_compute_err)?;
+                        let AvroSchema::Record(r) = schema else {
+                            polars_bail!(ComputeError: "avro schema must be a record");
+                        };
+                        record = Some(r);
+                    },
+                    b"avro.codec" => codec = AvroCodec::from_name(value)?,
+                    _ => {},
+                }
+            }
+        }
+
+        let Some(record) = record else {
+            polars_bail!(ComputeError: "corrupt avro file: missing schema");
+        };
+        let marker: [u8; 16] = bytes
+            .get(pos..pos + 16)
+            .and_then(|x| x.try_into().ok())
+            .ok_or_else(|| polars_err!(ComputeError: "corrupt avro file: missing sync marker"))?;
+        let schema = read::infer_schema(&record)?;
+
+        Ok(Self {
+            record,
+            codec,
+            marker,
+            schema,
+            data_offset: pos + 16,
+        })
+    }
+
+    /// Read the header from `reader`, only reading as much of it as needed.
+    pub fn try_from_reader<R: Read>(reader: &mut R) -> PolarsResult<Self> {
+        let mut bytes = Vec::new();
+        loop {
+            let read_size = INITIAL_HEADER_FETCH_SIZE.max(bytes.len()) as u64;
+            let n = reader.by_ref().take(read_size).read_to_end(&mut bytes)?;
+            match Self::try_from_bytes(&bytes) {
+                Ok(v) => return Ok(v),
+                // The header may not fit in what we read so far.
+                Err(_) if n > 0 => {},
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Read the header of the file, only fetching as much of the file as needed.
+    pub async fn try_from_byte_source(byte_source: &DynByteSource) -> PolarsResult<Self> {
+        let file_size = byte_source.get_size().await?;
+        let mut fetch_size = INITIAL_HEADER_FETCH_SIZE.min(file_size);
+
+        loop {
+            let bytes = byte_source.get_range(0..fetch_size).await?;
+            match Self::try_from_bytes(&bytes) {
+                Ok(v) => return Ok(v),
+                // The header may not fit in what we fetched so far.
+                Err(_) if fetch_size < file_size => {
+                    fetch_size = fetch_size.saturating_mul(2).min(file_size)
+                },
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    pub fn pl_schema(&self) -> Schema {
+        Schema::from_arrow_schema(&self.schema)
+    }
+
+    /// Decode a single block, `bytes` must span the block as located by [`read_block_index`].
+    pub fn decode_block(&self, bytes: &[u8], projection: &[bool]) -> PolarsResult<DataFrame> {
+        // Zstandard is not supported by `avro_schema`, we decompress those blocks ourselves.
+        let compression = match self.codec {
+            None | Some(AvroCodec::Zstd) => None,
+            Some(AvroCodec::Deflate) => Some(AvroCompression::Deflate),
+            Some(AvroCodec::Snappy) => Some(AvroCompression::Snappy),
+        };
+        let mut blocks = block_iterator(Cursor::new(bytes), compression, self.marker);
+        let Some(block) = blocks.next()? else {
+            polars_bail!(ComputeError: "corrupt avro file: missing block");
+        };
+
+        let batch = if self.codec == Some(AvroCodec::Zstd) {
+            let block = Block::new(
+                block.number_of_rows,
+                zstd::decode_all(block.data.as_slice())?,
+            );
+            read::deserialize(&block, &self.schema, &self.record.fields, projection)?
+        } else {
+            read::deserialize(block, &self.schema, &self.record.fields, projection)?
+        };
+        Ok(DataFrame::from(batch))
+    }
+}
+
+/// The location of a block within an Avro file.
+#[derive(Debug, Clone)]
+pub struct AvroBlock {
+    /// Byte range spanning the block header, the (compressed) data and the sync marker.
+    pub byte_range: Range<usize>,
+    pub n_rows: usize,
+}
+
+fn read_long(bytes: &[u8], pos: &mut usize) -> PolarsResult<i64> {
+    let mut value = 0u64;
+    let mut shift = 0;
+    loop {
+        let Some(&byte) = bytes.get(*pos) else {
+            polars_bail!(ComputeError: "corrupt avro file: unexpected end of file");
+        };
+        *pos += 1;
+        value |= u64::from(byte & 0x7F) << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+        polars_ensure!(shift < 64, ComputeError: "corrupt avro file: zigzag decoding failed");
+    }
+    Ok(((value >> 1) as i64) ^ -((value & 1) as i64))
+}
+
+fn read_bytes<'a>(bytes: &'a [u8], pos: &mut usize) -> PolarsResult<&'a [u8]> {
+    let len = read_long(bytes, pos)?;
+    let out = usize::try_from(len)
+        .ok()
+        .and_then(|len| bytes.get(*pos..pos.checked_add(len)?))
+        .ok_or_else(|| polars_err!(ComputeError: "corrupt avro file: unexpected end of file"))?;
+    *pos += out.len();
+    Ok(out)
+}
+
+/// Read the header of the block that starts at `bytes[0]`, which lies at `offset` in the file.
+///
+/// Only the header needs to be present in `bytes`, the caller must check that the returned byte
+/// range lies within the file.
+pub fn read_block_header(bytes: &[u8], offset: usize) -> PolarsResult<AvroBlock> {
+    let mut pos = 0;
+    let n_rows = read_long(bytes, &mut pos)?;
+    let size = read_long(bytes, &mut pos)?;
+    polars_ensure!(
+        n_rows >= 0 && size >= 0,
+        ComputeError: "corrupt avro file: negative block size"
+    );
+    let end = offset
+        .checked_add(pos)
+        .and_then(|end| end.checked_add(size as usize))
+        .and_then(|end| end.checked_add(SYNC_MARKER_SIZE))
+        .ok_or_else(|| polars_err!(ComputeError: "corrupt avro file: block out of bounds"))?;
+    Ok(AvroBlock {
+        byte_range: offset..end,
+        n_rows: n_rows as usize,
+    })
+}
+
+/// Locate all blocks in the file by only reading the block headers, which contain the number of
+/// rows and the size of every block.
+pub fn read_block_index(bytes: &[u8], data_offset: usize) -> PolarsResult<Vec<AvroBlock>> {
+    let mut blocks = Vec::new();
+    let mut pos = data_offset;
+    while pos < bytes.len() {
+        let block = read_block_header(&bytes[pos..], pos)?;
+        polars_ensure!(
+            block.byte_range.end <= bytes.len(),
+            ComputeError: "corrupt avro file: block out of bounds"
+        );
+        pos = block.byte_range.end;
+        blocks.push(block);
+    }
+    Ok(blocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SerWriter;
+    use crate::avro::AvroWriter;
+
+    #[test]
+    fn test_block_index_roundtrip() -> PolarsResult<()> {
+        let mut df = df![
+            "a" => (0..1000).collect::<Vec<i64>>(),
+            "b" => (0..1000).map(|i| format!("{i}")).collect::<Vec<_>>(),
+        ]?;
+
+        let mut buf = Vec::new();
+        AvroWriter::new(&mut buf).finish(&mut df)?;
+
+        let metadata = AvroFileMetadata::try_from_bytes(&buf)?;
+        let blocks = read_block_index(&buf, metadata.data_offset)?;
+        assert_eq!(blocks.iter().map(|b| b.n_rows).sum::<usize>(), 1000);
+        assert_eq!(blocks.last().unwrap().byte_range.end, buf.len());
+
+        let block = &blocks[0];
+        let out = metadata.decode_block(&buf[block.byte_range.clone()], &[false, true])?;
+        assert!(out.equals(&df.select(["b"])?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_block_zstd() -> PolarsResult<()> {
+        let mut df = df![
+            "a" => (0..100).collect::<Vec<i64>>(),
+            "b" => (0..100).map(|i| format!("{i}")).collect::<Vec<_>>(),
+        ]?;
+
+        let mut buf = Vec::new();
+        AvroWriter::new(&mut buf)
+            .with_codec(Some(AvroCodec::Zstd))
+            .with_block_size(Some(30))
+            .finish(&mut df)?;
+
+        let metadata = AvroFileMetadata::try_from_bytes(&buf)?;
+        assert_eq!(metadata.codec, Some(AvroCodec::Zstd));
+        let blocks = read_block_index(&buf, metadata.data_offset)?;
+        assert_eq!(blocks.len(), 4);
+
+        let out = metadata.decode_block(&buf[blocks[3].byte_range.clone()], &[true, true])?;
+        assert!(out.equals(&df.slice(90, 10)));
+        Ok(())
+    }
+}
@@ -1,12 +1,13 @@
 use std::io::{Read, Seek};
 
-use arrow::io::avro::{self, read};
+use arrow::io::avro::read;
 use arrow::record_batch::RecordBatch;
-use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 
+use super::{AvroFileMetadata, read_block_index};
 use crate::prelude::*;
-use crate::shared::{ArrowReader, finish_reader};
+use crate::shared::ArrowReader;
 
 /// Read [Apache Avro] format into a [`DataFrame`]
 ///
@@ -44,10 +45,8 @@ impl<R: Read + Seek> AvroReader<R> {
 
     /// Get arrow schema of the avro File, this is faster than a polars schema.
     pub fn arrow_schema(&mut self) -> PolarsResult<ArrowSchema> {
-        let metadata =
-            avro::avro_schema::read::read_metadata(&mut self.reader).map_err(to_compute_err)?;
-        let schema = read::infer_schema(&metadata.record)?;
-        Ok(schema)
+        let metadata = AvroFileMetadata::try_from_reader(&mut self.reader)?;
+        Ok(metadata.schema)
     }
 
     /// Stop reading when `n` rows are read.
@@ -99,34 +98,52 @@ where
     }
 
     fn finish(mut self) -> PolarsResult<DataFrame> {
-        let rechunk = self.rechunk;
-        let metadata =
-            avro::avro_schema::read::read_metadata(&mut self.reader).map_err(to_compute_err)?;
-        let schema = read::infer_schema(&metadata.record)?;
+        // We decode the blocks ourselves, as the arrow reader doesn't support all codecs.
+        let mut bytes = Vec::new();
+        self.reader.read_to_end(&mut bytes)?;
+        let metadata = AvroFileMetadata::try_from_bytes(&bytes)?;
+        let schema = &metadata.schema;
 
         if let Some(columns) = &self.columns {
-            self.projection = Some(columns_to_projection(columns, &schema)?);
+            self.projection = Some(columns_to_projection(columns, schema)?);
         }
 
-        let (projection, projected_schema) = if let Some(projection) = self.projection {
+        let (projection, projected_schema) = if let Some(projection) = &self.projection {
             let mut prj = vec![false; schema.len()];
             for &index in projection.iter() {
                 prj[index] = true;
             }
-            (Some(prj), apply_projection(&schema, &projection))
+            (prj, apply_projection(schema, projection))
         } else {
-            (None, schema.clone())
+            (vec![true; schema.len()], schema.clone())
         };
+        let projected_schema = Schema::from_arrow_schema(&projected_schema);
+
+        let n_rows = self.n_rows.unwrap_or(usize::MAX);
+        let mut n_rows_read = 0;
+        let mut dfs = Vec::new();
+        for block in read_block_index(&bytes, metadata.data_offset)? {
+            if n_rows_read >= n_rows {
+                break;
+            }
+            let df = metadata.decode_block(&bytes[block.byte_range], &projection)?;
+            n_rows_read += df.height();
+            dfs.push(df);
+        }
 
-        let avro_reader = avro::read::Reader::new(&mut self.reader, metadata, schema, projection);
-
-        finish_reader(
-            avro_reader,
-            rechunk,
-            self.n_rows,
-            None,
-            &projected_schema,
-            None,
-        )
+        let mut df = if dfs.is_empty() {
+            DataFrame::empty_with_schema(&projected_schema)
+        } else {
+            accumulate_dataframes_vertical_unchecked(dfs)
+        };
+        if n_rows_read > n_rows {
+            df = df.slice(0, n_rows);
+        }
+        // The blocks are decoded in the column order of the file.
+        let mut df = df.select(projected_schema.iter_names_cloned())?;
+        if self.rechunk {
+            df.rechunk_mut_par();
+        }
+        Ok(df)
     }
 }
@@ -1,14 +1,188 @@
 use std::io::Write;
 
 pub use Compression as AvroCompression;
+use arrow::array::Array;
 pub use arrow::io::avro::avro_schema::file::Compression;
+use arrow::io::avro::avro_schema::schema::{Record, Schema as AvroSchema};
 use arrow::io::avro::avro_schema::{self};
 use arrow::io::avro::write;
+use arrow::record_batch::RecordBatchT;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+use polars_utils::pl_str::PlSmallStr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::shared::{SerWriter, schema_to_arrow_checked};
 
+/// Compression codec of the blocks in an Avro object container file.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum AvroCodec {
+    Deflate,
+    Snappy,
+    Zstd,
+}
+
+impl AvroCodec {
+    /// The name of the codec in the `avro.codec` metadata of the file header.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Deflate => "deflate",
+            Self::Snappy => "snappy",
+            Self::Zstd => "zstandard",
+        }
+    }
+
+    pub(super) fn from_name(name: &[u8]) -> PolarsResult<Option<Self>> {
+        Ok(match name {
+            b"null" => None,
+            b"deflate" => Some(Self::Deflate),
+            b"snappy" => Some(Self::Snappy),
+            b"zstandard" => Some(Self::Zstd),
+            _ => polars_bail!(
+                ComputeError: "unsupported avro codec: {}", String::from_utf8_lossy(name)
+            ),
+        })
+    }
+}
+
+impl From<AvroCompression> for AvroCodec {
+    fn from(value: AvroCompression) -> Self {
+        match value {
+            AvroCompression::Deflate => Self::Deflate,
+            AvroCompression::Snappy => Self::Snappy,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct AvroWriterOptions {
+    /// Block compression
+    pub codec: Option<AvroCodec>,
+    /// Name of the record schema
+    pub name: PlSmallStr,
+    /// Namespace of the record schema
+    pub namespace: Option<PlSmallStr>,
+    /// Maximum number of rows per block. Every block is followed by a sync marker.
+    pub block_size: Option<usize>,
+}
+
+/// Encodes an Avro object container file. The header is written once, followed by any number of
+/// blocks.
+pub struct AvroBlockEncoder {
+    record: Record,
+    codec: Option<AvroCodec>,
+    /// The sync marker written after every block, randomly generated for every file.
+    sync_marker: [u8; 16],
+}
+
+impl AvroBlockEncoder {
+    pub fn try_new(schema: &Schema, options: &AvroWriterOptions) -> PolarsResult<Self> {
+        let schema = schema_to_arrow_checked(schema, CompatLevel::oldest(), "avro")?;
+        let mut record = write::to_record(&schema, options.name.to_string())?;
+        record.namespace = options.namespace.as_ref().map(|x| x.to_string());
+
+        Ok(Self {
+            record,
+            codec: options.codec,
+            sync_marker: new_sync_marker(),
+        })
+    }
+
+    /// A copy of this encoder with a new sync marker, to encode another file.
+    pub fn with_new_sync_marker(&self) -> Self {
+        Self {
+            record: self.record.clone(),
+            codec: self.codec,
+            sync_marker: new_sync_marker(),
+        }
+    }
+
+    /// Write the file header: the magic bytes, the schema, the codec and the sync marker.
+    pub fn write_header<W: Write>(&self, writer: &mut W) -> PolarsResult<()> {
+        let schema =
+            serde_json::to_vec(&AvroSchema::Record(self.record.clone())).map_err(to_compute_err)?;
+
+        let mut out = b"Obj\x01".to_vec();
+        let n_entries = if self.codec.is_some() { 2 } else { 1 };
+        encode_long(n_entries, &mut out);
+        encode_bytes(b"avro.schema", &mut out);
+        encode_bytes(&schema, &mut out);
+        if let Some(codec) = self.codec {
+            encode_bytes(b"avro.codec", &mut out);
+            encode_bytes(codec.name().as_bytes(), &mut out);
+        }
+        encode_long(0, &mut out);
+        out.extend_from_slice(&self.sync_marker);
+
+        writer.write_all(&out)?;
+        Ok(())
+    }
+
+    /// Encode a single chunk as a block, including the block header and the trailing sync marker.
+    pub fn encode_block(
+        &self,
+        chunk: &RecordBatchT<Box<dyn Array>>,
+        out: &mut Vec<u8>,
+    ) -> PolarsResult<()> {
+        let n_rows = chunk.height();
+        if n_rows == 0 {
+            return Ok(());
+        }
+
+        let mut serializers = chunk
+            .iter()
+            .zip(self.record.fields.iter())
+            .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
+            .collect::<Vec<_>>();
+        let mut block = avro_schema::file::Block::new(n_rows, vec![]);
+        write::serialize(&mut serializers, &mut block);
+
+        let data = match self.codec {
+            None => block.data,
+            Some(AvroCodec::Zstd) => zstd::bulk::compress(&block.data, 0)?,
+            Some(codec @ (AvroCodec::Deflate | AvroCodec::Snappy)) => {
+                let compression = match codec {
+                    AvroCodec::Deflate => AvroCompression::Deflate,
+                    _ => AvroCompression::Snappy,
+                };
+                let mut compressed = avro_schema::file::CompressedBlock::default();
+                avro_schema::write::compress(&mut block, &mut compressed, Some(compression))
+                    .map_err(to_compute_err)?;
+                compressed.data
+            },
+        };
+
+        encode_long(n_rows as i64, out);
+        encode_long(data.len() as i64, out);
+        out.extend_from_slice(&data);
+        out.extend_from_slice(&self.sync_marker);
+        Ok(())
+    }
+}
+
+fn new_sync_marker() -> [u8; 16] {
+    fastrand::u128(..).to_le_bytes()
+}
+
+fn encode_long(value: i64, out: &mut Vec<u8>) {
+    let mut value = ((value << 1) ^ (value >> 63)) as u64;
+    while value & !0x7F != 0 {
+        out.push((value & 0x7F) as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn encode_bytes(bytes: &[u8], out: &mut Vec<u8>) {
+    encode_long(bytes.len() as i64, out);
+    out.extend_from_slice(bytes);
+}
+
 /// Write a [`DataFrame`] to [Apache Avro] format
 ///
 /// [Apache Avro]: https://avro.apache.org
@@ -31,8 +205,7 @@ use crate::shared::{SerWriter, schema_to_arrow_checked};
 #[must_use]
 pub struct AvroWriter<W> {
     writer: W,
-    compression: Option<AvroCompression>,
-    name: String,
+    options: AvroWriterOptions,
 }
 
 impl<W> AvroWriter<W>
@@ -41,12 +214,29 @@ where
 {
     /// Set the compression used. Defaults to None.
     pub fn with_compression(mut self, compression: Option<AvroCompression>) -> Self {
-        self.compression = compression;
+        self.options.codec = compression.map(AvroCodec::from);
+        self
+    }
+
+    /// Set the block compression codec. Defaults to None.
+    pub fn with_codec(mut self, codec: Option<AvroCodec>) -> Self {
+        self.options.codec = codec;
         self
     }
 
     pub fn with_name(mut self, name: String) -> Self {
-        self.name = name;
+        self.options.name = name.into();
+        self
+    }
+
+    pub fn with_namespace(mut self, namespace: Option<String>) -> Self {
+        self.options.namespace = namespace.map(Into::into);
+        self
+    }
+
+    /// Set the maximum number of rows per block. Defaults to one block per chunk.
+    pub fn with_block_size(mut self, block_size: Option<usize>) -> Self {
+        self.options.block_size = block_size;
         self
     }
 }
@@ -58,43 +248,33 @@ where
     fn new(writer: W) -> Self {
         Self {
             writer,
-            compression: None,
-            name: "".to_string(),
+            options: AvroWriterOptions::default(),
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        let schema = schema_to_arrow_checked(df.schema(), CompatLevel::oldest(), "avro")?;
-        let record = write::to_record(&schema, self.name.clone())?;
-
-        let mut data = vec![];
-        let mut compressed_block = avro_schema::file::CompressedBlock::default();
-        for chunk in df.iter_chunks(CompatLevel::oldest(), true) {
-            let mut serializers = chunk
-                .iter()
-                .zip(record.fields.iter())
-                .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
-                .collect::<Vec<_>>();
-
-            let mut block =
-                avro_schema::file::Block::new(chunk.arrays()[0].len(), std::mem::take(&mut data));
-            write::serialize(&mut serializers, &mut block);
-            let _was_compressed =
-                avro_schema::write::compress(&mut block, &mut compressed_block, self.compression)
-                    .map_err(to_compute_err)?;
-
-            avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
-                .map_err(to_compute_err)?;
+        let encoder = AvroBlockEncoder::try_new(df.schema(), &self.options)?;
+        encoder.write_header(&mut self.writer)?;
 
-            avro_schema::write::write_block(&mut self.writer, &compressed_block)
-                .map_err(to_compute_err)?;
-            // reuse block for next iteration.
-            data = block.data;
-            data.clear();
+        let mut buf = vec![];
+        let mut write_chunks = |df: &DataFrame| {
+            for chunk in df.iter_chunks(CompatLevel::oldest(), true) {
+                buf.clear();
+                encoder.encode_block(&chunk, &mut buf)?;
+                self.writer.write_all(&buf)?;
+            }
+            PolarsResult::Ok(())
+        };
 
-            // reuse block for next iteration
-            compressed_block.data.clear();
-            compressed_block.number_of_rows = 0
+        match self.options.block_size {
+            Some(block_size) if block_size > 0 => {
+                let mut offset = 0;
+                while offset < df.height() {
+                    write_chunks(&df.slice(offset as i64, block_size))?;
+                    offset += block_size;
+                }
+            },
+            _ => write_chunks(df)?,
         }
 
         Ok(())
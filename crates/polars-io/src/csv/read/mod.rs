@@ -35,6 +35,6 @@ pub mod _csv_read_internal {
     pub use super::builder::validate_utf8;
     pub use super::options::{CommentPrefix, NullValuesCompiled};
     pub use super::parser::{CountLines, SplitLines, is_comment_line};
-    pub use super::read_impl::{cast_columns, read_chunk};
+    pub use super::read_impl::{cast_columns, read_chunk, read_chunk_with_rejects};
     pub use super::reader::prepare_csv_schema;
 }
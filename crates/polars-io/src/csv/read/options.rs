@@ -10,6 +10,7 @@ use polars_utils::pl_str::PlSmallStr;
 use serde::{Deserialize, Serialize};
 
 use crate::RowIndex;
+use crate::utils::transcode::{SourceEncoding, Utf8Transcoder};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -42,6 +43,8 @@ pub struct CsvReadOptions {
     pub raise_if_empty: bool,
     pub ignore_errors: bool,
     pub fields_to_cast: Vec<Field>,
+    /// Columns that hold JSON text, decoded into their nested dtype after parsing.
+    pub json_columns: Option<Arc<[PlSmallStr]>>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -88,6 +91,7 @@ impl Default for CsvReadOptions {
             raise_if_empty: true,
             ignore_errors: false,
             fields_to_cast: vec![],
+            json_columns: None,
         }
     }
 }
@@ -185,6 +189,14 @@ impl CsvReadOptions {
         self
     }
 
+    /// Columns that hold JSON text, as written with
+    /// [`SerializeOptions::nested_as_json`](crate::csv::write::SerializeOptions::nested_as_json).
+    /// Their dtype in the schema must be nested; they are read as strings and decoded into it.
+    pub fn with_json_columns(mut self, json_columns: Option<Arc<[PlSmallStr]>>) -> Self {
+        self.json_columns = json_columns;
+        self
+    }
+
     /// Sets the CSV parsing options. See [map_parse_options][Self::map_parse_options]
     /// for an easier way to mutate them in-place.
     pub fn with_parse_options(mut self, parse_options: CsvParseOptions) -> Self {
@@ -330,6 +342,12 @@ impl CsvParseOptions {
     }
 }
 
+/// Encoding of the CSV input.
+///
+/// The encodings other than UTF-8 are transcoded to UTF-8 chunk-wise by the streaming CSV source.
+/// The eager [`CsvReader`](super::CsvReader) transcodes its whole input at once before parsing,
+/// so it holds a transcoded copy next to the input, which is up to twice the input size for
+/// `Latin1` and `Windows1252`.
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
@@ -339,6 +357,38 @@ pub enum CsvEncoding {
     Utf8,
     /// Utf8 encoding and unknown bytes are replaced with �.
     LossyUtf8,
+    /// ISO-8859-1 encoding.
+    Latin1,
+    /// Windows-1252 encoding.
+    Windows1252,
+    /// UTF-16 encoding. The byte order is detected from the byte order mark, and is little endian
+    /// if there is none.
+    Utf16,
+    /// UTF-16 little endian encoding.
+    Utf16Le,
+    /// UTF-16 big endian encoding.
+    Utf16Be,
+}
+
+impl CsvEncoding {
+    /// Returns the transcoder that converts this encoding to UTF-8, if it isn't UTF-8 already.
+    pub fn transcoder(&self) -> Option<Utf8Transcoder> {
+        let encoding = match self {
+            Self::Utf8 | Self::LossyUtf8 => return None,
+            Self::Latin1 => SourceEncoding::Latin1,
+            Self::Windows1252 => SourceEncoding::Windows1252,
+            Self::Utf16 => SourceEncoding::Utf16,
+            Self::Utf16Le => SourceEncoding::Utf16Le,
+            Self::Utf16Be => SourceEncoding::Utf16Be,
+        };
+        Some(Utf8Transcoder::new(encoding))
+    }
+
+    /// Whether ASCII characters are encoded as the same single bytes as in UTF-8. If so, lines and
+    /// fields can be found in the raw bytes without transcoding them.
+    pub fn is_ascii_compatible(&self) -> bool {
+        !matches!(self, Self::Utf16 | Self::Utf16Le | Self::Utf16Be)
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
@@ -91,7 +91,7 @@ pub fn count_rows_from_reader_par(
         ..Default::default()
     };
 
-    let (_, mut leftover) = read_until_start_and_infer_schema(
+    let (_, mut leftover, _) = read_until_start_and_infer_schema(
         &reader_options,
         None,
         decompressed_size_hint,
@@ -705,6 +705,10 @@ impl CountLines {
         }
     }
 
+    pub fn eol_char(&self) -> u8 {
+        self.eol_char
+    }
+
     /// Analyzes a chunk of CSV data.
     ///
     /// Returns (newline_count, last_newline_offset, end_inside_string) twice,
@@ -11,10 +11,11 @@ use polars_utils::relaxed_cell::RelaxedCell;
 use rayon::prelude::*;
 
 use super::CsvParseOptions;
-use super::builder::init_builders;
+use super::builder::{init_builders, validate_utf8};
 use super::options::{CsvEncoding, NullValuesCompiled};
-use super::parser::{CountLines, is_comment_line, parse_lines};
+use super::parser::{CountLines, SplitLines, is_comment_line, parse_lines};
 use super::reader::prepare_csv_schema;
+use super::splitfields::SplitFields;
 #[cfg(feature = "decompress")]
 use super::utils::decompress;
 use crate::RowIndex;
@@ -22,11 +23,13 @@ use crate::csv::read::{CsvReadOptions, read_until_start_and_infer_schema_from_co
 use crate::mmap::ReaderBytes;
 use crate::predicates::PhysicalIoExpr;
 use crate::utils::compression::{CompressedReader, SupportedCompression};
+use crate::utils::rejects::{RejectedRow, parse_with_rejects};
 use crate::utils::update_row_counts2;
 
 pub fn cast_columns(
     df: &mut DataFrame,
     to_cast: &[Field],
+    json_columns: &[PlSmallStr],
     parallel: bool,
     ignore_errors: bool,
 ) -> PolarsResult<()> {
@@ -57,6 +60,10 @@ pub fn cast_columns(
                     &StringChunked::from_iter(std::iter::once("raise")),
                 )
                 .map(|ca| ca.into_column()),
+            #[cfg(feature = "json")]
+            (DataType::String, dt) if dt.is_nested() && json_columns.contains(c.name()) => {
+                decode_json(c.str().unwrap(), dt)
+            },
             (_, dt) => c.cast(dt),
         }?;
         if !ignore_errors && c.null_count() != out.null_count() {
@@ -91,6 +98,43 @@ pub fn cast_columns(
     Ok(())
 }
 
+/// Decodes JSON text into the nested `dtype`, nulls stay null.
+#[cfg(feature = "json")]
+fn decode_json(ca: &StringChunked, dtype: &DataType) -> PolarsResult<Column> {
+    // The dtype the JSON deserializer supports, the result is cast to `dtype` afterwards.
+    fn decode_dtype(dtype: &DataType) -> DataType {
+        match dtype {
+            dt if dt.is_list() || dt.is_array() => {
+                DataType::List(Box::new(decode_dtype(dt.inner_dtype().unwrap())))
+            },
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(fields) => DataType::Struct(
+                fields
+                    .iter()
+                    .map(|fld| Field::new(fld.name().clone(), decode_dtype(fld.dtype())))
+                    .collect(),
+            ),
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Enum(..) | DataType::Categorical(..) => DataType::String,
+            dt => dt.clone(),
+        }
+    }
+
+    let buf_size = ca.get_values_size() + ca.null_count() * "null".len();
+    let array = polars_json::ndjson::deserialize::deserialize_iter(
+        ca.iter().map(|opt_v| opt_v.unwrap_or("null")),
+        decode_dtype(dtype).to_arrow(CompatLevel::newest()),
+        buf_size,
+        ca.len(),
+        true,
+    )
+    .map_err(
+        |e| polars_err!(ComputeError: "error deserializing JSON in column '{}': {}", ca.name(), e),
+    )?;
+    let s = Series::try_from((ca.name().clone(), array))?;
+    Ok(s.cast(dtype)?.into_column())
+}
+
 struct ReaderBytesAndDependents<'a> {
     // Ensure lifetime dependents are dropped before `reader_bytes`, since their drop impls
     // could access themselves, this is achieved by placing them before `reader_bytes`.
@@ -118,6 +162,7 @@ pub(crate) struct CoreReader<'a> {
     null_values: Option<NullValuesCompiled>,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     to_cast: Vec<Field>,
+    json_columns: Arc<[PlSmallStr]>,
     row_index: Option<RowIndex>,
 }
 
@@ -150,13 +195,13 @@ impl<'a> CoreReader<'a> {
         dtype_overwrite: Option<Arc<Vec<DataType>>>,
         predicate: Option<Arc<dyn PhysicalIoExpr>>,
         mut to_cast: Vec<Field>,
+        json_columns: Option<Arc<[PlSmallStr]>>,
         skip_rows_after_header: usize,
         row_index: Option<RowIndex>,
         raise_if_empty: bool,
     ) -> PolarsResult<CoreReader<'a>> {
         let separator = parse_options.separator;
 
-        #[cfg(feature = "decompress")]
         let mut reader_bytes = reader_bytes;
 
         if !cfg!(feature = "decompress") && SupportedCompression::check(&reader_bytes).is_some() {
@@ -170,8 +215,10 @@ impl<'a> CoreReader<'a> {
         // again after decompression.
         #[cfg(feature = "decompress")]
         {
-            let total_n_rows =
-                n_rows.map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
+            // Lines can only be counted in the raw bytes if ASCII characters are single bytes.
+            let total_n_rows = n_rows
+                .filter(|_| parse_options.encoding.is_ascii_compatible())
+                .map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
             if let Some(b) = decompress(
                 &reader_bytes,
                 total_n_rows,
@@ -183,6 +230,16 @@ impl<'a> CoreReader<'a> {
             }
         }
 
+        // Transcode the whole input at once, the schema inference and the chunk splitting below
+        // work on UTF-8. Note that this keeps a transcoded copy next to the input, see
+        // `CsvEncoding`.
+        if let Some(mut transcoder) = parse_options.encoding.transcoder() {
+            let mut transcoded = Vec::with_capacity(reader_bytes.len());
+            transcoder.transcode(&reader_bytes, &mut transcoded);
+            transcoder.finish(&mut transcoded);
+            reader_bytes = ReaderBytes::Owned(transcoded.into());
+        }
+
         let reader_slice = match &reader_bytes {
             ReaderBytes::Borrowed(slice) => {
                 // SAFETY: The produced slice and derived slices MUST not live longer than
@@ -208,6 +265,7 @@ impl<'a> CoreReader<'a> {
             schema_overwrite,
             dtype_overwrite: dtype_overwrite.clone(),
             fields_to_cast: to_cast.clone(),
+            json_columns: json_columns.clone(),
             skip_rows_after_header,
             row_index: row_index.clone(),
             raise_if_empty,
@@ -238,7 +296,7 @@ impl<'a> CoreReader<'a> {
             }
         }
 
-        prepare_csv_schema(&mut schema, &mut to_cast)?;
+        prepare_csv_schema(&mut schema, &mut to_cast, json_columns.as_deref())?;
 
         // Create a null value for every column
         let null_values = parse_options
@@ -272,6 +330,7 @@ impl<'a> CoreReader<'a> {
             null_values,
             predicate,
             to_cast,
+            json_columns: json_columns.unwrap_or_default(),
             row_index,
         })
     }
@@ -314,7 +373,13 @@ impl<'a> CoreReader<'a> {
             starting_point_offset,
         )?;
 
-        cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
+        cast_columns(
+            &mut df,
+            &self.to_cast,
+            &self.json_columns,
+            false,
+            self.ignore_errors,
+        )?;
         Ok(df)
     }
 
@@ -342,7 +407,13 @@ impl<'a> CoreReader<'a> {
                 )
             };
 
-            cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
+            cast_columns(
+                &mut df,
+                &self.to_cast,
+                &self.json_columns,
+                false,
+                self.ignore_errors,
+            )?;
 
             if let Some(ref row_index) = self.row_index {
                 df.insert_column(0, Column::new_empty(row_index.name.clone(), &IDX_DTYPE))?;
@@ -604,3 +675,80 @@ pub fn read_chunk(
         .collect::<PolarsResult<Vec<_>>>()?;
     Ok(unsafe { DataFrame::new_unchecked_infer_height(columns) })
 }
+
+/// Parses `bytes` like [`read_chunk`], but rows that don't have a field for every column of
+/// `schema`, or whose projected fields fail to parse or cast, are returned separately instead of
+/// failing the chunk.
+#[allow(clippy::too_many_arguments)]
+pub fn read_chunk_with_rejects(
+    bytes: &[u8],
+    parse_options: &CsvParseOptions,
+    schema: &Schema,
+    projection: &[usize],
+    null_values: Option<&NullValuesCompiled>,
+    fields_to_cast: &[Field],
+    json_columns: &[PlSmallStr],
+) -> PolarsResult<(DataFrame, Vec<RejectedRow>)> {
+    let parse = |bytes: &[u8], n_rows: usize| {
+        // If projection is empty create a DataFrame with the correct height.
+        if projection.is_empty() {
+            return Ok(DataFrame::empty_with_height(n_rows));
+        }
+        let mut df = read_chunk(
+            bytes,
+            parse_options,
+            schema,
+            false,
+            projection,
+            0,
+            n_rows,
+            null_values,
+            usize::MAX,
+            bytes.len(),
+            Some(0),
+        )?;
+        cast_columns(&mut df, fields_to_cast, json_columns, false, false)?;
+        PolarsResult::Ok(df)
+    };
+
+    let rows = SplitLines::new(
+        bytes,
+        parse_options.quote_char,
+        parse_options.eol_char,
+        parse_options.comment_prefix.as_ref(),
+    )
+    .filter(|line| !is_comment_line(line, parse_options.comment_prefix.as_ref()))
+    .map(|line| {
+        let start = line.as_ptr() as usize - bytes.as_ptr() as usize;
+        start..start + line.len()
+    })
+    .collect::<Vec<_>>();
+
+    let check_utf8 = matches!(parse_options.encoding, CsvEncoding::Utf8)
+        && schema.iter_fields().any(|f| f.dtype().is_string());
+    let check_row = |line: &[u8]| {
+        polars_ensure!(
+            !check_utf8 || validate_utf8(line),
+            ComputeError: "invalid utf-8 sequence"
+        );
+        // Empty lines are read as a row of nulls, like they are without rejects.
+        if line.is_empty() || line == b"\r" {
+            return Ok(());
+        }
+        let n_fields = SplitFields::new(
+            line,
+            parse_options.separator,
+            parse_options.quote_char,
+            parse_options.eol_char,
+        )
+        .count();
+        polars_ensure!(
+            n_fields == schema.len()
+                || (n_fields > schema.len() && parse_options.truncate_ragged_lines),
+            ComputeError: "expected {} fields, found {}", schema.len(), n_fields
+        );
+        Ok(())
+    };
+
+    parse_with_rejects(bytes, &rows, check_row, parse)
+}
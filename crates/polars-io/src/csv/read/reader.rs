@@ -119,6 +119,7 @@ impl<R: MmapBytesReader> CsvReader<R> {
             self.options.dtype_overwrite.clone(),
             self.predicate.clone(),
             self.options.fields_to_cast.clone(),
+            self.options.json_columns.clone(),
             self.options.skip_rows_after_header,
             self.options.row_index.clone(),
             self.options.raise_if_empty,
@@ -172,15 +173,27 @@ impl<R: MmapBytesReader> CsvReader<R> {
 }
 
 /// Splits datatypes that cannot be natively read into a `fields_to_cast` for
-/// post-read casting.
+/// post-read casting. The `json_columns` are read as strings and decoded from JSON.
 pub fn prepare_csv_schema(
     schema: &mut SchemaRef,
     fields_to_cast: &mut Vec<Field>,
+    json_columns: Option<&[PlSmallStr]>,
 ) -> PolarsResult<()> {
     // This branch we check if there are dtypes we cannot parse.
     // We only support a few dtypes in the parser and later cast to the required dtype.
     let mut changed = false;
 
+    let json_columns = json_columns.unwrap_or_default();
+    for name in json_columns {
+        let dtype = schema.try_get(name)?;
+        polars_ensure!(
+            dtype.is_nested(),
+            InvalidOperation: "JSON column '{}' must have a nested dtype, got {}; \
+            declare it with `schema_overrides`",
+            name, dtype
+        );
+    }
+
     let new_schema = schema
         .iter_fields()
         .map(|mut fld| {
@@ -189,6 +202,11 @@ pub fn prepare_csv_schema(
             let mut matched = true;
 
             let out = match fld.dtype() {
+                _ if json_columns.contains(fld.name()) => {
+                    fields_to_cast.push(fld.clone());
+                    fld.coerce(String);
+                    PolarsResult::Ok(fld)
+                },
                 Time => {
                     fields_to_cast.push(fld.clone());
                     fld.coerce(String);
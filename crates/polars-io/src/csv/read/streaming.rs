@@ -198,6 +198,7 @@ pub fn read_until_start_and_infer_schema_from_compressed_reader(
 ///
 /// Returns the inferred schema and leftover bytes not yet consumed, which may be empty. The
 /// leftover bytes + `reader.read_next_slice` is guaranteed to start at first real content row.
+/// The last return value is the number of lines before the leftover bytes.
 ///
 /// `inspect_first_content_row_fn` allows looking at the first content row, this is where parsing
 /// will start. Beware even if the function is provided it's *not* guaranteed that the returned
@@ -213,7 +214,7 @@ pub fn read_until_start_and_infer_schema(
     decompressed_file_size_hint: Option<usize>,
     mut inspect_first_content_row_fn: Option<InspectContentFn<'_>>,
     reader: &mut ByteSourceReader<ReaderSource>,
-) -> PolarsResult<(Schema, Buffer<u8>)> {
+) -> PolarsResult<(Schema, Buffer<u8>, usize)> {
     // It's better to be above than below here.
     const ESTIMATED_BYTES_PER_ROW: usize = 200;
 
@@ -262,6 +263,7 @@ pub fn read_until_start_and_infer_schema(
         options.infer_schema_length
     };
 
+    let mut n_lines_skipped = options.skip_lines;
     let mut header_line = None;
     let mut content_lines = Vec::with_capacity(infer_schema_length.unwrap_or_else(|| {
         decompressed_file_size_hint
@@ -358,6 +360,12 @@ pub fn read_until_start_and_infer_schema(
                 }
             };
 
+            if let LineUse::ConsumeDiscard = done {
+                // Quoted fields may contain line breaks.
+                n_lines_skipped +=
+                    1 + memchr::memchr_iter(options.parse_options.eol_char, line).count();
+            }
+
             Ok(done)
         },
     )?;
@@ -372,7 +380,7 @@ pub fn read_until_start_and_infer_schema(
         projected_schema,
     )?;
 
-    Ok((inferred_schema, leftover))
+    Ok((inferred_schema, leftover, n_lines_skipped))
 }
 
 enum LineUse {
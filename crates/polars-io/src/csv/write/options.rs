@@ -64,6 +64,12 @@ pub struct SerializeOptions {
     pub line_terminator: PlSmallStr,
     /// When to insert quotes.
     pub quote_style: QuoteStyle,
+    /// Write [`DataType::List`](polars_core::datatypes::DataType::List),
+    /// [`DataType::Array`](polars_core::datatypes::DataType::Array) and
+    /// [`DataType::Struct`](polars_core::datatypes::DataType::Struct) values as compact JSON text
+    /// instead of raising an error.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub nested_as_json: bool,
 }
 
 impl Default for SerializeOptions {
@@ -80,6 +86,7 @@ impl Default for SerializeOptions {
             null: PlSmallStr::EMPTY,
             line_terminator: "\n".into(),
             quote_style: Default::default(),
+            nested_as_json: false,
         }
     }
 }
@@ -37,9 +37,10 @@ impl CsvSerializer {
     pub fn new(schema: SchemaRef, options: Arc<SerializeOptions>) -> PolarsResult<Self> {
         for dtype in schema.iter_values() {
             let nested = match dtype {
-                DataType::List(_) => true,
+                DataType::List(_) => !options.nested_as_json,
                 #[cfg(feature = "dtype-struct")]
-                DataType::Struct(_) => true,
+                DataType::Struct(_) => !options.nested_as_json,
+                dt if dt.is_array() => !options.nested_as_json,
                 #[cfg(feature = "object")]
                 DataType::Object(_) => {
                     return Err(PolarsError::ComputeError(
@@ -50,7 +51,7 @@ impl CsvSerializer {
             };
             polars_ensure!(
                 !nested,
-                ComputeError: "CSV format does not support nested data",
+                ComputeError: "CSV format does not support nested data\n\nConsider setting `nested_as_json` to write nested values as JSON text.",
             );
         }
 
@@ -895,6 +895,33 @@ pub(super) fn serializer_for<'a>(
         DataType::Decimal(_, scale) => {
             quote_wrapper!(decimal_serializer, *scale)
         },
+        #[cfg(feature = "json")]
+        dtype if options.nested_as_json && dtype.is_nested() => {
+            // The JSON serializer works on logical arrow arrays.
+            let series = unsafe {
+                Series::from_chunks_and_dtype_unchecked(
+                    PlSmallStr::EMPTY,
+                    vec![array.to_boxed()],
+                    dtype,
+                )
+            };
+            let json = polars_json::json::write::serialize_to_utf8(
+                series.to_arrow(0, CompatLevel::newest()).as_ref(),
+            )
+            .with_validity(array.validity().cloned());
+
+            let mut json = Some(json);
+            string_serializer(
+                |(arr, i): &mut (Utf8ViewArray, usize)| {
+                    let value = arr.get(*i);
+                    *i += 1;
+                    value
+                },
+                options,
+                move |_| (json.take().unwrap(), 0),
+                array,
+            )
+        },
         _ => {
             polars_bail!(ComputeError: "datatype {dtype} cannot be written to CSV\n\nConsider using JSON or a binary format.")
         },
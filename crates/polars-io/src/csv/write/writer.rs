@@ -162,6 +162,12 @@ where
         self
     }
 
+    /// Set whether to write nested values (lists, arrays and structs) as JSON text.
+    pub fn with_nested_as_json(mut self, nested_as_json: bool) -> Self {
+        self.options_mut().nested_as_json = nested_as_json;
+        self
+    }
+
     /// Set the CSV file's quoting behavior.
     /// See more on [`QuoteStyle`].
     pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
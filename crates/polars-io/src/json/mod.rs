@@ -63,6 +63,7 @@
 //! ```
 //!
 pub(crate) mod infer;
+pub mod records;
 
 use std::io::Write;
 use std::num::NonZeroUsize;
@@ -0,0 +1,489 @@
+//! Incremental extraction of the records of a JSON array, so that a single large JSON document
+//! can be read in a streaming fashion.
+//!
+//! The array is located by a JSON pointer ([RFC 6901](https://www.rfc-editor.org/rfc/rfc6901)),
+//! e.g. `""` for a top-level array or `"/data/items"`. Its elements are re-emitted one record per
+//! line, so that they can be batched like NDJSON, and are then parsed with [`parse_json_records`].
+
+use std::io::Read;
+
+use arrow::array::{LIST_VALUES_NAME, StructArray};
+use arrow::datatypes::ArrowDataType;
+use polars_core::chunked_array::cast::CastOptions;
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+use simd_json::BorrowedValue;
+
+const LF: u8 = b'\n';
+const CR: u8 = b'\r';
+
+/// Splits a JSON document, fed in arbitrary chunks, into the records of the array at a JSON
+/// pointer.
+///
+/// Only object records are supported. Keys are compared to the pointer tokens without resolving
+/// JSON escapes.
+#[derive(Debug, Clone)]
+pub struct JsonRecordSplitter {
+    pointer: Vec<Vec<u8>>,
+    state: State,
+    /// Bytes of a record that is not complete yet.
+    partial_record: Vec<u8>,
+    /// Total number of bytes fed so far, for error messages.
+    offset: usize,
+}
+
+#[derive(Debug, Clone)]
+enum State {
+    /// Walking the document to find the array at the pointer.
+    Seek(Seeker),
+    /// Inside the array, `depth` levels deep into the current record (0 between records).
+    Records {
+        depth: usize,
+        in_string: bool,
+        escaped: bool,
+    },
+    /// The array has been closed, the rest of the document is ignored.
+    Done,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Seeker {
+    /// Open containers, `None` for objects and the index of the current element for arrays.
+    stack: Vec<Option<usize>>,
+    /// Number of containers at the bottom of `stack` that lie on the pointer path.
+    n_matched: usize,
+    /// The key of the value that follows in the innermost object.
+    key: Vec<u8>,
+    in_string: bool,
+    in_key: bool,
+    escaped: bool,
+    expect_key: bool,
+    expect_value: bool,
+}
+
+impl JsonRecordSplitter {
+    pub fn try_new(pointer: &str) -> PolarsResult<Self> {
+        let pointer = if pointer.is_empty() {
+            vec![]
+        } else {
+            let Some(tokens) = pointer.strip_prefix('/') else {
+                polars_bail!(
+                    InvalidOperation: "JSON pointer must be empty or start with '/', got '{}'", pointer
+                );
+            };
+            tokens
+                .split('/')
+                .map(|token| token.replace("~1", "/").replace("~0", "~").into_bytes())
+                .collect()
+        };
+
+        Ok(Self {
+            pointer,
+            state: State::Seek(Seeker {
+                expect_value: true,
+                ..Default::default()
+            }),
+            partial_record: vec![],
+            offset: 0,
+        })
+    }
+
+    /// Whether the end of the array has been reached.
+    pub fn is_done(&self) -> bool {
+        matches!(self.state, State::Done)
+    }
+
+    /// Appends the records that are completed by `bytes` to `out`, each on its own line.
+    pub fn push(&mut self, bytes: &[u8], out: &mut Vec<u8>) -> PolarsResult<()> {
+        let mut i = 0;
+
+        while i < bytes.len() {
+            match &mut self.state {
+                State::Seek(seeker) => {
+                    if let Some(found) = seeker.seek(&bytes[i..], &self.pointer) {
+                        i += found;
+                        let c = bytes[i - 1];
+                        polars_ensure!(
+                            c == b'[',
+                            ComputeError: "expected a JSON array at pointer '{}', found '{}' at byte {}",
+                            self.pointer_display(), c as char, self.offset + i - 1
+                        );
+                        self.state = State::Records {
+                            depth: 0,
+                            in_string: false,
+                            escaped: false,
+                        };
+                    } else {
+                        i = bytes.len();
+                    }
+                },
+                State::Records {
+                    depth,
+                    in_string,
+                    escaped,
+                } => {
+                    if *depth == 0 {
+                        match bytes[i] {
+                            b' ' | b'\t' | LF | CR | b',' => {},
+                            b']' => self.state = State::Done,
+                            b'{' => {
+                                *depth = 1;
+                                self.partial_record.push(b'{');
+                            },
+                            c => polars_bail!(
+                                ComputeError: "expected a JSON object as record, found '{}' at byte {}",
+                                c as char, self.offset + i
+                            ),
+                        }
+                        i += 1;
+                        continue;
+                    }
+
+                    let start = i;
+                    while i < bytes.len() && *depth > 0 {
+                        let c = bytes[i];
+                        i += 1;
+                        if *in_string {
+                            if *escaped {
+                                *escaped = false;
+                            } else if c == b'\\' {
+                                *escaped = true;
+                            } else if c == b'"' {
+                                *in_string = false;
+                            }
+                        } else {
+                            match c {
+                                b'"' => *in_string = true,
+                                b'{' | b'[' => *depth += 1,
+                                b'}' | b']' => *depth -= 1,
+                                _ => {},
+                            }
+                        }
+                    }
+                    self.partial_record.extend_from_slice(&bytes[start..i]);
+
+                    if *depth == 0 {
+                        // Line breaks can only occur as whitespace between tokens.
+                        out.extend(self.partial_record.drain(..).map(|c| match c {
+                            LF | CR => b' ',
+                            c => c,
+                        }));
+                        out.push(LF);
+                    }
+                },
+                State::Done => break,
+            }
+        }
+
+        self.offset += bytes.len();
+        Ok(())
+    }
+
+    /// Checks that the array was found and closed, to be called after the document was fully fed.
+    pub fn finish(&self) -> PolarsResult<()> {
+        match self.state {
+            State::Seek(_) => polars_bail!(
+                ComputeError: "no JSON array found at pointer '{}'", self.pointer_display()
+            ),
+            State::Records { .. } => polars_bail!(
+                ComputeError: "unexpected end of JSON document in the array at pointer '{}'",
+                self.pointer_display()
+            ),
+            State::Done => Ok(()),
+        }
+    }
+
+    fn pointer_display(&self) -> String {
+        self.pointer
+            .iter()
+            .map(|token| format!("/{}", String::from_utf8_lossy(token)))
+            .collect()
+    }
+}
+
+impl Seeker {
+    /// Scans `bytes` for the start of the value at `pointer`, returning the offset directly after
+    /// its first byte.
+    fn seek(&mut self, bytes: &[u8], pointer: &[Vec<u8>]) -> Option<usize> {
+        for (i, &c) in bytes.iter().enumerate() {
+            if self.in_string {
+                if self.escaped {
+                    self.escaped = false;
+                } else if c == b'\\' {
+                    self.escaped = true;
+                } else if c == b'"' {
+                    self.in_string = false;
+                    self.in_key = false;
+                } else if self.in_key {
+                    self.key.push(c);
+                }
+                continue;
+            }
+
+            match c {
+                b' ' | b'\t' | LF | CR => continue,
+                b':' => {
+                    self.expect_value = true;
+                    continue;
+                },
+                b',' => {
+                    match self.stack.last_mut() {
+                        Some(Some(idx)) => {
+                            *idx += 1;
+                            self.expect_value = true;
+                        },
+                        Some(None) => self.expect_key = true,
+                        None => {},
+                    }
+                    continue;
+                },
+                b'}' | b']' => {
+                    self.stack.pop();
+                    self.n_matched = self.n_matched.min(self.stack.len());
+                    self.expect_key = false;
+                    self.expect_value = false;
+                    continue;
+                },
+                b'"' if self.expect_key => {
+                    self.expect_key = false;
+                    self.in_string = true;
+                    self.in_key = true;
+                    self.key.clear();
+                    continue;
+                },
+                _ => {},
+            }
+
+            if !self.expect_value {
+                // Remaining bytes of a number or literal.
+                if c == b'"' {
+                    self.in_string = true;
+                }
+                continue;
+            }
+            self.expect_value = false;
+
+            let depth = self.stack.len();
+            let on_path = self.n_matched == depth
+                && match (depth, self.stack.last()) {
+                    (0, _) => true,
+                    (_, Some(Some(idx))) => pointer
+                        .get(depth - 1)
+                        .is_some_and(|token| token.as_slice() == idx.to_string().as_bytes()),
+                    (_, _) => pointer
+                        .get(depth - 1)
+                        .is_some_and(|token| *token == self.key),
+                };
+
+            if on_path && depth == pointer.len() {
+                return Some(i + 1);
+            }
+
+            match c {
+                b'{' => {
+                    self.stack.push(None);
+                    self.expect_key = true;
+                },
+                b'[' => {
+                    self.stack.push(Some(0));
+                    self.expect_value = true;
+                },
+                b'"' => self.in_string = true,
+                _ => continue,
+            }
+
+            if on_path && matches!(c, b'{' | b'[') {
+                self.n_matched += 1;
+            }
+        }
+
+        None
+    }
+}
+
+/// Reads the first `n_records` records (or all of them) of the array at `pointer` as NDJSON, e.g.
+/// for schema inference.
+pub fn read_json_records_to_ndjson<R: Read>(
+    mut reader: R,
+    pointer: &str,
+    n_records: Option<usize>,
+) -> PolarsResult<Vec<u8>> {
+    let mut splitter = JsonRecordSplitter::try_new(pointer)?;
+    let mut buf = vec![0; 64 * 1024];
+    let mut out = vec![];
+    let mut n_read = 0;
+
+    loop {
+        let n = reader.read(&mut buf)?;
+        if n == 0 {
+            splitter.finish()?;
+            break;
+        }
+
+        let len_before = out.len();
+        splitter.push(&buf[..n], &mut out)?;
+        n_read += memchr::memchr_iter(LF, &out[len_before..]).count();
+
+        if splitter.is_done() || n_records.is_some_and(|n| n_read >= n) {
+            break;
+        }
+    }
+
+    Ok(out)
+}
+
+/// Parses lines of records, as emitted by [`JsonRecordSplitter`], into the columns of `schema`.
+///
+/// The records are deserialized as a single JSON array by the polars-json deserializer, which
+/// only materializes the fields in `schema` and skips the other keys of the records.
+pub fn parse_json_records(
+    records: &[u8],
+    schema: &Schema,
+    ignore_errors: bool,
+) -> PolarsResult<DataFrame> {
+    let mut json = Vec::with_capacity(records.len() + 2);
+    json.push(b'[');
+    for record in records.split(|&c| c == LF).filter(|r| !r.is_empty()) {
+        if json.len() > 1 {
+            json.push(b',');
+        }
+        json.extend_from_slice(record);
+    }
+    json.push(b']');
+
+    let json_value = simd_json::to_borrowed_value(&mut json).map_err(to_compute_err)?;
+    let height = match &json_value {
+        BorrowedValue::Array(rows) => rows.len(),
+        _ => unreachable!(),
+    };
+    if schema.is_empty() {
+        return Ok(DataFrame::empty_with_height(height));
+    }
+
+    let mut needs_cast = false;
+    let deserialize_schema = schema
+        .iter()
+        .map(|(name, dt)| {
+            Field::new(
+                name.clone(),
+                dt.clone().map_leaves(&mut |leaf_dt| {
+                    // Deserialize enums and categoricals as strings first.
+                    match leaf_dt {
+                        #[cfg(feature = "dtype-categorical")]
+                        DataType::Enum(..) | DataType::Categorical(..) => {
+                            needs_cast = true;
+                            DataType::String
+                        },
+                        leaf_dt => leaf_dt,
+                    }
+                }),
+            )
+        })
+        .collect();
+    let arrow_dtype = ArrowDataType::LargeList(Box::new(arrow::datatypes::Field::new(
+        LIST_VALUES_NAME,
+        DataType::Struct(deserialize_schema).to_arrow(CompatLevel::newest()),
+        true,
+    )));
+
+    let arr = polars_json::json::deserialize(&json_value, arrow_dtype, true)?;
+    let arr = arr
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .ok_or_else(|| polars_err!(ComputeError: "can only deserialize json objects"))?;
+    let mut df = DataFrame::try_from(arr.clone())?;
+
+    if needs_cast {
+        for (col, dt) in unsafe { df.columns_mut() }
+            .iter_mut()
+            .zip(schema.iter_values())
+        {
+            *col = col.cast_with_options(
+                dt,
+                if ignore_errors {
+                    CastOptions::NonStrict
+                } else {
+                    CastOptions::Strict
+                },
+            )?;
+        }
+    }
+
+    Ok(df)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(data: &[u8], pointer: &str, chunk_size: usize) -> PolarsResult<String> {
+        let mut splitter = JsonRecordSplitter::try_new(pointer)?;
+        let mut out = vec![];
+        for chunk in data.chunks(chunk_size) {
+            splitter.push(chunk, &mut out)?;
+        }
+        splitter.finish()?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn test_split_json_records() {
+        let data = br#"{
+            "meta": {"items": [{"x": 0}], "n": 1.5e3},
+            "data": {"tag": "]}\"", "items": [
+                {"a": 1,
+"b": {"c": [1, 2]}},
+                {"a": "[{,\n}]"}
+            ]},
+            "after": [1]
+        }"#;
+
+        for chunk_size in [1, 7, data.len()] {
+            assert_eq!(
+                split(data, "/data/items", chunk_size).unwrap(),
+                "{\"a\": 1, \"b\": {\"c\": [1, 2]}}\n{\"a\": \"[{,\\n}]\"}\n"
+            );
+        }
+
+        assert_eq!(
+            split(br#"[{"a": 1},{"a": 2}]"#, "", 3).unwrap(),
+            "{\"a\": 1}\n{\"a\": 2}\n"
+        );
+        assert_eq!(
+            split(br#"[[], [{"a": 1}]]"#, "/1", 3).unwrap(),
+            "{\"a\": 1}\n"
+        );
+        assert_eq!(split(b"[ ]", "", 1).unwrap(), "");
+    }
+
+    #[test]
+    fn test_parse_json_records() {
+        let records = split(
+            br#"{"d": [{"a": 1, "b": {"c": [1]}}, {"b": null, "a": 2}]}"#,
+            "/d",
+            5,
+        )
+        .unwrap();
+        let schema = Schema::from_iter([Field::new("a".into(), DataType::Int64)]);
+
+        let df = parse_json_records(records.as_bytes(), &schema, false).unwrap();
+        assert_eq!(df.width(), 1);
+        assert_eq!(
+            df.column("a").unwrap().i64().unwrap().to_vec(),
+            &[Some(1), Some(2)]
+        );
+
+        let df = parse_json_records(records.as_bytes(), &Schema::default(), false).unwrap();
+        assert_eq!(df.shape(), (2, 0));
+    }
+
+    #[test]
+    fn test_split_json_records_errors() {
+        assert!(split(br#"{"a": []}"#, "/b", 4).is_err());
+        assert!(split(br#"{"a": {}}"#, "/a", 4).is_err());
+        assert!(split(br#"{"a": [1]}"#, "/a", 4).is_err());
+        assert!(split(br#"{"a": [{}"#, "/a", 4).is_err());
+        assert!(JsonRecordSplitter::try_new("a").is_err());
+    }
+}
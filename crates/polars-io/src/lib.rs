@@ -31,6 +31,8 @@ pub mod path_utils;
 pub mod pl_async;
 pub mod predicates;
 pub mod prelude;
+#[cfg(feature = "fwf")]
+pub mod scan_fwf;
 #[cfg(feature = "scan_lines")]
 pub mod scan_lines;
 mod shared;
@@ -9,6 +9,8 @@ pub struct IOMetrics {
     pub bytes_requested: RelaxedCell<u64>,
     pub bytes_received: RelaxedCell<u64>,
     pub bytes_sent: RelaxedCell<u64>,
+    /// Number of row groups (or equivalent batches) skipped based on predicates.
+    pub row_groups_skipped: RelaxedCell<u64>,
 }
 
 #[derive(Debug, Clone)]
@@ -35,6 +37,12 @@ impl OptIOMetrics {
         self.0.as_ref().map(|x| x.bytes_sent.fetch_add(bytes_sent));
     }
 
+    pub fn add_row_groups_skipped(&self, row_groups_skipped: u64) {
+        self.0
+            .as_ref()
+            .map(|x| x.row_groups_skipped.fetch_add(row_groups_skipped));
+    }
+
     pub async fn record_io_read<F, O>(&self, num_bytes: u64, fut: F) -> O
     where
         F: Future<Output = O>,
@@ -13,6 +13,7 @@ use crate::mmap::ReaderBytes;
 use crate::ndjson::buffer::*;
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
+use crate::utils::rejects::{RejectedRow, parse_with_rejects};
 const NEWLINE: u8 = b'\n';
 const CLOSING_BRACKET: u8 = b'}';
 
@@ -268,6 +269,27 @@ pub fn parse_ndjson(
     )
 }
 
+/// Parses `bytes` like [`parse_ndjson`], but lines that fail to parse into `schema` are returned
+/// separately instead of failing the chunk.
+pub fn parse_ndjson_with_rejects(
+    bytes: &[u8],
+    schema: &Schema,
+) -> PolarsResult<(DataFrame, Vec<RejectedRow>)> {
+    let rows = json_lines(bytes)
+        .map(|line| {
+            let start = line.as_ptr() as usize - bytes.as_ptr() as usize;
+            start..start + line.len()
+        })
+        .collect::<Vec<_>>();
+
+    parse_with_rejects(
+        bytes,
+        &rows,
+        |_| Ok(()),
+        |bytes, n_rows| parse_ndjson(bytes, Some(n_rows), schema, false),
+    )
+}
+
 pub fn estimate_n_lines_in_file(file_bytes: &[u8], sample_size: usize) -> usize {
     if let Some((mean, std)) = get_line_stats_json(file_bytes, sample_size) {
         (file_bytes.len() as f32 / (mean - 0.01 * std)) as usize
@@ -0,0 +1,144 @@
+//! Parquet modular encryption options.
+//!
+//! The keys themselves are never part of the options; they are resolved from the key metadata
+//! stored in the file through a [`KeyRetriever`].
+
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::sync::Arc;
+
+use arrow::datatypes::ArrowSchema;
+use polars_error::{PolarsResult, polars_bail};
+pub use polars_parquet::parquet::encryption::KeyRetriever;
+use polars_parquet::parquet::encryption::{
+    ColumnKey, FileDecryptionProperties, FileEncryptionProperties,
+};
+use polars_utils::pl_str::PlSmallStr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A [`KeyRetriever`] that resolves the footer and column keys of encrypted Parquet files.
+#[derive(Clone)]
+pub struct ParquetKeyRetriever(pub Arc<dyn KeyRetriever>);
+
+impl ParquetKeyRetriever {
+    pub fn new(key_retriever: impl KeyRetriever + 'static) -> Self {
+        Self(Arc::new(key_retriever))
+    }
+
+    pub fn retrieve_key(&self, key_metadata: &[u8]) -> PolarsResult<Vec<u8>> {
+        Ok(self.0.retrieve_key(key_metadata)?)
+    }
+
+    pub fn decryption_properties(&self) -> FileDecryptionProperties {
+        FileDecryptionProperties::new(self.0.clone())
+    }
+}
+
+impl Debug for ParquetKeyRetriever {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "key retriever at 0x{:016x}",
+            self.0.as_ref() as *const _ as *const () as usize
+        )
+    }
+}
+
+impl Eq for ParquetKeyRetriever {}
+
+impl PartialEq for ParquetKeyRetriever {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Hash for ParquetKeyRetriever {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(Arc::as_ptr(&self.0) as *const () as usize);
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for ParquetKeyRetriever {
+    fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::Error;
+        Err(S::Error::custom(format!("cannot serialize {self:?}")))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ParquetKeyRetriever {
+    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+        Err(D::Error::custom("cannot deserialize ParquetKeyRetriever"))
+    }
+}
+
+/// How to encrypt a Parquet file with the `AES_GCM_V1` algorithm.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ParquetEncryptionOptions {
+    /// Resolves the footer key and the column keys from their metadata.
+    pub key_retriever: ParquetKeyRetriever,
+    /// Identifies the footer key, stored in the file.
+    pub footer_key_metadata: Vec<u8>,
+    /// Top-level columns that are encrypted with their own key, and the metadata identifying
+    /// that key. If empty, every column is encrypted with the footer key. Otherwise, columns that
+    /// aren't listed are not encrypted.
+    pub column_key_metadata: Vec<(PlSmallStr, Vec<u8>)>,
+    /// Write the footer in plaintext, signed with the footer key, so that readers without the
+    /// keys can read the schema and the unencrypted columns.
+    pub plaintext_footer: bool,
+}
+
+impl ParquetEncryptionOptions {
+    /// Encrypt every column and the footer with the key identified by `footer_key_metadata`.
+    pub fn new(key_retriever: ParquetKeyRetriever, footer_key_metadata: Vec<u8>) -> Self {
+        Self {
+            key_retriever,
+            footer_key_metadata,
+            column_key_metadata: vec![],
+            plaintext_footer: false,
+        }
+    }
+
+    /// Retrieves the keys and returns the properties to encrypt a file of `schema` with.
+    pub fn file_encryption_properties(
+        &self,
+        schema: &ArrowSchema,
+    ) -> PolarsResult<FileEncryptionProperties> {
+        for (name, _) in &self.column_key_metadata {
+            if schema.index_of(name).is_none() {
+                polars_bail!(ColumnNotFound: "encrypted column '{}' not found in schema", name);
+            }
+        }
+
+        let footer_key = self.key_retriever.retrieve_key(&self.footer_key_metadata)?;
+        let column_keys = self
+            .column_key_metadata
+            .iter()
+            .map(|(name, key_metadata)| {
+                let key = self.key_retriever.retrieve_key(key_metadata)?;
+                let column_key = ColumnKey {
+                    key,
+                    key_metadata: Some(key_metadata.clone()),
+                };
+                Ok((name.to_string(), column_key))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        Ok(FileEncryptionProperties {
+            footer_key_metadata: Some(self.footer_key_metadata.clone()),
+            column_keys,
+            plaintext_footer: self.plaintext_footer,
+            ..FileEncryptionProperties::new(footer_key)
+        })
+    }
+}
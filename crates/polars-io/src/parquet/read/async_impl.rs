@@ -4,9 +4,12 @@ use arrow::datatypes::ArrowSchemaRef;
 use object_store::path::Path as ObjectPath;
 use polars_buffer::Buffer;
 use polars_core::prelude::*;
+use polars_parquet::parquet::encryption::FileDecryptionProperties;
 use polars_parquet::parquet::error::ParquetError;
-use polars_parquet::parquet::read::{deserialize_metadata, deserialize_num_rows};
-use polars_parquet::parquet::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, PARQUET_MAGIC};
+use polars_parquet::parquet::read::{
+    deserialize_metadata_with_decryption, deserialize_num_rows, is_parquet_magic,
+};
+use polars_parquet::parquet::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE};
 use polars_parquet::write::FileMetadata;
 use polars_utils::pl_path::PlRefPath;
 
@@ -14,6 +17,7 @@ use crate::cloud::concurrency_config::{ConcurrencyStrategy, FetchConfig};
 use crate::cloud::{
     CloudLocation, CloudOptions, PolarsObjectStore, build_object_store, object_path_from_str,
 };
+use crate::parquet::encryption::ParquetKeyRetriever;
 use crate::parquet::metadata::FileMetadataRef;
 
 pub struct ParquetObjectStore {
@@ -22,6 +26,7 @@ pub struct ParquetObjectStore {
     length: Option<usize>,
     metadata: Option<FileMetadataRef>,
     schema: Option<ArrowSchemaRef>,
+    decryption: Option<ParquetKeyRetriever>,
 }
 
 impl ParquetObjectStore {
@@ -39,9 +44,16 @@ impl ParquetObjectStore {
             length: None,
             metadata,
             schema: None,
+            decryption: None,
         })
     }
 
+    /// Resolve the keys of an encrypted file with `decryption`.
+    pub fn with_decryption(mut self, decryption: Option<ParquetKeyRetriever>) -> Self {
+        self.decryption = decryption;
+        self
+    }
+
     /// Initialize the length property of the object, unless it has already been fetched.
     async fn length(&mut self) -> PolarsResult<usize> {
         if self.length.is_none() {
@@ -64,7 +76,11 @@ impl ParquetObjectStore {
     /// Fetch the metadata of the parquet file, do not memoize it.
     async fn fetch_metadata(&mut self) -> PolarsResult<FileMetadata> {
         let length = self.length().await?;
-        fetch_metadata(&self.store, &self.path, length).await
+        let decryption = self
+            .decryption
+            .as_ref()
+            .map(ParquetKeyRetriever::decryption_properties);
+        fetch_metadata(&self.store, &self.path, length, decryption.as_ref()).await
     }
 
     /// Fetch and memoize the metadata of the parquet file.
@@ -144,7 +160,7 @@ async fn fetch_footer_bytes(
         let footer_byte_size = read_i32le(reader).unwrap();
         let magic = read_n(reader).unwrap();
         debug_assert!(reader.is_empty());
-        if magic != PARQUET_MAGIC {
+        if !is_parquet_magic(&magic) {
             return Err(out_of_spec("incorrect magic in parquet footer").into());
         }
         footer_byte_size
@@ -177,9 +193,10 @@ pub async fn fetch_metadata(
     store: &PolarsObjectStore,
     path: &ObjectPath,
     file_byte_length: usize,
+    decryption: Option<&FileDecryptionProperties>,
 ) -> PolarsResult<FileMetadata> {
     let footer = fetch_footer_bytes(store, path, file_byte_length).await?;
-    Ok(deserialize_metadata(footer)?)
+    Ok(deserialize_metadata_with_decryption(footer, decryption)?)
 }
 
 /// Fetch only `FileMetaData.num_rows` from a remote parquet footer.
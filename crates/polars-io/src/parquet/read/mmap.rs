@@ -6,7 +6,7 @@ use arrow::datatypes::Field;
 use polars_buffer::Buffer;
 use polars_error::PolarsResult;
 use polars_parquet::read::{
-    BasicDecompressor, ColumnChunkMetadata, Filter, PageReader, column_iter_to_arrays,
+    BasicDecompressor, ColumnChunkMetadata, Filter, PageMetaData, PageReader, column_iter_to_arrays,
 };
 use polars_utils::mem::prefetch::prefetch_l2;
 
@@ -73,3 +73,29 @@ pub fn to_deserializer(
 
     column_iter_to_arrays(columns, types, field, filter)
 }
+
+/// Like [`to_deserializer`] for a flat column of which only some data pages were fetched. `chunk`
+/// holds the dictionary page of the column chunk, if any, followed by those data pages, which
+/// contain `num_values` values.
+pub fn to_sparse_deserializer(
+    column_meta: &ColumnChunkMetadata,
+    chunk: Buffer<u8>,
+    num_values: usize,
+    field: Field,
+    filter: Option<Filter>,
+) -> PolarsResult<(Vec<Box<dyn Array>>, Bitmap)> {
+    prefetch_l2(&chunk);
+
+    let page_meta = PageMetaData {
+        num_values: num_values as i64,
+        ..PageMetaData::from(column_meta)
+    };
+    let pages = PageReader::new_with_page_meta(Cursor::new(chunk), page_meta, vec![], usize::MAX);
+
+    column_iter_to_arrays(
+        vec![BasicDecompressor::new(pages, vec![])],
+        vec![&column_meta.descriptor().descriptor.primitive_type],
+        field,
+        filter,
+    )
+}
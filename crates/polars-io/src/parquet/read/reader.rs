@@ -10,6 +10,7 @@ use super::read_impl::read_parquet;
 use super::utils::{ensure_matching_dtypes_if_found, projected_arrow_schema_to_projection_indices};
 use crate::RowIndex;
 use crate::mmap::MmapBytesReader;
+use crate::parquet::encryption::ParquetKeyRetriever;
 use crate::parquet::metadata::FileMetadataRef;
 use crate::prelude::*;
 
@@ -28,6 +29,7 @@ pub struct ParquetReader<R: Read + Seek> {
     metadata: Option<FileMetadataRef>,
     hive_partition_columns: Option<Vec<Series>>,
     include_file_path: Option<(PlSmallStr, PlRefStr)>,
+    decryption: Option<ParquetKeyRetriever>,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -164,13 +166,26 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Resolve the keys of an encrypted file with `decryption`.
+    pub fn with_decryption(mut self, decryption: Option<ParquetKeyRetriever>) -> Self {
+        self.decryption = decryption;
+        self
+    }
+
     pub fn set_metadata(&mut self, metadata: FileMetadataRef) {
         self.metadata = Some(metadata);
     }
 
     pub fn get_metadata(&mut self) -> PolarsResult<&FileMetadataRef> {
         if self.metadata.is_none() {
-            self.metadata = Some(Arc::new(read::read_metadata(&mut self.reader)?));
+            let decryption = self
+                .decryption
+                .as_ref()
+                .map(ParquetKeyRetriever::decryption_properties);
+            self.metadata = Some(Arc::new(read::read_metadata_with_decryption(
+                &mut self.reader,
+                decryption.as_ref(),
+            )?));
         }
         Ok(self.metadata.as_ref().unwrap())
     }
@@ -192,6 +207,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             schema: None,
             hive_partition_columns: None,
             include_file_path: None,
+            decryption: None,
         }
     }
 
@@ -7,9 +7,10 @@ use polars_core::prelude::*;
 use polars_core::runtime::RAYON;
 use polars_parquet::read::{ParquetError, fallible_streaming_iterator};
 use polars_parquet::write::{
-    CompressedPage, Compressor, DynIter, DynStreamingIterator, Encoding, FallibleStreamingIterator,
-    FileWriter, Page, ParquetType, RowGroupIterColumns, SchemaDescriptor, WriteOptions,
-    array_to_columns, schema_to_metadata_key,
+    BloomFilterOptions, CompressedPage, Compressor, DynIter, DynStreamingIterator, Encoding,
+    FallibleStreamingIterator, FileWriter, Page, ParquetType, RowGroupIterColumns,
+    SchemaDescriptor, WriteOptions, array_to_bloom_filters, array_to_columns,
+    schema_to_metadata_key,
 };
 use rayon::prelude::*;
 
@@ -27,6 +28,8 @@ pub struct BatchedWriter<W: Write> {
     pub(super) options: WriteOptions,
     pub(super) parallel: bool,
     pub(super) key_value_metadata: Option<KeyValueMetadata>,
+    /// Bloom filter options per top-level column. Empty if no bloom filters are written.
+    pub(super) bloom_filters: Vec<Option<BloomFilterOptions>>,
 }
 
 impl<W: Write> BatchedWriter<W> {
@@ -44,6 +47,7 @@ impl<W: Write> BatchedWriter<W> {
             options,
             parallel,
             key_value_metadata,
+            bloom_filters: vec![],
         }
     }
 
@@ -76,13 +80,14 @@ impl<W: Write> BatchedWriter<W> {
             df,
             &self.parquet_schema,
             &self.encodings,
+            &self.bloom_filters,
             self.options,
             self.parallel,
         );
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
-        for (num_rows, group) in row_group_iter {
-            writer.write(num_rows as u64, group?)?;
+        for (num_rows, group, bloom_filters) in row_group_iter {
+            writer.write_with_bloom_filters(num_rows as u64, group?, bloom_filters)?;
         }
         Ok(())
     }
@@ -93,10 +98,13 @@ impl<W: Write> BatchedWriter<W> {
     }
 
     /// Note: `num_rows` can be passed as `u64::MAX` to infer `num_rows` from the encoded data.
+    ///
+    /// `bloom_filters` is either empty or holds a bloom filter bitset per column chunk.
     pub fn write_row_group(
         &mut self,
         num_rows: u64,
         rg: &[Vec<CompressedPage>],
+        bloom_filters: Vec<Option<Vec<u8>>>,
     ) -> PolarsResult<()> {
         let writer = self.writer.get_mut().unwrap();
         let rg = DynIter::new(rg.iter().map(|col_pages| {
@@ -104,7 +112,7 @@ impl<W: Write> BatchedWriter<W> {
                 fallible_streaming_iterator::convert(col_pages.iter().map(PolarsResult::Ok)),
             ))
         }));
-        writer.write(num_rows, rg)?;
+        writer.write_with_bloom_filters(num_rows, rg, bloom_filters)?;
         Ok(())
     }
 
@@ -154,26 +162,52 @@ fn prepare_rg_iter<'a>(
     df: &'a DataFrame,
     parquet_schema: &'a SchemaDescriptor,
     encodings: &'a [Vec<Encoding>],
+    bloom_filters: &'a [Option<BloomFilterOptions>],
     options: WriteOptions,
     parallel: bool,
 ) -> impl Iterator<
     Item = (
         usize,
         PolarsResult<RowGroupIterColumns<'static, PolarsError>>,
+        Vec<Option<Vec<u8>>>,
     ),
 > + 'a {
     let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
     rb_iter.filter_map(move |batch| match batch.len() {
         0 => None,
         num_rows => {
+            let bloom_filters = create_bloom_filters(&batch, encodings, bloom_filters);
             let row_group =
                 create_serializer(batch, parquet_schema.fields(), encodings, options, parallel);
 
-            Some((num_rows, row_group))
+            Some((num_rows, row_group, bloom_filters))
         },
     })
 }
 
+/// Builds the bloom filter bitsets for every column chunk of `batch`. Returns an empty `Vec`
+/// if no bloom filters are written.
+fn create_bloom_filters(
+    batch: &RecordBatch,
+    encodings: &[Vec<Encoding>],
+    bloom_filters: &[Option<BloomFilterOptions>],
+) -> Vec<Option<Vec<u8>>> {
+    if bloom_filters.iter().all(Option::is_none) {
+        return vec![];
+    }
+
+    batch
+        .columns()
+        .iter()
+        .zip(encodings)
+        .zip(bloom_filters)
+        .flat_map(|((array, encodings), options)| match options {
+            Some(options) => array_to_bloom_filters(array.as_ref(), options),
+            None => vec![None; encodings.len()],
+        })
+        .collect()
+}
+
 fn pages_iter_to_compressor(
     encoded_columns: Vec<DynIter<'static, PolarsResult<Page>>>,
     options: WriteOptions,
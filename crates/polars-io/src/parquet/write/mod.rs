@@ -8,5 +8,5 @@ mod writer;
 pub use batched_writer::BatchedWriter;
 pub use key_value_metadata::{KeyValueMetadata, ParquetMetadataContext};
 pub use options::{ParquetCompression, ParquetWriteOptions};
-pub use polars_parquet::write::{RowGroupIterColumns, StatisticsOptions};
-pub use writer::{ParquetWriter, get_encodings};
+pub use polars_parquet::write::{BloomFilterOptions, RowGroupIterColumns, StatisticsOptions};
+pub use writer::{ParquetWriter, get_bloom_filter_options, get_encodings};
@@ -1,12 +1,14 @@
 use arrow::datatypes::ArrowSchemaRef;
 use polars_core::prelude::CompatLevel;
 use polars_parquet::write::{
-    BrotliLevel, CompressionOptions, GzipLevel, StatisticsOptions, ZstdLevel,
+    BloomFilterOptions, BrotliLevel, CompressionOptions, GzipLevel, StatisticsOptions, ZstdLevel,
 };
+use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use super::KeyValueMetadata;
+use crate::parquet::encryption::ParquetEncryptionOptions;
 
 #[derive(Default, Clone, Debug, PartialEq, Hash, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -25,6 +27,13 @@ pub struct ParquetWriteOptions {
     pub arrow_schema: Option<ArrowSchemaRef>,
     #[cfg_attr(feature = "serde", serde(default))]
     pub compat_level: Option<CompatLevel>,
+    /// Write split-block bloom filters for these top-level columns.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub bloom_filters: Vec<(PlSmallStr, BloomFilterOptions)>,
+    /// Encrypt the file with Parquet modular encryption.
+    #[cfg_attr(feature = "serde", serde(default))]
+    #[cfg_attr(feature = "dsl-schema", schemars(skip))]
+    pub encryption: Option<ParquetEncryptionOptions>,
 }
 
 impl ParquetWriteOptions {
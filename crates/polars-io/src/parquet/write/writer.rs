@@ -5,13 +5,14 @@ use polars_buffer::Buffer;
 use polars_core::frame::chunk_df_for_writing;
 use polars_core::prelude::*;
 use polars_parquet::write::{
-    CompressionOptions, Encoding, FileWriter, StatisticsOptions, Version, WriteOptions,
-    get_dtype_encoding, to_parquet_schema,
+    BloomFilterOptions, CompressionOptions, Encoding, FileWriter, StatisticsOptions, Version,
+    WriteOptions, get_dtype_encoding, to_parquet_schema,
 };
 
 use super::batched_writer::BatchedWriter;
 use super::options::ParquetCompression;
 use super::{KeyValueMetadata, ParquetWriteOptions};
+use crate::parquet::encryption::ParquetEncryptionOptions;
 use crate::shared::schema_to_arrow_checked;
 
 impl ParquetWriteOptions {
@@ -25,6 +26,8 @@ impl ParquetWriteOptions {
             .with_row_group_size(self.row_group_size)
             .with_data_page_size(self.data_page_size)
             .with_key_value_metadata(self.key_value_metadata.clone())
+            .with_bloom_filters(self.bloom_filters.clone())
+            .with_encryption(self.encryption.clone())
     }
 }
 
@@ -46,6 +49,10 @@ pub struct ParquetWriter<W> {
     key_value_metadata: Option<KeyValueMetadata>,
     /// Context info for the Parquet file being written.
     context_info: Option<PlHashMap<String, String>>,
+    /// Write split-block bloom filters for these top-level columns.
+    bloom_filters: Vec<(PlSmallStr, BloomFilterOptions)>,
+    /// Encrypt the file with Parquet modular encryption.
+    encryption: Option<ParquetEncryptionOptions>,
 }
 
 impl<W> ParquetWriter<W>
@@ -66,6 +73,8 @@ where
             parallel: true,
             key_value_metadata: None,
             context_info: None,
+            bloom_filters: vec![],
+            encryption: None,
         }
     }
 
@@ -115,12 +124,38 @@ where
         self
     }
 
+    /// Write split-block bloom filters for the given top-level columns.
+    pub fn with_bloom_filters(
+        mut self,
+        bloom_filters: Vec<(PlSmallStr, BloomFilterOptions)>,
+    ) -> Self {
+        self.bloom_filters = bloom_filters;
+        self
+    }
+
+    /// Encrypt the file with Parquet modular encryption. Bloom filters are not written for
+    /// encrypted columns.
+    pub fn with_encryption(mut self, encryption: Option<ParquetEncryptionOptions>) -> Self {
+        self.encryption = encryption;
+        self
+    }
+
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
         let schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
         let parquet_schema = to_parquet_schema(&schema)?;
         let encodings = get_encodings(&schema);
+        let bloom_filters = get_bloom_filter_options(&schema, &self.bloom_filters)?;
         let options = self.materialize_options();
-        let writer = Mutex::new(FileWriter::try_new(self.writer, schema, options)?);
+        let encryption = self
+            .encryption
+            .as_ref()
+            .map(|e| e.file_encryption_properties(&schema))
+            .transpose()?;
+        let mut writer = FileWriter::try_new(self.writer, schema, options)?;
+        if let Some(encryption) = encryption {
+            writer = writer.with_encryption(encryption)?;
+        }
+        let writer = Mutex::new(writer);
 
         Ok(BatchedWriter {
             writer,
@@ -129,6 +164,7 @@ where
             options,
             parallel: self.parallel,
             key_value_metadata: self.key_value_metadata,
+            bloom_filters,
         })
     }
 
@@ -157,3 +193,19 @@ pub fn get_encodings(schema: &ArrowSchema) -> Buffer<Vec<Encoding>> {
         .map(|f| get_dtype_encoding(&f.dtype))
         .collect()
 }
+
+/// Resolves the bloom filter options of every top-level column of `schema`.
+pub fn get_bloom_filter_options(
+    schema: &ArrowSchema,
+    bloom_filters: &[(PlSmallStr, BloomFilterOptions)],
+) -> PolarsResult<Vec<Option<BloomFilterOptions>>> {
+    let mut out = vec![None; schema.len()];
+    for (name, options) in bloom_filters {
+        options.validate()?;
+        let Some(idx) = schema.index_of(name) else {
+            polars_bail!(ColumnNotFound: "bloom filter column '{}' not found in schema", name);
+        };
+        out[idx] = Some(*options);
+    }
+    Ok(out)
+}
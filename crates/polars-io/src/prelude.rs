@@ -9,7 +9,7 @@ pub use crate::json::*;
 pub use crate::ndjson::core::*;
 pub use crate::options::*;
 #[cfg(feature = "parquet")]
-pub use crate::parquet::{metadata::*, read::*, write::*};
+pub use crate::parquet::{encryption::*, metadata::*, read::*, write::*};
 pub use crate::path_utils::*;
 pub use crate::shared::{SerReader, SerWriter};
 pub use crate::utils::*;
@@ -0,0 +1,372 @@
+//! Fixed-width text files, where every column occupies the same span of each line.
+
+use std::sync::Arc;
+
+use arrow::array::MutableBinaryViewArray;
+use polars_core::prelude::*;
+use polars_error::{PolarsResult, polars_ensure};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub use crate::scan_lines::count_lines;
+
+const CR: u8 = b'\r';
+const LF: u8 = b'\n';
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FwfReadOptions {
+    /// Output schema, with one column per span.
+    pub schema: SchemaRef,
+    /// Half-open `(start, end)` span of every column in `schema`, in `span_unit`s.
+    pub spans: Arc<[(usize, usize)]>,
+    pub span_unit: FwfSpanUnit,
+    pub trim: FwfTrim,
+    /// Number of lines to skip at the start of every file, e.g. headers.
+    pub skip_lines: usize,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum FwfSpanUnit {
+    #[default]
+    Bytes,
+    /// UTF-8 characters.
+    Chars,
+}
+
+/// Whitespace (spaces and tabs) to remove from the values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum FwfTrim {
+    None,
+    Start,
+    End,
+    #[default]
+    Both,
+}
+
+impl FwfReadOptions {
+    pub fn try_new(schema: SchemaRef, spans: Vec<(usize, usize)>) -> PolarsResult<Self> {
+        polars_ensure!(
+            spans.len() == schema.len(),
+            InvalidOperation: "expected {} column spans, got {}", schema.len(), spans.len()
+        );
+
+        for (name, &(start, end)) in schema.iter_names().zip(&spans) {
+            polars_ensure!(
+                start <= end,
+                InvalidOperation: "invalid span {}..{} for column '{}'", start, end, name
+            );
+        }
+
+        Ok(Self {
+            schema,
+            spans: spans.into(),
+            span_unit: FwfSpanUnit::default(),
+            trim: FwfTrim::default(),
+            skip_lines: 0,
+        })
+    }
+
+    /// Creates the options for columns that directly follow each other.
+    pub fn try_from_widths(schema: SchemaRef, widths: &[usize]) -> PolarsResult<Self> {
+        let spans = widths
+            .iter()
+            .scan(0, |start, &width| {
+                let span = (*start, *start + width);
+                *start = span.1;
+                Some(span)
+            })
+            .collect();
+
+        Self::try_new(schema, spans)
+    }
+
+    pub fn with_span_unit(mut self, span_unit: FwfSpanUnit) -> Self {
+        self.span_unit = span_unit;
+        self
+    }
+
+    pub fn with_trim(mut self, trim: FwfTrim) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    pub fn with_skip_lines(mut self, skip_lines: usize) -> Self {
+        self.skip_lines = skip_lines;
+        self
+    }
+}
+
+/// Parses the lines of `bytes` into the columns of `projected_schema`, which must be a subset of
+/// the schema in `options`.
+///
+/// Values are cut from every line by their span and trimmed. Empty values and spans past the end
+/// of a line are read as null, other values are cast from string to the column type.
+pub fn parse_fwf_chunk(
+    bytes: &[u8],
+    options: &FwfReadOptions,
+    projected_schema: &Schema,
+) -> PolarsResult<DataFrame> {
+    let bytes = bytes.strip_suffix(&[LF]).unwrap_or(bytes);
+    let lines = if bytes.is_empty() {
+        Vec::new()
+    } else {
+        bytes
+            .split(|c| *c == LF)
+            .map(|line| line.strip_suffix(&[CR]).unwrap_or(line))
+            .collect::<Vec<_>>()
+    };
+
+    let spans = projected_schema
+        .iter_names()
+        .map(|name| {
+            let (index, _, _) = options.schema.try_get_full(name)?;
+            Ok(options.spans[index])
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let byte_spans = ByteSpans::new(&lines, &spans, options.span_unit);
+
+    let columns = projected_schema
+        .iter()
+        .enumerate()
+        .map(|(column_idx, (name, dtype))| {
+            let mut values = MutableBinaryViewArray::<[u8]>::with_capacity(lines.len());
+            for (line_idx, line) in lines.iter().enumerate() {
+                let (start, end) = byte_spans.get(line, line_idx, column_idx);
+                let value = trim(&line[start..end], options.trim);
+                values.push((!value.is_empty()).then_some(value));
+            }
+
+            // Performs UTF-8 validation.
+            let values = values.freeze().to_utf8view()?;
+            let column = StringChunked::with_chunk(name.clone(), values).into_series();
+
+            let column = if dtype.is_string() {
+                column
+            } else {
+                column.strict_cast(dtype)?
+            };
+
+            Ok(column.into_column())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    DataFrame::new(lines.len(), columns)
+}
+
+/// Byte spans of the projected columns in every line.
+enum ByteSpans<'a> {
+    Bytes(&'a [(usize, usize)]),
+    Chars {
+        /// Byte offsets of the distinct span boundaries, for every line.
+        offsets: Vec<usize>,
+        n_boundaries: usize,
+        /// Index of the start and end boundary of every span.
+        spans: Vec<(usize, usize)>,
+    },
+}
+
+impl<'a> ByteSpans<'a> {
+    fn new(lines: &[&[u8]], spans: &'a [(usize, usize)], unit: FwfSpanUnit) -> Self {
+        if unit == FwfSpanUnit::Bytes {
+            return Self::Bytes(spans);
+        }
+
+        let mut boundaries = spans
+            .iter()
+            .flat_map(|&(start, end)| [start, end])
+            .collect::<Vec<_>>();
+        boundaries.sort_unstable();
+        boundaries.dedup();
+        let boundary_idx = |offset| boundaries.binary_search(&offset).unwrap();
+        let spans = spans
+            .iter()
+            .map(|&(start, end)| (boundary_idx(start), boundary_idx(end)))
+            .collect();
+
+        let mut offsets = Vec::with_capacity(lines.len() * boundaries.len());
+        for line in lines {
+            char_to_byte_offsets(line, &boundaries, &mut offsets);
+        }
+
+        Self::Chars {
+            offsets,
+            n_boundaries: boundaries.len(),
+            spans,
+        }
+    }
+
+    fn get(&self, line: &[u8], line_idx: usize, column_idx: usize) -> (usize, usize) {
+        match self {
+            Self::Bytes(spans) => {
+                let (start, end) = spans[column_idx];
+                (start.min(line.len()), end.min(line.len()))
+            },
+            Self::Chars {
+                offsets,
+                n_boundaries,
+                spans,
+            } => {
+                let offsets = &offsets[line_idx * n_boundaries..][..*n_boundaries];
+                let (start, end) = spans[column_idx];
+                (offsets[start], offsets[end])
+            },
+        }
+    }
+}
+
+/// Pushes the byte offset of every character index in `char_offsets`, which must be sorted and
+/// distinct, or the length of `line` if it is shorter. The line is only scanned once.
+fn char_to_byte_offsets(line: &[u8], char_offsets: &[usize], out: &mut Vec<usize>) {
+    let mut char_starts = line
+        .iter()
+        .enumerate()
+        // Skip UTF-8 continuation bytes.
+        .filter(|(_, b)| (**b as i8) >= -0x40)
+        .map(|(i, _)| i);
+    let mut n_consumed = 0;
+
+    for &char_idx in char_offsets {
+        let offset = char_starts.nth(char_idx - n_consumed);
+        n_consumed = char_idx + 1;
+        out.push(offset.unwrap_or(line.len()));
+    }
+}
+
+fn trim(value: &[u8], trim: FwfTrim) -> &[u8] {
+    let is_space = |b: &u8| matches!(*b, b' ' | b'\t');
+
+    let start = match trim {
+        FwfTrim::Start | FwfTrim::Both => value.iter().position(|b| !is_space(b)),
+        FwfTrim::None | FwfTrim::End => Some(0),
+    };
+    let Some(start) = start else {
+        return &[];
+    };
+    let end = match trim {
+        FwfTrim::End | FwfTrim::Both => value
+            .iter()
+            .rposition(|b| !is_space(b))
+            .map_or(0, |i| i + 1),
+        FwfTrim::None | FwfTrim::Start => value.len(),
+    };
+
+    &value[start..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_fwf_chunk() {
+        let schema = Arc::new(Schema::from_iter([
+            Field::new("id".into(), DataType::Int64),
+            Field::new("name".into(), DataType::String),
+            Field::new("amount".into(), DataType::Float64),
+        ]));
+        let options = FwfReadOptions::try_from_widths(schema.clone(), &[4, 8, 6]).unwrap();
+
+        let data = b"   1alice     1.5\r\n   2        -2\n   3bob\n";
+        let df = parse_fwf_chunk(data, &options, &schema).unwrap();
+
+        assert_eq!(
+            df.column("id").unwrap().i64().unwrap().to_vec(),
+            &[Some(1), Some(2), Some(3)]
+        );
+        assert_eq!(
+            df.column("name")
+                .unwrap()
+                .str()
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            &[Some("alice"), None, Some("bob")]
+        );
+        assert_eq!(
+            df.column("amount").unwrap().f64().unwrap().to_vec(),
+            &[Some(1.5), Some(-2.0), None]
+        );
+
+        let projected = Schema::from_iter([Field::new("name".into(), DataType::String)]);
+        let df = parse_fwf_chunk(data, &options.with_trim(FwfTrim::None), &projected).unwrap();
+        assert_eq!(
+            df.column("name")
+                .unwrap()
+                .str()
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            &[Some("alice   "), Some("        "), Some("bob")]
+        );
+    }
+
+    #[test]
+    fn test_parse_fwf_chunk_trim_end_blank() {
+        let schema = Arc::new(Schema::from_iter([
+            Field::new("a".into(), DataType::String),
+            Field::new("b".into(), DataType::String),
+        ]));
+        let options = FwfReadOptions::try_from_widths(schema.clone(), &[2, 3])
+            .unwrap()
+            .with_trim(FwfTrim::End);
+
+        let df = parse_fwf_chunk(b"x    \n  \n y z", &options, &schema).unwrap();
+
+        assert_eq!(
+            df.column("a")
+                .unwrap()
+                .str()
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            &[Some("x"), None, Some(" y")]
+        );
+        assert_eq!(
+            df.column("b")
+                .unwrap()
+                .str()
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            &[None, None, Some(" z")]
+        );
+    }
+
+    #[test]
+    fn test_parse_fwf_chunk_chars() {
+        let schema = Arc::new(Schema::from_iter([
+            Field::new("a".into(), DataType::String),
+            Field::new("b".into(), DataType::String),
+        ]));
+        let options = FwfReadOptions::try_new(schema.clone(), vec![(0, 3), (3, 5)])
+            .unwrap()
+            .with_span_unit(FwfSpanUnit::Chars);
+
+        let df = parse_fwf_chunk("äöüxy\nabcde".as_bytes(), &options, &schema).unwrap();
+
+        assert_eq!(
+            df.column("a")
+                .unwrap()
+                .str()
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            &[Some("äöü"), Some("abc")]
+        );
+        assert_eq!(
+            df.column("b")
+                .unwrap()
+                .str()
+                .unwrap()
+                .iter()
+                .collect::<Vec<_>>(),
+            &[Some("xy"), Some("de")]
+        );
+    }
+}
@@ -8,6 +8,7 @@ use polars_error::{feature_gated, to_compute_err};
 use crate::utils::file::{Writeable, WriteableTrait};
 use crate::utils::stream_buf_reader::ReaderSource;
 use crate::utils::sync_on_close::SyncOnCloseType;
+use crate::utils::transcode::Utf8Transcoder;
 
 /// Represents the compression algorithms that we have decoders for
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
@@ -246,7 +247,8 @@ impl Read for CompressedReader {
 ///
 /// Implements `BufRead`, allowing uniform access regardless of whether
 /// the underlying data is an in-memory slice, a raw stream, or a
-/// compressed stream (gzip/zlib/zstd).
+/// compressed stream (gzip/zlib/zstd). The output can additionally be
+/// transcoded into UTF-8, see [`Self::with_transcoder`].
 ///
 /// This is the generic successor to [`CompressedReader`], which only
 /// supports in-memory (`Buffer<u8>`) sources.
@@ -262,6 +264,47 @@ pub enum ByteSourceReader<R: BufRead> {
     Zlib(flate2::bufread::ZlibDecoder<R>),
     #[cfg(feature = "decompress")]
     Zstd(zstd::Decoder<'static, R>),
+    Transcoded(Box<TranscodedReader<R>>),
+}
+
+/// The output of a [`ByteSourceReader`] transcoded into UTF-8.
+pub struct TranscodedReader<R: BufRead> {
+    inner: ByteSourceReader<R>,
+    transcoder: Utf8Transcoder,
+    finished: bool,
+}
+
+impl<R: BufRead> TranscodedReader<R> {
+    fn read_next_slice(
+        &mut self,
+        prev_leftover: &Buffer<u8>,
+        read_size: usize,
+        uncompressed_size_hint: Option<usize>,
+    ) -> std::io::Result<(Buffer<u8>, usize)> {
+        let prev_len = prev_leftover.len();
+        let mut buf = Vec::with_capacity(prev_len.saturating_add(cmp::min(
+            read_size,
+            uncompressed_size_hint.unwrap_or(4 * 1024 * 1024),
+        )));
+        buf.extend_from_slice(prev_leftover);
+
+        // A chunk may consist of only part of a character, so keep reading until we have output
+        // or reached the end.
+        while !self.finished && buf.len() == prev_len {
+            let (slice, bytes_read) =
+                self.inner
+                    .read_next_slice(&Buffer::new(), read_size, uncompressed_size_hint)?;
+            if bytes_read == 0 {
+                self.transcoder.finish(&mut buf);
+                self.finished = true;
+            } else {
+                self.transcoder.transcode(&slice, &mut buf);
+            }
+        }
+
+        let bytes_read = buf.len() - prev_len;
+        Ok((Buffer::from_vec(buf), bytes_read))
+    }
 }
 
 impl<R: BufRead> ByteSourceReader<R> {
@@ -283,17 +326,32 @@ impl<R: BufRead> ByteSourceReader<R> {
         })
     }
 
+    /// Transcodes the output of this reader into UTF-8 if a transcoder is given.
+    pub fn with_transcoder(self, transcoder: Option<Utf8Transcoder>) -> Self {
+        match transcoder {
+            None => self,
+            Some(transcoder) => Self::Transcoded(Box::new(TranscodedReader {
+                inner: self,
+                transcoder,
+                finished: false,
+            })),
+        }
+    }
+
     pub fn is_compressed(&self) -> bool {
-        !matches!(
-            &self,
-            Self::UncompressedMemory { .. } | Self::UncompressedStream(_)
-        )
+        match self {
+            Self::UncompressedMemory { .. } | Self::UncompressedStream(_) => false,
+            Self::Transcoded(reader) => reader.inner.is_compressed(),
+            #[cfg(feature = "decompress")]
+            _ => true,
+        }
     }
 
     pub fn compression(&self) -> Option<SupportedCompression> {
         match self {
             Self::UncompressedMemory { .. } => None,
             Self::UncompressedStream(_) => None,
+            Self::Transcoded(reader) => reader.inner.compression(),
             #[cfg(feature = "decompress")]
             Self::Gzip(_) => Some(SupportedCompression::GZIP),
             #[cfg(feature = "decompress")]
@@ -356,6 +414,9 @@ impl<R: BufRead> ByteSourceReader<R> {
                 return Ok((new_slice, bytes_read));
             },
             Self::UncompressedStream(reader) => reader,
+            Self::Transcoded(reader) => {
+                return reader.read_next_slice(prev_leftover, read_size, uncompressed_size_hint);
+            },
             #[cfg(feature = "decompress")]
             Self::Gzip(reader) => reader,
             #[cfg(feature = "decompress")]
@@ -6,9 +6,11 @@ pub use other::*;
 pub mod byte_source;
 pub mod file;
 pub mod mkdir;
+pub mod rejects;
 pub mod slice;
 pub mod stream_buf_reader;
 pub mod sync_on_close;
+pub mod transcode;
 
 /// Excludes only the unreserved URI characters in RFC-3986:
 ///
@@ -0,0 +1,77 @@
+//! Rows that a reader diverted instead of failing the read.
+
+use std::ops::Range;
+
+use polars_core::frame::DataFrame;
+use polars_error::{PolarsError, PolarsResult};
+
+/// A row that could not be read.
+#[derive(Debug)]
+pub struct RejectedRow {
+    /// Byte range of the row in the chunk it was read from, excluding the line terminator.
+    pub range: Range<usize>,
+    pub error: PolarsError,
+}
+
+/// Parses the `rows` of `bytes`, diverting the rows that fail `check_row` or `parse`.
+///
+/// `parse` is called with the bytes of a run of consecutive rows and the number of rows in it.
+/// Runs of rows that pass `check_row` are parsed at once; a run that fails to parse is split in
+/// halves that are parsed again, so only the rows around a failure are parsed on their own.
+pub fn parse_with_rejects(
+    bytes: &[u8],
+    rows: &[Range<usize>],
+    check_row: impl Fn(&[u8]) -> PolarsResult<()>,
+    parse: impl Fn(&[u8], usize) -> PolarsResult<DataFrame>,
+) -> PolarsResult<(DataFrame, Vec<RejectedRow>)> {
+    let mut dfs = Vec::new();
+    let mut rejected = Vec::new();
+
+    let mut run_start = 0;
+    for (i, range) in rows.iter().enumerate() {
+        if let Err(error) = check_row(&bytes[range.clone()]) {
+            parse_run(bytes, &rows[run_start..i], &parse, &mut dfs, &mut rejected);
+            rejected.push(RejectedRow {
+                range: range.clone(),
+                error,
+            });
+            run_start = i + 1;
+        }
+    }
+    parse_run(bytes, &rows[run_start..], &parse, &mut dfs, &mut rejected);
+
+    let mut dfs = dfs.into_iter();
+    let mut df = match dfs.next() {
+        Some(df) => df,
+        None => parse(&[], 0)?,
+    };
+    for other in dfs {
+        df.vstack_mut_owned(other)?;
+    }
+    Ok((df, rejected))
+}
+
+fn parse_run(
+    bytes: &[u8],
+    run: &[Range<usize>],
+    parse: &impl Fn(&[u8], usize) -> PolarsResult<DataFrame>,
+    dfs: &mut Vec<DataFrame>,
+    rejected: &mut Vec<RejectedRow>,
+) {
+    let (Some(first), Some(last)) = (run.first(), run.last()) else {
+        return;
+    };
+
+    match parse(&bytes[first.start..last.end], run.len()) {
+        Ok(df) => dfs.push(df),
+        Err(error) if run.len() == 1 => rejected.push(RejectedRow {
+            range: first.clone(),
+            error,
+        }),
+        Err(_) => {
+            let (left, right) = run.split_at(run.len() / 2);
+            parse_run(bytes, left, parse, dfs, rejected);
+            parse_run(bytes, right, parse, dfs, rejected);
+        },
+    }
+}
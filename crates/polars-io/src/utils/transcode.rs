@@ -0,0 +1,224 @@
+//! Transcoding of text in non-UTF-8 encodings into UTF-8.
+
+/// The characters of the bytes `0x80..=0x9F` in Windows-1252. The five bytes that are unassigned
+/// map to the C1 control characters, like in Latin-1.
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '\u{20AC}', '\u{81}', '\u{201A}', '\u{192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{2C6}', '\u{2030}', '\u{160}', '\u{2039}', '\u{152}', '\u{8D}', '\u{17D}', '\u{8F}',
+    '\u{90}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{2DC}', '\u{2122}', '\u{161}', '\u{203A}', '\u{153}', '\u{9D}', '\u{17E}', '\u{178}',
+];
+
+const UTF16_LE_BOM: [u8; 2] = [0xFF, 0xFE];
+const UTF16_BE_BOM: [u8; 2] = [0xFE, 0xFF];
+
+/// Encoding of the text that is transcoded by a [`Utf8Transcoder`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum SourceEncoding {
+    /// ISO-8859-1.
+    Latin1,
+    Windows1252,
+    /// UTF-16 with the byte order taken from the byte order mark, little endian if there is none.
+    Utf16,
+    Utf16Le,
+    Utf16Be,
+}
+
+/// Transcodes text into UTF-8 chunk by chunk.
+///
+/// Characters that are split over two chunks are completed once the next chunk is transcoded. A
+/// leading UTF-16 byte order mark is removed. Unpaired UTF-16 surrogates are replaced with `�`.
+#[derive(Clone, Debug)]
+pub struct Utf8Transcoder {
+    encoding: SourceEncoding,
+    /// Whether the start of the input, which may contain a byte order mark, has been seen.
+    started: bool,
+    /// Bytes at the end of the previous chunk that don't form a complete character yet.
+    pending: Vec<u8>,
+}
+
+impl Utf8Transcoder {
+    pub fn new(encoding: SourceEncoding) -> Self {
+        Self {
+            encoding,
+            started: false,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Transcodes the next chunk of the input and appends it to `out`.
+    pub fn transcode(&mut self, input: &[u8], out: &mut Vec<u8>) {
+        match self.encoding {
+            SourceEncoding::Latin1 => {
+                out.reserve(input.len());
+                for &b in input {
+                    push_char(b as char, out);
+                }
+            },
+            SourceEncoding::Windows1252 => {
+                out.reserve(input.len());
+                for &b in input {
+                    let c = match b {
+                        0x80..=0x9F => WINDOWS_1252_HIGH[(b - 0x80) as usize],
+                        _ => b as char,
+                    };
+                    push_char(c, out);
+                }
+            },
+            SourceEncoding::Utf16 | SourceEncoding::Utf16Le | SourceEncoding::Utf16Be => {
+                self.transcode_utf16(input, out)
+            },
+        }
+    }
+
+    /// Flushes the characters that were left incomplete at the end of the input.
+    pub fn finish(&mut self, out: &mut Vec<u8>) {
+        if !self.pending.is_empty() {
+            self.pending.clear();
+            push_char(char::REPLACEMENT_CHARACTER, out);
+        }
+    }
+
+    fn transcode_utf16(&mut self, input: &[u8], out: &mut Vec<u8>) {
+        let joined;
+        let mut bytes = if self.pending.is_empty() {
+            input
+        } else {
+            let mut buf = std::mem::take(&mut self.pending);
+            buf.extend_from_slice(input);
+            joined = buf;
+            joined.as_slice()
+        };
+
+        if !self.started {
+            if bytes.len() < 2 {
+                self.pending.extend_from_slice(bytes);
+                return;
+            }
+            self.started = true;
+
+            let bom = [bytes[0], bytes[1]];
+            self.encoding = match self.encoding {
+                SourceEncoding::Utf16 if bom == UTF16_BE_BOM => SourceEncoding::Utf16Be,
+                SourceEncoding::Utf16 => SourceEncoding::Utf16Le,
+                encoding => encoding,
+            };
+            let expected_bom = if self.encoding == SourceEncoding::Utf16Be {
+                UTF16_BE_BOM
+            } else {
+                UTF16_LE_BOM
+            };
+            if bom == expected_bom {
+                bytes = &bytes[2..];
+            }
+        }
+
+        let from_bytes = if self.encoding == SourceEncoding::Utf16Be {
+            u16::from_be_bytes
+        } else {
+            u16::from_le_bytes
+        };
+
+        let mut n_units = bytes.len() / 2;
+        // Keep a trailing high surrogate until we've seen the low surrogate that follows it.
+        if n_units > 0 {
+            let last = from_bytes([bytes[2 * n_units - 2], bytes[2 * n_units - 1]]);
+            if (0xD800..0xDC00).contains(&last) {
+                n_units -= 1;
+            }
+        }
+        let (complete, rest) = bytes.split_at(2 * n_units);
+        self.pending.extend_from_slice(rest);
+
+        out.reserve(n_units);
+        let units = complete
+            .chunks_exact(2)
+            .map(|unit| from_bytes([unit[0], unit[1]]));
+        for c in char::decode_utf16(units) {
+            push_char(c.unwrap_or(char::REPLACEMENT_CHARACTER), out);
+        }
+    }
+}
+
+#[inline]
+fn push_char(c: char, out: &mut Vec<u8>) {
+    if c.is_ascii() {
+        out.push(c as u8);
+    } else {
+        out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transcode_chunked(encoding: SourceEncoding, input: &[u8], chunk_size: usize) -> String {
+        let mut transcoder = Utf8Transcoder::new(encoding);
+        let mut out = Vec::new();
+        for chunk in input.chunks(chunk_size) {
+            transcoder.transcode(chunk, &mut out);
+        }
+        transcoder.finish(&mut out);
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_transcode_single_byte() {
+        let input = b"a,\xe9\x80\n";
+        assert_eq!(
+            transcode_chunked(SourceEncoding::Latin1, input, 1),
+            "a,é\u{80}\n"
+        );
+        assert_eq!(
+            transcode_chunked(SourceEncoding::Windows1252, input, 1),
+            "a,é€\n"
+        );
+    }
+
+    #[test]
+    fn test_transcode_utf16() {
+        let text = "a,b\n😀,é\n";
+        let le = text
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect::<Vec<_>>();
+        let be = text
+            .encode_utf16()
+            .flat_map(u16::to_be_bytes)
+            .collect::<Vec<_>>();
+
+        for chunk_size in 1..8 {
+            let with_bom = [&UTF16_LE_BOM[..], &le].concat();
+            assert_eq!(
+                transcode_chunked(SourceEncoding::Utf16, &with_bom, chunk_size),
+                text
+            );
+            assert_eq!(
+                transcode_chunked(SourceEncoding::Utf16, &le, chunk_size),
+                text
+            );
+            assert_eq!(
+                transcode_chunked(SourceEncoding::Utf16Le, &le, chunk_size),
+                text
+            );
+
+            let with_bom = [&UTF16_BE_BOM[..], &be].concat();
+            assert_eq!(
+                transcode_chunked(SourceEncoding::Utf16, &with_bom, chunk_size),
+                text
+            );
+            assert_eq!(
+                transcode_chunked(SourceEncoding::Utf16Be, &be, chunk_size),
+                text
+            );
+        }
+
+        // Truncated input and unpaired surrogates.
+        assert_eq!(transcode_chunked(SourceEncoding::Utf16Le, b"a\0b", 2), "a�");
+        assert_eq!(
+            transcode_chunked(SourceEncoding::Utf16Le, b"\x3d\xd8a\0", 4),
+            "�a"
+        );
+    }
+}
@@ -6,6 +6,8 @@ mod cached_arenas;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+#[cfg(feature = "pivot")]
+mod pivot;
 
 use std::num::NonZeroUsize;
 use std::sync::mpsc::{Receiver, sync_channel};
@@ -556,6 +558,10 @@ impl LazyFrame {
             Option<std::time::Duration>,
         ) -> PolarsResult<()>,
     {
+        #[cfg(feature = "pivot")]
+        {
+            self = self.resolve_deferred_pivots(Engine::InMemory)?;
+        }
         let (mut lp_arena, mut expr_arena) = self.get_arenas();
 
         let mut scratch = vec![];
@@ -621,7 +627,19 @@ impl LazyFrame {
     /// `engine`.
     ///
     /// The query is optimized prior to execution.
-    pub fn collect_with_engine(mut self, engine: Engine) -> PolarsResult<QueryResult> {
+    pub fn collect_with_engine(self, engine: Engine) -> PolarsResult<QueryResult> {
+        self.collect_with_engine_options(engine, StreamingExecutionOptions::default())
+    }
+
+    /// Execute all the lazy operations and collect them into a [`DataFrame`] using a specified
+    /// `engine`, running the query with the given options if it uses the streaming engine.
+    ///
+    /// The query is optimized prior to execution.
+    pub fn collect_with_engine_options(
+        mut self,
+        engine: Engine,
+        streaming_options: StreamingExecutionOptions,
+    ) -> PolarsResult<QueryResult> {
         let engine = match engine {
             Engine::Streaming => Engine::Streaming,
             _ if std::env::var("POLARS_FORCE_STREAMING").as_deref() == Ok("1") => Engine::Streaming,
@@ -629,6 +647,11 @@ impl LazyFrame {
             v => v,
         };
 
+        #[cfg(feature = "pivot")]
+        {
+            self = self.resolve_deferred_pivots(engine)?;
+        }
+
         if engine != Engine::Streaming
             && std::env::var("POLARS_AUTO_STREAMING").as_deref() == Ok("1")
         {
@@ -652,10 +675,11 @@ impl LazyFrame {
 
         match engine {
             Engine::Streaming => feature_gated!("streaming", {
-                polars_stream::run_query(
+                polars_stream::run_query_with_options(
                     ir_plan.lp_top,
                     &mut ir_plan.lp_arena,
                     &mut ir_plan.expr_arena,
+                    streaming_options,
                 )
             }),
             Engine::InMemory | Engine::Gpu => {
@@ -1057,6 +1081,7 @@ impl LazyFrame {
                 maintain_order: false,
                 dynamic_options: None,
                 rolling_options: None,
+                grouping_sets: None,
             }
         }
 
@@ -1068,6 +1093,7 @@ impl LazyFrame {
                 keys,
                 predicates: vec![],
                 maintain_order: false,
+                grouping_sets: None,
             }
         }
     }
@@ -1107,6 +1133,7 @@ impl LazyFrame {
             maintain_order: true,
             dynamic_options: None,
             rolling_options: Some(options),
+            grouping_sets: None,
         }
     }
 
@@ -1153,6 +1180,7 @@ impl LazyFrame {
             maintain_order: true,
             dynamic_options: Some(options),
             rolling_options: None,
+            grouping_sets: None,
         }
     }
 
@@ -1175,6 +1203,7 @@ impl LazyFrame {
                 maintain_order: true,
                 dynamic_options: None,
                 rolling_options: None,
+                grouping_sets: None,
             }
         }
 
@@ -1186,6 +1215,7 @@ impl LazyFrame {
                 keys,
                 predicates: vec![],
                 maintain_order: true,
+                grouping_sets: None,
             }
         }
     }
@@ -1810,6 +1840,45 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Pivot without knowing the distinct values of `on` up front.
+    ///
+    /// The distinct values of `on` are discovered when the query is collected: the columns used by
+    /// the pivot are collected once, with the engine and optimizations of the query, and their
+    /// distinct `on` values (in order of appearance) are used like the `on_columns` of
+    /// [`LazyFrame::pivot`]. Since the output columns are unknown before that, resolving the schema
+    /// or explaining a plan containing this pivot errors.
+    ///
+    /// If `max_on_columns` is set, this errors when `on` has more distinct values than that.
+    #[cfg(feature = "pivot")]
+    #[expect(clippy::too_many_arguments)]
+    pub fn pivot_deferred(
+        self,
+        on: Selector,
+        index: Selector,
+        values: Selector,
+        agg: Expr,
+        maintain_order: bool,
+        separator: PlSmallStr,
+        column_naming: PivotColumnNaming,
+        max_on_columns: Option<usize>,
+    ) -> LazyFrame {
+        let opt_state = self.get_opt_state();
+        let lp = self
+            .get_plan_builder()
+            .pivot_deferred(
+                on,
+                index,
+                values,
+                agg,
+                maintain_order,
+                separator,
+                column_naming,
+                max_on_columns,
+            )
+            .build();
+        Self::from_logical_plan(lp, opt_state)
+    }
+
     /// Unpivot the DataFrame from wide to long format.
     ///
     /// See [`UnpivotArgsIR`] for information on how to unpivot a DataFrame.
@@ -1825,6 +1894,16 @@ impl LazyFrame {
         self.slice(0, n)
     }
 
+    /// Take a random sample of the rows.
+    ///
+    /// Unlike [`DataFrame::sample_n_literal`], this doesn't require the input to be
+    /// materialized: the streaming engine samples every morsel as it arrives and keeps at most
+    /// a bounded reservoir for [`SampleMethod::Reservoir`]. The order of the rows is maintained.
+    /// See [`SampleOptions`] for the available methods.
+    pub fn sample(self, options: SampleOptions) -> LazyFrame {
+        self.map_private(DslFunction::Sample(options))
+    }
+
     /// Apply a function/closure once the logical plan get executed.
     ///
     /// The function has access to the whole materialized DataFrame at the time it is
@@ -1992,6 +2071,41 @@ pub struct LazyGroupBy {
     dynamic_options: Option<DynamicGroupOptions>,
     #[cfg(feature = "dynamic_group_by")]
     rolling_options: Option<RollingGroupOptions>,
+    grouping_sets: Option<GroupingSets>,
+}
+
+/// The grouping sets of a [`LazyGroupBy`], as indices into its keys.
+#[derive(Clone, Debug)]
+struct GroupingSets {
+    key_names: Vec<PlSmallStr>,
+    sets: Vec<Vec<usize>>,
+    grouping_id: Option<PlSmallStr>,
+}
+
+/// Splits an aggregation into one over a finer grouping and one that combines its results into
+/// those of a coarser grouping, or returns `None` if the aggregation can't be combined that way.
+fn decompose_agg(agg: &Expr) -> Option<(Expr, Expr)> {
+    let name = expr_output_name(agg).ok()?;
+    let inner = match agg {
+        Expr::Alias(inner, _) => inner.as_ref(),
+        agg => agg,
+    };
+    let partial = Arc::new(col(name.clone()));
+    let combine = match inner {
+        Expr::Agg(AggExpr::Sum(_) | AggExpr::Count { .. }) | Expr::Len => {
+            Expr::Agg(AggExpr::Sum(partial))
+        },
+        Expr::Agg(AggExpr::Min { propagate_nans, .. }) => Expr::Agg(AggExpr::Min {
+            input: partial,
+            propagate_nans: *propagate_nans,
+        }),
+        Expr::Agg(AggExpr::Max { propagate_nans, .. }) => Expr::Agg(AggExpr::Max {
+            input: partial,
+            propagate_nans: *propagate_nans,
+        }),
+        _ => return None,
+    };
+    Some((agg.clone().alias(name.clone()), combine.alias(name)))
 }
 
 impl From<LazyGroupBy> for LazyFrame {
@@ -2030,6 +2144,238 @@ impl LazyGroupBy {
         self
     }
 
+    /// Aggregate over several grouping sets in one pass over the input.
+    ///
+    /// Every set is a list of indices into the keys of this group_by; the result is the
+    /// concatenation of the aggregations grouped by each of the sets, in order, with the keys
+    /// that are not part of a set set to null. `HAVING` predicates apply to every set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use polars_core::prelude::*;
+    /// use polars_lazy::prelude::*;
+    ///
+    /// fn example(df: DataFrame) -> PolarsResult<LazyFrame> {
+    ///     // group by (year, month), (year) and ()
+    ///     Ok(df
+    ///         .lazy()
+    ///         .group_by([col("year"), col("month")])
+    ///         .grouping_sets(vec![vec![0, 1], vec![0], vec![]])?
+    ///         .with_grouping_id("grouping_id")
+    ///         .agg([col("rain").sum()]))
+    /// }
+    /// ```
+    pub fn grouping_sets(mut self, sets: Vec<Vec<usize>>) -> PolarsResult<Self> {
+        #[cfg(feature = "dynamic_group_by")]
+        polars_ensure!(
+            self.dynamic_options.is_none() && self.rolling_options.is_none(),
+            InvalidOperation: "grouping sets are not supported for dynamic or rolling group_by"
+        );
+        polars_ensure!(
+            self.keys.len() < u32::BITS as usize,
+            InvalidOperation: "grouping sets support at most {} keys; got {}",
+            u32::BITS - 1, self.keys.len()
+        );
+        polars_ensure!(!sets.is_empty(), InvalidOperation: "expected at least one grouping set");
+        for set in &sets {
+            for (i, &idx) in set.iter().enumerate() {
+                polars_ensure!(
+                    idx < self.keys.len(),
+                    OutOfBounds: "grouping set index {} is out of bounds for {} keys",
+                    idx, self.keys.len()
+                );
+                polars_ensure!(
+                    !set[..i].contains(&idx),
+                    Duplicate: "grouping set contains key {} more than once", idx
+                );
+            }
+        }
+        let key_names = self
+            .keys
+            .iter()
+            .map(expr_output_name)
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let grouping_id = self.grouping_sets.take().and_then(|gs| gs.grouping_id);
+        self.grouping_sets = Some(GroupingSets {
+            key_names,
+            sets,
+            grouping_id,
+        });
+        Ok(self)
+    }
+
+    /// Aggregate over the grouping sets `ROLLUP(k1, ..., kn)`: `(k1, ..., kn)`,
+    /// `(k1, ..., kn-1)`, ..., `(k1)` and `()`.
+    pub fn rollup(self) -> PolarsResult<Self> {
+        let n = self.keys.len();
+        let sets = (0..=n).rev().map(|len| (0..len).collect()).collect();
+        self.grouping_sets(sets)
+    }
+
+    /// Aggregate over the grouping sets `CUBE(k1, ..., kn)`: every subset of the keys.
+    pub fn cube(self) -> PolarsResult<Self> {
+        let n = self.keys.len();
+        polars_ensure!(
+            n < u32::BITS as usize,
+            InvalidOperation: "grouping sets support at most {} keys; got {}", u32::BITS - 1, n
+        );
+        // Ordered by grouping id, so the finest set comes first and the empty set last.
+        let sets = (0..1u32 << n)
+            .map(|id| (0..n).filter(|i| id & (1 << (n - 1 - i)) == 0).collect())
+            .collect();
+        self.grouping_sets(sets)
+    }
+
+    /// Add a `UInt32` column with the given name that identifies the grouping set of each row.
+    ///
+    /// Bit `n - 1 - i` of the grouping id is set if the `i`-th of the `n` keys is not part of
+    /// the grouping set, as with `GROUPING(k1, ..., kn)` in SQL. This has no effect if no
+    /// grouping sets are set.
+    pub fn with_grouping_id(mut self, name: impl Into<PlSmallStr>) -> Self {
+        if let Some(grouping_sets) = &mut self.grouping_sets {
+            grouping_sets.grouping_id = Some(name.into());
+        }
+        self
+    }
+
+    fn agg_grouping_sets(self, grouping_sets: GroupingSets, aggs: &[Expr]) -> LazyFrame {
+        let GroupingSets {
+            key_names,
+            sets,
+            grouping_id,
+        } = grouping_sets;
+        let n_keys = self.keys.len();
+        let maintain_order = self.maintain_order;
+
+        let input = LazyFrame::from_logical_plan(self.logical_plan, self.opt_state);
+
+        let decomposed = if self.predicates.is_empty() {
+            aggs.iter().map(decompose_agg).collect::<Option<Vec<_>>>()
+        } else {
+            None
+        };
+
+        let set_aggregations = if let Some(decomposed) = decomposed {
+            // Aggregate once by the union of all sets and roll the partial aggregates up into
+            // the coarser sets.
+            let mut finest = sets.iter().flatten().copied().collect::<Vec<_>>();
+            finest.sort_unstable();
+            finest.dedup();
+
+            let (partial_aggs, combine_aggs): (Vec<_>, Vec<_>) = decomposed.into_iter().unzip();
+            let mut lgb = input.group_by(
+                finest
+                    .iter()
+                    .map(|&i| self.keys[i].clone())
+                    .collect::<Vec<_>>(),
+            );
+            lgb.maintain_order = maintain_order;
+            let partial = lgb.agg(partial_aggs).cache();
+
+            sets.iter()
+                .map(|set| {
+                    if set.len() == finest.len() {
+                        partial.clone()
+                    } else if set.is_empty() {
+                        partial.clone().select(combine_aggs.clone())
+                    } else {
+                        let mut lgb = partial.clone().group_by(
+                            set.iter()
+                                .map(|&i| col(key_names[i].clone()))
+                                .collect::<Vec<_>>(),
+                        );
+                        lgb.maintain_order = maintain_order;
+                        lgb.agg(&combine_aggs)
+                    }
+                })
+                .collect::<Vec<_>>()
+        } else {
+            // All sets aggregate the same cached input, so it is only computed once.
+            let input = input.cache();
+            let predicates = self.predicates;
+
+            sets.iter()
+                .map(|set| {
+                    if set.is_empty() {
+                        // The empty set aggregates the whole input into a single row.
+                        const HAVING: &str = "__POLARS_GROUPING_HAVING";
+                        let mut exprs = aggs.to_vec();
+                        exprs.extend(
+                            predicates
+                                .iter()
+                                .enumerate()
+                                .map(|(i, p)| p.clone().alias(format!("{HAVING}_{i}"))),
+                        );
+                        let mut lf = input.clone().select(exprs);
+                        if let Some(predicate) = (0..predicates.len())
+                            .map(|i| col(format!("{HAVING}_{i}")))
+                            .reduce(|acc, p| acc.and(p))
+                        {
+                            lf = lf.filter(predicate).select([(all()
+                                - by_name(
+                                    (0..predicates.len()).map(|i| format!("{HAVING}_{i}")),
+                                    true,
+                                    false,
+                                ))
+                            .as_expr()]);
+                        }
+                        lf
+                    } else {
+                        let mut lgb = input.clone().group_by(
+                            set.iter()
+                                .map(|&i| self.keys[i].clone())
+                                .collect::<Vec<_>>(),
+                        );
+                        lgb.predicates = predicates.clone();
+                        lgb.maintain_order = maintain_order;
+                        lgb.agg(aggs)
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let inputs = sets
+            .iter()
+            .zip(set_aggregations)
+            .map(|(set, lf)| {
+                let set_names = set.iter().map(|&i| key_names[i].clone());
+                let mut exprs = key_names
+                    .iter()
+                    .enumerate()
+                    .map(|(i, name)| {
+                        if set.contains(&i) {
+                            col(name.clone())
+                        } else {
+                            lit(NULL).alias(name.clone())
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                exprs.push((all() - by_name(set_names, true, false)).as_expr());
+                if let Some(name) = &grouping_id {
+                    let id = (0..n_keys)
+                        .filter(|i| !set.contains(i))
+                        .fold(0u32, |id, i| id | (1 << (n_keys - 1 - i)));
+                    exprs.push(lit(id).alias(name.clone()));
+                }
+                lf.select(exprs).logical_plan
+            })
+            .collect::<Vec<_>>();
+
+        // `grouping_sets` ensures there is at least one set. The keys that are not part of a set
+        // are null, which is cast to the type of the key.
+        let lp = DslPlan::Union {
+            inputs,
+            args: UnionArgs {
+                parallel: true,
+                to_supertypes: true,
+                maintain_order: true,
+                ..Default::default()
+            },
+        };
+        LazyFrame::from_logical_plan(lp, self.opt_state)
+    }
+
     /// Group by and aggregate.
     ///
     /// Select a column with [col] and choose an aggregation.
@@ -2051,7 +2397,11 @@ impl LazyGroupBy {
     ///        ])
     /// }
     /// ```
-    pub fn agg<E: AsRef<[Expr]>>(self, aggs: E) -> LazyFrame {
+    pub fn agg<E: AsRef<[Expr]>>(mut self, aggs: E) -> LazyFrame {
+        if let Some(grouping_sets) = self.grouping_sets.take() {
+            return self.agg_grouping_sets(grouping_sets, aggs.as_ref());
+        }
+
         #[cfg(feature = "dynamic_group_by")]
         let lp = DslBuilder::from(self.logical_plan)
             .group_by(
@@ -0,0 +1,112 @@
+use polars_utils::unique_id::UniqueId;
+
+use super::*;
+
+fn has_deferred_pivot(lp: &DslPlan) -> bool {
+    lp.into_iter()
+        .any(|lp| matches!(lp, DslPlan::PivotDeferred { .. }))
+}
+
+impl LazyFrame {
+    /// Replace every [`DslPlan::PivotDeferred`] with a regular [`DslPlan::Pivot`].
+    ///
+    /// The input of a deferred pivot is collected once with `engine` and the optimizations of this
+    /// query, so that the pivot and everything planned on top of it see the real output schema.
+    pub(super) fn resolve_deferred_pivots(self, engine: Engine) -> PolarsResult<Self> {
+        if !has_deferred_pivot(&self.logical_plan) {
+            return Ok(self);
+        }
+
+        let opt_state = self.opt_state;
+        let mut lp = self.logical_plan;
+        resolve_deferred_pivots(&mut lp, opt_state, engine, &mut PlHashMap::default())?;
+        Ok(Self::from_logical_plan(lp, opt_state))
+    }
+}
+
+fn resolve_deferred_pivots(
+    lp: &mut DslPlan,
+    opt_state: OptFlags,
+    engine: Engine,
+    resolved_caches: &mut PlHashMap<UniqueId, Arc<DslPlan>>,
+) -> PolarsResult<()> {
+    if !has_deferred_pivot(lp) {
+        return Ok(());
+    }
+
+    match lp {
+        // A cached input is shared between branches, only resolve (and execute) it once.
+        DslPlan::Cache { input, id } => {
+            match resolved_caches.get(id) {
+                Some(resolved) => *input = resolved.clone(),
+                None => {
+                    resolve_deferred_pivots(
+                        Arc::make_mut(input),
+                        opt_state,
+                        engine,
+                        resolved_caches,
+                    )?;
+                    resolved_caches.insert(*id, input.clone());
+                },
+            }
+            return Ok(());
+        },
+        DslPlan::PivotDeferred { .. } => {},
+        _ => {
+            return lp.try_for_each_input_mut(|input| {
+                resolve_deferred_pivots(input, opt_state, engine, resolved_caches)
+            });
+        },
+    }
+
+    let DslPlan::PivotDeferred {
+        input,
+        on,
+        index,
+        values,
+        agg,
+        maintain_order,
+        separator,
+        column_naming,
+        max_on_columns,
+    } = std::mem::take(lp)
+    else {
+        unreachable!()
+    };
+
+    let mut input = Arc::unwrap_or_clone(input);
+    resolve_deferred_pivots(&mut input, opt_state, engine, resolved_caches)?;
+
+    let used = (on.clone() | index.clone() | values.clone()).as_expr();
+    let df = LazyFrame::from_logical_plan(input, opt_state)
+        .select([used])
+        .collect_with_engine(engine)?
+        .unwrap_single();
+
+    let on_names = on.into_columns(df.schema(), &Default::default())?;
+    polars_ensure!(!on_names.is_empty(), InvalidOperation: "`pivot` called without `on` columns.");
+
+    let on_columns =
+        df.select(on_names.iter())?
+            .unique_stable(None, UniqueKeepStrategy::First, None)?;
+    if let Some(max_on_columns) = max_on_columns {
+        polars_ensure!(
+            on_columns.height() <= max_on_columns,
+            ComputeError: "`pivot` found {} distinct values for `on`, which exceeds `max_on_columns` ({})",
+            on_columns.height(), max_on_columns
+        );
+    }
+
+    *lp = DslPlan::Pivot {
+        input: Arc::new(df.lazy().logical_plan),
+        on: cols(on_names),
+        on_columns: Arc::new(on_columns),
+        index,
+        values,
+        agg,
+        maintain_order,
+        separator,
+        column_naming,
+    };
+    Ok(())
+}
@@ -62,6 +62,7 @@ impl LazyFrame {
                 deletion_files: None,
                 table_statistics: None,
                 row_count: None,
+                rejects: None,
             },
         )?
         .build()
@@ -23,6 +23,7 @@ pub struct LazyCsvReader {
     cloud_options: Option<CloudOptions>,
     include_file_paths: Option<PlSmallStr>,
     missing_columns_policy: Option<MissingColumnsPolicy>,
+    rejects: Option<PlanCallback<DataFrame, ()>>,
 }
 
 #[cfg(feature = "csv")]
@@ -49,6 +50,7 @@ impl LazyCsvReader {
             cloud_options: Default::default(),
             include_file_paths: None,
             missing_columns_policy: None,
+            rejects: None,
         }
     }
 
@@ -132,6 +134,14 @@ impl LazyCsvReader {
         self
     }
 
+    /// Decode these columns from JSON text into their nested dtype, which must be set through the
+    /// schema or [`with_dtype_overwrite`](Self::with_dtype_overwrite).
+    #[must_use]
+    pub fn with_json_columns(mut self, json_columns: Option<Arc<[PlSmallStr]>>) -> Self {
+        self.read_options.json_columns = json_columns;
+        self
+    }
+
     /// Set whether the CSV file has headers
     #[must_use]
     pub fn with_has_header(mut self, has_header: bool) -> Self {
@@ -261,7 +271,8 @@ impl LazyCsvReader {
             use polars_io::utils::compression::ByteSourceReader;
 
             let bytes_len = bytes.len();
-            let mut reader = ByteSourceReader::from_memory(bytes)?;
+            let mut reader = ByteSourceReader::from_memory(bytes)?
+                .with_transcoder(self.read_options.parse_options.encoding.transcoder());
             let decompressed_size_hint = Some(
                 bytes_len
                     * reader
@@ -269,7 +280,7 @@ impl LazyCsvReader {
                         .map_or(1, |_| ASSUMED_COMPRESSION_RATIO),
             );
 
-            let (inferred_schema, _) = read_until_start_and_infer_schema(
+            let (inferred_schema, _, _) = read_until_start_and_infer_schema(
                 &self.read_options,
                 None,
                 decompressed_size_hint,
@@ -342,6 +353,16 @@ impl LazyCsvReader {
         self.missing_columns_policy = policy;
         self
     }
+
+    /// Pass the rows that could not be read to `rejects` instead of failing the scan.
+    ///
+    /// The rejected rows are passed as a [`DataFrame`] with the columns `path`, `line`, `raw`
+    /// and `error`.
+    #[must_use]
+    pub fn with_rejects(mut self, rejects: Option<PlanCallback<DataFrame, ()>>) -> Self {
+        self.rejects = rejects;
+        self
+    }
 }
 
 impl LazyFileListReader for LazyCsvReader {
@@ -376,6 +397,7 @@ impl LazyFileListReader for LazyCsvReader {
                 deletion_files: None,
                 table_statistics: None,
                 row_count: None,
+                rejects: self.rejects,
             },
         )?
         .build()
@@ -7,7 +7,7 @@ use polars_io::{HiveOptions, RowIndex};
 use polars_plan::dsl::{
     CastColumnsPolicy, DslPlan, ExtraColumnsPolicy, FileScanDsl, MissingColumnsPolicy, ScanSources,
 };
-use polars_plan::prelude::{NDJsonReadOptions, UnifiedScanArgs};
+use polars_plan::prelude::{NDJsonReadOptions, PlanCallback, UnifiedScanArgs};
 use polars_utils::pl_path::PlRefPath;
 use polars_utils::slice_enum::Slice;
 
@@ -28,6 +28,8 @@ pub struct LazyJsonLineReader {
     pub(crate) ignore_errors: bool,
     pub(crate) include_file_paths: Option<PlSmallStr>,
     pub(crate) cloud_options: Option<CloudOptions>,
+    pub(crate) rejects: Option<PlanCallback<DataFrame, ()>>,
+    pub(crate) record_pointer: Option<PlSmallStr>,
 }
 
 impl LazyJsonLineReader {
@@ -49,6 +51,8 @@ impl LazyJsonLineReader {
             n_rows: None,
             include_file_paths: None,
             cloud_options: None,
+            rejects: None,
+            record_pointer: None,
         }
     }
 
@@ -121,6 +125,26 @@ impl LazyJsonLineReader {
         self.include_file_paths = include_file_paths;
         self
     }
+
+    /// Pass the lines that could not be read to `rejects` instead of failing the scan.
+    ///
+    /// The rejected lines are passed as a [`DataFrame`] with the columns `path`, `line`, `raw`
+    /// and `error`.
+    #[must_use]
+    pub fn with_rejects(mut self, rejects: Option<PlanCallback<DataFrame, ()>>) -> Self {
+        self.rejects = rejects;
+        self
+    }
+
+    /// Read every file as a single JSON document, whose records are the elements of the array at
+    /// the JSON pointer `record_pointer`, e.g. `""` for a top-level array or `"/data/items"`.
+    ///
+    /// The document is read in a streaming fashion, so it does not have to fit in memory.
+    #[must_use]
+    pub fn with_record_pointer(mut self, record_pointer: Option<PlSmallStr>) -> Self {
+        self.record_pointer = record_pointer;
+        self
+    }
 }
 
 impl LazyFileListReader for LazyJsonLineReader {
@@ -145,6 +169,7 @@ impl LazyFileListReader for LazyJsonLineReader {
             deletion_files: None,
             table_statistics: None,
             row_count: None,
+            rejects: self.rejects,
         };
 
         let options = NDJsonReadOptions {
@@ -155,6 +180,7 @@ impl LazyFileListReader for LazyJsonLineReader {
             ignore_errors: self.ignore_errors,
             schema: self.schema,
             schema_overwrite: self.schema_overwrite,
+            record_pointer: self.record_pointer,
         };
 
         let scan_type = Box::new(FileScanDsl::NDJson { options });
@@ -1,6 +1,7 @@
 use polars_buffer::Buffer;
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
+use polars_io::parquet::encryption::ParquetKeyRetriever;
 use polars_io::parquet::read::ParallelStrategy;
 use polars_io::prelude::ParquetOptions;
 use polars_io::{HiveOptions, RowIndex};
@@ -25,6 +26,8 @@ pub struct ScanArgsParquet {
     pub glob: bool,
     pub include_file_paths: Option<PlSmallStr>,
     pub allow_missing_columns: bool,
+    /// Resolves the keys of encrypted files.
+    pub decryption: Option<ParquetKeyRetriever>,
 }
 
 impl Default for ScanArgsParquet {
@@ -43,6 +46,7 @@ impl Default for ScanArgsParquet {
             glob: true,
             include_file_paths: None,
             allow_missing_columns: false,
+            decryption: None,
         }
     }
 }
@@ -70,6 +74,7 @@ impl LazyFileListReader for LazyParquetReader {
             parallel: self.args.parallel,
             low_memory: self.args.low_memory,
             use_statistics: self.args.use_statistics,
+            decryption: self.args.decryption,
         };
 
         let unified_scan_args = UnifiedScanArgs {
@@ -100,6 +105,7 @@ impl LazyFileListReader for LazyParquetReader {
             deletion_files: None,
             table_statistics: None,
             row_count: None,
+            rejects: None,
         };
 
         let mut lf: LazyFrame =
@@ -480,6 +480,7 @@ fn create_physical_plan_impl(
                         feature = "ipc",
                         feature = "csv",
                         feature = "json",
+                        feature = "avro",
                         feature = "scan_lines"
                     )),
                     expect(unreachable_patterns)
@@ -480,6 +480,7 @@ where
         deletion_files,
         table_statistics,
         row_count,
+        rejects: _,
     } = unified_scan_args.as_mut()
     else {
         panic!("{unified_scan_args:?}")
@@ -1,5 +1,7 @@
 mod global_alloc;
 mod memory_manager;
+mod query_memory;
+mod spill_codec;
 mod spill_context;
 mod spill_file;
 mod spill_frame;
@@ -7,12 +9,15 @@ mod spill_token;
 
 pub use global_alloc::{Allocator, estimate_memory_usage};
 pub use memory_manager::memory_manager;
+pub use polars_config::{SpillFormat, SpillPolicy};
+use polars_core::error::PolarsResult;
+pub use query_memory::QueryMemoryContext;
 pub use spill_context::{
     LeastRecentSpillContext, MostRecentSpillContext, ParameterFreeSpillContext, RandomSpillContext,
     SpillContext,
 };
 pub use spill_file::{flush_ooc_cleanup, init_ooc_cleaner};
-pub use spill_frame::SpillFrame;
+pub use spill_frame::{SpillFrame, SpilledFrame};
 pub use spill_token::{DynSpillToken, PinnedMut, PinnedRef, SpillToken};
 
 pub trait Spillable: Send + Sync + 'static {
@@ -21,9 +26,18 @@ pub trait Spillable: Send + Sync + 'static {
     /// Estimates how many bytes this object takes up in memory.
     fn estimate_byte_size(&self) -> usize;
 
-    /// Spills this value, returning a spilled representation.
-    fn spill(&self, context_id: &str) -> impl Future<Output = Self::Spilled> + Send;
+    /// Spills this value in the given format, returning a spilled representation. Fails if the
+    /// value can't be written, e.g. because the spill directory is full, in which case the value
+    /// stays in memory.
+    fn spill(
+        &self,
+        context_id: &str,
+        format: SpillFormat,
+    ) -> impl Future<Output = PolarsResult<Self::Spilled>> + Send;
 
-    /// Given a previously spilled representation
-    fn unspill(location: &Self::Spilled) -> impl Future<Output = Self> + Send;
+    /// Given a previously spilled representation, restores the value. Fails if the spilled
+    /// data can't be read back, e.g. because it was corrupted on disk.
+    fn unspill(location: &Self::Spilled) -> impl Future<Output = PolarsResult<Self>> + Send
+    where
+        Self: Sized;
 }
@@ -16,7 +16,7 @@ const MAX_PARALLEL_SPILL_TASKS: usize = 64;
 
 use crate::spill_context::UNEXPLORED_SCORE;
 use crate::spill_token::TrySpillError;
-use crate::{DynSpillToken, SpillContext};
+use crate::{DynSpillToken, QueryMemoryContext, SpillContext};
 
 static MEMORY_MANAGER: LazyLock<MemoryManager> = LazyLock::new(MemoryManager::new);
 
@@ -25,8 +25,17 @@ pub fn memory_manager() -> &'static MemoryManager {
     &MEMORY_MANAGER
 }
 
+/// What a spill round tries to bring back under budget.
+enum SpillTarget {
+    /// The memory usage of the whole process.
+    Global,
+    /// The spillable buffers of a single query.
+    Query(Arc<QueryMemoryContext>),
+}
+
 pub struct MemoryManager {
     contexts: RwLock<Vec<Weak<dyn SpillContext>>>,
+    queries: RwLock<Vec<Weak<QueryMemoryContext>>>,
     finding_spill_lock: AsyncMutex<()>,
     spill_semaphore: Arc<AsyncSemaphore>,
     est_spill_in_progress: AtomicU64,
@@ -36,6 +45,7 @@ impl MemoryManager {
     fn new() -> Self {
         Self {
             contexts: RwLock::new(Vec::new()),
+            queries: RwLock::new(Vec::new()),
             finding_spill_lock: AsyncMutex::new(()),
             spill_semaphore: Arc::new(AsyncSemaphore::new(MAX_PARALLEL_SPILL_TASKS)),
             est_spill_in_progress: AtomicU64::new(0),
@@ -48,6 +58,28 @@ impl MemoryManager {
         usage.saturating_sub(likely_dealt_with) > config().ooc_memory_budget_bytes()
     }
 
+    fn spill_target(&self) -> Option<SpillTarget> {
+        if self.should_spill() {
+            return Some(SpillTarget::Global);
+        }
+
+        // If the lock is taken a query is being registered, we'll check it next time.
+        let queries = self.queries.try_read().ok()?;
+        queries
+            .iter()
+            .filter_map(Weak::upgrade)
+            .find(|query| query.is_over_budget())
+            .map(SpillTarget::Query)
+    }
+
+    /// Whether the estimated memory usage currently exceeds the memory budget.
+    ///
+    /// Unlike the check that triggers spilling this includes the memory that spills which are in
+    /// progress will release, as it is still in use until they finish.
+    pub fn is_over_budget(&self) -> bool {
+        crate::estimate_memory_usage() > config().ooc_memory_budget_bytes()
+    }
+
     fn clean_contexts(&self) {
         if let Ok(mut ctxs) = self.contexts.try_write() {
             ctxs.retain(|ctx| ctx.strong_count() > 0);
@@ -59,31 +91,39 @@ impl MemoryManager {
         self.contexts.write().unwrap().push(weak);
     }
 
+    /// Registers a query whose spill contexts are spilled when they exceed the budget of the
+    /// query.
+    pub fn register_query(&self, query: &Arc<QueryMemoryContext>) {
+        let mut queries = self.queries.write().unwrap();
+        queries.retain(|q| q.strong_count() > 0);
+        queries.push(Arc::downgrade(query));
+    }
+
     #[inline(always)]
     pub async fn spill(&self) {
-        if self.should_spill() {
-            self.do_spill().await
+        if let Some(target) = self.spill_target() {
+            self.do_spill(target).await
         }
     }
 
     #[inline(always)]
     pub fn spill_blocking(&self) {
-        if self.should_spill() {
-            self.do_spill_blocking()
+        if let Some(target) = self.spill_target() {
+            self.do_spill_blocking(target)
         }
     }
 
     #[inline(never)]
     #[cold]
-    fn do_spill_blocking(&self) {
-        ASYNC.block_in_place_on(self.do_spill())
+    fn do_spill_blocking(&self, target: SpillTarget) {
+        ASYNC.block_in_place_on(self.do_spill(target))
     }
 
     #[inline(never)]
     #[cold]
-    async fn do_spill(&self) {
-        while self.should_spill() {
-            let Some((ctx, spillables)) = self.find_spillables().await else {
+    async fn do_spill(&self, mut target: SpillTarget) {
+        loop {
+            let Some((ctx, spillables)) = self.find_spillables(&target).await else {
                 return;
             };
 
@@ -111,11 +151,11 @@ impl MemoryManager {
                                     ctx.stats().finish_exploration_event(true);
                                 }
                             } else {
-                                ctx.reinsert(&spillable, id);
+                                ctx.reinsert(&spillable, id, sz);
                             }
                         },
                         Err(TrySpillError::Pinned) => {
-                            ctx.reinsert(&spillable, id);
+                            ctx.reinsert(&spillable, id, sz);
                         },
                         Err(TrySpillError::AlreadySpilled) => {},
                     }
@@ -127,6 +167,11 @@ impl MemoryManager {
                     drop(permit);
                 });
             }
+
+            match self.spill_target() {
+                Some(next_target) => target = next_target,
+                None => return,
+            }
         }
     }
 
@@ -134,6 +179,7 @@ impl MemoryManager {
     #[cold]
     async fn find_spillables(
         &self,
+        target: &SpillTarget,
     ) -> Option<(
         Arc<dyn SpillContext>,
         Vec<(Arc<dyn DynSpillToken>, u64, usize)>,
@@ -142,22 +188,32 @@ impl MemoryManager {
         let finding_spill_guard = self.finding_spill_lock.lock().await;
 
         // TODO: don't loop over all contexts here, keep track of good ones and inspect those plus a couple random ones.
-        let contexts = self.contexts.read().unwrap();
         let mut has_dead_context = false;
         let mut live_contexts = Vec::new();
         let mut rng = rand::rng();
-        for weak_ctx in contexts.iter() {
-            let Some(ctx) = weak_ctx.upgrade() else {
-                has_dead_context = true;
-                continue;
-            };
-
+        let mut push_live = |ctx: Arc<dyn SpillContext>| {
             // Thompson sampling.
             let score_sample = ctx.stats().sample_score(&mut rng);
             assert!(!score_sample.is_nan());
             live_contexts.push((ctx, score_sample));
+        };
+        match target {
+            SpillTarget::Global => {
+                let contexts = self.contexts.read().unwrap();
+                for weak_ctx in contexts.iter() {
+                    let Some(ctx) = weak_ctx.upgrade() else {
+                        has_dead_context = true;
+                        continue;
+                    };
+                    push_live(ctx);
+                }
+            },
+            SpillTarget::Query(query) => {
+                for ctx in query.contexts() {
+                    push_live(ctx);
+                }
+            },
         }
-        drop(contexts);
 
         // Find the best context and loop over its candidates. For each
         // candidate we check if it can be spilled else we reinsert it.
@@ -181,7 +237,7 @@ impl MemoryManager {
 
             let mut total_est_spill = 0;
             let mut candidates = Vec::new();
-            for (cand, id) in ctx.pop() {
+            for (cand, id, registered_sz) in ctx.pop() {
                 if cand.can_spill()
                     && let Some(sz) = cand.estimate_byte_size()
                     && sz as u64 >= min_spill
@@ -190,7 +246,7 @@ impl MemoryManager {
                     candidates.push((cand, id, sz));
                 } else {
                     if !cand.is_spilled_or_dropped() {
-                        ctx.reinsert(&cand, id);
+                        ctx.reinsert(&cand, id, registered_sz);
                     }
                 }
             }
@@ -0,0 +1,76 @@
+use std::sync::{Arc, RwLock, Weak};
+
+use polars_config::SpillPolicy;
+
+use crate::{SpillContext, memory_manager};
+
+/// Memory accounting and spill settings of a single query.
+///
+/// Spill contexts attached to a query are spilled by the [`MemoryManager`] as soon as their
+/// combined resident bytes exceed the budget of the query, independent of the global memory
+/// budget. This keeps one query from holding on to memory other queries in the same process need.
+///
+/// [`MemoryManager`]: crate::memory_manager::MemoryManager
+pub struct QueryMemoryContext {
+    budget_bytes: Option<u64>,
+    policy: SpillPolicy,
+    contexts: RwLock<Vec<Weak<dyn SpillContext>>>,
+}
+
+impl QueryMemoryContext {
+    pub fn new(budget_bytes: Option<u64>, policy: SpillPolicy) -> Arc<Self> {
+        let slf = Arc::new(Self {
+            budget_bytes,
+            policy,
+            contexts: RwLock::new(Vec::new()),
+        });
+        if budget_bytes.is_some() {
+            memory_manager().register_query(&slf);
+        }
+        slf
+    }
+
+    pub fn budget_bytes(&self) -> Option<u64> {
+        self.budget_bytes
+    }
+
+    pub fn policy(&self) -> SpillPolicy {
+        self.policy
+    }
+
+    /// Attaches a spill context to this query, applying the spill policy of the query to it.
+    ///
+    /// Attaching the same context more than once has no effect.
+    pub fn attach(&self, ctx: Arc<dyn SpillContext>) {
+        let weak = Arc::downgrade(&ctx);
+        let mut contexts = self.contexts.write().unwrap();
+        if contexts.iter().any(|c| Weak::ptr_eq(c, &weak)) {
+            return;
+        }
+        contexts.retain(|c| c.strong_count() > 0);
+        ctx.set_policy(self.policy);
+        contexts.push(weak);
+    }
+
+    /// The live spill contexts attached to this query.
+    pub fn contexts(&self) -> Vec<Arc<dyn SpillContext>> {
+        let contexts = self.contexts.read().unwrap();
+        contexts.iter().filter_map(Weak::upgrade).collect()
+    }
+
+    /// The estimated number of bytes this query holds in memory in spillable form.
+    pub fn resident_bytes(&self) -> u64 {
+        let contexts = self.contexts.read().unwrap();
+        contexts
+            .iter()
+            .filter_map(Weak::upgrade)
+            .map(|ctx| ctx.stats().resident_bytes())
+            .sum()
+    }
+
+    /// Whether the spillable buffers of this query exceed its budget.
+    pub fn is_over_budget(&self) -> bool {
+        self.budget_bytes
+            .is_some_and(|budget| self.resident_bytes() > budget)
+    }
+}
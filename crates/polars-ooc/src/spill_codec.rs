@@ -0,0 +1,133 @@
+use std::io::Cursor;
+
+use polars_config::SpillFormat;
+use polars_core::chunked_array::ops::row_encode::{
+    _get_rows_encoded_unordered, get_row_encoding_context,
+};
+use polars_core::prelude::*;
+use polars_io::ipc::{IpcCompression, IpcReader, IpcWriter};
+use polars_io::{SerReader, SerWriter};
+use polars_row::RowEncodingOptions;
+use polars_utils::compression::ZstdLevel;
+
+/// Returns the format a frame is actually spilled in.
+///
+/// The row encoding canonicalizes floats (e.g. `-0.0` becomes `0.0`), can't encode objects and
+/// doesn't record the height of frames without columns, so those are spilled as IPC instead.
+pub(crate) fn resolve_format(df: &DataFrame, format: SpillFormat) -> SpillFormat {
+    if format == SpillFormat::Row
+        && (df.width() == 0
+            || df
+                .columns()
+                .iter()
+                .any(|c| !is_row_encodable_losslessly(c.dtype())))
+    {
+        return SpillFormat::Ipc;
+    }
+    format
+}
+
+fn is_row_encodable_losslessly(dtype: &DataType) -> bool {
+    fn is_lossless(dtype: &ArrowDataType) -> bool {
+        match dtype {
+            ArrowDataType::Float16 | ArrowDataType::Float32 | ArrowDataType::Float64 => false,
+            ArrowDataType::List(field)
+            | ArrowDataType::LargeList(field)
+            | ArrowDataType::FixedSizeList(field, _) => is_lossless(field.dtype()),
+            ArrowDataType::Struct(fields) => fields.iter().all(|f| is_lossless(f.dtype())),
+            _ => true,
+        }
+    }
+
+    !dtype.contains_objects() && is_lossless(&dtype.to_physical().to_arrow(CompatLevel::newest()))
+}
+
+/// Encodes a frame into the contents of a spill file. The format must come from
+/// [`resolve_format`].
+pub(crate) fn encode(df: &DataFrame, format: SpillFormat) -> PolarsResult<Vec<u8>> {
+    let mut buf = Vec::new();
+    match format {
+        SpillFormat::Ipc | SpillFormat::IpcLz4 => {
+            let compression = if format == SpillFormat::IpcLz4 {
+                Some(IpcCompression::LZ4)
+            } else {
+                let clvl = polars_config::config().ooc_spill_compression_level();
+                (clvl > 0).then(|| {
+                    IpcCompression::ZSTD(ZstdLevel::try_new(clvl.try_into().unwrap()).unwrap())
+                })
+            };
+            IpcWriter::new(&mut buf)
+                .with_parallel(false)
+                .with_compression(compression)
+                .finish(&mut df.clone())?;
+        },
+        SpillFormat::Row => {
+            // Layout: (height + 1) little-endian i64 offsets, followed by the row bytes.
+            let rows = _get_rows_encoded_unordered(df.columns())?.into_array();
+            buf.reserve(rows.offsets().buffer().len() * size_of::<i64>() + rows.values().len());
+            for offset in rows.offsets().buffer().iter() {
+                buf.extend_from_slice(&offset.to_le_bytes());
+            }
+            buf.extend_from_slice(rows.values());
+        },
+    }
+    Ok(buf)
+}
+
+/// Decodes the contents of a spill file written by [`encode`].
+pub(crate) fn decode(
+    bytes: Vec<u8>,
+    format: SpillFormat,
+    schema: &Schema,
+    height: usize,
+) -> PolarsResult<DataFrame> {
+    match format {
+        SpillFormat::Ipc | SpillFormat::IpcLz4 => IpcReader::new(Cursor::new(bytes)).finish(),
+        SpillFormat::Row => decode_rows(&bytes, schema, height),
+    }
+}
+
+fn decode_rows(bytes: &[u8], schema: &Schema, height: usize) -> PolarsResult<DataFrame> {
+    let offsets_len = (height + 1) * size_of::<i64>();
+    polars_ensure!(
+        bytes.len() >= offsets_len,
+        ComputeError: "row-encoded spill file is truncated"
+    );
+    let (offsets, values) = bytes.split_at(offsets_len);
+    let offsets = offsets
+        .chunks_exact(size_of::<i64>())
+        .map(|b| i64::from_le_bytes(b.try_into().unwrap()) as usize)
+        .collect::<Vec<_>>();
+    polars_ensure!(
+        offsets[0] == 0
+            && offsets.windows(2).all(|w| w[0] <= w[1])
+            && offsets[height] == values.len(),
+        ComputeError: "row-encoded spill file has invalid offsets"
+    );
+
+    let mut rows = offsets
+        .windows(2)
+        .map(|w| &values[w[0]..w[1]])
+        .collect::<Vec<_>>();
+    let opts = vec![RowEncodingOptions::new_unsorted(); schema.len()];
+    let ctxts = schema
+        .iter_values()
+        .map(get_row_encoding_context)
+        .collect::<Vec<_>>();
+    let dtypes = schema
+        .iter_values()
+        .map(|dt| dt.to_physical().to_arrow(CompatLevel::newest()))
+        .collect::<Vec<_>>();
+
+    // SAFETY: the rows were encoded with the same options and dtypes, and the checksum of the
+    // file was verified before decoding.
+    let arrays = unsafe { polars_row::decode::decode_rows(&mut rows, &opts, &ctxts, &dtypes) };
+    let columns = schema
+        .iter()
+        .zip(arrays)
+        .map(|((name, dtype), array)| unsafe {
+            Series::from_chunks_and_dtype_unchecked(name.clone(), vec![array], dtype).into_column()
+        })
+        .collect();
+    DataFrame::new(height, columns)
+}
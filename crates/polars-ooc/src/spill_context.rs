@@ -3,6 +3,7 @@ use std::fmt::Debug;
 use std::sync::{Arc, Mutex, RwLock, Weak};
 use std::time::{Duration, Instant};
 
+use polars_config::{SpillFormat, SpillPolicy};
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::relaxed_cell::RelaxedCell;
 use rand::rngs::ThreadRng;
@@ -14,257 +15,291 @@ use crate::{DynSpillToken, SpillToken, Spillable, memory_manager};
 
 #[derive(Default)]
 struct LocalSpillQueue {
-    tokens: VecDeque<(Weak<dyn DynSpillToken>, u64)>,
+    tokens: VecDeque<(Weak<dyn DynSpillToken>, u64, usize)>,
     retain_amort: usize,
 }
 
 impl LocalSpillQueue {
-    pub fn push_back(&mut self, token: &Arc<dyn DynSpillToken>, id: u64) {
-        self.gc();
+    pub fn push_back(
+        &mut self,
+        token: &Arc<dyn DynSpillToken>,
+        id: u64,
+        n_bytes: usize,
+        stats: &SpillContextStatistics,
+    ) {
+        self.gc(stats);
         if token.current_registration_id() == id {
-            self.tokens.push_back((Arc::downgrade(token), id));
+            self.tokens.push_back((Arc::downgrade(token), id, n_bytes));
+            stats.add_resident(n_bytes);
         }
     }
 
-    pub fn push_front(&mut self, token: &Arc<dyn DynSpillToken>, id: u64) {
-        self.gc();
+    pub fn push_front(
+        &mut self,
+        token: &Arc<dyn DynSpillToken>,
+        id: u64,
+        n_bytes: usize,
+        stats: &SpillContextStatistics,
+    ) {
+        self.gc(stats);
         if token.current_registration_id() == id {
-            self.tokens.push_front((Arc::downgrade(token), id));
+            self.tokens.push_front((Arc::downgrade(token), id, n_bytes));
+            stats.add_resident(n_bytes);
         }
     }
 
-    pub fn pop_front(&mut self) -> Option<(Arc<dyn DynSpillToken>, u64)> {
+    pub fn pop_front(
+        &mut self,
+        stats: &SpillContextStatistics,
+    ) -> Option<(Arc<dyn DynSpillToken>, u64, usize)> {
         loop {
-            let (weak, id) = self.tokens.pop_front()?;
+            let (weak, id, n_bytes) = self.tokens.pop_front()?;
+            stats.remove_resident(n_bytes);
             if let Some(token) = weak.upgrade()
                 && token.current_registration_id() == id
             {
-                return Some((token, id));
+                return Some((token, id, n_bytes));
             }
         }
     }
 
-    pub fn pop_back(&mut self) -> Option<(Arc<dyn DynSpillToken>, u64)> {
+    pub fn pop_back(
+        &mut self,
+        stats: &SpillContextStatistics,
+    ) -> Option<(Arc<dyn DynSpillToken>, u64, usize)> {
         loop {
-            let (weak, id) = self.tokens.pop_back()?;
+            let (weak, id, n_bytes) = self.tokens.pop_back()?;
+            stats.remove_resident(n_bytes);
             if let Some(token) = weak.upgrade()
                 && token.current_registration_id() == id
             {
-                return Some((token, id));
+                return Some((token, id, n_bytes));
             }
         }
     }
 
-    pub fn pop_random(&mut self, rng: &mut ThreadRng) -> Option<(Arc<dyn DynSpillToken>, u64)> {
+    pub fn pop_random(
+        &mut self,
+        rng: &mut ThreadRng,
+        stats: &SpillContextStatistics,
+    ) -> Option<(Arc<dyn DynSpillToken>, u64, usize)> {
         while !self.tokens.is_empty() {
             let idx = rng.random_range(0..self.tokens.len());
-            let (weak, id) = self.tokens.swap_remove_back(idx).unwrap();
+            let (weak, id, n_bytes) = self.tokens.swap_remove_back(idx).unwrap();
+            stats.remove_resident(n_bytes);
             if let Some(token) = weak.upgrade()
                 && token.current_registration_id() == id
             {
-                return Some((token, id));
+                return Some((token, id, n_bytes));
             }
         }
         None
     }
 
-    fn gc(&mut self) {
+    fn gc(&mut self, stats: &SpillContextStatistics) {
         self.retain_amort += 2; // Grows twice as fast as push.
         if self.retain_amort >= self.tokens.len() {
             self.retain_amort = 0;
-            self.tokens.retain(|(token, id)| {
-                token
-                    .upgrade()
-                    .is_some_and(|t| t.current_registration_id() == *id)
-            });
+            self.retain_live(stats);
         }
     }
-}
-
-pub trait SpillContext: Send + Sync + 'static {
-    fn stats(&self) -> &Arc<SpillContextStatistics>;
-    fn pop(&self) -> Vec<(Arc<dyn DynSpillToken>, u64)>;
-    fn reinsert(&self, token: &Arc<dyn DynSpillToken>, id: u64);
-}
 
-pub trait ParameterFreeSpillContext {
-    fn register<T, S>(&self, token: &T)
-    where
-        T: AsRef<SpillToken<S>>,
-        S: Spillable,
-        Self: Sized;
+    fn retain_live(&mut self, stats: &SpillContextStatistics) {
+        self.tokens.retain(|(token, id, n_bytes)| {
+            let live = token
+                .upgrade()
+                .is_some_and(|t| t.current_registration_id() == *id);
+            if !live {
+                stats.remove_resident(*n_bytes);
+            }
+            live
+        });
+    }
 }
 
-/// A context that spills the most-recently registered spillable when asked.
-pub struct MostRecentSpillContext {
+/// The per-thread queues of a spill context, popped in the order given by its [`SpillPolicy`].
+struct SpillQueues {
     local: ThreadLocal<RwLock<LocalSpillQueue>>,
     stats: Arc<SpillContextStatistics>,
+    policy: RelaxedCell<u8>,
 }
 
-impl MostRecentSpillContext {
-    pub fn new(name: PlSmallStr) -> Arc<Self> {
-        let slf = Arc::new(Self {
+impl SpillQueues {
+    fn new(name: PlSmallStr, policy: SpillPolicy, format: SpillFormat) -> Self {
+        debug_assert!(policy != SpillPolicy::Auto);
+        Self {
             local: ThreadLocal::default(),
-            stats: Arc::new(SpillContextStatistics::new(name)),
-        });
-        memory_manager().register_ctx(&slf);
-        slf
+            stats: Arc::new(SpillContextStatistics::new(name, format)),
+            policy: RelaxedCell::new_u8(policy as u8),
+        }
+    }
+
+    fn policy(&self) -> SpillPolicy {
+        SpillPolicy::from_discriminant(self.policy.load())
     }
-}
 
-impl SpillContext for MostRecentSpillContext {
-    fn stats(&self) -> &Arc<SpillContextStatistics> {
-        &self.stats
+    fn set_policy(&self, policy: SpillPolicy) {
+        if policy != SpillPolicy::Auto {
+            self.policy.store(policy as u8);
+        }
     }
 
-    fn pop(&self) -> Vec<(Arc<dyn DynSpillToken>, u64)> {
+    fn pop(&self) -> Vec<(Arc<dyn DynSpillToken>, u64, usize)> {
+        let policy = self.policy();
         let mut out = Vec::new();
+        let mut rng = rand::rng();
         for local_lock in self.local.iter() {
             if let Ok(mut local) = local_lock.try_write() {
-                out.extend(local.pop_back());
+                out.extend(match policy {
+                    SpillPolicy::MostRecent | SpillPolicy::Auto => local.pop_back(&self.stats),
+                    SpillPolicy::LeastRecent => local.pop_front(&self.stats),
+                    SpillPolicy::Random => local.pop_random(&mut rng, &self.stats),
+                });
             }
         }
         out
     }
 
-    fn reinsert(&self, token: &Arc<dyn DynSpillToken>, id: u64) {
-        // Reinsertions always act like least recent, so we use push_front.
+    fn reinsert(&self, token: &Arc<dyn DynSpillToken>, id: u64, n_bytes: usize) {
         let mut local = self.local.get_or_default().write().unwrap();
-        local.push_front(token, id);
+        match self.policy() {
+            // Reinsertions always act like least recent, so we use push_front.
+            SpillPolicy::MostRecent | SpillPolicy::Auto => {
+                local.push_front(token, id, n_bytes, &self.stats)
+            },
+            SpillPolicy::LeastRecent | SpillPolicy::Random => {
+                local.push_back(token, id, n_bytes, &self.stats)
+            },
+        }
     }
-}
 
-impl ParameterFreeSpillContext for MostRecentSpillContext {
-    fn register<T, S>(&self, token: &T)
-    where
-        T: AsRef<SpillToken<S>>,
-        S: Spillable,
-    {
-        let dyn_arc = token.as_ref().upcast();
+    fn register<S: Spillable>(&self, token: &SpillToken<S>) {
+        let n_bytes = token
+            .try_get()
+            .map(|v| v.estimate_byte_size())
+            .unwrap_or_default();
+        let dyn_arc = token.upcast();
         let mut local = self.local.get_or_default().write().unwrap();
-        local.push_back(&dyn_arc, dyn_arc.new_registration_id());
-    }
-}
-
-impl Debug for MostRecentSpillContext {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("MostRecentSpillContext").finish()
-    }
-}
-
-/// A context that spills the least-recently registered spillable when asked.
-pub struct LeastRecentSpillContext {
-    local: ThreadLocal<RwLock<LocalSpillQueue>>,
-    stats: Arc<SpillContextStatistics>,
-}
-
-impl LeastRecentSpillContext {
-    pub fn new(name: PlSmallStr) -> Arc<Self> {
-        let slf = Arc::new(Self {
-            local: ThreadLocal::default(),
-            stats: Arc::new(SpillContextStatistics::new(name)),
-        });
-        memory_manager().register_ctx(&slf);
-        slf
-    }
-}
-
-impl SpillContext for LeastRecentSpillContext {
-    fn stats(&self) -> &Arc<SpillContextStatistics> {
-        &self.stats
+        local.push_back(
+            &dyn_arc,
+            dyn_arc.new_registration_id(),
+            n_bytes,
+            &self.stats,
+        );
     }
 
-    fn pop(&self) -> Vec<(Arc<dyn DynSpillToken>, u64)> {
-        let mut out = Vec::new();
+    fn release_dropped(&self) {
         for local_lock in self.local.iter() {
             if let Ok(mut local) = local_lock.try_write() {
-                out.extend(local.pop_front());
+                local.retain_live(&self.stats);
             }
         }
-        out
     }
+}
 
-    fn reinsert(&self, token: &Arc<dyn DynSpillToken>, id: u64) {
-        let mut local = self.local.get_or_default().write().unwrap();
-        local.push_back(token, id);
-    }
+pub trait SpillContext: Send + Sync + 'static {
+    fn stats(&self) -> &Arc<SpillContextStatistics>;
+    fn pop(&self) -> Vec<(Arc<dyn DynSpillToken>, u64, usize)>;
+    fn reinsert(&self, token: &Arc<dyn DynSpillToken>, id: u64, n_bytes: usize);
+
+    /// Overrides the order in which this context spills. [`SpillPolicy::Auto`] keeps the
+    /// current order.
+    fn set_policy(&self, policy: SpillPolicy);
+
+    /// Stops accounting for registered spillables which were dropped or moved to another context
+    /// since they were registered.
+    fn release_dropped(&self);
 }
 
-impl ParameterFreeSpillContext for LeastRecentSpillContext {
+pub trait ParameterFreeSpillContext {
     fn register<T, S>(&self, token: &T)
     where
         T: AsRef<SpillToken<S>>,
         S: Spillable,
-    {
-        let dyn_arc = token.as_ref().upcast();
-        let mut local = self.local.get_or_default().write().unwrap();
-        local.push_back(&dyn_arc, dyn_arc.new_registration_id());
-    }
+        Self: Sized;
 }
 
-impl Debug for LeastRecentSpillContext {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("LeastRecentSpillContext").finish()
-    }
-}
+macro_rules! impl_queue_spill_context {
+    ($T:ident, $policy:expr) => {
+        impl $T {
+            pub fn new(name: PlSmallStr) -> Arc<Self> {
+                Self::new_with_format(name, polars_config::config().ooc_spill_format())
+            }
 
-/// A context that spills a random registered spillable when asked.
-pub struct RandomSpillContext {
-    local: ThreadLocal<RwLock<LocalSpillQueue>>,
-    stats: Arc<SpillContextStatistics>,
-}
+            /// Creates a context whose spillables are spilled in the given format, regardless
+            /// of the configured format.
+            pub fn new_with_format(name: PlSmallStr, format: SpillFormat) -> Arc<Self> {
+                let slf = Arc::new(Self {
+                    queues: SpillQueues::new(name, $policy, format),
+                });
+                memory_manager().register_ctx(&slf);
+                slf
+            }
+        }
 
-impl RandomSpillContext {
-    pub fn new(name: PlSmallStr) -> Arc<Self> {
-        let slf = Arc::new(Self {
-            local: ThreadLocal::default(),
-            stats: Arc::new(SpillContextStatistics::new(name)),
-        });
-        memory_manager().register_ctx(&slf);
-        slf
-    }
-}
+        impl SpillContext for $T {
+            fn stats(&self) -> &Arc<SpillContextStatistics> {
+                &self.queues.stats
+            }
 
-impl SpillContext for RandomSpillContext {
-    fn stats(&self) -> &Arc<SpillContextStatistics> {
-        &self.stats
-    }
+            fn pop(&self) -> Vec<(Arc<dyn DynSpillToken>, u64, usize)> {
+                self.queues.pop()
+            }
 
-    fn pop(&self) -> Vec<(Arc<dyn DynSpillToken>, u64)> {
-        let mut out = Vec::new();
-        let mut rng = rand::rng();
-        for local_lock in self.local.iter() {
-            if let Ok(mut local) = local_lock.try_write() {
-                out.extend(local.pop_random(&mut rng));
+            fn reinsert(&self, token: &Arc<dyn DynSpillToken>, id: u64, n_bytes: usize) {
+                self.queues.reinsert(token, id, n_bytes)
+            }
+
+            fn set_policy(&self, policy: SpillPolicy) {
+                self.queues.set_policy(policy)
+            }
+
+            fn release_dropped(&self) {
+                self.queues.release_dropped()
             }
         }
-        out
-    }
 
-    fn reinsert(&self, token: &Arc<dyn DynSpillToken>, id: u64) {
-        let mut local = self.local.get_or_default().write().unwrap();
-        local.push_back(token, id);
-    }
+        impl ParameterFreeSpillContext for $T {
+            fn register<T, S>(&self, token: &T)
+            where
+                T: AsRef<SpillToken<S>>,
+                S: Spillable,
+            {
+                self.queues.register(token.as_ref())
+            }
+        }
+
+        impl Debug for $T {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.debug_struct(stringify!($T))
+                    .field("policy", &self.queues.policy())
+                    .finish()
+            }
+        }
+    };
 }
 
-impl ParameterFreeSpillContext for RandomSpillContext {
-    fn register<T, S>(&self, token: &T)
-    where
-        T: AsRef<SpillToken<S>>,
-        S: Spillable,
-    {
-        let dyn_arc = token.as_ref().upcast();
-        let mut local = self.local.get_or_default().write().unwrap();
-        local.push_back(&dyn_arc, dyn_arc.new_registration_id());
-    }
+/// A context that spills the most-recently registered spillable when asked.
+pub struct MostRecentSpillContext {
+    queues: SpillQueues,
 }
 
-impl Debug for RandomSpillContext {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("RandomSpillContext").finish()
-    }
+impl_queue_spill_context!(MostRecentSpillContext, SpillPolicy::MostRecent);
+
+/// A context that spills the least-recently registered spillable when asked.
+pub struct LeastRecentSpillContext {
+    queues: SpillQueues,
 }
 
+impl_queue_spill_context!(LeastRecentSpillContext, SpillPolicy::LeastRecent);
+
+/// A context that spills a random registered spillable when asked.
+pub struct RandomSpillContext {
+    queues: SpillQueues,
+}
+
+impl_queue_spill_context!(RandomSpillContext, SpillPolicy::Random);
+
 // Used to normalize divisor to avoid absurdly high scores. Set to 1us.
 const BASE_IO_TIME: f64 = 1e-6;
 pub(crate) const UNEXPLORED_SCORE: f64 = 1e30_f64;
@@ -274,17 +309,23 @@ const NANOSECONDS_IN_SECOND: f64 = 1e9;
 
 pub struct SpillContextStatistics {
     score_cache: RelaxedCell<u64>,
+    resident_bytes: RelaxedCell<u64>,
+    peak_resident_bytes: RelaxedCell<u64>,
     stats: Mutex<Statistics>,
     name: PlSmallStr,
+    format: SpillFormat,
 }
 
 impl SpillContextStatistics {
-    fn new(name: PlSmallStr) -> Self {
+    fn new(name: PlSmallStr, format: SpillFormat) -> Self {
         Self {
             // TODO: starting score based on context.
             score_cache: RelaxedCell::new_u64(UNEXPLORED_SCORE.to_bits()),
+            resident_bytes: RelaxedCell::new_u64(0),
+            peak_resident_bytes: RelaxedCell::new_u64(0),
             stats: Mutex::default(),
             name,
+            format,
         }
     }
 }
@@ -367,6 +408,36 @@ impl SpillContextStatistics {
         &self.name
     }
 
+    /// The format the spillables of this context are spilled in.
+    pub fn format(&self) -> SpillFormat {
+        self.format
+    }
+
+    /// The estimated number of bytes of the spillables which are registered to this context and
+    /// are currently in memory.
+    pub fn resident_bytes(&self) -> u64 {
+        self.resident_bytes.load()
+    }
+
+    /// The highest value [`Self::resident_bytes`] has reached so far.
+    pub fn peak_resident_bytes(&self) -> u64 {
+        self.peak_resident_bytes.load()
+    }
+
+    /// The number of bytes of this context which are currently spilled.
+    pub fn spilled_bytes(&self) -> u64 {
+        self.stats.lock().unwrap().active_spills_bytes
+    }
+
+    fn add_resident(&self, n_bytes: usize) {
+        let new = self.resident_bytes.fetch_add(n_bytes as u64) + n_bytes as u64;
+        self.peak_resident_bytes.fetch_max(new);
+    }
+
+    fn remove_resident(&self, n_bytes: usize) {
+        self.resident_bytes.fetch_sub(n_bytes as u64);
+    }
+
     // Returns a sample of the expected performance of this context, discounting
     // older data. The score is the number of spilled byte-seconds divided by
     // the IO time in seconds. The discount is a time-based exponential decay
@@ -397,7 +468,8 @@ impl SpillContextStatistics {
         stats.bandit_explore_success += success as u64 as f64;
     }
 
-    pub fn add_failed_spill(&self, spill_start: Instant) {
+    /// Returns whether this was the first failed spill of this context.
+    pub fn add_failed_spill(&self, spill_start: Instant) -> bool {
         let mut stats = self.stats.lock().unwrap();
         let now = Instant::now();
         stats.step_time(now); // Important: step time before mutating.
@@ -406,6 +478,7 @@ impl SpillContextStatistics {
         stats.spill_time += spill_time_sec;
         stats.failed_spills += 1;
         stats.add_bandit_spill_event(0, spill_time_sec, 0.0, 0.0);
+        stats.failed_spills == 1
     }
 
     /// Returns the number of nanoseconds the spilling took, as well as the
@@ -1,40 +1,69 @@
 use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::mpsc::{Receiver, Sender, channel};
 
+use polars_core::error::{PolarsResult, polars_bail, polars_warn};
 use polars_io::create_dir_owner_only;
 
-/// On-disk layout:
+/// On-disk layout, for each of the configured spill directories:
 ///
 /// ```text
 /// <spill_dir>/
-///   <pid>/                     <- process directory (one per OS process)
-///     spill-<ctx>-<uuid>.ipc   <- individual spill file (unique per spill)
+///   <pid>/                       <- process directory (one per OS process)
+///     spill-<ctx>-<uuid>.<fmt>   <- individual spill file (unique per spill)
 /// ```
-static SPILL_DIR: LazyLock<PathBuf> = LazyLock::new(|| {
-    let spill_dir = polars_config::config().ooc_spill_dir();
-    let process_dir = spill_dir.join(std::process::id().to_string());
-    create_dir_owner_only(&process_dir).unwrap_or_else(|e| {
-        panic!("failed to create spill directory: {e} (path = {process_dir:?})")
-    });
-    process_dir
+///
+/// The extension is the [`SpillFormat`](polars_config::SpillFormat) the file
+/// was written in, i.e. `ipc`, `ipc_lz4` or `row`.
+///
+/// Directories that can't be created are skipped with a warning.
+static SPILL_DIRS: LazyLock<Vec<PathBuf>> = LazyLock::new(|| {
+    polars_config::config()
+        .ooc_spill_dirs()
+        .into_iter()
+        .filter_map(|spill_dir| {
+            let process_dir = spill_dir.join(std::process::id().to_string());
+            match create_dir_owner_only(&process_dir) {
+                Ok(()) => Some(process_dir),
+                Err(e) => {
+                    polars_warn!("skipping spill directory {}: {e}", process_dir.display());
+                    None
+                },
+            }
+        })
+        .collect()
 });
 
+/// Spill files are spread round-robin over the spill directories.
+static NEXT_SPILL_DIR: AtomicUsize = AtomicUsize::new(0);
+
+fn next_spill_dir() -> PolarsResult<&'static Path> {
+    if SPILL_DIRS.is_empty() {
+        polars_bail!(
+            ComputeError: "none of the configured spill directories could be created: {:?}",
+            polars_config::config().ooc_spill_dirs()
+        );
+    }
+    let idx = NEXT_SPILL_DIR.fetch_add(1, Ordering::Relaxed);
+    Ok(&SPILL_DIRS[idx % SPILL_DIRS.len()])
+}
+
 pub struct SpillFile {
     path: PathBuf,
 }
 
 impl SpillFile {
-    pub fn new(context_id: &str, ext: &str) -> Self {
+    pub fn new(context_id: &str, ext: &str) -> PolarsResult<Self> {
         let uuid = uuid::Uuid::now_v7();
-        Self {
-            path: SPILL_DIR
+        Ok(Self {
+            path: next_spill_dir()?
                 .join(format!(
                     "spill-{context_id}-{uuid}.{ext}",
                     uuid = uuid.as_hyphenated()
                 ))
                 .with_extension(ext),
-        }
+        })
     }
 
     pub fn path(&self) -> &Path {
@@ -107,18 +136,19 @@ enum CleanRequest {
 /// Each subdirectory is named by its owning PID. Skips our own PID and
 /// any directory whose PID is still alive.
 fn cleanup_stale_dirs() {
-    let spill_dir = polars_config::config().ooc_spill_dir();
-    let Ok(entries) = std::fs::read_dir(&spill_dir) else {
-        return;
-    };
-
     let our_pid = std::process::id();
-    let dead_pid_dirs = entries.flatten().filter_map(|e| {
-        let pid = e.file_name().to_str()?.parse::<u32>().ok()?;
-        (pid != our_pid && !polars_utils::sys::is_process_alive(pid)).then(|| e.path())
-    });
-    for path in dead_pid_dirs {
-        let _ = std::fs::remove_dir_all(&path);
+    for spill_dir in polars_config::config().ooc_spill_dirs() {
+        let Ok(entries) = std::fs::read_dir(&spill_dir) else {
+            continue;
+        };
+
+        let dead_pid_dirs = entries.flatten().filter_map(|e| {
+            let pid = e.file_name().to_str()?.parse::<u32>().ok()?;
+            (pid != our_pid && !polars_utils::sys::is_process_alive(pid)).then(|| e.path())
+        });
+        for path in dead_pid_dirs {
+            let _ = std::fs::remove_dir_all(&path);
+        }
     }
 }
 
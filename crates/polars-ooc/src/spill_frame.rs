@@ -3,75 +3,94 @@ use std::io::Write;
 use std::ops::{Deref, DerefMut};
 
 use polars_async::ASYNC;
+use polars_config::SpillFormat;
+use polars_core::error::{PolarsResult, polars_ensure, polars_err};
 use polars_core::frame::DataFrame;
-use polars_io::ipc::{IpcCompression, IpcReader, IpcWriter};
-use polars_io::{SerReader, SerWriter};
-use polars_utils::compression::ZstdLevel;
+use polars_core::schema::SchemaRef;
 
 use crate::spill_context::ParameterFreeSpillContext;
 use crate::spill_file::SpillFile;
-use crate::{PinnedMut, PinnedRef, SpillToken, Spillable, memory_manager};
+use crate::{PinnedMut, PinnedRef, SpillToken, Spillable, memory_manager, spill_codec};
+
+/// A [`DataFrame`] which was written to a spill file.
+pub struct SpilledFrame {
+    file: SpillFile,
+    format: SpillFormat,
+    schema: SchemaRef,
+    height: usize,
+    /// CRC32 of the contents of the file, verified when unspilling.
+    checksum: u32,
+}
 
 impl Spillable for DataFrame {
-    // TODO: just a dummy spill for now. Boxed to reduce size.
-    type Spilled = SpillFile;
+    type Spilled = SpilledFrame;
 
     fn estimate_byte_size(&self) -> usize {
         self.estimated_size()
     }
 
-    async fn spill(&self, context_id: &str) -> Self::Spilled {
-        let mut df = self.clone();
+    async fn spill(&self, context_id: &str, format: SpillFormat) -> PolarsResult<Self::Spilled> {
         let context_id = context_id.to_owned();
 
         // Encode in the current task (on computational executor).
-        let mut buf = Vec::new();
-
-        let mut writer = IpcWriter::new(&mut buf).with_parallel(false);
-        let clvl = polars_config::config().ooc_spill_compression_level();
-        if clvl > 0 {
-            let zstd_lvl = ZstdLevel::try_new(clvl.try_into().unwrap()).unwrap();
-            writer = writer.with_compression(Some(IpcCompression::ZSTD(zstd_lvl)));
-        }
-        writer
-            .finish(&mut df)
-            .unwrap_or_else(|e| panic!("failed to encode spill file for '{context_id}': {e}",));
+        let format = spill_codec::resolve_format(self, format);
+        let buf = spill_codec::encode(self, format).map_err(
+            |e| polars_err!(ComputeError: "failed to encode spill file for '{context_id}': {e}"),
+        )?;
+        let checksum = crc32fast::hash(&buf);
 
         // Do file creation / writing on tokio.
-        ASYNC
+        let file = ASYNC
             .spawn_blocking(move || {
-                let spill_file = SpillFile::new(&context_id, "ipc");
-                let mut file = std::fs::File::create(spill_file.path()).unwrap_or_else(|e| {
-                    panic!(
-                        "failed to create spill file '{}': {e}",
+                // Dropping the spill file on failure removes what was written so far.
+                let spill_file = SpillFile::new(&context_id, format.as_static_str())?;
+                let mut file = std::fs::File::create(spill_file.path()).map_err(|e| {
+                    polars_err!(
+                        ComputeError: "failed to create spill file '{}': {e}",
                         spill_file.path().display()
                     )
-                });
-                file.write_all(&buf).unwrap_or_else(|e| {
-                    panic!(
-                        "failed to write to spill file '{}': {e}",
+                })?;
+                file.write_all(&buf).map_err(|e| {
+                    polars_err!(
+                        ComputeError: "failed to write to spill file '{}': {e}",
                         spill_file.path().display()
                     )
-                });
-                spill_file
+                })?;
+                PolarsResult::Ok(spill_file)
             })
             .await
-            .unwrap()
+            .unwrap()?;
+
+        Ok(SpilledFrame {
+            file,
+            format,
+            schema: self.schema().clone(),
+            height: self.height(),
+            checksum,
+        })
     }
 
-    async fn unspill(location: &Self::Spilled) -> Self {
-        let path = location.path().to_owned();
-        ASYNC
+    async fn unspill(location: &Self::Spilled) -> PolarsResult<Self> {
+        let path = location.file.path().to_owned();
+        let bytes = ASYNC
             .spawn_blocking(move || {
-                let file = std::fs::File::open(&path).unwrap_or_else(|e| {
-                    panic!("failed to open spill file {:?}: {e}", path.display())
-                });
-                IpcReader::new(file).finish().unwrap_or_else(|e| {
-                    panic!("failed to read spill file {:?}: {e}", path.display())
+                std::fs::read(&path).map_err(|e| {
+                    polars_err!(
+                        ComputeError: "failed to read spill file '{}': {e}", path.display()
+                    )
                 })
             })
             .await
-            .unwrap()
+            .unwrap()?;
+
+        let checksum = crc32fast::hash(&bytes);
+        polars_ensure!(
+            checksum == location.checksum,
+            ComputeError: "spill file '{}' is corrupted: expected CRC32 {:08x}, found {:08x}",
+            location.file.path().display(), location.checksum, checksum
+        );
+
+        spill_codec::decode(bytes, location.format, &location.schema, location.height)
     }
 }
 
@@ -119,39 +138,39 @@ impl SpillFrame {
 
     /// Get a reference to the underlying DataFrame, unspilling it if it
     /// was spilled.
-    pub async fn get(&self) -> PinnedRef<'_, DataFrame> {
+    pub async fn get(&self) -> PolarsResult<PinnedRef<'_, DataFrame>> {
         self.token.get().await
     }
 
     /// Blocking version of get.
-    pub fn get_blocking(&self) -> PinnedRef<'_, DataFrame> {
+    pub fn get_blocking(&self) -> PolarsResult<PinnedRef<'_, DataFrame>> {
         self.token.get_blocking()
     }
 
     /// Get a mutable reference to the underlying DataFrame, unspilling it if it
     /// was spilled.
-    pub async fn get_mut(&mut self) -> PinnedFrameMut<'_> {
-        PinnedFrameMut {
-            inner: self.token.get_mut().await,
+    pub async fn get_mut(&mut self) -> PolarsResult<PinnedFrameMut<'_>> {
+        Ok(PinnedFrameMut {
+            inner: self.token.get_mut().await?,
             height: &mut self.height,
-        }
+        })
     }
 
     /// Blocking version of get_mut.
-    pub fn get_mut_blocking(&mut self) -> PinnedFrameMut<'_> {
-        PinnedFrameMut {
-            inner: self.token.get_mut_blocking(),
+    pub fn get_mut_blocking(&mut self) -> PolarsResult<PinnedFrameMut<'_>> {
+        Ok(PinnedFrameMut {
+            inner: self.token.get_mut_blocking()?,
             height: &mut self.height,
-        }
+        })
     }
 
     /// Consumes this SpillFrame, unspilling it if it were spilled.
-    pub async fn into_df(self) -> DataFrame {
+    pub async fn into_df(self) -> PolarsResult<DataFrame> {
         self.token.into_inner().await
     }
 
     /// Blocking version of into_df.
-    pub fn into_df_blocking(self) -> DataFrame {
+    pub fn into_df_blocking(self) -> PolarsResult<DataFrame> {
         self.token.into_inner_blocking()
     }
 }
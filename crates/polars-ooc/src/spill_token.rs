@@ -7,6 +7,7 @@ use std::task::{Poll, Waker};
 use std::time::Instant;
 
 use polars_async::ASYNC;
+use polars_core::error::{PolarsResult, polars_warn};
 use polars_utils::UnitVec;
 use polars_utils::with_drop::WithDrop;
 
@@ -173,15 +174,15 @@ impl<T: Spillable> SpillTokenInner<T> {
         }
     }
 
-    async fn pin(slf: &Arc<Self>) -> PinnedRef<'_, T> {
+    async fn pin(slf: &Arc<Self>) -> PolarsResult<PinnedRef<'_, T>> {
         if let Some(r) = slf.try_pin() {
-            return r;
+            return Ok(r);
         }
 
         std::hint::cold_path();
 
         if let Some(r) = slf.pin_or_lock().await {
-            return r;
+            return Ok(r);
         }
 
         // We now hold the lock, meaning the value was spilled.
@@ -194,9 +195,10 @@ impl<T: Spillable> SpillTokenInner<T> {
                 slf.wake_waiters(slf.state.fetch_and(!LOCK_BIT, Ordering::AcqRel));
             });
 
+            // On failure the value stays spilled, dropping the guard releases the lock.
             let unspill_start = Instant::now();
             let spilled = (*slf.spilled_value.get()).as_ref().unwrap();
-            let value = T::unspill(spilled).await;
+            let value = T::unspill(spilled).await?;
             let ValueSlot::Spilled {
                 n_bytes,
                 spill_ctx_stats,
@@ -213,7 +215,7 @@ impl<T: Spillable> SpillTokenInner<T> {
             if reinsert_id == slf.registration_id.load(Ordering::Relaxed) {
                 if let Some(ctx) = reinsert_ctx.upgrade() {
                     let dyn_slf: Arc<dyn DynSpillToken> = slf.clone();
-                    ctx.reinsert(&dyn_slf, reinsert_id);
+                    ctx.reinsert(&dyn_slf, reinsert_id, n_bytes);
                 }
             }
 
@@ -222,13 +224,13 @@ impl<T: Spillable> SpillTokenInner<T> {
                 slf.state
                     .fetch_add(RO_PIN_COUNT_UNIT - LOCK_BIT - SPILLED_BIT, Ordering::AcqRel),
             );
-            PinnedRef { inner: slf }
+            Ok(PinnedRef { inner: slf })
         }
     }
 
-    fn pin_blocking(slf: &Arc<Self>) -> PinnedRef<'_, T> {
+    fn pin_blocking(slf: &Arc<Self>) -> PolarsResult<PinnedRef<'_, T>> {
         if let Some(r) = slf.try_pin() {
-            return r;
+            return Ok(r);
         }
 
         std::hint::cold_path();
@@ -236,7 +238,7 @@ impl<T: Spillable> SpillTokenInner<T> {
         ASYNC.block_in_place_on(Self::pin(slf))
     }
 
-    async fn pin_mut(slf: &Arc<Self>) -> PinnedMut<'_, T> {
+    async fn pin_mut(slf: &Arc<Self>) -> PolarsResult<PinnedMut<'_, T>> {
         unsafe {
             slf.lock().await;
             let lock_guard = WithDrop::new(slf, |slf| {
@@ -254,9 +256,11 @@ impl<T: Spillable> SpillTokenInner<T> {
             } = value_slot
             {
                 debug_assert!(slf.state.load(Ordering::Relaxed) & SPILLED_BIT == SPILLED_BIT);
+                // On failure the value stays spilled, dropping the guard releases the lock.
                 let unspill_start = Instant::now();
-                let spilled = (*slf.spilled_value.get()).take().unwrap();
-                let value = T::unspill(&spilled).await;
+                let spilled = (*slf.spilled_value.get()).as_ref().unwrap();
+                let value = T::unspill(spilled).await?;
+                *slf.spilled_value.get() = None;
 
                 spill_ctx_stats.add_unspill(
                     *n_bytes,
@@ -267,7 +271,7 @@ impl<T: Spillable> SpillTokenInner<T> {
                 if *reinsert_id == slf.registration_id.load(Ordering::Relaxed) {
                     if let Some(ctx) = reinsert_ctx.upgrade() {
                         let dyn_slf: Arc<dyn DynSpillToken> = slf.clone();
-                        ctx.reinsert(&dyn_slf, *reinsert_id);
+                        ctx.reinsert(&dyn_slf, *reinsert_id, *n_bytes);
                     }
                 }
 
@@ -279,11 +283,11 @@ impl<T: Spillable> SpillTokenInner<T> {
             }
 
             WithDrop::dismiss(lock_guard);
-            PinnedMut { inner: slf }
+            Ok(PinnedMut { inner: slf })
         }
     }
 
-    fn pin_mut_blocking(slf: &Arc<Self>) -> PinnedMut<'_, T> {
+    fn pin_mut_blocking(slf: &Arc<Self>) -> PolarsResult<PinnedMut<'_, T>> {
         ASYNC.block_in_place_on(Self::pin_mut(slf))
     }
 
@@ -422,7 +426,25 @@ impl<T: Spillable> DynSpillToken for SpillTokenInner<T> {
                         .fetch_add(RO_PIN_COUNT_UNIT - LOCK_BIT, Ordering::AcqRel),
                 );
                 let pin_guard = PinnedRef { inner: self };
-                let spilled = pin_guard.spill(stats.name()).await;
+                let spilled = match pin_guard.spill(stats.name(), stats.format()).await {
+                    Ok(spilled) => spilled,
+                    Err(e) => {
+                        // The value stays in memory, releasing our pin is all
+                        // that's needed to undo the spill attempt.
+                        drop(pin_guard);
+                        // Warn once per context, a full disk would otherwise
+                        // repeat this for every spill attempt.
+                        if stats.add_failed_spill(spill_start) {
+                            polars_warn!(
+                                "failed to spill '{}', keeping data in memory: {e}",
+                                stats.name()
+                            );
+                        } else if polars_config::config().verbose() {
+                            eprintln!("[ooc]: failed to spill '{}': {e}", stats.name());
+                        }
+                        return false;
+                    },
+                };
                 core::mem::forget(pin_guard);
                 // We can simply re-acquire the lock here blindly, as we still hold
                 // our pin meaning no one else could've gotten the lock.
@@ -499,43 +521,43 @@ impl<T: Spillable> SpillToken<T> {
     }
 
     /// Get a reference to the underlying value, unspilling it if it was spilled.
-    pub async fn get(&self) -> PinnedRef<'_, T> {
+    pub async fn get(&self) -> PolarsResult<PinnedRef<'_, T>> {
         SpillTokenInner::pin(&self.inner).await
     }
 
     /// Blocking version of get.
-    pub fn get_blocking(&self) -> PinnedRef<'_, T> {
+    pub fn get_blocking(&self) -> PolarsResult<PinnedRef<'_, T>> {
         SpillTokenInner::pin_blocking(&self.inner)
     }
 
     /// Get a mutable reference to the underlying value, unspilling it if it was spilled.
-    pub async fn get_mut(&mut self) -> PinnedMut<'_, T> {
+    pub async fn get_mut(&mut self) -> PolarsResult<PinnedMut<'_, T>> {
         SpillTokenInner::pin_mut(&self.inner).await
     }
 
     /// Blocking version of get_mut.
-    pub fn get_mut_blocking(&mut self) -> PinnedMut<'_, T> {
+    pub fn get_mut_blocking(&mut self) -> PolarsResult<PinnedMut<'_, T>> {
         SpillTokenInner::pin_mut_blocking(&self.inner)
     }
 
     /// Consumes this SpillToken, unspilling it if it were spilled.
-    pub async fn into_inner(mut self) -> T {
-        let pin = self.get_mut().await;
+    pub async fn into_inner(mut self) -> PolarsResult<T> {
+        let pin = self.get_mut().await?;
         let slot = unsafe { pin.inner.value_slot.get().replace(ValueSlot::Dropped) };
         let ValueSlot::InMemory(value) = slot else {
             unreachable!()
         };
-        value
+        Ok(value)
     }
 
     /// Blocking version of into_inner.
-    pub fn into_inner_blocking(mut self) -> T {
-        let pin = self.get_mut_blocking();
+    pub fn into_inner_blocking(mut self) -> PolarsResult<T> {
+        let pin = self.get_mut_blocking()?;
         let slot = unsafe { pin.inner.value_slot.get().replace(ValueSlot::Dropped) };
         let ValueSlot::InMemory(value) = slot else {
             unreachable!()
         };
-        value
+        Ok(value)
     }
 }
 
@@ -18,25 +18,24 @@ use futures::{AsyncRead, AsyncSeek};
 use polars_error::PolarsResult;
 pub use schema::{FileMetadata, infer_schema};
 
+// re-exports of crate::parquet's relevant APIs
+pub use crate::parquet::encryption::FileDecryptionProperties;
+pub use crate::parquet::error::ParquetError;
+pub use crate::parquet::metadata::{ColumnChunkMetadata, ColumnDescriptor, RowGroupMetadata};
+pub use crate::parquet::page::{CompressedDataPage, DataPageHeader, Page};
+pub use crate::parquet::read::{
+    BasicDecompressor, MutStreamingIterator, PageMetaData, PageReader, ReadColumnIterator, State,
+    decompress, get_column_iterator, read_metadata as _read_metadata,
+    read_metadata_with_decryption as _read_metadata_with_decryption,
+};
 #[cfg(feature = "async")]
 pub use crate::parquet::read::{get_page_stream, read_metadata_async as _read_metadata_async};
-// re-exports of crate::parquet's relevant APIs
-pub use crate::parquet::{
-    FallibleStreamingIterator,
-    error::ParquetError,
-    fallible_streaming_iterator,
-    metadata::{ColumnChunkMetadata, ColumnDescriptor, RowGroupMetadata},
-    page::{CompressedDataPage, DataPageHeader, Page},
-    read::{
-        BasicDecompressor, MutStreamingIterator, PageReader, ReadColumnIterator, State, decompress,
-        get_column_iterator, read_metadata as _read_metadata,
-    },
-    schema::types::{
-        GroupLogicalType, ParquetType, PhysicalType, PrimitiveConvertedType, PrimitiveLogicalType,
-        TimeUnit as ParquetTimeUnit,
-    },
-    types::int96_to_i64_ns,
+pub use crate::parquet::schema::types::{
+    GroupLogicalType, ParquetType, PhysicalType, PrimitiveConvertedType, PrimitiveLogicalType,
+    TimeUnit as ParquetTimeUnit,
 };
+pub use crate::parquet::types::int96_to_i64_ns;
+pub use crate::parquet::{FallibleStreamingIterator, fallible_streaming_iterator};
 
 /// Returns all [`ColumnChunkMetadata`] associated to `field_name`.
 /// For non-nested parquet types, this returns a single column
@@ -58,6 +57,14 @@ pub fn read_metadata<R: Read + Seek>(reader: &mut R) -> PolarsResult<FileMetadat
     Ok(_read_metadata(reader)?)
 }
 
+/// Reads the metadata of a possibly encrypted parquet file synchronously.
+pub fn read_metadata_with_decryption<R: Read + Seek>(
+    reader: &mut R,
+    decryption: Option<&FileDecryptionProperties>,
+) -> PolarsResult<FileMetadata> {
+    Ok(_read_metadata_with_decryption(reader, decryption)?)
+}
+
 /// Reads parquets' metadata asynchronously.
 #[cfg(feature = "async")]
 pub async fn read_metadata_async<R: AsyncRead + AsyncSeek + Send + Unpin>(
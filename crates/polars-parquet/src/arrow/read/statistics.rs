@@ -15,7 +15,8 @@ use polars_utils::pl_str::PlSmallStr;
 
 use super::{ParquetTimeUnit, RowGroupMetadata};
 use crate::parquet::error::{ParquetError, ParquetResult};
-use crate::parquet::schema::types::PhysicalType as ParquetPhysicalType;
+use crate::parquet::read::ColumnPageIndex;
+use crate::parquet::schema::types::{PhysicalType as ParquetPhysicalType, PrimitiveType};
 use crate::parquet::statistics::Statistics as ParquetStatistics;
 use crate::read::{
     ColumnChunkMetadata, PrimitiveLogicalType, convert_days_ms, convert_i128, convert_i256,
@@ -295,6 +296,62 @@ pub fn deserialize_all(
     footer_buf: &[u8],
 ) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
     assert!(!row_groups.is_empty());
+
+    let primitive_type = &row_groups[0].parquet_columns()[field_idx]
+        .descriptor()
+        .descriptor
+        .primitive_type;
+
+    deserialize_arrays(
+        field,
+        primitive_type,
+        row_groups.len(),
+        |i| {
+            row_groups[i].parquet_columns()[field_idx]
+                .statistics(footer_buf)
+                .transpose()
+        },
+        |i| row_groups[i].num_rows(),
+    )
+}
+
+/// Deserializes the page-level statistics of a single column chunk from its
+/// [`ColumnPageIndex`] into [`ArrowColumnStatisticsArrays`] with one entry per data page.
+///
+/// `num_rows` is the number of rows in the row group of the column chunk.
+///
+/// # Errors
+/// This function errors if the deserialization of the statistics fails (e.g. invalid utf8)
+pub fn deserialize_page_statistics(
+    field: &Field,
+    column: &ColumnChunkMetadata,
+    page_index: &ColumnPageIndex,
+    num_rows: usize,
+) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
+    let primitive_type = &column.descriptor().descriptor.primitive_type;
+    let page_row_ranges = page_index.page_row_ranges(num_rows).collect::<Vec<_>>();
+
+    deserialize_arrays(
+        field,
+        primitive_type,
+        page_index.num_pages(),
+        |i| {
+            ParquetStatistics::deserialize(page_index.statistics(i), primitive_type.clone())
+                .map(Some)
+        },
+        |i| page_row_ranges[i].len(),
+    )
+}
+
+/// Deserializes `len` parquet statistics, fetched with `get_statistics`, into arrays of
+/// `field`'s type. `get_num_rows` is only used for the number of rows of `Null` columns.
+fn deserialize_arrays(
+    field: &Field,
+    primitive_type: &PrimitiveType,
+    len: usize,
+    get_statistics: impl Fn(usize) -> ParquetResult<Option<ParquetStatistics>>,
+    get_num_rows: impl Fn(usize) -> usize,
+) -> ParquetResult<Option<ArrowColumnStatisticsArrays>> {
     use ArrowDataType as D;
     match field.dtype() {
         // @TODO: These are all a bit more complex, skip for now.
@@ -304,26 +361,19 @@ pub fn deserialize_all(
         D::Struct(..) => Ok(None),
 
         _ => {
-            let mut null_count = MutablePrimitiveArray::<IdxSize>::with_capacity(row_groups.len());
-            let mut distinct_count =
-                MutablePrimitiveArray::<IdxSize>::with_capacity(row_groups.len());
-
-            let primitive_type = &row_groups[0].parquet_columns()[field_idx]
-                .descriptor()
-                .descriptor
-                .primitive_type;
+            let mut null_count = MutablePrimitiveArray::<IdxSize>::with_capacity(len);
+            let mut distinct_count = MutablePrimitiveArray::<IdxSize>::with_capacity(len);
 
             let logical_type = &primitive_type.logical_type;
             let physical_type = &primitive_type.physical_type;
 
             macro_rules! rmap {
                 ($expect:ident, $map:expr, $arr:ty$(, $arg:expr)?) => {{
-                    let mut min_arr = <$arr>::with_capacity(row_groups.len()$(, $arg)?);
-                    let mut max_arr = <$arr>::with_capacity(row_groups.len()$(, $arg)?);
+                    let mut min_arr = <$arr>::with_capacity(len$(, $arg)?);
+                    let mut max_arr = <$arr>::with_capacity(len$(, $arg)?);
 
-                    for rg in row_groups {
-                        let column = &rg.parquet_columns()[field_idx];
-                        let s = column.statistics(footer_buf).transpose()?;
+                    for i in 0..len {
+                        let s = get_statistics(i)?;
 
                         let (v_min, v_max, v_null_count, v_distinct_count) = match s {
                             None => (None, None, None, None),
@@ -398,13 +448,13 @@ pub fn deserialize_all(
             use ParquetPhysicalType as PPT;
             let (min_value, max_value) = match (field.dtype(), physical_type) {
                 (D::Null, _) => {
-                    for rg in row_groups {
-                        null_count.push(Some(rg.num_rows() as IdxSize));
+                    for i in 0..len {
+                        null_count.push(Some(get_num_rows(i) as IdxSize));
                         distinct_count.push(Some(0));
                     }
                     (
-                        NullArray::new(ArrowDataType::Null, row_groups.len()).to_boxed(),
-                        NullArray::new(ArrowDataType::Null, row_groups.len()).to_boxed(),
+                        NullArray::new(ArrowDataType::Null, len).to_boxed(),
+                        NullArray::new(ArrowDataType::Null, len).to_boxed(),
                     )
                 },
 
@@ -0,0 +1,165 @@
+use std::hash::{Hash, Hasher};
+
+use arrow::array::*;
+use arrow::datatypes::ArrowDataType;
+use arrow::types::NativeType;
+use polars_error::{PolarsResult, polars_bail};
+
+use super::to_leaves;
+use crate::parquet::bloom_filter::{hash_byte, hash_native, insert, optimal_num_bytes};
+use crate::parquet::types::NativeType as ParquetNativeType;
+
+/// Options to write split-block bloom filters for a column
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct BloomFilterOptions {
+    /// The expected number of distinct values in a column chunk. If `None`, the number of
+    /// non-null values of the column chunk is used.
+    pub ndv: Option<u64>,
+    /// The desired false positive probability, in `(0, 1)`.
+    pub fpp: f64,
+}
+
+impl Default for BloomFilterOptions {
+    fn default() -> Self {
+        Self {
+            ndv: None,
+            fpp: 0.05,
+        }
+    }
+}
+
+impl Eq for BloomFilterOptions {}
+
+impl Hash for BloomFilterOptions {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ndv.hash(state);
+        self.fpp.to_bits().hash(state);
+    }
+}
+
+impl BloomFilterOptions {
+    pub fn validate(&self) -> PolarsResult<()> {
+        if !(self.fpp > 0.0 && self.fpp < 1.0) {
+            polars_bail!(InvalidOperation:
+                "bloom filter false positive probability must be in (0, 1), got {}", self.fpp
+            )
+        }
+        Ok(())
+    }
+
+    fn num_bytes(&self, num_values: usize) -> usize {
+        optimal_num_bytes(self.ndv.unwrap_or(num_values as u64), self.fpp)
+    }
+}
+
+/// Builds a split-block bloom filter bitset for every parquet leaf column of `array`.
+///
+/// Leaves with a type for which no bloom filter is written yield `None`.
+pub fn array_to_bloom_filters(
+    array: &dyn Array,
+    options: &BloomFilterOptions,
+) -> Vec<Option<Vec<u8>>> {
+    let mut leaves = vec![];
+    to_leaves(array, &mut leaves);
+    leaves
+        .iter()
+        .map(|leaf| leaf_to_bloom_filter(leaf.as_ref(), options))
+        .collect()
+}
+
+fn leaf_to_bloom_filter(array: &dyn Array, options: &BloomFilterOptions) -> Option<Vec<u8>> {
+    use ArrowDataType as D;
+
+    let dtype = array.dtype().to_storage();
+    let is_supported = matches!(
+        dtype,
+        D::UInt8
+            | D::UInt16
+            | D::UInt32
+            | D::UInt64
+            | D::Int8
+            | D::Int16
+            | D::Int32
+            | D::Date32
+            | D::Time32(_)
+            | D::Int64
+            | D::Date64
+            | D::Time64(_)
+            | D::Timestamp(_, _)
+            | D::Duration(_)
+            | D::Float32
+            | D::Float64
+            | D::LargeUtf8
+            | D::LargeBinary
+            | D::Utf8View
+            | D::BinaryView
+            | D::FixedSizeBinary(_)
+    );
+    if !is_supported {
+        return None;
+    }
+
+    let mut bitset = vec![0; options.num_bytes(array.len() - array.null_count())];
+
+    // casts below MUST match the casts done when encoding the pages, as the hash is taken over
+    // the parquet physical value.
+    match dtype {
+        D::UInt8 => insert_native::<u8, i32>(array, &mut bitset),
+        D::UInt16 => insert_native::<u16, i32>(array, &mut bitset),
+        D::UInt32 => insert_native::<u32, i32>(array, &mut bitset),
+        D::UInt64 => insert_native::<u64, i64>(array, &mut bitset),
+        D::Int8 => insert_native::<i8, i32>(array, &mut bitset),
+        D::Int16 => insert_native::<i16, i32>(array, &mut bitset),
+        D::Int32 | D::Date32 | D::Time32(_) => insert_native::<i32, i32>(array, &mut bitset),
+        D::Int64 | D::Date64 | D::Time64(_) | D::Timestamp(_, _) | D::Duration(_) => {
+            insert_native::<i64, i64>(array, &mut bitset)
+        },
+        D::Float32 => insert_native::<f32, f32>(array, &mut bitset),
+        D::Float64 => insert_native::<f64, f64>(array, &mut bitset),
+        D::LargeUtf8 => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            insert_bytes(array.non_null_values_iter(), &mut bitset)
+        },
+        D::LargeBinary => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            insert_bytes(array.non_null_values_iter(), &mut bitset)
+        },
+        D::Utf8View => {
+            let array = array.as_any().downcast_ref::<Utf8ViewArray>().unwrap();
+            insert_bytes(array.non_null_values_iter(), &mut bitset)
+        },
+        D::BinaryView => {
+            let array = array.as_any().downcast_ref::<BinaryViewArray>().unwrap();
+            insert_bytes(array.non_null_values_iter(), &mut bitset)
+        },
+        D::FixedSizeBinary(_) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap();
+            insert_bytes(array.iter().flatten(), &mut bitset)
+        },
+        _ => unreachable!(),
+    }
+
+    Some(bitset)
+}
+
+fn insert_native<T, P>(array: &dyn Array, bitset: &mut [u8])
+where
+    T: NativeType + num_traits::AsPrimitive<P>,
+    P: ParquetNativeType,
+{
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    for value in array.non_null_values_iter() {
+        insert(bitset, hash_native::<P>(value.as_()));
+    }
+}
+
+fn insert_bytes<A: AsRef<[u8]>>(values: impl Iterator<Item = A>, bitset: &mut [u8]) {
+    for value in values {
+        insert(bitset, hash_byte(value));
+    }
+}
@@ -5,6 +5,7 @@ use polars_error::{PolarsError, PolarsResult};
 
 use super::schema::schema_to_metadata_key;
 use super::{ThriftFileMetadata, WriteOptions, to_parquet_schema};
+use crate::parquet::encryption::FileEncryptionProperties;
 use crate::parquet::metadata::{KeyValue, SchemaDescriptor};
 use crate::parquet::write::{RowGroupIterColumns, WriteOptions as FileWriteOptions};
 
@@ -73,6 +74,15 @@ impl<W: Write> FileWriter<W> {
         ))
     }
 
+    /// Encrypts the file with `properties`, see
+    /// [`crate::parquet::write::FileWriter::with_encryption`].
+    pub fn with_encryption(self, properties: FileEncryptionProperties) -> PolarsResult<Self> {
+        Ok(Self {
+            writer: self.writer.with_encryption(properties)?,
+            ..self
+        })
+    }
+
     /// Writes a row group to the file.
     pub fn write(
         &mut self,
@@ -82,6 +92,19 @@ impl<W: Write> FileWriter<W> {
         Ok(self.writer.write(num_rows, row_group)?)
     }
 
+    /// Writes a row group to the file, together with the bloom filter bitsets of its column
+    /// chunks.
+    pub fn write_with_bloom_filters(
+        &mut self,
+        num_rows: u64,
+        row_group: RowGroupIterColumns<'_, PolarsError>,
+        bloom_filters: Vec<Option<Vec<u8>>>,
+    ) -> PolarsResult<()> {
+        Ok(self
+            .writer
+            .write_with_bloom_filters(num_rows, row_group, bloom_filters)?)
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file.
     /// If `key_value_metadata` is provided, the value is taken as-is. If it is not provided,
     /// the Arrow schema is added to the metadata.
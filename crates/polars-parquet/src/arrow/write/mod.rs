@@ -14,6 +14,7 @@
 
 mod binary;
 mod binview;
+mod bloom_filter;
 mod boolean;
 mod dictionary;
 mod file;
@@ -29,6 +30,7 @@ use arrow::array::*;
 use arrow::bitmap::Bitmap;
 use arrow::datatypes::*;
 use arrow::types::{NativeType, days_ms, i256};
+pub use bloom_filter::{BloomFilterOptions, array_to_bloom_filters};
 pub use nested::{num_values, write_rep_and_def};
 pub use pages::{to_leaves, to_nested, to_parquet_leaves};
 use polars_config::config;
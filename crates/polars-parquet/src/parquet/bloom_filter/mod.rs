@@ -1,11 +1,13 @@
-//! API to read and use bloom filters
+//! API to read, write and use bloom filters
 mod hash;
 mod read;
 mod split_block;
+mod write;
 
 pub use hash::{hash_byte, hash_native};
-pub use read::read;
-pub use split_block::{insert, is_in_set};
+pub use read::{deserialize, read};
+pub use split_block::{insert, is_in_set, optimal_num_bytes};
+pub use write::write;
 
 #[cfg(test)]
 mod tests {
@@ -68,4 +70,30 @@ mod tests {
         ];
         assert_eq!(bitset, expected);
     }
+
+    #[test]
+    fn num_bytes() {
+        assert_eq!(optimal_num_bytes(0, 0.05), 32);
+        assert_eq!(optimal_num_bytes(10, 0.05), 32);
+        assert_eq!(optimal_num_bytes(1_000_000, 0.01), 2 * 1024 * 1024);
+        assert_eq!(optimal_num_bytes(u64::MAX, 0.01), 128 * 1024 * 1024);
+    }
+
+    #[test]
+    fn write_read() {
+        let mut bitset = vec![0; optimal_num_bytes(100, 0.01)];
+        for a in 0..100i64 {
+            insert(&mut bitset, hash_native(a));
+        }
+
+        let mut buffer = vec![];
+        let len = write(&mut buffer, &bitset).unwrap();
+        assert_eq!(len as usize, buffer.len());
+        assert!(buffer.ends_with(&bitset));
+
+        let mut deserialized = vec![];
+        deserialize(&buffer, &mut deserialized).unwrap();
+        assert_eq!(deserialized, bitset);
+        assert!(deserialize(&buffer[..buffer.len() - 1], &mut deserialized).is_err());
+    }
 }
@@ -2,11 +2,11 @@ use std::io::{Read, Seek, SeekFrom};
 
 use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
 use polars_parquet_format::{
-    BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHeader, SplitBlockAlgorithm,
-    Uncompressed,
+    BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader,
+    SplitBlockAlgorithm, Uncompressed, XxHash,
 };
 
-use crate::parquet::error::ParquetResult;
+use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::ColumnChunkMetadata;
 
 /// Reads the bloom filter associated to [`ColumnChunkMetadata`] into `bitset`.
@@ -47,3 +47,36 @@ pub fn read<R: Read + Seek>(
 
     Ok(())
 }
+
+/// Deserializes a bloom filter, i.e. its header followed by its bitset, from `buffer` into
+/// `bitset`.
+/// Results in an empty `bitset` if the algorithm, hash or compression is not supported.
+/// # Error
+/// Errors if `buffer` does not contain the whole bloom filter or it can't be deserialized.
+pub fn deserialize(buffer: &[u8], bitset: &mut Vec<u8>) -> ParquetResult<()> {
+    let mut reader = buffer;
+
+    let mut prot = TCompactInputProtocol::new(&mut reader, usize::MAX); // max is ok since `BloomFilterHeader` never allocates
+    let header = BloomFilterHeader::read_from_in_protocol(&mut prot)?;
+
+    bitset.clear();
+
+    if header.algorithm != BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {})
+        || header.hash != BloomFilterHash::XXHASH(XxHash {})
+        || header.compression != BloomFilterCompression::UNCOMPRESSED(Uncompressed {})
+    {
+        return Ok(());
+    }
+
+    let length: usize = header.num_bytes.try_into()?;
+    if reader.len() < length {
+        return Err(ParquetError::oos(
+            "The bloom filter bitset is larger than its buffer",
+        ));
+    }
+
+    bitset.try_reserve(length)?;
+    bitset.extend_from_slice(&reader[..length]);
+
+    Ok(())
+}
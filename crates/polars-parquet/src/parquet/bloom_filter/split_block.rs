@@ -78,3 +78,21 @@ pub fn insert(bitset: &mut [u8], hash: u64) {
         unload_block(block_mask, mut_slice)
     }
 }
+
+/// Smallest bitset that can be written: a single block.
+const MIN_NUM_BYTES: usize = 32;
+/// Largest bitset that will be written, as in parquet-mr.
+const MAX_NUM_BYTES: usize = 128 * 1024 * 1024;
+
+/// Returns the size in bytes of a bitset for `ndv` distinct values with a false positive
+/// probability of `fpp`.
+///
+/// The size is rounded up to a power of two and clamped to `[32 B, 128 MiB]`.
+pub fn optimal_num_bytes(ndv: u64, fpp: f64) -> usize {
+    // Every insertion sets 8 bits, so `m = -8 * ndv / ln(1 - fpp^(1/8))`.
+    let num_bits = -8.0 * ndv as f64 / (1.0 - fpp.powf(1.0 / 8.0)).ln();
+    let num_bytes = (num_bits / 8.0).ceil() as usize;
+    num_bytes
+        .clamp(MIN_NUM_BYTES, MAX_NUM_BYTES)
+        .next_power_of_two()
+}
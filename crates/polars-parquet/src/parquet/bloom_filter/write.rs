@@ -0,0 +1,26 @@
+use std::io::Write;
+
+use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
+use polars_parquet_format::{
+    BloomFilterAlgorithm, BloomFilterCompression, BloomFilterHash, BloomFilterHeader,
+    SplitBlockAlgorithm, Uncompressed, XxHash,
+};
+
+use crate::parquet::error::ParquetResult;
+
+/// Writes the header and the `bitset` of a split-block bloom filter to `writer`.
+/// Returns the number of bytes written.
+pub fn write<W: Write>(writer: &mut W, bitset: &[u8]) -> ParquetResult<u64> {
+    let header = BloomFilterHeader {
+        num_bytes: bitset.len().try_into()?,
+        algorithm: BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {}),
+        hash: BloomFilterHash::XXHASH(XxHash {}),
+        compression: BloomFilterCompression::UNCOMPRESSED(Uncompressed {}),
+    };
+
+    let mut protocol = TCompactOutputProtocol::new(&mut *writer);
+    let header_size = header.write_to_out_protocol(&mut protocol)? as u64;
+    writer.write_all(bitset)?;
+
+    Ok(header_size + bitset.len() as u64)
+}
@@ -0,0 +1,244 @@
+//! `AES_GCM_V1` module encryption, see
+//! <https://github.com/apache/parquet-format/blob/master/Encryption.md#51-encrypted-module-serialization>.
+//!
+//! An encrypted module is serialized as `length (4 bytes, LE) | nonce (12 bytes) | ciphertext |
+//! tag (16 bytes)`, where `length` counts every byte after itself.
+
+use crate::parquet::error::{ParquetError, ParquetResult};
+
+pub(crate) const SIZE_LEN: usize = 4;
+pub(crate) const NONCE_LEN: usize = 12;
+pub(crate) const TAG_LEN: usize = 16;
+
+/// The number of bytes an encrypted module takes on top of its plaintext.
+pub const MODULE_OVERHEAD: usize = SIZE_LEN + NONCE_LEN + TAG_LEN;
+
+/// The length of the nonce and tag appended to a signed plaintext footer.
+pub(crate) const SIGNATURE_LEN: usize = NONCE_LEN + TAG_LEN;
+
+fn check_key_len(key: &[u8]) -> ParquetResult<()> {
+    if !matches!(key.len(), 16 | 24 | 32) {
+        return Err(ParquetError::InvalidParameter(format!(
+            "AES-GCM keys must be 16, 24 or 32 bytes long, got {} bytes",
+            key.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the length of the encrypted module at the start of `buffer`, including its length
+/// prefix.
+pub(crate) fn module_len(buffer: &[u8]) -> ParquetResult<usize> {
+    let Some(len) = buffer.get(..SIZE_LEN) else {
+        return Err(ParquetError::oos(
+            "An encrypted module must start with its length",
+        ));
+    };
+    let len = u32::from_le_bytes(len.try_into().unwrap()) as usize;
+    if len < NONCE_LEN + TAG_LEN {
+        return Err(ParquetError::oos(format!(
+            "An encrypted module must contain at least {} bytes, got {len}",
+            NONCE_LEN + TAG_LEN
+        )));
+    }
+    Ok(SIZE_LEN + len)
+}
+
+#[cfg(feature = "encryption")]
+mod aes_gcm_impl {
+    use std::sync::Arc;
+
+    use aes_gcm::aead::consts::{U12, U16};
+    use aes_gcm::aes::Aes192;
+    use aes_gcm::{AeadInPlace, Aes128Gcm, Aes256Gcm, AesGcm, KeyInit, Nonce, Tag};
+    use subtle::ConstantTimeEq;
+
+    use super::*;
+
+    enum Inner {
+        Aes128(Aes128Gcm),
+        Aes192(AesGcm<Aes192, U12>),
+        Aes256(Aes256Gcm),
+    }
+
+    macro_rules! dispatch {
+        ($inner:expr, |$cipher:ident| $body:expr) => {
+            match $inner {
+                Inner::Aes128($cipher) => $body,
+                Inner::Aes192($cipher) => $body,
+                Inner::Aes256($cipher) => $body,
+            }
+        };
+    }
+
+    /// An AES-GCM cipher keyed with a footer or column key.
+    #[derive(Clone)]
+    pub(crate) struct AesGcmCipher(Arc<Inner>);
+
+    impl AesGcmCipher {
+        pub(crate) fn new(key: &[u8]) -> ParquetResult<Self> {
+            check_key_len(key)?;
+            let inner = match key.len() {
+                16 => Inner::Aes128(Aes128Gcm::new_from_slice(key).unwrap()),
+                24 => Inner::Aes192(AesGcm::<Aes192, U12>::new_from_slice(key).unwrap()),
+                _ => Inner::Aes256(Aes256Gcm::new_from_slice(key).unwrap()),
+            };
+            Ok(Self(Arc::new(inner)))
+        }
+
+        fn tag(&self, nonce: &[u8], aad: &[u8], buffer: &mut [u8]) -> ParquetResult<[u8; TAG_LEN]> {
+            let nonce = Nonce::<U12>::from_slice(nonce);
+            let tag = dispatch!(self.0.as_ref(), |cipher| cipher
+                .encrypt_in_place_detached(nonce, aad, buffer))
+            .map_err(|_| ParquetError::oos("Failed to encrypt a module"))?;
+            Ok(tag.into())
+        }
+
+        /// Encrypts `plaintext` into a serialized module.
+        pub(crate) fn encrypt(&self, plaintext: &[u8], aad: &[u8]) -> ParquetResult<Vec<u8>> {
+            let len = u32::try_from(NONCE_LEN + plaintext.len() + TAG_LEN).map_err(|_| {
+                ParquetError::oos("An encrypted module can contain at most u32::MAX bytes")
+            })?;
+
+            let mut module = Vec::with_capacity(MODULE_OVERHEAD + plaintext.len());
+            module.extend_from_slice(&len.to_le_bytes());
+            module.extend_from_slice(&random_bytes::<NONCE_LEN>()?);
+            module.extend_from_slice(plaintext);
+
+            let (nonce, ciphertext) = module[SIZE_LEN..].split_at_mut(NONCE_LEN);
+            let tag = self.tag(nonce, aad, ciphertext)?;
+            module.extend_from_slice(&tag);
+            Ok(module)
+        }
+
+        /// Decrypts the serialized module `module` and returns its plaintext.
+        pub(crate) fn decrypt(&self, module: &[u8], aad: &[u8]) -> ParquetResult<Vec<u8>> {
+            if module_len(module)? != module.len() {
+                return Err(ParquetError::oos(
+                    "The length of an encrypted module does not match its length prefix",
+                ));
+            }
+
+            let nonce = Nonce::<U12>::from_slice(&module[SIZE_LEN..SIZE_LEN + NONCE_LEN]);
+            let (ciphertext, tag) = module[SIZE_LEN + NONCE_LEN..]
+                .split_at(module.len() - SIZE_LEN - NONCE_LEN - TAG_LEN);
+            let tag = Tag::<U16>::from_slice(tag);
+
+            let mut plaintext = ciphertext.to_vec();
+            dispatch!(self.0.as_ref(), |cipher| cipher.decrypt_in_place_detached(
+                nonce,
+                aad,
+                &mut plaintext,
+                tag
+            ))
+            .map_err(|_| {
+                ParquetError::oos(
+                    "Failed to decrypt a module, the key or additional authenticated data is wrong",
+                )
+            })?;
+            Ok(plaintext)
+        }
+
+        /// Computes the signature (`nonce | tag`) of a plaintext footer.
+        pub(crate) fn sign(
+            &self,
+            plaintext: &[u8],
+            aad: &[u8],
+        ) -> ParquetResult<[u8; SIGNATURE_LEN]> {
+            let mut signature = [0u8; SIGNATURE_LEN];
+            signature[..NONCE_LEN].copy_from_slice(&random_bytes::<NONCE_LEN>()?);
+            let tag = self.tag(&signature[..NONCE_LEN], aad, &mut plaintext.to_vec())?;
+            signature[NONCE_LEN..].copy_from_slice(&tag);
+            Ok(signature)
+        }
+
+        /// Verifies the signature (`nonce | tag`) of a plaintext footer.
+        pub(crate) fn verify(
+            &self,
+            plaintext: &[u8],
+            aad: &[u8],
+            signature: &[u8],
+        ) -> ParquetResult<()> {
+            if signature.len() != SIGNATURE_LEN {
+                return Err(ParquetError::oos(format!(
+                    "The signature of the plaintext footer must be {SIGNATURE_LEN} bytes, got {}",
+                    signature.len()
+                )));
+            }
+            let (nonce, expected) = signature.split_at(NONCE_LEN);
+            let tag = self.tag(nonce, aad, &mut plaintext.to_vec())?;
+            if !bool::from(tag.as_slice().ct_eq(expected)) {
+                return Err(ParquetError::oos(
+                    "The signature of the plaintext footer does not match the footer key",
+                ));
+            }
+            Ok(())
+        }
+    }
+
+    pub(crate) fn random_bytes<const N: usize>() -> ParquetResult<[u8; N]> {
+        let mut bytes = [0u8; N];
+        getrandom::fill(&mut bytes)
+            .map_err(|e| ParquetError::oos(format!("Failed to generate random bytes: {e}")))?;
+        Ok(bytes)
+    }
+}
+
+#[cfg(feature = "encryption")]
+pub(crate) use aes_gcm_impl::{AesGcmCipher, random_bytes};
+
+#[cfg(not(feature = "encryption"))]
+mod disabled_impl {
+    use super::*;
+    use crate::parquet::error::Feature;
+
+    fn not_active() -> ParquetError {
+        ParquetError::FeatureNotActive(
+            Feature::Encryption,
+            "encrypt or decrypt parquet modules".to_string(),
+        )
+    }
+
+    /// Stub for builds without the `encryption` feature. It can't be constructed.
+    #[derive(Clone)]
+    pub(crate) struct AesGcmCipher(());
+
+    impl AesGcmCipher {
+        pub(crate) fn new(key: &[u8]) -> ParquetResult<Self> {
+            check_key_len(key)?;
+            Err(not_active())
+        }
+
+        pub(crate) fn encrypt(&self, _plaintext: &[u8], _aad: &[u8]) -> ParquetResult<Vec<u8>> {
+            Err(not_active())
+        }
+
+        pub(crate) fn decrypt(&self, _module: &[u8], _aad: &[u8]) -> ParquetResult<Vec<u8>> {
+            Err(not_active())
+        }
+
+        pub(crate) fn sign(
+            &self,
+            _plaintext: &[u8],
+            _aad: &[u8],
+        ) -> ParquetResult<[u8; SIGNATURE_LEN]> {
+            Err(not_active())
+        }
+
+        pub(crate) fn verify(
+            &self,
+            _plaintext: &[u8],
+            _aad: &[u8],
+            _signature: &[u8],
+        ) -> ParquetResult<()> {
+            Err(not_active())
+        }
+    }
+
+    pub(crate) fn random_bytes<const N: usize>() -> ParquetResult<[u8; N]> {
+        Err(not_active())
+    }
+}
+
+#[cfg(not(feature = "encryption"))]
+pub(crate) use disabled_impl::{AesGcmCipher, random_bytes};
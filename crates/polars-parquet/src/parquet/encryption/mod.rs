@@ -0,0 +1,542 @@
+//! Parquet modular encryption, see
+//! <https://github.com/apache/parquet-format/blob/master/Encryption.md>.
+//!
+//! Only the `AES_GCM_V1` algorithm is supported, in both the encrypted footer mode and the
+//! plaintext (signed) footer mode. The ciphers are only available with the `encryption` feature;
+//! without it, reading or writing an encrypted file errors.
+mod ciphers;
+
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+pub use ciphers::MODULE_OVERHEAD;
+pub(crate) use ciphers::{AesGcmCipher, SIGNATURE_LEN, module_len, random_bytes};
+use polars_parquet_format::{
+    AesGcmV1, ColumnCryptoMetaData, EncryptionAlgorithm, EncryptionWithColumnKey,
+    EncryptionWithFooterKey,
+};
+use polars_utils::aliases::{InitHashMaps, PlHashMap};
+
+use crate::parquet::error::{ParquetError, ParquetResult};
+use crate::parquet::metadata::ColumnDescriptor;
+
+/// Magic bytes at the start and end of files with an encrypted footer.
+pub const PARQUET_ENCRYPTED_MAGIC: [u8; 4] = [b'P', b'A', b'R', b'E'];
+
+/// Resolves the keys that are referenced by the key metadata stored in a file.
+pub trait KeyRetriever: Send + Sync {
+    /// Returns the AES key (16, 24 or 32 bytes) identified by `key_metadata`.
+    fn retrieve_key(&self, key_metadata: &[u8]) -> ParquetResult<Vec<u8>>;
+}
+
+/// The key of a column that isn't encrypted with the footer key.
+#[derive(Clone)]
+pub struct ColumnKey {
+    pub key: Vec<u8>,
+    /// Stored in the file so readers can retrieve the key.
+    pub key_metadata: Option<Vec<u8>>,
+}
+
+impl Debug for ColumnKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColumnKey")
+            .field("key", &"<redacted>")
+            .field("key_metadata", &self.key_metadata)
+            .finish()
+    }
+}
+
+/// How to encrypt a file.
+#[derive(Clone)]
+pub struct FileEncryptionProperties {
+    /// Encrypts (or signs) the footer, and every column without a column key.
+    pub footer_key: Vec<u8>,
+    /// Stored in the file so readers can retrieve the footer key.
+    pub footer_key_metadata: Option<Vec<u8>>,
+    /// Keys of the columns that are encrypted with their own key, by top-level column name. All
+    /// leaf columns under a top-level column share its key.
+    ///
+    /// If empty, every column is encrypted with the footer key. Otherwise, columns that aren't
+    /// listed are not encrypted.
+    pub column_keys: Vec<(String, ColumnKey)>,
+    /// Write the footer in plaintext, signed with the footer key, so that readers without the
+    /// keys can still read the schema and the unencrypted columns.
+    pub plaintext_footer: bool,
+    /// Prefix of the additional authenticated data of every module.
+    pub aad_prefix: Option<Vec<u8>>,
+    /// Store `aad_prefix` in the file. Otherwise readers must supply it.
+    pub store_aad_prefix: bool,
+}
+
+impl FileEncryptionProperties {
+    /// Encrypt every column and the footer with `footer_key`.
+    pub fn new(footer_key: Vec<u8>) -> Self {
+        Self {
+            footer_key,
+            footer_key_metadata: None,
+            column_keys: vec![],
+            plaintext_footer: false,
+            aad_prefix: None,
+            store_aad_prefix: true,
+        }
+    }
+}
+
+impl Debug for FileEncryptionProperties {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileEncryptionProperties")
+            .field("footer_key", &"<redacted>")
+            .field("footer_key_metadata", &self.footer_key_metadata)
+            .field("column_keys", &self.column_keys)
+            .field("plaintext_footer", &self.plaintext_footer)
+            .field("aad_prefix", &self.aad_prefix)
+            .field("store_aad_prefix", &self.store_aad_prefix)
+            .finish()
+    }
+}
+
+/// How to decrypt a file.
+#[derive(Clone)]
+pub struct FileDecryptionProperties {
+    pub key_retriever: Arc<dyn KeyRetriever>,
+    /// The prefix of the additional authenticated data, for files that don't store it.
+    pub aad_prefix: Option<Vec<u8>>,
+}
+
+impl FileDecryptionProperties {
+    pub fn new(key_retriever: Arc<dyn KeyRetriever>) -> Self {
+        Self {
+            key_retriever,
+            aad_prefix: None,
+        }
+    }
+}
+
+impl Debug for FileDecryptionProperties {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileDecryptionProperties")
+            .field("aad_prefix", &self.aad_prefix)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The kinds of modules of an encrypted file, which are part of their additional authenticated
+/// data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub(crate) enum ModuleType {
+    Footer = 0,
+    ColumnMetaData = 1,
+    DataPage = 2,
+    DictionaryPage = 3,
+    DataPageHeader = 4,
+    DictionaryPageHeader = 5,
+    ColumnIndex = 6,
+    OffsetIndex = 7,
+}
+
+fn ordinal(ordinal: usize, name: &str) -> ParquetResult<[u8; 2]> {
+    i16::try_from(ordinal).map(i16::to_le_bytes).map_err(|_| {
+        ParquetError::oos(format!(
+            "Encrypted files can contain at most {} {name}s",
+            i16::MAX as usize + 1
+        ))
+    })
+}
+
+/// The additional authenticated data of the footer.
+pub(crate) fn footer_aad(file_aad: &[u8]) -> Vec<u8> {
+    let mut aad = file_aad.to_vec();
+    aad.push(ModuleType::Footer as u8);
+    aad
+}
+
+/// Builds the file AAD (`aad_prefix | aad_file_unique`) of a file written with `algorithm`.
+fn file_aad(
+    algorithm: &EncryptionAlgorithm,
+    supplied_aad_prefix: Option<&[u8]>,
+) -> ParquetResult<Vec<u8>> {
+    let EncryptionAlgorithm::AESGCMV1(algorithm) = algorithm else {
+        return Err(ParquetError::FeatureNotSupported(
+            "Only the AES_GCM_V1 encryption algorithm is supported".to_string(),
+        ));
+    };
+
+    let aad_prefix = match (&algorithm.aad_prefix, supplied_aad_prefix) {
+        (_, Some(prefix)) => prefix,
+        (Some(prefix), None) => prefix.as_slice(),
+        (None, None) if algorithm.supply_aad_prefix == Some(true) => {
+            return Err(ParquetError::InvalidParameter(
+                "The file was encrypted with an AAD prefix that is not stored in it, it must be supplied".to_string(),
+            ));
+        },
+        (None, None) => &[],
+    };
+
+    let aad_file_unique = algorithm.aad_file_unique.as_deref().unwrap_or_default();
+    Ok([aad_prefix, aad_file_unique].concat())
+}
+
+/// Retrieves the column keys of a file being read on first use, once per key metadata. Keys that
+/// are not accessible are cached as such.
+struct ColumnKeys {
+    key_retriever: Arc<dyn KeyRetriever>,
+    ciphers: Mutex<PlHashMap<Vec<u8>, ParquetResult<AesGcmCipher>>>,
+}
+
+impl ColumnKeys {
+    fn cipher(&self, key_metadata: &[u8]) -> ParquetResult<AesGcmCipher> {
+        if let Some(cipher) = self.ciphers.lock().unwrap().get(key_metadata) {
+            return cipher.clone();
+        }
+        // Don't hold the lock while retrieving, the retriever might be slow.
+        let cipher = self
+            .key_retriever
+            .retrieve_key(key_metadata)
+            .and_then(|key| AesGcmCipher::new(&key));
+        self.ciphers
+            .lock()
+            .unwrap()
+            .insert(key_metadata.to_vec(), cipher.clone());
+        cipher
+    }
+}
+
+#[derive(Clone)]
+enum ColumnKeyCipher {
+    Resolved(AesGcmCipher),
+    /// The column key is retrieved when the first module of the column chunk is encrypted or
+    /// decrypted, such that only the keys of the columns that are read need to be accessible.
+    Deferred {
+        keys: Arc<ColumnKeys>,
+        key_metadata: Arc<[u8]>,
+    },
+}
+
+/// Encrypts and decrypts the modules of a single column chunk.
+#[derive(Clone)]
+pub(crate) struct ColumnCipher {
+    cipher: ColumnKeyCipher,
+    /// `file AAD | module type | row group ordinal | column ordinal`, the module type is patched
+    /// per module.
+    aad: Arc<[u8]>,
+}
+
+impl ColumnCipher {
+    fn new(
+        cipher: ColumnKeyCipher,
+        file_aad: &[u8],
+        row_group_ordinal: usize,
+        column_ordinal: usize,
+    ) -> ParquetResult<Self> {
+        let mut aad = file_aad.to_vec();
+        aad.push(0);
+        aad.extend_from_slice(&ordinal(row_group_ordinal, "row group")?);
+        aad.extend_from_slice(&ordinal(column_ordinal, "column")?);
+        Ok(Self {
+            cipher,
+            aad: aad.into(),
+        })
+    }
+
+    fn module_aad(
+        &self,
+        module_type: ModuleType,
+        page_ordinal: Option<usize>,
+    ) -> ParquetResult<Vec<u8>> {
+        let mut aad = self.aad.to_vec();
+        let type_idx = aad.len() - 5;
+        aad[type_idx] = module_type as u8;
+        if let Some(page_ordinal) = page_ordinal {
+            aad.extend_from_slice(&ordinal(page_ordinal, "page")?);
+        }
+        Ok(aad)
+    }
+
+    fn cipher(&self) -> ParquetResult<AesGcmCipher> {
+        match &self.cipher {
+            ColumnKeyCipher::Resolved(cipher) => Ok(cipher.clone()),
+            ColumnKeyCipher::Deferred { keys, key_metadata } => keys.cipher(key_metadata),
+        }
+    }
+
+    /// Retrieves the key of this column chunk, if that wasn't done yet. Fails if the key is not
+    /// accessible.
+    pub(crate) fn resolve(&mut self) -> ParquetResult<()> {
+        if matches!(self.cipher, ColumnKeyCipher::Deferred { .. }) {
+            self.cipher = ColumnKeyCipher::Resolved(self.cipher()?);
+        }
+        Ok(())
+    }
+
+    /// Encrypts a module of this column chunk. Only (data) page modules have a page ordinal.
+    pub(crate) fn encrypt(
+        &self,
+        module_type: ModuleType,
+        page_ordinal: Option<usize>,
+        plaintext: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        let aad = self.module_aad(module_type, page_ordinal)?;
+        self.cipher()?.encrypt(plaintext, &aad)
+    }
+
+    /// Decrypts a module of this column chunk. Only (data) page modules have a page ordinal.
+    pub(crate) fn decrypt(
+        &self,
+        module_type: ModuleType,
+        page_ordinal: Option<usize>,
+        module: &[u8],
+    ) -> ParquetResult<Vec<u8>> {
+        let aad = self.module_aad(module_type, page_ordinal)?;
+        self.cipher()?.decrypt(module, &aad)
+    }
+}
+
+impl Debug for ColumnCipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ColumnCipher").finish_non_exhaustive()
+    }
+}
+
+/// The encryption of a column chunk being written.
+pub(crate) struct ColumnEncryptor {
+    pub(crate) cipher: ColumnCipher,
+    /// `None` if the column is encrypted with the footer key.
+    column_key_cipher: Option<(AesGcmCipher, Option<Vec<u8>>)>,
+    plaintext_footer: bool,
+}
+
+impl ColumnEncryptor {
+    /// Sets the crypto metadata of an encrypted column chunk and encrypts its column metadata,
+    /// unless it is encrypted with the footer key as part of an encrypted footer.
+    pub(crate) fn finish_column_chunk(
+        &self,
+        column_chunk: &mut polars_parquet_format::ColumnChunk,
+        descriptor: &ColumnDescriptor,
+    ) -> ParquetResult<()> {
+        use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
+
+        column_chunk.crypto_metadata = Some(match &self.column_key_cipher {
+            None => ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(EncryptionWithFooterKey {}),
+            Some((_, key_metadata)) => {
+                ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(EncryptionWithColumnKey {
+                    path_in_schema: descriptor
+                        .path_in_schema
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect(),
+                    key_metadata: key_metadata.clone(),
+                })
+            },
+        });
+        if self.column_key_cipher.is_none() && !self.plaintext_footer {
+            return Ok(());
+        }
+
+        let metadata = column_chunk.meta_data.as_mut().unwrap();
+        let mut serialized = vec![];
+        let mut protocol = TCompactOutputProtocol::new(&mut serialized);
+        metadata.write_to_out_protocol(&mut protocol)?;
+        column_chunk.encrypted_column_metadata = Some(self.cipher.encrypt(
+            ModuleType::ColumnMetaData,
+            None,
+            &serialized,
+        )?);
+
+        if self.plaintext_footer {
+            // Legacy readers can still find the column chunk, but not its statistics.
+            metadata.statistics = None;
+            metadata.encoding_stats = None;
+            metadata.size_statistics = None;
+        } else {
+            column_chunk.meta_data = None;
+        }
+        Ok(())
+    }
+}
+
+/// Encrypts the modules of a file being written.
+pub(crate) struct FileEncryptor {
+    properties: FileEncryptionProperties,
+    footer_cipher: AesGcmCipher,
+    column_ciphers: Vec<(String, AesGcmCipher)>,
+    algorithm: EncryptionAlgorithm,
+    file_aad: Vec<u8>,
+}
+
+impl FileEncryptor {
+    pub(crate) fn try_new(properties: FileEncryptionProperties) -> ParquetResult<Self> {
+        let footer_cipher = AesGcmCipher::new(&properties.footer_key)?;
+        let column_ciphers = properties
+            .column_keys
+            .iter()
+            .map(|(name, key)| Ok((name.clone(), AesGcmCipher::new(&key.key)?)))
+            .collect::<ParquetResult<Vec<_>>>()?;
+
+        let aad_file_unique = random_bytes::<8>()?.to_vec();
+        let algorithm = EncryptionAlgorithm::AESGCMV1(AesGcmV1 {
+            aad_prefix: properties
+                .aad_prefix
+                .clone()
+                .filter(|_| properties.store_aad_prefix),
+            aad_file_unique: Some(aad_file_unique),
+            supply_aad_prefix: properties
+                .aad_prefix
+                .as_ref()
+                .map(|_| !properties.store_aad_prefix),
+        });
+        let file_aad = file_aad(&algorithm, properties.aad_prefix.as_deref())?;
+
+        Ok(Self {
+            properties,
+            footer_cipher,
+            column_ciphers,
+            algorithm,
+            file_aad,
+        })
+    }
+
+    pub(crate) fn plaintext_footer(&self) -> bool {
+        self.properties.plaintext_footer
+    }
+
+    pub(crate) fn algorithm(&self) -> &EncryptionAlgorithm {
+        &self.algorithm
+    }
+
+    pub(crate) fn footer_key_metadata(&self) -> Option<&Vec<u8>> {
+        self.properties.footer_key_metadata.as_ref()
+    }
+
+    /// Whether the column is encrypted, either with the footer key or its own key.
+    pub(crate) fn is_column_encrypted(&self, descriptor: &ColumnDescriptor) -> bool {
+        let root = descriptor.path_in_schema[0].as_str();
+        self.column_ciphers.is_empty() || self.column_ciphers.iter().any(|(name, _)| name == root)
+    }
+
+    /// Returns the encryptor of a column chunk, or `None` if the column is not encrypted.
+    pub(crate) fn column_encryptor(
+        &self,
+        row_group_ordinal: usize,
+        column_ordinal: usize,
+        descriptor: &ColumnDescriptor,
+    ) -> ParquetResult<Option<ColumnEncryptor>> {
+        let column_key_cipher = if self.column_ciphers.is_empty() {
+            None
+        } else {
+            let root = descriptor.path_in_schema[0].as_str();
+            let Some(idx) = self
+                .column_ciphers
+                .iter()
+                .position(|(name, _)| name == root)
+            else {
+                return Ok(None);
+            };
+            let (_, column_key) = &self.properties.column_keys[idx];
+            Some((
+                self.column_ciphers[idx].1.clone(),
+                column_key.key_metadata.clone(),
+            ))
+        };
+
+        let cipher = column_key_cipher
+            .as_ref()
+            .map_or(&self.footer_cipher, |(cipher, _)| cipher);
+        let cipher = ColumnCipher::new(
+            ColumnKeyCipher::Resolved(cipher.clone()),
+            &self.file_aad,
+            row_group_ordinal,
+            column_ordinal,
+        )?;
+
+        Ok(Some(ColumnEncryptor {
+            cipher,
+            column_key_cipher,
+            plaintext_footer: self.properties.plaintext_footer,
+        }))
+    }
+
+    /// Encrypts the serialized `FileMetaData` into the footer module.
+    pub(crate) fn encrypt_footer(&self, footer: &[u8]) -> ParquetResult<Vec<u8>> {
+        self.footer_cipher
+            .encrypt(footer, &footer_aad(&self.file_aad))
+    }
+
+    /// Signs the serialized plaintext `FileMetaData`.
+    pub(crate) fn sign_footer(&self, footer: &[u8]) -> ParquetResult<[u8; SIGNATURE_LEN]> {
+        self.footer_cipher.sign(footer, &footer_aad(&self.file_aad))
+    }
+}
+
+/// Decrypts the metadata of a file being read, and creates the ciphers of its column chunks.
+pub(crate) struct FileDecryptor<'a> {
+    properties: &'a FileDecryptionProperties,
+    footer_key_metadata: Option<Vec<u8>>,
+    footer_cipher: Option<AesGcmCipher>,
+    column_keys: Arc<ColumnKeys>,
+    file_aad: Vec<u8>,
+}
+
+impl<'a> FileDecryptor<'a> {
+    pub(crate) fn try_new(
+        properties: &'a FileDecryptionProperties,
+        algorithm: &EncryptionAlgorithm,
+        footer_key_metadata: Option<Vec<u8>>,
+    ) -> ParquetResult<Self> {
+        let file_aad = file_aad(algorithm, properties.aad_prefix.as_deref())?;
+        let column_keys = Arc::new(ColumnKeys {
+            key_retriever: properties.key_retriever.clone(),
+            ciphers: Mutex::new(PlHashMap::new()),
+        });
+        Ok(Self {
+            properties,
+            footer_key_metadata,
+            footer_cipher: None,
+            column_keys,
+            file_aad,
+        })
+    }
+
+    fn footer_cipher(&mut self) -> ParquetResult<&AesGcmCipher> {
+        if self.footer_cipher.is_none() {
+            let key = self
+                .properties
+                .key_retriever
+                .retrieve_key(self.footer_key_metadata.as_deref().unwrap_or_default())?;
+            self.footer_cipher = Some(AesGcmCipher::new(&key)?);
+        }
+        Ok(self.footer_cipher.as_ref().unwrap())
+    }
+
+    /// Decrypts the encrypted footer module.
+    pub(crate) fn decrypt_footer(&mut self, module: &[u8]) -> ParquetResult<Vec<u8>> {
+        let aad = footer_aad(&self.file_aad);
+        self.footer_cipher()?.decrypt(module, &aad)
+    }
+
+    /// Verifies the signature of a plaintext footer.
+    pub(crate) fn verify_footer(&mut self, footer: &[u8], signature: &[u8]) -> ParquetResult<()> {
+        let aad = footer_aad(&self.file_aad);
+        self.footer_cipher()?.verify(footer, &aad, signature)
+    }
+
+    /// Returns the cipher of a column chunk with the given crypto metadata. Column keys are only
+    /// retrieved once the cipher is used, and then once per key metadata of the file.
+    pub(crate) fn column_cipher(
+        &mut self,
+        crypto_metadata: &ColumnCryptoMetaData,
+        row_group_ordinal: usize,
+        column_ordinal: usize,
+    ) -> ParquetResult<ColumnCipher> {
+        let cipher = match crypto_metadata {
+            ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(_) => {
+                ColumnKeyCipher::Resolved(self.footer_cipher()?.clone())
+            },
+            ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(column) => ColumnKeyCipher::Deferred {
+                keys: self.column_keys.clone(),
+                key_metadata: column.key_metadata.as_deref().unwrap_or_default().into(),
+            },
+        };
+        ColumnCipher::new(cipher, &self.file_aad, row_group_ordinal, column_ordinal)
+    }
+}
@@ -12,14 +12,18 @@
 //!   See <https://github.com/apache/parquet-format/blob/96edf77704b60b6f3ca2232c218c64eff6c874d3/src/main/thrift/parquet.thrift> for spec
 
 use polars_buffer::Buffer;
-use polars_parquet_format::{ColumnOrder, KeyValue, SchemaElement, SortingColumn};
+use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+use polars_parquet_format::{
+    ColumnCryptoMetaData, ColumnOrder, EncryptionAlgorithm, KeyValue, SchemaElement, SortingColumn,
+};
 
 use super::parquet_thrift::{FieldType, ThriftCompactInputProtocol, ThriftSliceInputProtocol};
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::{ColumnCipher, FileDecryptor, ModuleType};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::{
-    ByteRange, CompactColumnChunk, CompactColumnMetaData, CompactFileMetaData, CompactRowGroup,
-    CompactStatistics,
+    ByteRange, CompactColumnChunk, CompactColumnCrypto, CompactColumnMetaData, CompactFileMetaData,
+    CompactRowGroup, CompactStatistics,
 };
 
 trait RequireField<T> {
@@ -68,21 +72,112 @@ macro_rules! read_struct_fields {
     };
 }
 
+/// Skip the value of a field and return its serialized bytes.
+fn skip_field<'a>(
+    prot: &mut ThriftSliceInputProtocol<'a>,
+    field_type: FieldType,
+) -> ParquetResult<&'a [u8]> {
+    let start = prot.as_slice();
+    prot.skip(field_type)?;
+    Ok(&start[..start.len() - prot.as_slice().len()])
+}
+
+/// Decode a rarely present struct or union with the format crate.
+fn read_with_format_crate<T>(
+    mut bytes: &[u8],
+    read: fn(&mut TCompactInputProtocol<&mut &[u8]>) -> polars_parquet_format::thrift::Result<T>,
+) -> ParquetResult<T> {
+    let max_size = bytes.len().saturating_mul(9).saturating_add(1024);
+    let mut prot = TCompactInputProtocol::new(&mut bytes, max_size);
+    Ok(read(&mut prot)?)
+}
+
+/// Encrypted column metadata gets decrypted while decoding. Its
+/// statistics `ByteRange`s point past the end of the footer, into the
+/// decrypted bytes that get appended to the footer buffer afterwards.
+struct DecodeState<'a, 'b> {
+    decryptor: Option<&'b mut FileDecryptor<'a>>,
+    footer_len: usize,
+    decrypted: Vec<u8>,
+}
+
+impl DecodeState<'_, '_> {
+    fn decrypt_column_meta_data(
+        &mut self,
+        cipher: &ColumnCipher,
+        module: &[u8],
+    ) -> ParquetResult<CompactColumnMetaData> {
+        let plaintext = cipher.decrypt(ModuleType::ColumnMetaData, None, module)?;
+        let mut prot = ThriftSliceInputProtocol::new(&plaintext);
+        let mut meta_data = read_column_meta_data(&mut prot, plaintext.as_ptr())?;
+
+        let shift = (self.footer_len + self.decrypted.len()) as u32;
+        if let Some(statistics) = &mut meta_data.statistics {
+            for range in [&mut statistics.min_value, &mut statistics.max_value]
+                .into_iter()
+                .flatten()
+            {
+                range.offset += shift;
+            }
+        }
+        self.decrypted.extend_from_slice(&plaintext);
+        Ok(meta_data)
+    }
+}
+
 /// Decode a Parquet `FileMetaData` footer into [`CompactFileMetaData`].
 ///
 /// `footer` holds the bytes `&buf` views. `ByteRange` offsets into stats
 /// min/max are recorded relative to `footer.as_ptr()`, and the [`Buffer`]
 /// is cloned (refcount-bump) into the output so they stay resolvable.
 ///
+/// With a `decryptor`, encrypted column chunks get their cipher attached and
+/// their encrypted column metadata is decrypted. Without one, encrypted
+/// column chunks are decoded from their plaintext metadata, if any.
+///
 /// Crate-internal: external callers go through
 /// [`crate::parquet::read::deserialize_metadata`] which combines this with
 /// [`crate::parquet::metadata::FileMetadata::from_compact`] to produce the
 /// public [`crate::parquet::metadata::FileMetadata`].
-pub(crate) fn decode_file_metadata(footer: Buffer<u8>) -> ParquetResult<CompactFileMetaData> {
+pub(crate) fn decode_file_metadata(
+    footer: Buffer<u8>,
+    decryptor: Option<&mut FileDecryptor<'_>>,
+) -> ParquetResult<CompactFileMetaData> {
     let buf: &[u8] = footer.as_ref();
     let origin_ptr = buf.as_ptr();
     let mut prot = ThriftSliceInputProtocol::new(buf);
-    read_file_metadata(&mut prot, origin_ptr, &footer)
+    let mut state = DecodeState {
+        decryptor,
+        footer_len: buf.len(),
+        decrypted: vec![],
+    };
+    let mut metadata = read_file_metadata(&mut prot, origin_ptr, &footer, &mut state)?;
+    if !state.decrypted.is_empty() {
+        metadata.footer_buf = Buffer::from_vec([buf, &state.decrypted].concat());
+    }
+    Ok(metadata)
+}
+
+/// Decode `FileMetaData.encryption_algorithm` and
+/// `FileMetaData.footer_signing_key_metadata` (fields 8/9), which are only
+/// set in plaintext footers of encrypted files.
+pub(crate) fn decode_footer_encryption(
+    footer: &[u8],
+) -> ParquetResult<Option<(EncryptionAlgorithm, Option<Vec<u8>>)>> {
+    let mut prot = ThriftSliceInputProtocol::new(footer);
+    let mut algorithm: Option<EncryptionAlgorithm> = None;
+    let mut key_metadata: Option<Vec<u8>> = None;
+    read_struct_fields!(prot, |f| {
+        8 => {
+            let bytes = skip_field(&mut prot, f.field_type)?;
+            algorithm = Some(read_with_format_crate(
+                bytes,
+                EncryptionAlgorithm::read_from_in_protocol,
+            )?);
+        },
+        9 => key_metadata = Some(prot.read_bytes()?.to_vec()),
+    });
+    Ok(algorithm.map(|algorithm| (algorithm, key_metadata)))
 }
 
 /// Decode just `FileMetaData.num_rows` (field 3) for the `RowCounts`
@@ -105,6 +200,7 @@ fn read_file_metadata(
     prot: &mut ThriftSliceInputProtocol<'_>,
     origin_ptr: *const u8,
     footer: &Buffer<u8>,
+    state: &mut DecodeState<'_, '_>,
 ) -> ParquetResult<CompactFileMetaData> {
     let mut version: Option<i32> = None;
     let mut schema: Option<Vec<SchemaElement>> = None;
@@ -114,12 +210,17 @@ fn read_file_metadata(
     let mut created_by: Option<String> = None;
     let mut column_orders: Option<Vec<ColumnOrder>> = None;
 
-    // 8/9 (encryption): polars has no encryption support; skip via fallthrough.
+    // 8/9 (encryption): only read for plaintext footers, see
+    // `decode_footer_encryption`; skip via fallthrough.
+    let mut row_group_ordinal = 0;
     read_struct_fields!(prot, |f| {
         1 => version = Some(prot.read_i32()?),
         2 => schema = Some(read_list(prot, read_schema_element)?),
         3 => num_rows = Some(prot.read_i64()?),
-        4 => row_groups = Some(read_list(prot, |p| read_row_group(p, origin_ptr))?),
+        4 => row_groups = Some(read_list(prot, |p| {
+            row_group_ordinal += 1;
+            read_row_group(p, origin_ptr, state, row_group_ordinal - 1)
+        })?),
         5 => key_value_metadata = Some(read_list(prot, read_key_value)?),
         6 => created_by = Some(prot.read_string()?.to_owned()),
         7 => column_orders = Some(read_list(prot, read_column_order)?),
@@ -190,6 +291,8 @@ fn read_schema_element(prot: &mut ThriftSliceInputProtocol<'_>) -> ParquetResult
 fn read_row_group(
     prot: &mut ThriftSliceInputProtocol<'_>,
     origin_ptr: *const u8,
+    state: &mut DecodeState<'_, '_>,
+    row_group_ordinal: usize,
 ) -> ParquetResult<CompactRowGroup> {
     let mut columns: Option<Vec<CompactColumnChunk>> = None;
     let mut total_byte_size: Option<i64> = None;
@@ -198,8 +301,12 @@ fn read_row_group(
 
     // Inlined skips at ids 5/6/7 (file_offset, total_compressed_size, ordinal):
     // no in-tree consumer; bypass the generic recursive `skip_till_depth`.
+    let mut column_ordinal = 0;
     read_struct_fields!(prot, |f| {
-        1 => columns = Some(read_list(prot, |p| read_column_chunk(p, origin_ptr))?),
+        1 => columns = Some(read_list(prot, |p| {
+            column_ordinal += 1;
+            read_column_chunk(p, origin_ptr, state, row_group_ordinal, column_ordinal - 1)
+        })?),
         2 => total_byte_size = Some(prot.read_i64()?),
         3 => num_rows = Some(prot.read_i64()?),
         4 => sorting_columns = Some(read_list(prot, read_sorting_column)?),
@@ -218,20 +325,24 @@ fn read_row_group(
 
 /// Decode a `ColumnChunk` into a [`CompactColumnChunk`].
 ///
-/// Skip-decode: `file_path`, `file_offset`, encryption fields.
-fn read_column_chunk(
-    prot: &mut ThriftSliceInputProtocol<'_>,
+/// Skip-decode: `file_path`, `file_offset`.
+fn read_column_chunk<'a>(
+    prot: &mut ThriftSliceInputProtocol<'a>,
     origin_ptr: *const u8,
+    state: &mut DecodeState<'_, '_>,
+    row_group_ordinal: usize,
+    column_ordinal: usize,
 ) -> ParquetResult<CompactColumnChunk> {
     let mut meta_data: Option<CompactColumnMetaData> = None;
     let mut offset_index_offset: Option<i64> = None;
     let mut offset_index_length: Option<i32> = None;
     let mut column_index_offset: Option<i64> = None;
     let mut column_index_length: Option<i32> = None;
+    let mut crypto_metadata: Option<ColumnCryptoMetaData> = None;
+    let mut encrypted_column_metadata: Option<&'a [u8]> = None;
 
     // Inlined skips at ids 1/2 (file_path, file_offset): no in-tree consumer,
     // hot path runs once per column chunk × 200k chunks on wide fixtures.
-    // 8/9 (encryption_algorithm, encrypted_column_metadata): rare; fall through.
     read_struct_fields!(prot, |f| {
         1 => prot.skip_binary()?,
         2 => prot.skip_vlq()?,
@@ -240,17 +351,72 @@ fn read_column_chunk(
         5 => offset_index_length = Some(prot.read_i32()?),
         6 => column_index_offset = Some(prot.read_i64()?),
         7 => column_index_length = Some(prot.read_i32()?),
+        8 => {
+            let bytes = skip_field(prot, f.field_type)?;
+            crypto_metadata = Some(read_with_format_crate(
+                bytes,
+                ColumnCryptoMetaData::read_from_in_protocol,
+            )?);
+        },
+        9 => encrypted_column_metadata = Some(prot.read_bytes()?),
     });
 
+    let crypto = match crypto_metadata {
+        None => None,
+        Some(crypto_metadata) => {
+            let mut cipher = match state.decryptor.as_deref_mut() {
+                Some(decryptor) => Some(decryptor.column_cipher(
+                    &crypto_metadata,
+                    row_group_ordinal,
+                    column_ordinal,
+                )?),
+                None => None,
+            };
+
+            // Encrypted chunks only have stripped (plaintext footer) or no
+            // (encrypted footer) plaintext metadata, unless they are encrypted
+            // with the footer key as part of an encrypted footer. If the
+            // column key is not accessible the chunk can't be read, which
+            // only fails once its pages are decrypted.
+            if let (Some(cipher), Some(module)) = (&mut cipher, encrypted_column_metadata) {
+                if cipher.resolve().is_ok() {
+                    meta_data = Some(state.decrypt_column_meta_data(cipher, module)?);
+                } else if meta_data.is_none() {
+                    meta_data = Some(unreadable_column_meta_data());
+                }
+            }
+
+            Some(Box::new(CompactColumnCrypto { cipher }))
+        },
+    };
+
     Ok(CompactColumnChunk {
         meta_data: meta_data.require("ColumnChunk.meta_data")?,
         offset_index_offset,
         offset_index_length,
         column_index_offset,
         column_index_length,
+        crypto,
     })
 }
 
+/// Placeholder metadata of a column chunk whose encrypted metadata can't be
+/// decrypted. It has no statistics, so it is never pruned on.
+fn unreadable_column_meta_data() -> CompactColumnMetaData {
+    CompactColumnMetaData {
+        codec: Compression::Uncompressed,
+        num_values: 0,
+        total_uncompressed_size: 0,
+        total_compressed_size: 0,
+        data_page_offset: 0,
+        index_page_offset: None,
+        dictionary_page_offset: None,
+        statistics: None,
+        bloom_filter_offset: None,
+        bloom_filter_length: None,
+    }
+}
+
 /// Decode a `ColumnMetaData` into a [`CompactColumnMetaData`].
 ///
 /// Skip-decode: `type_`, `encodings`, `path_in_schema`, `key_value_metadata`,
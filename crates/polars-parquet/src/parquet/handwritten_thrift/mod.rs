@@ -14,4 +14,6 @@ mod parquet_macros;
 mod file_metadata_thrift;
 mod parquet_thrift;
 
-pub(crate) use file_metadata_thrift::{decode_file_metadata, decode_num_rows};
+pub(crate) use file_metadata_thrift::{
+    decode_file_metadata, decode_footer_encryption, decode_num_rows,
+};
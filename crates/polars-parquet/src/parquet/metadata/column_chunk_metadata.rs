@@ -3,6 +3,7 @@ use polars_parquet_format::Statistics as ParquetStatistics;
 use super::column_descriptor::{ColumnDescriptor, ColumnDescriptorRef};
 use super::compact::{CompactColumnChunk, CompactColumnMetaData, CompactStatistics};
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::ColumnCipher;
 use crate::parquet::error::ParquetResult;
 use crate::parquet::schema::types::PhysicalType;
 use crate::parquet::statistics::Statistics;
@@ -28,7 +29,7 @@ pub struct ColumnChunkMetadata {
 // Represents common operations for a column chunk.
 impl ColumnChunkMetadata {
     /// The compact column metadata for this chunk. Always present;
-    /// encrypted columns are rejected at footer-decode time.
+    /// encrypted column metadata is decrypted at footer-decode time.
     ///
     /// Crate-internal: callers outside `polars-parquet` should use the
     /// typed accessors below (`compression()`, `num_values()`, etc.)
@@ -147,6 +148,16 @@ impl ColumnChunkMetadata {
         self.column_chunk.column_index_length
     }
 
+    /// Whether the pages of this column chunk are encrypted.
+    pub fn is_encrypted(&self) -> bool {
+        self.column_chunk.crypto.is_some()
+    }
+
+    /// The cipher of an encrypted column chunk, if the footer was decoded with the keys.
+    pub(crate) fn cipher(&self) -> Option<&ColumnCipher> {
+        self.column_chunk.crypto.as_ref()?.cipher.as_ref()
+    }
+
     /// Returns the offset and length in bytes of the column chunk within the file.
     pub fn byte_range(&self) -> core::ops::Range<u64> {
         column_metadata_byte_range_compact(self.compact_metadata())
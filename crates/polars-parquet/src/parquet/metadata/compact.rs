@@ -20,6 +20,7 @@ use polars_buffer::Buffer;
 use polars_parquet_format::{ColumnOrder, KeyValue, SchemaElement, SortingColumn};
 
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::ColumnCipher;
 
 /// `(offset, len)` into a shared [`Buffer<u8>`] holding the footer bytes.
 /// Used by [`CompactStatistics`] to reference `min_value` / `max_value`
@@ -82,16 +83,26 @@ pub(crate) struct CompactColumnMetaData {
     pub bloom_filter_length: Option<i32>,
 }
 
+/// Encryption state of an encrypted `ColumnChunk`. Boxed on
+/// [`CompactColumnChunk`] so unencrypted chunks only pay for a null pointer.
+#[derive(Debug, Clone)]
+pub(crate) struct CompactColumnCrypto {
+    /// Decrypts the pages of the chunk. `None` if the footer was decoded
+    /// without decryption properties.
+    pub cipher: Option<ColumnCipher>,
+}
+
 /// Compact replacement for `polars_parquet_format::ColumnChunk`.
 ///
-/// Drops `file_path`, `file_offset`, encryption fields. None have read-path
-/// consumers in this build (the write path constructs format-crate types).
+/// Drops `file_path`, `file_offset`. Neither has read-path consumers in this
+/// build (the write path constructs format-crate types).
 ///
-/// `meta_data` is non-`Option` because polars has no encryption support: a
-/// chunk without unencrypted metadata is unrepresentable here. The decoder
-/// rejects such chunks at footer-decode time with `"ColumnChunk.meta_data
-/// missing"`, so by the time a `CompactColumnChunk` exists the field is
-/// guaranteed present.
+/// `meta_data` is non-`Option`: encrypted column metadata is decrypted
+/// into it at footer-decode time. A chunk whose column key is not accessible
+/// gets placeholder metadata without statistics, reading its pages fails. A
+/// chunk whose metadata is neither in plaintext nor encrypted is rejected
+/// with `"ColumnChunk.meta_data missing"`, so by the time a
+/// `CompactColumnChunk` exists the field is guaranteed present.
 #[derive(Debug, Clone)]
 pub(crate) struct CompactColumnChunk {
     pub meta_data: CompactColumnMetaData,
@@ -99,6 +110,7 @@ pub(crate) struct CompactColumnChunk {
     pub offset_index_length: Option<i32>,
     pub column_index_offset: Option<i64>,
     pub column_index_length: Option<i32>,
+    pub crypto: Option<Box<CompactColumnCrypto>>,
 }
 
 /// Compact replacement for `polars_parquet_format::RowGroup`.
@@ -14,7 +14,8 @@ use polars_buffer::Buffer;
 use serde::{Deserialize, Serialize};
 
 use super::compact::{
-    ByteRange, CompactColumnChunk, CompactColumnMetaData, CompactRowGroup, CompactStatistics,
+    ByteRange, CompactColumnChunk, CompactColumnCrypto, CompactColumnMetaData, CompactRowGroup,
+    CompactStatistics,
 };
 use super::schema_descriptor::SchemaDescriptor;
 use super::{ColumnChunkMetadata, FileMetadata, RowGroupMetadata};
@@ -74,6 +75,10 @@ struct ChunkWire {
     chunk_offset: i64,
     chunk_size: i64,
     statistics: Option<StatWire>,
+    /// Ciphers don't go over the wire; workers can't decrypt the pages and
+    /// must error instead of misreading them.
+    #[serde(default)]
+    encrypted: bool,
 }
 
 /// Stat wire entry. Drops fields with zero read-path consumers:
@@ -140,6 +145,7 @@ fn chunk_to_wire(c: &ColumnChunkMetadata, footer: &[u8]) -> ChunkWire {
         chunk_offset: byte_range.start as i64,
         chunk_size: m.total_compressed_size,
         statistics,
+        encrypted: c.is_encrypted(),
     }
 }
 
@@ -263,6 +269,9 @@ fn chunk_from_wire(c: ChunkWire, footer: &mut Vec<u8>) -> CompactColumnChunk {
         offset_index_length: None,
         column_index_offset: None,
         column_index_length: None,
+        crypto: c
+            .encrypted
+            .then(|| Box::new(CompactColumnCrypto { cipher: None })),
     }
 }
 
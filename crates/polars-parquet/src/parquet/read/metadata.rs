@@ -2,11 +2,18 @@ use std::cmp::min;
 use std::io::{Read, Seek, SeekFrom};
 
 use polars_buffer::Buffer;
+use polars_parquet_format::FileCryptoMetaData;
+use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
 
 use super::super::metadata::FileMetadata;
 use super::super::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, HEADER_SIZE, PARQUET_MAGIC};
+use crate::parquet::encryption::{
+    FileDecryptionProperties, FileDecryptor, PARQUET_ENCRYPTED_MAGIC, SIGNATURE_LEN,
+};
 use crate::parquet::error::{ParquetError, ParquetResult};
-use crate::parquet::handwritten_thrift::{decode_file_metadata, decode_num_rows};
+use crate::parquet::handwritten_thrift::{
+    decode_file_metadata, decode_footer_encryption, decode_num_rows,
+};
 
 pub(super) fn metadata_len(buffer: &[u8]) -> u32 {
     let len = buffer.len();
@@ -43,21 +50,87 @@ pub fn read_metadata_with_size<R: Read + Seek>(
     deserialize_metadata(footer)
 }
 
+/// Reads a [`FileMetadata`] of a possibly encrypted file from the reader, located at the end of
+/// the file.
+pub fn read_metadata_with_decryption<R: Read + Seek>(
+    reader: &mut R,
+    decryption: Option<&FileDecryptionProperties>,
+) -> ParquetResult<FileMetadata> {
+    let file_size = stream_len(reader)?;
+    let footer = fetch_footer_buf(reader, file_size)?;
+    deserialize_metadata_with_decryption(footer, decryption)
+}
+
 /// Parse loaded metadata bytes via the hand-written Thrift compact decoder.
 ///
 /// `footer` must be a [`Buffer<u8>`] because [`FileMetadata`] holds the buffer
 /// for the lifetime of the metadata; column-chunk statistics store
 /// `ByteRange`s into it instead of allocating per-stat byte vecs.
 pub fn deserialize_metadata(footer: Buffer<u8>) -> ParquetResult<FileMetadata> {
-    let compact = decode_file_metadata(footer)?;
+    deserialize_metadata_with_decryption(footer, None)
+}
+
+/// As [`deserialize_metadata`], for files that may be encrypted.
+///
+/// `footer` must end with the metadata length and the magic bytes. An encrypted footer can only
+/// be read with `decryption`. A signed plaintext footer is verified if `decryption` is given,
+/// otherwise only the unencrypted columns of the file can be read.
+pub fn deserialize_metadata_with_decryption(
+    footer: Buffer<u8>,
+    decryption: Option<&FileDecryptionProperties>,
+) -> ParquetResult<FileMetadata> {
+    let magic = &footer[footer.len().saturating_sub(4)..];
+
+    let compact = if magic == PARQUET_ENCRYPTED_MAGIC {
+        let Some(decryption) = decryption else {
+            return Err(ParquetError::InvalidParameter(
+                "The file has an encrypted footer and can't be read without decryption properties"
+                    .to_string(),
+            ));
+        };
+
+        let metadata = &footer[..footer.len() - FOOTER_SIZE as usize];
+        let mut reader = metadata;
+        let mut prot = TCompactInputProtocol::new(&mut reader, metadata.len() * 9 + 1024);
+        let crypto_metadata = FileCryptoMetaData::read_from_in_protocol(&mut prot)?;
+
+        let mut decryptor = FileDecryptor::try_new(
+            decryption,
+            &crypto_metadata.encryption_algorithm,
+            crypto_metadata.key_metadata,
+        )?;
+        let plaintext = decryptor.decrypt_footer(reader)?;
+        decode_file_metadata(Buffer::from_vec(plaintext), Some(&mut decryptor))?
+    } else if let Some(decryption) = decryption
+        && let Some((algorithm, key_metadata)) = decode_footer_encryption(&footer)?
+    {
+        let metadata = &footer[..footer.len() - FOOTER_SIZE as usize];
+        let Some(signed_len) = metadata.len().checked_sub(SIGNATURE_LEN) else {
+            return Err(ParquetError::oos(
+                "The plaintext footer of an encrypted file must end with a signature",
+            ));
+        };
+
+        let mut decryptor = FileDecryptor::try_new(decryption, &algorithm, key_metadata)?;
+        decryptor.verify_footer(&metadata[..signed_len], &metadata[signed_len..])?;
+        decode_file_metadata(footer, Some(&mut decryptor))?
+    } else {
+        decode_file_metadata(footer, None)?
+    };
+
     FileMetadata::from_compact(compact)
 }
 
+/// Whether the last bytes of a file are the magic bytes of a plain or an encrypted parquet file.
+pub fn is_parquet_magic(magic: &[u8]) -> bool {
+    magic == PARQUET_MAGIC || magic == PARQUET_ENCRYPTED_MAGIC
+}
+
 /// Decode only `FileMetaData.num_rows` (thrift field 3) from `footer`.
 /// Used by Polars multi-file scans in `RowCounts` resolve mode. See
 /// [`crate::parquet::handwritten_thrift::decode_num_rows`].
 pub fn deserialize_num_rows(footer: Buffer<u8>) -> ParquetResult<i64> {
-    decode_num_rows(footer)
+    decode_plaintext_num_rows(footer)
 }
 
 /// Sync variant of [`deserialize_num_rows`] that owns the reader.
@@ -72,6 +145,15 @@ pub(crate) fn read_num_rows_with_size<R: Read + Seek>(
     file_size: u64,
 ) -> ParquetResult<i64> {
     let footer = fetch_footer_buf(reader, file_size)?;
+    decode_plaintext_num_rows(footer)
+}
+
+fn decode_plaintext_num_rows(footer: Buffer<u8>) -> ParquetResult<i64> {
+    if footer.ends_with(&PARQUET_ENCRYPTED_MAGIC) {
+        return Err(ParquetError::not_supported(
+            "reading the number of rows of a file with an encrypted footer without decrypting it",
+        ));
+    }
     decode_num_rows(footer)
 }
 
@@ -97,8 +179,8 @@ fn fetch_footer_buf<R: Read + Seek>(reader: &mut R, file_size: u64) -> ParquetRe
         .read_to_end(&mut buffer)?;
 
     // Check this is indeed a parquet file.
-    if buffer[default_end_len - 4..] != PARQUET_MAGIC {
-        return Err(ParquetError::oos("The file must end with PAR1"));
+    if !is_parquet_magic(&buffer[default_end_len - 4..]) {
+        return Err(ParquetError::oos("The file must end with PAR1 or PARE"));
     }
 
     let metadata_len = metadata_len(&buffer) as u64;
@@ -3,6 +3,7 @@ mod compression;
 pub mod levels;
 mod metadata;
 mod page;
+mod page_index;
 #[cfg(feature = "async")]
 mod stream;
 
@@ -11,12 +12,16 @@ use std::io::{Cursor, Seek, SeekFrom};
 pub use column::*;
 pub use compression::{BasicDecompressor, decompress};
 pub use metadata::{
-    deserialize_metadata, deserialize_num_rows, read_metadata, read_metadata_with_size,
+    deserialize_metadata, deserialize_metadata_with_decryption, deserialize_num_rows,
+    is_parquet_magic, read_metadata, read_metadata_with_decryption, read_metadata_with_size,
     read_num_rows,
 };
 pub use page::{PageIterator, PageMetaData, PageReader};
 #[cfg(feature = "async")]
 pub use page::{get_page_stream, get_page_stream_from_column_start};
+pub use page_index::{
+    ColumnPageIndex, PageLocation, deserialize_offset_index, deserialize_page_index,
+};
 use polars_buffer::Buffer;
 #[cfg(feature = "async")]
 pub use stream::read_metadata as read_metadata_async;
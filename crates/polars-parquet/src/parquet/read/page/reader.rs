@@ -7,6 +7,7 @@ use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
 use super::PageIterator;
 use crate::parquet::CowBuffer;
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::{ColumnCipher, ModuleType, module_len};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::{ColumnChunkMetadata, Descriptor};
 use crate::parquet::page::{
@@ -56,6 +57,30 @@ impl From<&ColumnChunkMetadata> for PageMetaData {
     }
 }
 
+/// The state needed to read the pages of an encrypted column chunk.
+struct PageDecryption {
+    /// `None` if the footer was read without the key of this column.
+    cipher: Option<ColumnCipher>,
+    /// Encrypted page headers can't be peeked at, so whether the column chunk starts with a
+    /// dictionary page is taken from its metadata.
+    has_dictionary: bool,
+    /// The ordinal of the next data page, which is part of its additional authenticated data.
+    page_ordinal: usize,
+}
+
+impl PageDecryption {
+    /// Returns the cipher of the column chunk, retrieving its key on first use.
+    fn cipher(&mut self) -> ParquetResult<&ColumnCipher> {
+        let cipher = self.cipher.as_mut().ok_or_else(|| {
+            ParquetError::InvalidParameter(
+                "The column chunk is encrypted and no key was provided to decrypt it".to_string(),
+            )
+        })?;
+        cipher.resolve()?;
+        Ok(cipher)
+    }
+}
+
 /// A fallible [`Iterator`] of [`CompressedDataPage`]. This iterator reads pages back
 /// to back until all pages have been consumed.
 ///
@@ -81,6 +106,9 @@ pub struct PageReader {
 
     // Maximum page size (compressed or uncompressed) to limit allocations
     max_page_size: usize,
+
+    // Set if the column chunk is encrypted.
+    decryption: Option<PageDecryption>,
 }
 
 impl PageReader {
@@ -94,7 +122,16 @@ impl PageReader {
         scratch: Vec<u8>,
         max_page_size: usize,
     ) -> Self {
-        Self::new_with_page_meta(reader, column.into(), scratch, max_page_size)
+        let mut page_reader =
+            Self::new_with_page_meta(reader, column.into(), scratch, max_page_size);
+        if column.is_encrypted() {
+            page_reader.decryption = Some(PageDecryption {
+                cipher: column.cipher().cloned(),
+                has_dictionary: column.dictionary_page_offset().is_some(),
+                page_ordinal: 0,
+            });
+        }
+        page_reader
     }
 
     /// Create a new [`PageReader`] with [`PageMetaData`].
@@ -114,6 +151,7 @@ impl PageReader {
             descriptor: reader_meta.descriptor,
             scratch,
             max_page_size,
+            decryption: None,
         }
     }
 
@@ -134,6 +172,26 @@ impl PageReader {
             return Ok(None);
         }
 
+        if let Some(decryption) = &mut self.decryption {
+            if !decryption.has_dictionary {
+                return Ok(None);
+            }
+
+            let cipher = decryption.cipher()?.clone();
+            let (page_header, buffer) = self.read_encrypted_page(
+                &cipher,
+                ModuleType::DictionaryPageHeader,
+                ModuleType::DictionaryPage,
+                None,
+            )?;
+            return match finish_page(page_header, buffer, self.compression, &self.descriptor)? {
+                CompressedPage::Dict(d) => Ok(Some(d)),
+                CompressedPage::Data(_) => Err(ParquetError::oos(
+                    "The first page of the column chunk is not a dictionary page",
+                )),
+            };
+        }
+
         // a dictionary page exists iff the first data page is not at the start of
         // the column
         let seek_offset = self.reader.position();
@@ -172,6 +230,42 @@ impl PageReader {
             }
         })
     }
+
+    /// Reads and decrypts the page header module and the page module at the current position.
+    fn read_encrypted_page(
+        &mut self,
+        cipher: &ColumnCipher,
+        header_module_type: ModuleType,
+        page_module_type: ModuleType,
+        page_ordinal: Option<usize>,
+    ) -> ParquetResult<(ParquetPageHeader, Buffer<u8>)> {
+        let orig_buf = self.reader.get_ref();
+        let pos = (self.reader.position() as usize).min(orig_buf.len());
+
+        let header_len = module_len(&orig_buf[pos..])?;
+        let header_module = orig_buf.get(pos..pos + header_len).ok_or_else(|| {
+            ParquetError::oos("The encrypted page header is longer than the column chunk")
+        })?;
+        let header = cipher.decrypt(header_module_type, page_ordinal, header_module)?;
+
+        let mut header_reader = header.as_slice();
+        let mut prot = TCompactInputProtocol::new(&mut header_reader, self.max_page_size);
+        let page_header = ParquetPageHeader::read_from_in_protocol(&mut prot)?;
+
+        let read_size: usize = page_header.compressed_page_size.try_into()?;
+        if read_size > self.max_page_size {
+            return Err(ParquetError::WouldOverAllocate);
+        }
+
+        let page_start = pos + header_len;
+        let page_module = orig_buf
+            .get(page_start..page_start + read_size)
+            .ok_or_else(|| ParquetError::oos("The page header reported the wrong page size"))?;
+        let page = cipher.decrypt(page_module_type, page_ordinal, page_module)?;
+
+        self.reader.set_position((page_start + read_size) as u64);
+        Ok((page_header, Buffer::from_vec(page)))
+    }
 }
 
 impl PageIterator for PageReader {
@@ -207,6 +301,11 @@ pub(super) fn read_page_header(
 /// This function is lightweight and executes a minimal amount of work so that it is IO bounded.
 // Any un-necessary CPU-intensive tasks SHOULD be executed on individual pages.
 fn next_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
+    // The metadata of a column chunk whose key is not accessible is unknown, so fail before
+    // relying on its number of values.
+    if let Some(decryption) = &mut reader.decryption {
+        decryption.cipher()?;
+    }
     if reader.seen_num_values >= reader.total_num_values {
         return Ok(None);
     };
@@ -214,6 +313,21 @@ fn next_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
 }
 
 pub(super) fn build_page(reader: &mut PageReader) -> ParquetResult<Option<CompressedPage>> {
+    if let Some(decryption) = &mut reader.decryption {
+        let cipher = decryption.cipher()?.clone();
+        let page_ordinal = decryption.page_ordinal;
+        decryption.page_ordinal += 1;
+
+        let (page_header, buffer) = reader.read_encrypted_page(
+            &cipher,
+            ModuleType::DataPageHeader,
+            ModuleType::DataPage,
+            Some(page_ordinal),
+        )?;
+        reader.seen_num_values += get_page_num_values(&page_header)? as i64;
+        return finish_page(page_header, buffer, reader.compression, &reader.descriptor).map(Some);
+    }
+
     let page_header = read_page_header(&mut reader.reader, reader.max_page_size)?;
 
     reader.seen_num_values += get_page_num_values(&page_header)? as i64;
@@ -19,6 +19,7 @@ pub async fn get_page_stream<'a, RR: AsyncRead + Unpin + Send + AsyncSeek>(
     scratch: Vec<u8>,
     max_page_size: usize,
 ) -> ParquetResult<impl Stream<Item = ParquetResult<CompressedPage>> + 'a> {
+    check_not_encrypted(column_metadata)?;
     get_page_stream_with_page_meta(column_metadata.into(), reader, scratch, max_page_size).await
 }
 
@@ -29,6 +30,7 @@ pub async fn get_page_stream_from_column_start<'a, R: AsyncRead + Unpin + Send>(
     scratch: Vec<u8>,
     max_header_size: usize,
 ) -> ParquetResult<impl Stream<Item = ParquetResult<CompressedPage>> + 'a> {
+    check_not_encrypted(column_metadata)?;
     let page_metadata: PageMetaData = column_metadata.into();
     Ok(_get_page_stream(
         reader,
@@ -40,6 +42,15 @@ pub async fn get_page_stream_from_column_start<'a, R: AsyncRead + Unpin + Send>(
     ))
 }
 
+fn check_not_encrypted(column_metadata: &ColumnChunkMetadata) -> ParquetResult<()> {
+    if column_metadata.is_encrypted() {
+        return Err(ParquetError::not_supported(
+            "reading the pages of an encrypted column chunk as a stream",
+        ));
+    }
+    Ok(())
+}
+
 /// Returns a stream of compressed data pages with [`PageMetaData`]
 pub async fn get_page_stream_with_page_meta<RR: AsyncRead + Unpin + Send + AsyncSeek>(
     page_metadata: PageMetaData,
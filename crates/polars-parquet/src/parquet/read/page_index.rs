@@ -0,0 +1,169 @@
+use std::ops::Range;
+
+use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+use polars_parquet_format::{ColumnIndex, OffsetIndex};
+
+use crate::parquet::error::{ParquetError, ParquetResult};
+use crate::parquet::thrift_format::Statistics as ParquetStatistics;
+
+/// The page index of a column chunk, i.e. the statistics and the first row of each of its data
+/// pages, as stored in its `ColumnIndex` and `OffsetIndex`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnPageIndex {
+    first_row_indexes: Vec<usize>,
+    statistics: Vec<ParquetStatistics>,
+}
+
+impl ColumnPageIndex {
+    /// Number of data pages in the column chunk.
+    pub fn num_pages(&self) -> usize {
+        self.first_row_indexes.len()
+    }
+
+    /// The row ranges within the row group covered by each data page. `num_rows` is the number
+    /// of rows of the row group.
+    pub fn page_row_ranges(&self, num_rows: usize) -> impl Iterator<Item = Range<usize>> + '_ {
+        self.first_row_indexes
+            .iter()
+            .enumerate()
+            .map(move |(i, &start)| {
+                let end = self
+                    .first_row_indexes
+                    .get(i + 1)
+                    .copied()
+                    .unwrap_or(num_rows);
+                start..end
+            })
+    }
+
+    pub(crate) fn statistics(&self, page_idx: usize) -> &ParquetStatistics {
+        &self.statistics[page_idx]
+    }
+}
+
+/// Every serialized list element takes at least one byte and is accounted as at most 8 bytes,
+/// plus the length of its binary value.
+fn max_allocation_size(buffer: &[u8]) -> usize {
+    buffer.len().saturating_mul(9).saturating_add(1024)
+}
+
+/// The location of a data page of a column chunk, as stored in its `OffsetIndex`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageLocation {
+    /// Offset of the page header in the file.
+    pub offset: usize,
+    /// Size of the page in the file, including its header.
+    pub compressed_page_size: usize,
+    /// Index of the first row of the page within the row group.
+    pub first_row_index: usize,
+}
+
+fn read_offset_index(offset_index: &[u8]) -> ParquetResult<OffsetIndex> {
+    let mut reader = offset_index;
+    let mut prot = TCompactInputProtocol::new(&mut reader, max_allocation_size(offset_index));
+    Ok(OffsetIndex::read_from_in_protocol(&mut prot)?)
+}
+
+/// Deserializes the data page locations of a column chunk from the bytes of its `OffsetIndex`.
+/// # Error
+/// Errors if the index can't be deserialized or its pages are not in row and file order.
+pub fn deserialize_offset_index(offset_index: &[u8]) -> ParquetResult<Vec<PageLocation>> {
+    let locations = read_offset_index(offset_index)?
+        .page_locations
+        .into_iter()
+        .map(|location| {
+            Ok(PageLocation {
+                offset: usize::try_from(location.offset)?,
+                compressed_page_size: usize::try_from(location.compressed_page_size)?,
+                first_row_index: usize::try_from(location.first_row_index)?,
+            })
+        })
+        .collect::<ParquetResult<Vec<_>>>()?;
+
+    if !locations.is_sorted_by_key(|l| l.first_row_index)
+        || locations
+            .windows(2)
+            .any(|w| w[0].offset + w[0].compressed_page_size > w[1].offset)
+    {
+        return Err(ParquetError::oos(
+            "The pages of the offset index are not in row and file order",
+        ));
+    }
+
+    Ok(locations)
+}
+
+/// Deserializes the page index of a column chunk from the bytes of its `ColumnIndex` and its
+/// `OffsetIndex`.
+/// # Error
+/// Errors if either index can't be deserialized or they don't describe the same pages.
+pub fn deserialize_page_index(
+    column_index: &[u8],
+    offset_index: &[u8],
+) -> ParquetResult<ColumnPageIndex> {
+    let mut reader = column_index;
+    let mut prot = TCompactInputProtocol::new(&mut reader, max_allocation_size(column_index));
+    let column_index = ColumnIndex::read_from_in_protocol(&mut prot)?;
+
+    let offset_index = read_offset_index(offset_index)?;
+
+    let num_pages = offset_index.page_locations.len();
+
+    if column_index.null_pages.len() != num_pages
+        || column_index.min_values.len() != num_pages
+        || column_index.max_values.len() != num_pages
+        || column_index
+            .null_counts
+            .as_ref()
+            .is_some_and(|x| x.len() != num_pages)
+    {
+        return Err(ParquetError::oos(
+            "The column index and offset index of a column chunk have a different number of pages",
+        ));
+    }
+
+    let first_row_indexes = offset_index
+        .page_locations
+        .iter()
+        .map(|location| usize::try_from(location.first_row_index))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if !first_row_indexes.is_sorted() {
+        return Err(ParquetError::oos(
+            "The pages of the offset index are not in row order",
+        ));
+    }
+
+    let null_counts = column_index.null_counts.unwrap_or_default();
+    let statistics = column_index
+        .null_pages
+        .into_iter()
+        .zip(column_index.min_values)
+        .zip(column_index.max_values)
+        .enumerate()
+        .map(|(i, ((is_null_page, min_value), max_value))| {
+            // The min and max of null pages are placeholders.
+            let (min_value, max_value) = if is_null_page {
+                (None, None)
+            } else {
+                (Some(min_value), Some(max_value))
+            };
+
+            ParquetStatistics {
+                max: None,
+                min: None,
+                null_count: null_counts.get(i).copied(),
+                distinct_count: None,
+                max_value,
+                min_value,
+                is_max_value_exact: None,
+                is_min_value_exact: None,
+            }
+        })
+        .collect();
+
+    Ok(ColumnPageIndex {
+        first_row_indexes,
+        statistics,
+    })
+}
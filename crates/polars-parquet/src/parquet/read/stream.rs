@@ -4,8 +4,8 @@ use futures::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
 use polars_buffer::Buffer;
 
 use super::super::metadata::FileMetadata;
-use super::super::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE, PARQUET_MAGIC};
-use super::metadata::{deserialize_metadata, metadata_len};
+use super::super::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE};
+use super::metadata::{deserialize_metadata, is_parquet_magic, metadata_len};
 use crate::parquet::HEADER_SIZE;
 use crate::parquet::error::{ParquetError, ParquetResult};
 
@@ -50,8 +50,8 @@ pub async fn read_metadata<R: AsyncRead + AsyncSeek + Send + std::marker::Unpin>
         .await?;
 
     // check this is indeed a parquet file
-    if buffer[default_end_len - 4..] != PARQUET_MAGIC {
-        return Err(ParquetError::oos("The file must end with PAR1"));
+    if !is_parquet_magic(&buffer[default_end_len - 4..]) {
+        return Err(ParquetError::oos("The file must end with PAR1 or PARE"));
     }
 
     let metadata_len: u32 = metadata_len(&buffer);
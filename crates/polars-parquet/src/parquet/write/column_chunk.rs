@@ -16,6 +16,7 @@ use super::statistics::reduce;
 use crate::parquet::FallibleStreamingIterator;
 use crate::parquet::compression::Compression;
 use crate::parquet::encoding::Encoding;
+use crate::parquet::encryption::ColumnEncryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::ColumnDescriptor;
 use crate::parquet::page::{CompressedPage, PageType};
@@ -25,6 +26,7 @@ pub fn write_column_chunk<W, E>(
     mut offset: u64,
     descriptor: &ColumnDescriptor,
     mut compressed_pages: DynStreamingIterator<'_, CompressedPage, E>,
+    encryptor: Option<&ColumnEncryptor>,
 ) -> ParquetResult<(ColumnChunk, Vec<PageWriteSpec>, u64)>
 where
     W: Write,
@@ -36,8 +38,13 @@ where
     let initial = offset;
 
     let mut specs = vec![];
+    let mut num_data_pages = 0;
     while let Some(compressed_page) = compressed_pages.next()? {
-        let spec = write_page(writer, offset, compressed_page)?;
+        let encryption = encryptor.map(|e| (&e.cipher, num_data_pages));
+        let spec = write_page(writer, offset, compressed_page, encryption)?;
+        if matches!(compressed_page, CompressedPage::Data(_)) {
+            num_data_pages += 1;
+        }
         offset += spec.bytes_written;
         specs.push(spec);
     }
@@ -45,6 +52,11 @@ where
 
     let column_chunk = build_column_chunk(&specs, descriptor)?;
 
+    // The metadata of encrypted column chunks is only stored in the footer.
+    if encryptor.is_some() {
+        return Ok((column_chunk, specs, bytes_written));
+    }
+
     // write metadata
     let mut protocol = TCompactOutputProtocol::new(writer);
     bytes_written += column_chunk
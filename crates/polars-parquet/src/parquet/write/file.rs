@@ -7,11 +7,33 @@ use super::indexes::{write_column_index, write_offset_index};
 use super::page::PageWriteSpec;
 use super::row_group::write_row_group;
 use super::{RowGroupIterColumns, WriteOptions};
+use crate::parquet::encryption::{
+    ColumnEncryptor, FileEncryptionProperties, FileEncryptor, ModuleType, PARQUET_ENCRYPTED_MAGIC,
+};
 use crate::parquet::error::{ParquetError, ParquetResult};
 pub use crate::parquet::metadata::KeyValue;
 use crate::parquet::metadata::{SchemaDescriptor, ThriftFileMetadata};
 use crate::parquet::write::State;
-use crate::parquet::{FOOTER_SIZE, PARQUET_MAGIC};
+use crate::parquet::{FOOTER_SIZE, PARQUET_MAGIC, bloom_filter};
+
+/// Writes the column or offset index of a column chunk, as an encrypted module if the column is
+/// encrypted.
+fn write_index<W: Write>(
+    writer: &mut W,
+    pages: &[PageWriteSpec],
+    column_encryptor: Option<&ColumnEncryptor>,
+    module_type: ModuleType,
+    write_index: fn(&mut Vec<u8>, &[PageWriteSpec]) -> ParquetResult<u64>,
+) -> ParquetResult<u64> {
+    let mut index = vec![];
+    write_index(&mut index, pages)?;
+    let index = match column_encryptor {
+        Some(column_encryptor) => column_encryptor.cipher.encrypt(module_type, None, &index)?,
+        None => index,
+    };
+    writer.write_all(&index)?;
+    Ok(index.len() as u64)
+}
 
 pub(super) fn start_file<W: Write>(writer: &mut W) -> ParquetResult<u64> {
     writer.write_all(&PARQUET_MAGIC)?;
@@ -26,16 +48,56 @@ pub(super) fn end_file<W: Write>(
     let mut protocol = TCompactOutputProtocol::new(&mut writer);
     let metadata_len = metadata.write_to_out_protocol(&mut protocol)? as i32;
 
-    // Write footer
+    write_footer(writer, metadata_len, PARQUET_MAGIC)?;
+    Ok(metadata_len as u64 + FOOTER_SIZE)
+}
+
+/// Writes the length of the metadata and the magic bytes that end the file.
+fn write_footer<W: Write>(writer: &mut W, metadata_len: i32, magic: [u8; 4]) -> ParquetResult<()> {
     let metadata_bytes = metadata_len.to_le_bytes();
     let mut footer_buffer = [0u8; FOOTER_SIZE as usize];
     (0..4).for_each(|i| {
         footer_buffer[i] = metadata_bytes[i];
     });
 
-    (&mut footer_buffer[4..]).write_all(&PARQUET_MAGIC)?;
+    (&mut footer_buffer[4..]).write_all(&magic)?;
     writer.write_all(&footer_buffer)?;
     writer.flush()?;
+    Ok(())
+}
+
+/// Ends a file with encrypted column chunks, either with an encrypted footer or with a signed
+/// plaintext footer.
+fn end_encrypted_file<W: Write>(
+    writer: &mut W,
+    metadata: &ThriftFileMetadata,
+    encryptor: &FileEncryptor,
+) -> ParquetResult<u64> {
+    let mut serialized = vec![];
+    let mut protocol = TCompactOutputProtocol::new(&mut serialized);
+    metadata.write_to_out_protocol(&mut protocol)?;
+
+    let (magic, metadata_len) = if encryptor.plaintext_footer() {
+        let signature = encryptor.sign_footer(&serialized)?;
+        writer.write_all(&serialized)?;
+        writer.write_all(&signature)?;
+        (PARQUET_MAGIC, serialized.len() + signature.len())
+    } else {
+        let crypto_metadata = polars_parquet_format::FileCryptoMetaData {
+            encryption_algorithm: encryptor.algorithm().clone(),
+            key_metadata: encryptor.footer_key_metadata().cloned(),
+        };
+        let mut protocol = TCompactOutputProtocol::new(&mut *writer);
+        let crypto_metadata_len = crypto_metadata.write_to_out_protocol(&mut protocol)?;
+        let footer = encryptor.encrypt_footer(&serialized)?;
+        writer.write_all(&footer)?;
+        (PARQUET_ENCRYPTED_MAGIC, crypto_metadata_len + footer.len())
+    };
+
+    let metadata_len: i32 = metadata_len
+        .try_into()
+        .map_err(|_| ParquetError::oos("The footer can contain at most i32::MAX bytes"))?;
+    write_footer(writer, metadata_len, magic)?;
     Ok(metadata_len as u64 + FOOTER_SIZE)
 }
 
@@ -66,10 +128,13 @@ pub struct FileWriter<W: Write> {
     offset: u64,
     row_groups: Vec<RowGroup>,
     page_specs: Vec<Vec<Vec<PageWriteSpec>>>,
+    /// Bloom filter bitsets of every column chunk, written right before the page indexes
+    bloom_filters: Vec<Vec<Option<Vec<u8>>>>,
     /// Used to store the current state for writing the file
     state: State,
     // when the file is written, metadata becomes available
     metadata: Option<ThriftFileMetadata>,
+    encryptor: Option<FileEncryptor>,
 }
 
 /// Writes a parquet file containing only the header and footer
@@ -125,9 +190,28 @@ impl<W: Write> FileWriter<W> {
             offset: 0,
             row_groups: vec![],
             page_specs: vec![],
+            bloom_filters: vec![],
             state: State::Initialised,
             metadata: None,
+            encryptor: None,
+        }
+    }
+
+    /// Encrypts the file with parquet modular encryption.
+    ///
+    /// The page indexes of encrypted columns are encrypted as well. Bloom filters can't be
+    /// written for encrypted columns.
+    ///
+    /// # Errors
+    /// Returns an error if data has been written to the file or a key is invalid.
+    pub fn with_encryption(mut self, properties: FileEncryptionProperties) -> ParquetResult<Self> {
+        if self.offset != 0 {
+            return Err(ParquetError::InvalidParameter(
+                "Encryption must be set before writing to the file".to_string(),
+            ));
         }
+        self.encryptor = Some(FileEncryptor::try_new(properties)?);
+        Ok(self)
     }
 
     /// Writes the header of the file.
@@ -138,7 +222,13 @@ impl<W: Write> FileWriter<W> {
     /// Returns an error if data has been written to the file.
     fn start(&mut self) -> ParquetResult<()> {
         if self.offset == 0 {
-            self.offset = start_file(&mut self.writer)?;
+            self.offset = match &self.encryptor {
+                Some(encryptor) if !encryptor.plaintext_footer() => {
+                    self.writer.write_all(&PARQUET_ENCRYPTED_MAGIC)?;
+                    PARQUET_ENCRYPTED_MAGIC.len() as u64
+                },
+                _ => start_file(&mut self.writer)?,
+            };
             self.state = State::Started;
             Ok(())
         } else {
@@ -160,6 +250,42 @@ impl<W: Write> FileWriter<W> {
         ParquetError: From<E>,
         E: std::error::Error,
     {
+        self.write_with_bloom_filters(num_rows, row_group, vec![])
+    }
+
+    /// Writes a row group to the file, together with the split-block bloom filter bitsets of
+    /// its column chunks.
+    ///
+    /// `bloom_filters` is either empty or has an entry per column chunk. The bitsets are
+    /// written when the file is ended.
+    pub fn write_with_bloom_filters<E>(
+        &mut self,
+        num_rows: u64,
+        row_group: RowGroupIterColumns<'_, E>,
+        bloom_filters: Vec<Option<Vec<u8>>>,
+    ) -> ParquetResult<()>
+    where
+        ParquetError: From<E>,
+        E: std::error::Error,
+    {
+        if !bloom_filters.is_empty() && bloom_filters.len() != self.schema.columns().len() {
+            return Err(ParquetError::InvalidParameter(
+                "The number of bloom filters must equal the number of columns".to_string(),
+            ));
+        }
+        if let Some(encryptor) = &self.encryptor {
+            let columns = self.schema.columns();
+            if let Some((column, _)) = columns
+                .iter()
+                .zip(&bloom_filters)
+                .find(|(column, bitset)| bitset.is_some() && encryptor.is_column_encrypted(column))
+            {
+                return Err(ParquetError::FeatureNotSupported(format!(
+                    "Bloom filters can't be written for the encrypted column '{}'",
+                    column.path_in_schema.join(".")
+                )));
+            }
+        }
         if self.offset == 0 {
             self.start()?;
         }
@@ -171,10 +297,12 @@ impl<W: Write> FileWriter<W> {
             self.schema.columns(),
             row_group,
             ordinal,
+            self.encryptor.as_ref(),
         )?;
         self.offset += size;
         self.row_groups.push(group);
         self.page_specs.push(specs);
+        self.bloom_filters.push(bloom_filters);
         Ok(())
     }
 
@@ -193,46 +321,101 @@ impl<W: Write> FileWriter<W> {
         // compute file stats
         let num_rows = self.row_groups.iter().map(|group| group.num_rows).sum();
 
-        if self.options.write_statistics {
-            // write column indexes (require page statistics)
-            self.row_groups
-                .iter_mut()
-                .zip(self.page_specs.iter())
-                .try_for_each(|(group, pages)| {
-                    group.columns.iter_mut().zip(pages.iter()).try_for_each(
-                        |(column, pages)| {
-                            let offset = self.offset;
-                            column.column_index_offset = Some(offset as i64);
-                            self.offset += write_column_index(&mut self.writer, pages)?;
-                            let length = self.offset - offset;
-                            column.column_index_length = Some(length as i32);
-                            ParquetResult::Ok(())
-                        },
-                    )?;
-                    ParquetResult::Ok(())
-                })?;
-        };
-
-        // write offset index
+        // write bloom filters
         self.row_groups
             .iter_mut()
-            .zip(self.page_specs.iter())
-            .try_for_each(|(group, pages)| {
+            .zip(self.bloom_filters.iter())
+            .try_for_each(|(group, bloom_filters)| {
                 group
                     .columns
                     .iter_mut()
-                    .zip(pages.iter())
-                    .try_for_each(|(column, pages)| {
+                    .zip(bloom_filters.iter())
+                    .try_for_each(|(column, bitset)| {
+                        let Some(bitset) = bitset else {
+                            return ParquetResult::Ok(());
+                        };
                         let offset = self.offset;
-                        column.offset_index_offset = Some(offset as i64);
-                        self.offset += write_offset_index(&mut self.writer, pages)?;
-                        column.offset_index_length = Some((self.offset - offset) as i32);
+                        self.offset += bloom_filter::write(&mut self.writer, bitset)?;
+                        let metadata = column.meta_data.as_mut().unwrap();
+                        metadata.bloom_filter_offset = Some(offset as i64);
+                        metadata.bloom_filter_length = Some((self.offset - offset) as i32);
                         ParquetResult::Ok(())
                     })?;
                 ParquetResult::Ok(())
             })?;
 
-        let metadata = ThriftFileMetadata::new(
+        // The page indexes of encrypted columns are encrypted with the cipher of their column
+        // chunk.
+        let column_encryptors = match &self.encryptor {
+            Some(encryptor) => (0..self.row_groups.len())
+                .map(|ordinal| {
+                    self.schema
+                        .columns()
+                        .iter()
+                        .enumerate()
+                        .map(|(column_ordinal, descriptor)| {
+                            encryptor.column_encryptor(ordinal, column_ordinal, descriptor)
+                        })
+                        .collect::<ParquetResult<Vec<_>>>()
+                })
+                .collect::<ParquetResult<Vec<_>>>()?,
+            None => vec![],
+        };
+        let column_encryptor = |ordinal: usize, column_ordinal: usize| {
+            column_encryptors
+                .get(ordinal)
+                .and_then(|columns| columns[column_ordinal].as_ref())
+        };
+
+        if self.options.write_statistics {
+            // write column indexes (require page statistics)
+            for (ordinal, (group, pages)) in self
+                .row_groups
+                .iter_mut()
+                .zip(self.page_specs.iter())
+                .enumerate()
+            {
+                for (column_ordinal, (column, pages)) in
+                    group.columns.iter_mut().zip(pages.iter()).enumerate()
+                {
+                    let offset = self.offset;
+                    column.column_index_offset = Some(offset as i64);
+                    self.offset += write_index(
+                        &mut self.writer,
+                        pages,
+                        column_encryptor(ordinal, column_ordinal),
+                        ModuleType::ColumnIndex,
+                        write_column_index,
+                    )?;
+                    column.column_index_length = Some((self.offset - offset) as i32);
+                }
+            }
+        };
+
+        // write offset index
+        for (ordinal, (group, pages)) in self
+            .row_groups
+            .iter_mut()
+            .zip(self.page_specs.iter())
+            .enumerate()
+        {
+            for (column_ordinal, (column, pages)) in
+                group.columns.iter_mut().zip(pages.iter()).enumerate()
+            {
+                let offset = self.offset;
+                column.offset_index_offset = Some(offset as i64);
+                self.offset += write_index(
+                    &mut self.writer,
+                    pages,
+                    column_encryptor(ordinal, column_ordinal),
+                    ModuleType::OffsetIndex,
+                    write_offset_index,
+                )?;
+                column.offset_index_length = Some((self.offset - offset) as i32);
+            }
+        }
+
+        let mut metadata = ThriftFileMetadata::new(
             self.options.version.into(),
             self.schema.clone().into_thrift(),
             num_rows,
@@ -244,7 +427,16 @@ impl<W: Write> FileWriter<W> {
             None,
         );
 
-        let len = end_file(&mut self.writer, &metadata)?;
+        let len = match &self.encryptor {
+            Some(encryptor) => {
+                if encryptor.plaintext_footer() {
+                    metadata.encryption_algorithm = Some(encryptor.algorithm().clone());
+                    metadata.footer_signing_key_metadata = encryptor.footer_key_metadata().cloned();
+                }
+                end_encrypted_file(&mut self.writer, &metadata, encryptor)?
+            },
+            None => end_file(&mut self.writer, &metadata)?,
+        };
         self.state = State::Finished;
         self.metadata = Some(metadata);
         Ok(self.offset + len)
@@ -8,6 +8,7 @@ use polars_parquet_format::thrift::protocol::TCompactOutputStreamProtocol;
 use polars_parquet_format::{DictionaryPageHeader, Encoding, PageType};
 
 use crate::parquet::compression::Compression;
+use crate::parquet::encryption::{ColumnCipher, ModuleType};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::page::{
     CompressedDataPage, CompressedDictPage, CompressedPage, DataPageHeader, ParquetPageHeader,
@@ -52,33 +53,64 @@ pub struct PageWriteSpec {
     pub statistics: Option<Statistics>,
 }
 
+/// Writes `compressed_page` to `writer`.
+///
+/// If `encryption` is set, the page header and the page are written as modules encrypted by the
+/// column cipher. The `usize` is the ordinal of the page among the data pages of its column
+/// chunk.
 pub fn write_page<W: Write>(
     writer: &mut W,
     offset: u64,
     compressed_page: &CompressedPage,
+    encryption: Option<(&ColumnCipher, usize)>,
 ) -> ParquetResult<PageWriteSpec> {
     let num_values = compressed_page.num_values();
     let num_rows = compressed_page
         .num_rows()
         .expect("We should have num_rows when we are writing");
 
-    let header = match &compressed_page {
+    let mut header = match &compressed_page {
         CompressedPage::Data(compressed_page) => assemble_data_page_header(compressed_page),
         CompressedPage::Dict(compressed_page) => assemble_dict_page_header(compressed_page),
     }?;
 
-    let header_size = write_page_header(writer, &header)?;
-    let mut bytes_written = header_size;
+    let buffer: &[u8] = match &compressed_page {
+        CompressedPage::Data(compressed_page) => &compressed_page.buffer,
+        CompressedPage::Dict(compressed_page) => &compressed_page.buffer,
+    };
 
-    bytes_written += match &compressed_page {
-        CompressedPage::Data(compressed_page) => {
-            writer.write_all(&compressed_page.buffer)?;
-            compressed_page.buffer.len() as u64
-        },
-        CompressedPage::Dict(compressed_page) => {
-            writer.write_all(&compressed_page.buffer)?;
-            compressed_page.buffer.len() as u64
-        },
+    let (header_size, bytes_written) = if let Some((cipher, page_ordinal)) = encryption {
+        let (header_module_type, page_module_type, page_ordinal) = match &compressed_page {
+            CompressedPage::Data(_) => (
+                ModuleType::DataPageHeader,
+                ModuleType::DataPage,
+                Some(page_ordinal),
+            ),
+            CompressedPage::Dict(_) => (
+                ModuleType::DictionaryPageHeader,
+                ModuleType::DictionaryPage,
+                None,
+            ),
+        };
+
+        // The size of an encrypted page is the size of its module.
+        let page = cipher.encrypt(page_module_type, page_ordinal, buffer)?;
+        (_, header.compressed_page_size) = maybe_bytes(0, page.len())?;
+
+        let mut serialized_header = vec![];
+        write_page_header(&mut serialized_header, &header)?;
+        let header_module = cipher.encrypt(header_module_type, page_ordinal, &serialized_header)?;
+
+        writer.write_all(&header_module)?;
+        writer.write_all(&page)?;
+        (
+            header_module.len() as u64,
+            (header_module.len() + page.len()) as u64,
+        )
+    } else {
+        let header_size = write_page_header(writer, &header)?;
+        writer.write_all(buffer)?;
+        (header_size, header_size + buffer.len() as u64)
     };
 
     let statistics = match &compressed_page {
@@ -9,6 +9,7 @@ use super::column_chunk::write_column_chunk;
 use super::column_chunk::write_column_chunk_async;
 use super::page::{PageWriteSpec, is_data_page};
 use super::{DynIter, DynStreamingIterator};
+use crate::parquet::encryption::FileEncryptor;
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::{ColumnChunkMetadata, ColumnDescriptor};
 use crate::parquet::page::CompressedPage;
@@ -77,6 +78,7 @@ pub fn write_row_group<
     descriptors: &[ColumnDescriptor],
     columns: DynIter<'a, std::result::Result<DynStreamingIterator<'a, CompressedPage, E>, E>>,
     ordinal: usize,
+    encryptor: Option<&FileEncryptor>,
 ) -> ParquetResult<(RowGroup, Vec<Vec<PageWriteSpec>>, u64)>
 where
     W: Write,
@@ -86,11 +88,23 @@ where
     let column_iter = descriptors.iter().zip(columns);
 
     let initial = offset;
-    let columns = column_iter
-        .map(|(descriptor, page_iter)| {
-            let (column, page_specs, size) =
-                write_column_chunk(writer, offset, descriptor, page_iter?)?;
+    let mut column_encryptors = Vec::with_capacity(descriptors.len());
+    let mut columns = column_iter
+        .enumerate()
+        .map(|(column_ordinal, (descriptor, page_iter))| {
+            let column_encryptor = encryptor
+                .map(|e| e.column_encryptor(ordinal, column_ordinal, descriptor))
+                .transpose()?
+                .flatten();
+            let (column, page_specs, size) = write_column_chunk(
+                writer,
+                offset,
+                descriptor,
+                page_iter?,
+                column_encryptor.as_ref(),
+            )?;
             offset += size;
+            column_encryptors.push(column_encryptor);
             Ok((column, page_specs))
         })
         .collect::<ParquetResult<Vec<_>>>()?;
@@ -125,6 +139,15 @@ where
         .map(|(c, _)| c.meta_data.as_ref().unwrap().total_compressed_size)
         .sum();
 
+    // This may move the column metadata into the encrypted column metadata.
+    for (((column, _), column_encryptor), descriptor) in
+        columns.iter_mut().zip(&column_encryptors).zip(descriptors)
+    {
+        if let Some(column_encryptor) = column_encryptor {
+            column_encryptor.finish_column_chunk(column, descriptor)?;
+        }
+    }
+
     let (columns, specs) = columns.into_iter().unzip();
 
     Ok((
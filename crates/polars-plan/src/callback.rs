@@ -264,6 +264,12 @@ mod _python {
         }
     }
 
+    impl super::PlanCallbackOut for () {
+        fn from_pyany<'py>(_pyany: Py<PyAny>, _py: Python<'py>) -> PyResult<Self> {
+            Ok(())
+        }
+    }
+
     impl<T: super::PlanCallbackOut> super::PlanCallbackOut for Arc<T> {
         fn from_pyany<'py>(pyany: Py<PyAny>, py: Python<'py>) -> PyResult<Self> {
             T::from_pyany(pyany, py).map(Arc::from)
@@ -9,6 +9,8 @@ use polars_io::csv::read::CsvReadOptions;
 use polars_io::ipc::IpcScanOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::read::ParquetOptions;
+#[cfg(feature = "fwf")]
+use polars_io::scan_fwf::FwfReadOptions;
 use polars_utils::unique_id::UniqueId;
 
 use crate::dsl::functions::lit;
@@ -83,6 +85,20 @@ impl DslBuilder {
         .into())
     }
 
+    #[cfg(feature = "avro")]
+    pub fn scan_avro(
+        sources: ScanSources,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScanDsl::Avro),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
     #[cfg(feature = "scan_lines")]
     pub fn scan_lines(
         sources: ScanSources,
@@ -98,6 +114,23 @@ impl DslBuilder {
         .into())
     }
 
+    #[cfg(feature = "fwf")]
+    pub fn scan_fwf(
+        sources: ScanSources,
+        options: FwfReadOptions,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        Ok(DslPlan::Scan {
+            sources,
+            unified_scan_args: Box::new(unified_scan_args),
+            scan_type: Box::new(FileScanDsl::Fwf {
+                options: Arc::new(options),
+            }),
+            cached_ir: Default::default(),
+        }
+        .into())
+    }
+
     pub fn expand_paths(
         sources: ScanSources,
         unified_scan_args: UnifiedScanArgs,
@@ -359,6 +392,33 @@ impl DslBuilder {
         .into()
     }
 
+    #[cfg(feature = "pivot")]
+    #[expect(clippy::too_many_arguments)]
+    pub fn pivot_deferred(
+        self,
+        on: Selector,
+        index: Selector,
+        values: Selector,
+        agg: Expr,
+        maintain_order: bool,
+        separator: PlSmallStr,
+        column_naming: PivotColumnNaming,
+        max_on_columns: Option<usize>,
+    ) -> Self {
+        DslPlan::PivotDeferred {
+            input: Arc::new(self.0),
+            on,
+            index,
+            values,
+            agg,
+            maintain_order,
+            separator,
+            column_naming,
+            max_on_columns,
+        }
+        .into()
+    }
+
     #[cfg(feature = "pivot")]
     pub fn unpivot(self, args: UnpivotArgsDSL) -> Self {
         DslPlan::MapFunction {
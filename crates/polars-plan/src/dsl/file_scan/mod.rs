@@ -13,6 +13,8 @@ use polars_io::ipc::IpcScanOptions;
 use polars_io::parquet::metadata::FileMetadataRef;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::read::ParquetOptions;
+#[cfg(feature = "fwf")]
+use polars_io::scan_fwf::FwfReadOptions;
 use polars_io::{HiveOptions, RowIndex};
 use polars_utils::slice_enum::Slice;
 #[cfg(feature = "serde")]
@@ -20,6 +22,7 @@ use serde::{Deserialize, Serialize};
 use strum_macros::IntoStaticStr;
 
 use super::*;
+use crate::callback::PlanCallback;
 use crate::dsl::default_values::DefaultFieldValues;
 pub mod default_values;
 pub mod deletion;
@@ -68,6 +71,9 @@ pub enum FileScanDsl {
         options: IpcScanOptions,
     },
 
+    #[cfg(feature = "avro")]
+    Avro,
+
     #[cfg(feature = "python")]
     PythonDataset {
         dataset_object: Arc<python_dataset::PythonDatasetProvider>,
@@ -78,6 +84,11 @@ pub enum FileScanDsl {
         name: PlSmallStr,
     },
 
+    #[cfg(feature = "fwf")]
+    Fwf {
+        options: Arc<FwfReadOptions>,
+    },
+
     ExpandedPaths {
         name: PlSmallStr,
     },
@@ -131,6 +142,9 @@ pub enum FileScanIR {
         metadata: Option<Arc<arrow::io::ipc::read::FileMetadata>>,
     },
 
+    #[cfg(feature = "avro")]
+    Avro,
+
     #[cfg(feature = "python")]
     PythonDataset {
         dataset_object: Arc<python_dataset::PythonDatasetProvider>,
@@ -142,6 +156,11 @@ pub enum FileScanIR {
         name: PlSmallStr,
     },
 
+    #[cfg(feature = "fwf")]
+    Fwf {
+        options: Arc<FwfReadOptions>,
+    },
+
     ExpandedPaths {
         name: PlSmallStr,
     },
@@ -224,6 +243,8 @@ impl FileScanIR {
             Self::Csv { options: _ } => {},
             #[cfg(feature = "json")]
             Self::NDJson { options: _ } => {},
+            #[cfg(feature = "avro")]
+            Self::Avro => {},
             #[cfg(feature = "python")]
             Self::PythonDataset {
                 dataset_object: _,
@@ -235,6 +256,8 @@ impl FileScanIR {
             },
             #[cfg(feature = "scan_lines")]
             Self::Lines { name: _ } => {},
+            #[cfg(feature = "fwf")]
+            Self::Fwf { options: _ } => {},
             Self::ExpandedPaths { name: _ } => {},
             Self::Anonymous {
                 options: _,
@@ -394,6 +417,9 @@ pub struct UnifiedScanArgs {
     ///
     /// Note, intentionally store u64 instead of IdxSize to avoid erroring if it's unused.
     pub row_count: Option<(u64, u64)>,
+    /// Receives the rows that could not be read instead of failing the scan. Only supported by
+    /// CSV and NDJSON scans.
+    pub rejects: Option<PlanCallback<DataFrame, ()>>,
 }
 
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -435,6 +461,7 @@ impl Default for UnifiedScanArgs {
             deletion_files: None,
             table_statistics: None,
             row_count: None,
+            rejects: None,
         }
     }
 }
@@ -491,6 +518,9 @@ mod _file_scan_eq_hash {
             metadata: Option<usize>,
         },
 
+        #[cfg(feature = "avro")]
+        Avro,
+
         #[cfg(feature = "python")]
         PythonDataset {
             dataset_object: usize,
@@ -502,6 +532,11 @@ mod _file_scan_eq_hash {
             name: &'a PlSmallStr,
         },
 
+        #[cfg(feature = "fwf")]
+        Fwf {
+            options: &'a FwfReadOptions,
+        },
+
         ExpandedPaths {
             name: &'a PlSmallStr,
         },
@@ -542,6 +577,9 @@ mod _file_scan_eq_hash {
                     metadata: metadata.as_ref().map(arc_as_ptr),
                 },
 
+                #[cfg(feature = "avro")]
+                FileScanIR::Avro => FileScanEqHashWrap::Avro,
+
                 #[cfg(feature = "python")]
                 FileScanIR::PythonDataset {
                     dataset_object,
@@ -554,6 +592,9 @@ mod _file_scan_eq_hash {
                 #[cfg(feature = "scan_lines")]
                 FileScanIR::Lines { name } => FileScanEqHashWrap::Lines { name },
 
+                #[cfg(feature = "fwf")]
+                FileScanIR::Fwf { options } => FileScanEqHashWrap::Fwf { options },
+
                 FileScanIR::ExpandedPaths { name } => FileScanEqHashWrap::ExpandedPaths { name },
 
                 FileScanIR::Anonymous { options, function } => FileScanEqHashWrap::Anonymous {
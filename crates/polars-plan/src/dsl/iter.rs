@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use super::plan::*;
 
 impl DslPlan {
@@ -38,7 +40,7 @@ impl DslPlan {
             IR { dsl, .. } => scratch.push(dsl),
             Scan { .. } | DataFrameScan { .. } => (),
             #[cfg(feature = "pivot")]
-            Pivot { input, .. } => scratch.push(input),
+            Pivot { input, .. } | PivotDeferred { input, .. } => scratch.push(input),
             #[cfg(feature = "python")]
             PythonScan { .. } => (),
             #[cfg(feature = "merge_sorted")]
@@ -52,6 +54,63 @@ impl DslPlan {
             },
         }
     }
+
+    /// Call `f` on every direct input of this plan, cloning shared inputs before they are
+    /// handed out mutably.
+    pub fn try_for_each_input_mut<E>(
+        &mut self,
+        mut f: impl FnMut(&mut DslPlan) -> Result<(), E>,
+    ) -> Result<(), E> {
+        use DslPlan::*;
+        match self {
+            Select { input, .. }
+            | GroupBy { input, .. }
+            | Filter { input, .. }
+            | Distinct { input, .. }
+            | Sort { input, .. }
+            | Slice { input, .. }
+            | HStack { input, .. }
+            | MatchToSchema { input, .. }
+            | MapFunction { input, .. }
+            | Sink { input, .. }
+            | Cache { input, .. } => f(Arc::make_mut(input)),
+            Union { inputs, .. } | HConcat { inputs, .. } | SinkMultiple { inputs } => {
+                inputs.iter_mut().try_for_each(f)
+            },
+            PipeWithSchema { input, .. } => Arc::make_mut(input).iter_mut().try_for_each(f),
+            Join {
+                input_left,
+                input_right,
+                ..
+            } => {
+                f(Arc::make_mut(input_left))?;
+                f(Arc::make_mut(input_right))
+            },
+            Gather { input, idxs, .. } => {
+                f(Arc::make_mut(input))?;
+                f(Arc::make_mut(idxs))
+            },
+            ExtContext { input, contexts } => {
+                f(Arc::make_mut(input))?;
+                contexts.iter_mut().try_for_each(f)
+            },
+            IR { dsl, .. } => f(Arc::make_mut(dsl)),
+            Scan { .. } | DataFrameScan { .. } => Ok(()),
+            #[cfg(feature = "pivot")]
+            Pivot { input, .. } | PivotDeferred { input, .. } => f(Arc::make_mut(input)),
+            #[cfg(feature = "python")]
+            PythonScan { .. } => Ok(()),
+            #[cfg(feature = "merge_sorted")]
+            MergeSorted {
+                input_left,
+                input_right,
+                ..
+            } => {
+                f(Arc::make_mut(input_left))?;
+                f(Arc::make_mut(input_right))
+            },
+        }
+    }
 }
 
 pub struct DslPlanIter<'a> {
@@ -981,6 +981,15 @@ impl Expr {
         self.map_unary(FunctionExpr::ApproxNUnique)
     }
 
+    /// Get the approximate quantile value.
+    ///
+    /// The quantile is estimated from a t-digest sketch rather than by sorting the values, so
+    /// the memory usage per group is bounded. Null and NaN values are ignored.
+    #[cfg(feature = "approx_quantile")]
+    pub fn approx_quantile(self, quantile: f64) -> Self {
+        self.map_unary(FunctionExpr::ApproxQuantile { quantile })
+    }
+
     /// Bitwise "and" operation.
     pub fn and<E: Into<Expr>>(self, expr: E) -> Self {
         binary_expr(self, Operator::And, expr.into())
@@ -6,9 +6,11 @@ use std::sync::Arc;
 pub mod file_provider;
 pub mod iceberg_sink_state;
 pub mod sink;
-pub use polars_config::Engine;
+pub use polars_config::{Engine, SpillPolicy, StreamingExecutionOptions};
 use polars_core::error::PolarsResult;
 use polars_core::prelude::*;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroWriterOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
@@ -194,6 +196,108 @@ pub struct UnpivotArgsDSL {
     pub value_name: Option<PlSmallStr>,
 }
 
+/// How rows are selected by a sample operation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum SampleMethod {
+    /// Keep every row independently with probability `fraction`.
+    Bernoulli { fraction: f64 },
+    /// Keep every block of rows independently with probability `fraction`.
+    System { fraction: f64 },
+    /// Keep exactly `n` rows (or all rows if there are fewer), chosen uniformly at random.
+    Reservoir { n: usize },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct SampleOptions {
+    pub method: SampleMethod,
+    /// Seed for the random number generator. If `None`, a seed is drawn from the global
+    /// random state.
+    pub seed: Option<u64>,
+}
+
+impl SampleOptions {
+    pub fn bernoulli(fraction: f64) -> Self {
+        Self {
+            method: SampleMethod::Bernoulli { fraction },
+            seed: None,
+        }
+    }
+
+    pub fn system(fraction: f64) -> Self {
+        Self {
+            method: SampleMethod::System { fraction },
+            seed: None,
+        }
+    }
+
+    pub fn reservoir(n: usize) -> Self {
+        Self {
+            method: SampleMethod::Reservoir { n },
+            seed: None,
+        }
+    }
+
+    pub fn with_seed(mut self, seed: Option<u64>) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn validate(&self) -> PolarsResult<()> {
+        match self.method {
+            SampleMethod::Bernoulli { fraction } | SampleMethod::System { fraction } => {
+                polars_ensure!(
+                    (0.0..=1.0).contains(&fraction),
+                    InvalidOperation: "sample fraction must be between 0.0 and 1.0, got {}", fraction
+                );
+            },
+            SampleMethod::Reservoir { .. } => {},
+        }
+        Ok(())
+    }
+
+    /// Sample an entire [`DataFrame`] at once.
+    pub fn sample(&self, df: &DataFrame) -> PolarsResult<DataFrame> {
+        match self.method {
+            SampleMethod::Bernoulli { fraction } => df.sample_bernoulli(fraction, self.seed),
+            SampleMethod::System { fraction } => df.sample_system(fraction, self.seed),
+            SampleMethod::Reservoir { n } => {
+                df.sample_n_literal(n.min(df.height()), false, false, self.seed)
+            },
+        }
+    }
+}
+
+impl Hash for SampleOptions {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(&self.method).hash(state);
+        match self.method {
+            SampleMethod::Bernoulli { fraction } | SampleMethod::System { fraction } => {
+                fraction.to_bits().hash(state)
+            },
+            SampleMethod::Reservoir { n } => n.hash(state),
+        }
+        self.seed.hash(state);
+    }
+}
+
+impl std::fmt::Display for SampleOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.method {
+            SampleMethod::Bernoulli { fraction } => write!(f, "SAMPLE BERNOULLI({fraction})")?,
+            SampleMethod::System { fraction } => write!(f, "SAMPLE SYSTEM({fraction})")?,
+            SampleMethod::Reservoir { n } => write!(f, "SAMPLE RESERVOIR({n} ROWS)")?,
+        }
+        if let Some(seed) = self.seed {
+            write!(f, ", seed: {seed}")?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Clone, Debug, Copy, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct UnionOptions {
@@ -326,6 +430,8 @@ pub enum FileWriteFormat {
     Csv(CsvWriterOptions),
     #[cfg(feature = "json")]
     NDJson(NDJsonWriterOptions),
+    #[cfg(feature = "avro")]
+    Avro(Arc<AvroWriterOptions>),
 }
 
 impl FileWriteFormat {
@@ -339,6 +445,8 @@ impl FileWriteFormat {
             Self::Csv(_) => "csv",
             #[cfg(feature = "json")]
             Self::NDJson(_) => "jsonl",
+            #[cfg(feature = "avro")]
+            Self::Avro(_) => "avro",
 
             #[allow(unreachable_patterns)]
             _ => unreachable!("enable file type features"),
@@ -403,4 +511,7 @@ pub struct NDJsonReadOptions {
     pub ignore_errors: bool,
     pub schema: Option<SchemaRef>,
     pub schema_overwrite: Option<SchemaRef>,
+    /// If set, every file is a single JSON document and the records are the elements of the
+    /// array at this JSON pointer, e.g. `""` for a top-level array or `"/data/items"`.
+    pub record_pointer: Option<PlSmallStr>,
 }
@@ -125,6 +125,20 @@ pub enum DslPlan {
         separator: PlSmallStr,
         column_naming: PivotColumnNaming,
     },
+    /// A pivot on the distinct values of `on`, which are only discovered when the query is
+    /// executed. It has no schema before that.
+    #[cfg(feature = "pivot")]
+    PivotDeferred {
+        input: Arc<DslPlan>,
+        on: Selector,
+        index: Selector,
+        values: Selector,
+        agg: Expr,
+        maintain_order: bool,
+        separator: PlSmallStr,
+        column_naming: PivotColumnNaming,
+        max_on_columns: Option<usize>,
+    },
     /// Remove duplicates from the table
     Distinct {
         input: Arc<DslPlan>,
@@ -220,6 +234,8 @@ impl Clone for DslPlan {
             Self::SinkMultiple { inputs } => Self::SinkMultiple { inputs: inputs.clone() },
             #[cfg(feature = "pivot")]
             Self::Pivot { input, on, on_columns, index, values, agg, separator, maintain_order, column_naming }  => Self::Pivot { input: input.clone(), on: on.clone(), on_columns: on_columns.clone(), index: index.clone(), values: values.clone(), agg: agg.clone(), separator: separator.clone(), maintain_order: *maintain_order, column_naming: *column_naming },
+            #[cfg(feature = "pivot")]
+            Self::PivotDeferred { input, on, index, values, agg, separator, maintain_order, column_naming, max_on_columns }  => Self::PivotDeferred { input: input.clone(), on: on.clone(), index: index.clone(), values: values.clone(), agg: agg.clone(), separator: separator.clone(), maintain_order: *maintain_order, column_naming: *column_naming, max_on_columns: *max_on_columns },
             #[cfg(feature = "merge_sorted")]
             Self::MergeSorted { input_left, input_right, key, maintain_order } => Self::MergeSorted { input_left: input_left.clone(), input_right: input_right.clone(), key: key.clone(), maintain_order: *maintain_order },
             Self::IR {node, dsl, version, opt_flags} => Self::IR {node: *node, dsl: dsl.clone(), version: *version, opt_flags: *opt_flags},
@@ -108,6 +108,18 @@ pub(crate) enum SerializableDslPlanNode {
         separator: PlSmallStr,
         column_naming: PivotColumnNaming,
     },
+    #[cfg(feature = "pivot")]
+    PivotDeferred {
+        input: DslPlanKey,
+        on: Selector,
+        index: Selector,
+        values: Selector,
+        agg: Expr,
+        maintain_order: bool,
+        separator: PlSmallStr,
+        column_naming: PivotColumnNaming,
+        max_on_columns: Option<usize>,
+    },
     Distinct {
         input: DslPlanKey,
         options: DistinctOptionsDSL,
@@ -315,6 +327,28 @@ fn convert_dsl_plan_to_serializable_plan(
             separator: separator.clone(),
             column_naming: *column_naming,
         },
+        #[cfg(feature = "pivot")]
+        DP::PivotDeferred {
+            input,
+            on,
+            index,
+            values,
+            agg,
+            maintain_order,
+            separator,
+            column_naming,
+            max_on_columns,
+        } => SP::PivotDeferred {
+            input: dsl_plan_key(input, arenas),
+            on: on.clone(),
+            index: index.clone(),
+            values: values.clone(),
+            agg: agg.clone(),
+            maintain_order: *maintain_order,
+            separator: separator.clone(),
+            column_naming: *column_naming,
+            max_on_columns: *max_on_columns,
+        },
         DP::Distinct { input, options } => SP::Distinct {
             input: dsl_plan_key(input, arenas),
             options: options.clone(),
@@ -575,6 +609,28 @@ fn try_convert_serializable_plan_to_dsl_plan(
             separator: separator.clone(),
             column_naming: *column_naming,
         }),
+        #[cfg(feature = "pivot")]
+        SP::PivotDeferred {
+            input,
+            on,
+            index,
+            values,
+            agg,
+            maintain_order,
+            separator,
+            column_naming,
+            max_on_columns,
+        } => Ok(DP::PivotDeferred {
+            input: get_dsl_plan(*input, ser_dsl_plan, arenas)?,
+            on: on.clone(),
+            index: index.clone(),
+            values: values.clone(),
+            agg: agg.clone(),
+            maintain_order: *maintain_order,
+            separator: separator.clone(),
+            column_naming: *column_naming,
+            max_on_columns: *max_on_columns,
+        }),
         SP::Distinct { input, options } => Ok(DP::Distinct {
             input: get_dsl_plan(*input, ser_dsl_plan, arenas)?,
             options: options.clone(),
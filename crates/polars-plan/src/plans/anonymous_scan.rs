@@ -13,11 +13,22 @@ pub struct AnonymousScanArgs {
     pub predicate: Option<Expr>,
 }
 
+pub type AnonymousScanBatches = Box<dyn Iterator<Item = PolarsResult<DataFrame>> + Send>;
+
 pub trait AnonymousScan: Send + Sync {
     fn as_any(&self) -> &dyn Any;
     /// Creates a DataFrame from the supplied function & scan options.
     fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame>;
 
+    /// Creates an iterator over the batches of the scan. This is used by the streaming engine,
+    /// which processes the batches as they are produced instead of materializing the whole scan.
+    ///
+    /// Defaults to a single batch created by [`AnonymousScan::scan`].
+    fn scan_batched(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<AnonymousScanBatches> {
+        let df = self.scan(scan_opts)?;
+        Ok(Box::new(std::iter::once(Ok(df))))
+    }
+
     /// function to supply the schema.
     /// Allows for an optional infer schema argument for data sources with dynamic schemas
     fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
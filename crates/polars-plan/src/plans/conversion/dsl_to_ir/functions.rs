@@ -875,6 +875,14 @@ pub(super) fn convert_functions(
         F::UniqueCounts => I::UniqueCounts,
         #[cfg(feature = "approx_unique")]
         F::ApproxNUnique => I::ApproxNUnique,
+        #[cfg(feature = "approx_quantile")]
+        F::ApproxQuantile { quantile } => {
+            polars_ensure!(
+                (0.0..=1.0).contains(&quantile),
+                ComputeError: "quantile should be between 0.0 and 1.0"
+            );
+            I::ApproxQuantile { quantile }
+        },
         F::Coalesce => I::Coalesce,
         #[cfg(feature = "diff")]
         F::Diff(n) => {
@@ -865,6 +865,12 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             return to_alp_impl(input_adjusted, ctxt);
         },
         #[cfg(feature = "pivot")]
+        DslPlan::PivotDeferred { .. } => polars_bail!(
+            InvalidOperation:
+            "the output columns of a pivot without `on_columns` are only known once the query is collected; \
+            resolving its schema or explaining it is not supported, pass `on_columns` instead"
+        ),
+        #[cfg(feature = "pivot")]
         DslPlan::Pivot {
             input,
             on,
@@ -48,6 +48,30 @@ pub(super) async fn dsl_to_ir(
             }
         }
 
+        if unified_scan_args.rejects.is_some() {
+            let ignore_errors = match &*scan_type {
+                #[cfg(feature = "csv")]
+                FileScanDsl::Csv { options } => options.ignore_errors,
+                #[cfg(feature = "json")]
+                FileScanDsl::NDJson { options } => options.ignore_errors,
+                _ => polars_bail!(
+                    InvalidOperation:
+                    "`rejects` is only supported for CSV and NDJSON scans"
+                ),
+            };
+            polars_ensure!(
+                !ignore_errors,
+                InvalidOperation: "`rejects` cannot be combined with `ignore_errors`"
+            );
+            #[cfg(feature = "json")]
+            if let FileScanDsl::NDJson { options } = &*scan_type {
+                polars_ensure!(
+                    options.record_pointer.is_none(),
+                    InvalidOperation: "`rejects` is not supported when reading JSON documents"
+                );
+            }
+        }
+
         let sources_before_expansion = &sources;
 
         let sources = match &*scan_type {
@@ -67,6 +91,12 @@ pub(super) async fn dsl_to_ir(
             FileScanDsl::Csv { .. } => sources.expand_paths(unified_scan_args).await?,
             #[cfg(feature = "json")]
             FileScanDsl::NDJson { .. } => sources.expand_paths(unified_scan_args).await?,
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro => {
+                sources
+                    .expand_paths_with_hive_update(unified_scan_args)
+                    .await?
+            },
             #[cfg(feature = "python")]
             FileScanDsl::PythonDataset { .. } => {
                 // There are a lot of places that short-circuit if the paths is empty,
@@ -75,6 +105,8 @@ pub(super) async fn dsl_to_ir(
             },
             #[cfg(feature = "scan_lines")]
             FileScanDsl::Lines { .. } => sources.expand_paths(unified_scan_args).await?,
+            #[cfg(feature = "fwf")]
+            FileScanDsl::Fwf { .. } => sources.expand_paths(unified_scan_args).await?,
             FileScanDsl::ExpandedPaths { .. } => sources.expand_paths(unified_scan_args).await?,
             FileScanDsl::Anonymous { .. } => sources.clone(),
         };
@@ -211,7 +243,7 @@ pub(super) fn insert_row_index_to_schema(
     Ok(())
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "avro"))]
 fn prepare_output_schema(
     mut schema: Schema,
     row_index: Option<&RowIndex>,
@@ -242,6 +274,7 @@ pub(super) async fn parquet_file_info(
     sources: &ScanSources,
     row_index: Option<&RowIndex>,
     #[allow(unused)] cloud_options: Option<&polars_io::cloud::CloudOptions>,
+    decryption: Option<&ParquetKeyRetriever>,
 ) -> PolarsResult<(
     FileInfo,
     Option<FileMetadataRef>,
@@ -260,7 +293,9 @@ pub(super) async fn parquet_file_info(
             let first_path = first_scan_source.as_path().unwrap();
             feature_gated!("cloud", {
                 let mut reader =
-                    ParquetObjectStore::from_uri(first_path.clone(), cloud_options, None).await?;
+                    ParquetObjectStore::from_uri(first_path.clone(), cloud_options, None)
+                        .await?
+                        .with_decryption(decryption.cloned());
 
                 (
                     reader.schema().await?,
@@ -270,7 +305,8 @@ pub(super) async fn parquet_file_info(
             })
         } else {
             let memslice = first_scan_source.to_memslice()?;
-            let mut reader = ParquetReader::new(Cursor::new(memslice));
+            let mut reader =
+                ParquetReader::new(Cursor::new(memslice)).with_decryption(decryption.cloned());
             (
                 reader.schema()?,
                 reader.num_rows()?,
@@ -296,12 +332,11 @@ pub(super) async fn parquet_file_info(
         match mode {
             ResolveMode::None => (None, None, first_num_rows * n_sources),
             ResolveMode::RowCounts => {
-                let mut futures =
-                    (1..n_sources)
-                        .map(|i| async move {
-                            read_parquet_num_rows(sources.at(i), cloud_options).await
-                        })
-                        .collect::<FuturesUnordered<_>>();
+                let mut futures = (1..n_sources)
+                    .map(|i| async move {
+                        read_parquet_num_rows(sources.at(i), cloud_options, decryption).await
+                    })
+                    .collect::<FuturesUnordered<_>>();
 
                 // Best-effort: a file that fails to decode at plan time (e.g.
                 // an invalid file in a hive partition not yet pruned) simply
@@ -320,7 +355,7 @@ pub(super) async fn parquet_file_info(
                 // Each file decoded with its own schema: per-file schemas
                 // may differ in columns, dtypes, or column order.
                 let mut futures = (1..n_sources)
-                    .map(|i| read_parquet_metadata(sources.at(i), cloud_options))
+                    .map(|i| read_parquet_metadata(sources.at(i), cloud_options, decryption))
                     .collect::<FuturesOrdered<_>>();
 
                 // Push slot 0 (satisfying the `metadata_per_source[0] ==
@@ -361,20 +396,26 @@ pub(super) async fn parquet_file_info(
 async fn read_parquet_metadata(
     source: ScanSourceRef<'_>,
     #[allow(unused)] cloud_options: Option<&polars_io::cloud::CloudOptions>,
+    decryption: Option<&ParquetKeyRetriever>,
 ) -> PolarsResult<FileMetadataRef> {
     use polars_core::error::feature_gated;
 
     if source.is_cloud_url() {
         let path = source.as_path().unwrap();
         feature_gated!("cloud", {
-            let mut reader =
-                ParquetObjectStore::from_uri(path.clone(), cloud_options, None).await?;
+            let mut reader = ParquetObjectStore::from_uri(path.clone(), cloud_options, None)
+                .await?
+                .with_decryption(decryption.cloned());
             reader.get_metadata().await.cloned()
         })
     } else {
         let memslice = source.to_memslice()?;
         let mut cursor = Cursor::new(memslice);
-        let md = polars_parquet::parquet::read::read_metadata(&mut cursor)?;
+        let decryption = decryption.map(ParquetKeyRetriever::decryption_properties);
+        let md = polars_parquet::parquet::read::read_metadata_with_decryption(
+            &mut cursor,
+            decryption.as_ref(),
+        )?;
         Ok(Arc::new(md))
     }
 }
@@ -386,9 +427,16 @@ async fn read_parquet_metadata(
 async fn read_parquet_num_rows(
     source: ScanSourceRef<'_>,
     #[allow(unused)] cloud_options: Option<&polars_io::cloud::CloudOptions>,
+    decryption: Option<&ParquetKeyRetriever>,
 ) -> PolarsResult<i64> {
     use polars_core::error::feature_gated;
 
+    // The number of rows of an encrypted footer can't be decoded without decrypting it.
+    if decryption.is_some() {
+        let md = read_parquet_metadata(source, cloud_options, decryption).await?;
+        return Ok(md.num_rows as i64);
+    }
+
     if source.is_cloud_url() {
         let path = source.as_path().unwrap();
         feature_gated!("cloud", {
@@ -461,6 +509,31 @@ pub(super) async fn ipc_file_info(
     Ok((file_info, metadata))
 }
 
+#[cfg(feature = "avro")]
+pub(super) async fn avro_file_info(
+    first_scan_source: ScanSourceRef<'_>,
+    row_index: Option<&RowIndex>,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    use polars_io::avro::AvroFileMetadata;
+
+    let byte_source_builder = if first_scan_source.run_async() {
+        DynByteSourceBuilder::ObjectStore(FetchConfig::random_access())
+    } else {
+        DynByteSourceBuilder::Mmap
+    };
+    let byte_source = first_scan_source
+        .to_dyn_byte_source(&byte_source_builder, cloud_options, None)
+        .await?;
+    let metadata = AvroFileMetadata::try_from_byte_source(&byte_source).await?;
+
+    Ok(FileInfo::new(
+        prepare_output_schema(metadata.pl_schema(), row_index)?,
+        Some(Either::Left(Arc::new(metadata.schema))),
+        (None, usize::MAX),
+    ))
+}
+
 #[cfg(feature = "csv")]
 pub async fn csv_file_info(
     sources: &ScanSources,
@@ -509,7 +582,9 @@ pub async fn csv_file_info(
         const ASSUMED_COMPRESSION_RATIO: usize = 4;
         let source = sources.at(i);
 
+        // Counting the rows of a partial download requires the lines to be found in the raw bytes.
         let (mem_slice_raw, file_size, decompressed_slice_size_hint) = if run_async
+            && csv_options.parse_options.encoding.is_ascii_compatible()
             && let Some(infer_schema_length) = infer_schema_length
         {
             // Only download what we need for schema inference.
@@ -634,11 +709,12 @@ pub async fn csv_file_info(
             (mem_slice_raw, file_size, decompressed_slice_size_hint)
         };
 
-        let mut reader = ByteSourceReader::from_memory(mem_slice_raw)?;
+        let mut reader = ByteSourceReader::from_memory(mem_slice_raw)?
+            .with_transcoder(csv_options.parse_options.encoding.transcoder());
         let compression = reader.compression();
 
         let mut first_row_len = 0;
-        let (schema, _) = read_until_start_and_infer_schema(
+        let (schema, _, _) = read_until_start_and_infer_schema(
             csv_options,
             None,
             decompressed_slice_size_hint,
@@ -738,7 +814,15 @@ pub async fn ndjson_file_info(
     ndjson_options: &NDJsonReadOptions,
     cloud_options: Option<&polars_io::cloud::CloudOptions>,
 ) -> PolarsResult<FileInfo> {
+    use std::num::NonZeroUsize;
+
     use polars_core::error::feature_gated;
+    use polars_io::json::records::{JsonRecordSplitter, read_json_records_to_ndjson};
+
+    if let Some(pointer) = &ndjson_options.record_pointer {
+        // Validate the pointer before any data is read.
+        JsonRecordSplitter::try_new(pointer)?;
+    }
 
     let run_async =
         sources.is_cloud_url() || (sources.is_paths() && polars_config::config().force_async());
@@ -811,6 +895,16 @@ pub async fn ndjson_file_info(
         let mut truncated_bytes: Vec<u8> = Vec::with_capacity(INITIAL_FETCH);
         let mut reached_eof = false;
 
+        // Only the records at the pointer are used for inference. The decompressed prefix only
+        // grows between retries, so the splitter is kept and only fed the new bytes.
+        let mut record_splitter = ndjson_options
+            .record_pointer
+            .as_deref()
+            .map(JsonRecordSplitter::try_new)
+            .transpose()?;
+        let mut records = vec![];
+        let mut n_fed = 0;
+
         // Collect enough rows to satisfy infer_schema_length
         let memslice = loop {
             let range = offset..std::cmp::min(file_size, offset + fetch_size);
@@ -859,7 +953,21 @@ pub async fn ndjson_file_info(
                     Err(e) => Err(e)?,
                 };
 
-            if polars_io::ndjson::count_rows(&slice) < infer_schema_length.into() && !reached_eof {
+            let n_rows = match &mut record_splitter {
+                Some(splitter) => {
+                    if slice.len() > n_fed {
+                        splitter.push(&slice[n_fed..], &mut records)?;
+                        n_fed = slice.len();
+                    }
+                    polars_io::ndjson::count_rows(&records)
+                },
+                None => polars_io::ndjson::count_rows(&slice),
+            };
+
+            // The rest of the document after the record array is not needed.
+            let records_done = record_splitter.as_ref().is_some_and(|s| s.is_done());
+
+            if n_rows < infer_schema_length.into() && !reached_eof && !records_done {
                 if compression.is_some() && bytes_read == read_size {
                     // Decompressor had more to give — read_size too small
                     try_read_size *= 2;
@@ -871,7 +979,10 @@ pub async fn ndjson_file_info(
                 continue;
             }
 
-            break slice;
+            break match &record_splitter {
+                Some(_) => Buffer::from_owner(std::mem::take(&mut records)),
+                None => slice,
+            };
         };
 
         let mut buf_reader = BufReader::new(Cursor::new(memslice));
@@ -887,10 +998,23 @@ pub async fn ndjson_file_info(
             first_scan_source.to_buffer_possibly_async(run_async, cache_entries.as_ref(), 0)?;
         let mut reader = BufReader::new(CompressedReader::try_new(mem_slice)?);
 
-        Arc::new(polars_io::ndjson::infer_schema(
-            &mut reader,
-            ndjson_options.infer_schema_length,
-        )?)
+        if let Some(pointer) = &ndjson_options.record_pointer {
+            let records = read_json_records_to_ndjson(
+                reader,
+                pointer,
+                infer_schema_length.map(NonZeroUsize::get),
+            )?;
+
+            Arc::new(polars_io::ndjson::infer_schema(
+                &mut Cursor::new(records),
+                ndjson_options.infer_schema_length,
+            )?)
+        } else {
+            Arc::new(polars_io::ndjson::infer_schema(
+                &mut reader,
+                ndjson_options.infer_schema_length,
+            )?)
+        }
     };
 
     if let Some(overwriting_schema) = &ndjson_options.schema_overwrite {
@@ -997,6 +1121,7 @@ this scan to succeed with an empty DataFrame.",
                                 sources,
                                 unified_scan_args.row_index.as_ref(),
                                 cloud_options,
+                                options.decryption.as_ref(),
                             )
                             .await?;
 
@@ -1129,6 +1254,32 @@ this scan to succeed with an empty DataFrame.",
                 PolarsResult::Ok((file_info, FileScanIR::NDJson { options }))
             }
             .map_err(|e| e.context(failed_here!(ndjson scan)))?,
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro => {
+                let first_scan_source =
+                    require_first_source("failed to retrieve first file schema (avro)", "")?;
+
+                if verbose() {
+                    eprintln!(
+                        "sourcing avro scan file schema from: '{}'",
+                        first_scan_source.to_include_path_name()
+                    )
+                }
+
+                let mut file_info = scans::avro_file_info(
+                    first_scan_source,
+                    unified_scan_args.row_index.as_ref(),
+                    cloud_options,
+                )
+                .await?;
+
+                if let Some(exact_row_estimation) = exact_row_estimation {
+                    file_info.row_estimation = exact_row_estimation;
+                }
+
+                PolarsResult::Ok((file_info, FileScanIR::Avro))
+            }
+            .map_err(|e| e.context(failed_here!(avro scan)))?,
             #[cfg(feature = "python")]
             FileScanDsl::PythonDataset { dataset_object } => (|| {
                 if crate::dsl::DATASET_PROVIDER_VTABLE.get().is_none() {
@@ -1168,6 +1319,19 @@ this scan to succeed with an empty DataFrame.",
                     FileScanIR::Lines { name },
                 )
             },
+            #[cfg(feature = "fwf")]
+            FileScanDsl::Fwf { options } => {
+                let schema = options.schema.clone();
+
+                (
+                    FileInfo {
+                        schema: schema.clone(),
+                        reader_schema: Some(either::Either::Right(schema)),
+                        row_estimation: exact_row_estimation.unwrap_or(DEFAULT_ROW_ESTIMATION),
+                    },
+                    FileScanIR::Fwf { options },
+                )
+            },
             FileScanDsl::ExpandedPaths { name } => {
                 let schema = Arc::new(Schema::from_iter([(name.clone(), DataType::String)]));
 
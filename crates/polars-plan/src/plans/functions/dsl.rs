@@ -50,6 +50,7 @@ pub enum DslFunction {
         separator: Option<PlSmallStr>,
     },
     Stats(StatsFunction),
+    Sample(SampleOptions),
     /// FillValue
     FillNan(Expr),
     // Function that is already converted to IR.
@@ -161,6 +162,10 @@ impl DslFunction {
                 FunctionIR::Unnest { columns, separator }
             },
             DslFunction::Hint(h) => FunctionIR::Hint(h),
+            DslFunction::Sample(options) => {
+                options.validate()?;
+                FunctionIR::Sample(options)
+            },
             #[cfg(feature = "python")]
             DslFunction::OpaquePython(inner) => FunctionIR::OpaquePython(inner),
             DslFunction::Stats(_)
@@ -76,6 +76,7 @@ pub enum FunctionIR {
         fmt_str: PlSmallStr,
     },
     Hint(HintIR),
+    Sample(SampleOptions),
 }
 
 impl Hash for FunctionIR {
@@ -126,6 +127,7 @@ impl Hash for FunctionIR {
                 offset.hash(state);
             },
             FunctionIR::Hint(hint) => hint.hash(state),
+            FunctionIR::Sample(options) => options.hash(state),
         }
     }
 }
@@ -147,6 +149,8 @@ impl FunctionIR {
             Rechunk => false,
             FastCount { .. } => false,
             RowIndex { .. } => false,
+            // Has dedicated streaming nodes.
+            Sample(_) => false,
         }
     }
 
@@ -170,7 +174,7 @@ impl FunctionIR {
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
             Rechunk | Unnest { .. } | Explode { .. } | Hint(_) => true,
-            RowIndex { .. } | FastCount { .. } => false,
+            RowIndex { .. } | FastCount { .. } | Sample(_) => false,
         }
     }
 
@@ -183,7 +187,7 @@ impl FunctionIR {
             Rechunk | FastCount { .. } | Unnest { .. } | Explode { .. } | Hint(_) => true,
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
-            RowIndex { .. } => true,
+            RowIndex { .. } | Sample(_) => true,
         }
     }
 
@@ -236,6 +240,7 @@ impl FunctionIR {
                 df.unpivot2(args)
             },
             RowIndex { name, offset, .. } => df.with_row_index(name.clone(), *offset),
+            Sample(options) => options.sample(&df),
             Hint(hint) => {
                 let HintIR::Sorted(s) = &hint;
                 if let Some(s) = s.first() {
@@ -269,6 +274,7 @@ impl FunctionIR {
             FunctionIR::Unpivot { .. } => true,
             FunctionIR::Opaque { .. } => true,
             FunctionIR::Hint(_) => is_input_ordered,
+            FunctionIR::Sample(_) => is_input_ordered,
         }
     }
 
@@ -283,7 +289,8 @@ impl FunctionIR {
             | Self::FastCount { .. }
             | Self::Rechunk
             | Self::Explode { .. }
-            | Self::Opaque { .. } => false,
+            | Self::Opaque { .. }
+            | Self::Sample(_) => false,
         }
     }
 
@@ -302,7 +309,8 @@ impl FunctionIR {
             Self::RowIndex { .. }
             | Self::FastCount { .. }
             | Self::Explode { .. }
-            | Self::Opaque { .. } => false,
+            | Self::Opaque { .. }
+            | Self::Sample(_) => false,
         }
     }
 }
@@ -321,6 +329,7 @@ impl Display for FunctionIR {
                 write!(f, "hint.{hint}")
             },
             Opaque { fmt_str, .. } => write!(f, "{fmt_str}"),
+            Sample(options) => write!(f, "{options}"),
             Unnest { columns, separator } => {
                 write!(f, "UNNEST by:")?;
                 let columns = columns.as_ref();
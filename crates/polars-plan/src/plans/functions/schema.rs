@@ -99,7 +99,7 @@ impl FunctionIR {
             } => explode_schema(schema, input_schema, columns),
             #[cfg(feature = "pivot")]
             Unpivot { schema, args } => unpivot_schema(args, schema, input_schema),
-            Hint(_) => Ok(Cow::Borrowed(input_schema)),
+            Hint(_) | Sample(_) => Ok(Cow::Borrowed(input_schema)),
         }
     }
 }
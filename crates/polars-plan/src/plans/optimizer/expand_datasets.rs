@@ -220,6 +220,7 @@ pub(super) fn expand_datasets(
                             deletion_files,
                             table_statistics,
                             row_count,
+                            rejects: _rejects @ None,
                         } = resolved_unified_scan_args.as_ref()
                         else {
                             panic!(
@@ -300,6 +301,9 @@ pub(super) fn expand_datasets(
                             #[cfg(feature = "json")]
                             FileScanDsl::NDJson { options } => FileScanIR::NDJson { options },
 
+                            #[cfg(feature = "avro")]
+                            FileScanDsl::Avro => FileScanIR::Avro,
+
                             #[cfg(feature = "python")]
                             FileScanDsl::PythonDataset { dataset_object } => {
                                 FileScanIR::PythonDataset {
@@ -311,6 +315,9 @@ pub(super) fn expand_datasets(
                             #[cfg(feature = "scan_lines")]
                             FileScanDsl::Lines { name } => FileScanIR::Lines { name },
 
+                            #[cfg(feature = "fwf")]
+                            FileScanDsl::Fwf { options } => FileScanIR::Fwf { options },
+
                             FileScanDsl::ExpandedPaths { name } => {
                                 FileScanIR::ExpandedPaths { name }
                             },
@@ -1501,8 +1501,10 @@ impl ProjectionPushdownVisitor<'_, '_> {
                 // In the future we can potentially remove the dedicated count codepaths.
                 #[cfg(feature = "csv")]
                 if out_edge.projection() == Projection::Len
-                    && let FileScanIR::Csv { .. } = scan_type.as_ref()
+                    && let FileScanIR::Csv { options } = scan_type.as_ref()
+                    && options.parse_options.encoding.is_ascii_compatible()
                     && unified_scan_args.pre_slice.is_none()
+                    && unified_scan_args.rejects.is_none()
                     && (predicate.is_none()
                         || matches!(
                             predicate_file_skip_applied,
@@ -364,12 +364,18 @@ impl SlicePushDown {
                     #[cfg(feature = "json")]
                     FileScanIR::NDJson { .. } => true,
 
+                    #[cfg(feature = "avro")]
+                    FileScanIR::Avro => true,
+
                     #[cfg(feature = "python")]
                     FileScanIR::PythonDataset { .. } => true,
 
                     #[cfg(feature = "scan_lines")]
                     FileScanIR::Lines { .. } => true,
 
+                    #[cfg(feature = "fwf")]
+                    FileScanIR::Fwf { .. } => true,
+
                     FileScanIR::ExpandedPaths { .. } => false,
 
                     // TODO: This can be `true` after Anonymous scan dispatches to new-streaming.
@@ -516,6 +516,8 @@ fn is_sorted_rec(
                 #[expect(unreachable_patterns)]
                 _ => rec!(*input),
             },
+            // Sampling only removes rows.
+            FunctionIR::Sample(_) => rec!(*input),
             FunctionIR::Explode { columns, .. } => {
                 let mut sorted = rec!(*input);
 
@@ -15,7 +15,9 @@ use polars::chunked_array::object::PolarsObjectSafe;
 use polars::frame::PivotColumnNaming;
 use polars::frame::row::Row;
 #[cfg(feature = "avro")]
-use polars::io::avro::AvroCompression;
+use polars::io::avro::AvroCodec;
+#[cfg(feature = "fwf")]
+use polars::io::scan_fwf::{FwfSpanUnit, FwfTrim};
 use polars::prelude::ColumnMapping;
 use polars::prelude::default_values::{
     DefaultFieldValues, IcebergIdentityTransformedPartitionFields,
@@ -870,17 +872,18 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<InterpolationMethod> {
 }
 
 #[cfg(feature = "avro")]
-impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<Option<AvroCompression>> {
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<Option<AvroCodec>> {
     type Error = PyErr;
 
     fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
             "uncompressed" => None,
-            "snappy" => Some(AvroCompression::Snappy),
-            "deflate" => Some(AvroCompression::Deflate),
+            "snappy" => Some(AvroCodec::Snappy),
+            "deflate" => Some(AvroCodec::Deflate),
+            "zstd" => Some(AvroCodec::Zstd),
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "avro `compression` must be one of {{'uncompressed', 'snappy', 'deflate'}}, got {v}",
+                    "avro `compression` must be one of {{'uncompressed', 'snappy', 'deflate', 'zstd'}}, got {v}",
                 )));
             },
         };
@@ -956,9 +959,52 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<CsvEncoding> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
             "utf8" => CsvEncoding::Utf8,
             "utf8-lossy" => CsvEncoding::LossyUtf8,
+            "latin1" | "iso-8859-1" => CsvEncoding::Latin1,
+            "windows-1252" | "cp1252" => CsvEncoding::Windows1252,
+            "utf16" | "utf-16" => CsvEncoding::Utf16,
+            "utf16-le" | "utf-16-le" => CsvEncoding::Utf16Le,
+            "utf16-be" | "utf-16-be" => CsvEncoding::Utf16Be,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "csv `encoding` must be one of {{'utf8', 'utf8-lossy'}}, got {v}",
+                    "csv `encoding` must be one of {{'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'utf16', 'utf16-le', 'utf16-be'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+#[cfg(feature = "fwf")]
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<FwfSpanUnit> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "bytes" => FwfSpanUnit::Bytes,
+            "chars" => FwfSpanUnit::Chars,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`span_unit` must be one of {{'bytes', 'chars'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+#[cfg(feature = "fwf")]
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<FwfTrim> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "none" => FwfTrim::None,
+            "start" => FwfTrim::Start,
+            "end" => FwfTrim::End,
+            "both" => FwfTrim::Both,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`trim` must be one of {{'none', 'start', 'end', 'both'}}, got {v}",
                 )));
             },
         };
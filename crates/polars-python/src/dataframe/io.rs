@@ -4,7 +4,7 @@ use std::sync::Arc;
 
 use polars::io::RowIndex;
 #[cfg(feature = "avro")]
-use polars::io::avro::AvroCompression;
+use polars::io::avro::AvroCodec;
 use polars::prelude::*;
 use pyo3::prelude::*;
 use pyo3::pybacked::PyBackedStr;
@@ -24,7 +24,8 @@ impl PyDataFrame {
     skip_rows, skip_lines, projection, separator, rechunk, columns, encoding, n_threads, path,
     overwrite_dtype, overwrite_dtype_slice, low_memory, comment_prefix, quote_char,
     null_values, missing_utf8_is_empty_string, try_parse_dates, skip_rows_after_header,
-    row_index, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, schema)
+    row_index, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, schema,
+    json_columns=None)
 )]
     pub fn read_csv(
         py: Python<'_>,
@@ -58,6 +59,7 @@ impl PyDataFrame {
         truncate_ragged_lines: bool,
         decimal_comma: bool,
         schema: Option<Wrap<Schema>>,
+        json_columns: Option<Vec<String>>,
     ) -> PyResult<Self> {
         let null_values = null_values.map(|w| w.0);
         let eol_char = eol_char.as_bytes()[0];
@@ -106,6 +108,7 @@ impl PyDataFrame {
                 .with_skip_rows_after_header(skip_rows_after_header)
                 .with_row_index(row_index)
                 .with_raise_if_empty(raise_if_empty)
+                .with_json_columns(json_columns.map(|x| x.into_iter().map(|x| x.into()).collect()))
                 .with_parse_options(
                     CsvParseOptions::default()
                         .with_separator(separator.as_bytes()[0])
@@ -269,14 +272,14 @@ impl PyDataFrame {
         &self,
         py: Python<'_>,
         py_f: Py<PyAny>,
-        compression: Wrap<Option<AvroCompression>>,
+        compression: Wrap<Option<AvroCodec>>,
         name: String,
     ) -> PyResult<()> {
         use polars::io::avro::AvroWriter;
         let mut buf = get_file_like(py_f, true)?;
         py.enter_polars(|| {
             AvroWriter::new(&mut buf)
-                .with_compression(compression.0)
+                .with_codec(compression.0)
                 .with_name(name)
                 .finish(&mut self.df.write())
         })
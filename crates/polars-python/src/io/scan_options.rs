@@ -136,6 +136,7 @@ impl PyScanOptions<'_> {
             deletion_files,
             table_statistics: table_statistics.map(|x| x.0),
             row_count,
+            rejects: None,
         };
 
         Ok(unified_scan_args)
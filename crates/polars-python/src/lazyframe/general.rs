@@ -7,12 +7,14 @@ use parking_lot::Mutex;
 #[cfg(feature = "pivot")]
 use polars::frame::PivotColumnNaming;
 use polars::io::RowIndex;
+#[cfg(feature = "fwf")]
+use polars::io::scan_fwf::{FwfReadOptions, FwfSpanUnit, FwfTrim};
 use polars::prelude::iceberg_sink_state::IcebergSinkState;
 use polars::time::*;
 use polars_core::prelude::*;
 use polars_core::query_result::QueryResult;
 #[cfg(feature = "parquet")]
-use polars_parquet::arrow::write::StatisticsOptions;
+use polars_parquet::arrow::write::{BloomFilterOptions, StatisticsOptions};
 use polars_plan::dsl::ScanSources;
 use polars_plan::plans::{AExpr, HintIR, IR, Sorted};
 use polars_utils::arena::{Arena, Node};
@@ -81,6 +83,21 @@ fn post_opt_callback(
     })
 }
 
+/// Wraps a Python function that takes the rejected rows of a scan as a `pl.DataFrame`, as plan
+/// callbacks are called with a `PyDataFrame`.
+fn rejects_callback(function: Py<PyAny>) -> PyResult<PlanCallback<DataFrame, ()>> {
+    Python::attach(|py| {
+        let call = py
+            .import("polars._utils.wrap")?
+            .getattr("call_with_wrapped_df")?;
+        let function = py
+            .import("functools")?
+            .getattr("partial")?
+            .call1((call, function))?;
+        Ok(PlanCallback::new_python(PythonObject(function.unbind())))
+    })
+}
+
 #[pymethods]
 #[allow(clippy::should_implement_trait)]
 impl PyLazyFrame {
@@ -89,7 +106,8 @@ impl PyLazyFrame {
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (
         source, sources, infer_schema_length, schema, schema_overrides, batch_size, n_rows, low_memory, rechunk,
-        row_index, ignore_errors, include_file_paths, cloud_options, credential_provider
+        row_index, ignore_errors, include_file_paths, cloud_options, credential_provider, rejects=None,
+        record_pointer=None
     ))]
     fn new_from_ndjson(
         source: Option<Py<PyAny>>,
@@ -106,6 +124,8 @@ impl PyLazyFrame {
         include_file_paths: Option<String>,
         cloud_options: OptPyCloudOptions,
         credential_provider: Option<Py<PyAny>>,
+        rejects: Option<Py<PyAny>>,
+        record_pointer: Option<String>,
     ) -> PyResult<Self> {
         let row_index = row_index.map(|(name, offset)| RowIndex {
             name: name.into(),
@@ -142,6 +162,8 @@ impl PyLazyFrame {
             .with_row_index(row_index)
             .with_ignore_errors(ignore_errors)
             .with_include_file_paths(include_file_paths.map(|x| x.into()))
+            .with_rejects(rejects.map(rejects_callback).transpose()?)
+            .with_record_pointer(record_pointer.map(|x| x.into()))
             .finish()
             .map_err(PyPolarsErr::from)?;
 
@@ -154,7 +176,8 @@ impl PyLazyFrame {
         low_memory, comment_prefix, quote_char, null_values, missing_utf8_is_empty_string,
         infer_schema_length, with_schema_modify, rechunk, skip_rows_after_header,
         encoding, row_index, try_parse_dates, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, glob, schema,
-        cloud_options, credential_provider, include_file_paths, missing_columns
+        cloud_options, credential_provider, include_file_paths, missing_columns, rejects=None,
+        json_columns=None
     )
     )]
     fn new_from_csv(
@@ -190,6 +213,8 @@ impl PyLazyFrame {
         credential_provider: Option<Py<PyAny>>,
         include_file_paths: Option<String>,
         missing_columns: Option<Wrap<MissingColumnsPolicy>>,
+        rejects: Option<Py<PyAny>>,
+        json_columns: Option<Vec<PyBackedStr>>,
     ) -> PyResult<Self> {
         let null_values = null_values.map(|w| w.0);
         let quote_char = quote_char.and_then(|s| s.as_bytes().first()).copied();
@@ -261,7 +286,11 @@ impl PyLazyFrame {
             .with_glob(glob)
             .with_raise_if_empty(raise_if_empty)
             .with_include_file_paths(include_file_paths.map(|x| x.into()))
-            .with_missing_columns_policy(missing_columns.map(|x| x.0));
+            .with_missing_columns_policy(missing_columns.map(|x| x.0))
+            .with_rejects(rejects.map(rejects_callback).transpose()?)
+            .with_json_columns(
+                json_columns.map(|cols| cols.iter().map(|name| (&**name).into()).collect()),
+            );
 
         if let Some(lambda) = with_schema_modify {
             let f = |schema: Schema| {
@@ -311,6 +340,7 @@ impl PyLazyFrame {
             parallel,
             low_memory,
             use_statistics,
+            decryption: None,
         };
 
         let sources = sources.0;
@@ -373,6 +403,38 @@ impl PyLazyFrame {
         Ok(lf.into())
     }
 
+    #[cfg(feature = "fwf")]
+    #[staticmethod]
+    #[pyo3(signature = (sources, scan_options, schema, spans, span_unit, trim, skip_lines))]
+    fn new_from_scan_fwf(
+        sources: Wrap<ScanSources>,
+        scan_options: PyScanOptions,
+        schema: Wrap<Schema>,
+        spans: Vec<(usize, usize)>,
+        span_unit: Wrap<FwfSpanUnit>,
+        trim: Wrap<FwfTrim>,
+        skip_lines: usize,
+    ) -> PyResult<Self> {
+        let sources = sources.0;
+        let first_path = sources.first_path();
+
+        let unified_scan_args =
+            scan_options.extract_unified_scan_args(first_path.and_then(|x| x.scheme()))?;
+
+        let options = FwfReadOptions::try_new(Arc::new(schema.0), spans)
+            .map_err(PyPolarsErr::from)?
+            .with_span_unit(span_unit.0)
+            .with_trim(trim.0)
+            .with_skip_lines(skip_lines);
+
+        let dsl: DslPlan = DslBuilder::scan_fwf(sources, options, unified_scan_args)
+            .map_err(to_py_err)?
+            .build();
+        let lf: LazyFrame = dsl.into();
+
+        Ok(lf.into())
+    }
+
     #[cfg(feature = "scan_lines")]
     #[staticmethod]
     #[pyo3(signature = (sources, scan_options, name))]
@@ -589,13 +651,23 @@ impl PyLazyFrame {
         Ok((df.into(), time_df.into()))
     }
 
-    #[pyo3(signature = (engine, lambda_post_opt))]
+    #[pyo3(signature = (engine, lambda_post_opt, memory_budget=None, spill_policy=None))]
     fn collect(
         &self,
         py: Python<'_>,
         engine: Wrap<Engine>,
         lambda_post_opt: Option<Py<PyAny>>,
+        memory_budget: Option<u64>,
+        spill_policy: Option<Wrap<SpillPolicy>>,
     ) -> PyResult<PyDataFrame> {
+        let mut streaming_options = StreamingExecutionOptions::default();
+        if memory_budget.is_some() {
+            streaming_options.memory_budget_bytes = memory_budget;
+        }
+        if let Some(spill_policy) = spill_policy {
+            streaming_options.spill_policy = spill_policy.0;
+        }
+
         py.enter_polars_df(|| {
             let ldf = self.ldf.read().clone();
             if let Some(lambda) = lambda_post_opt {
@@ -603,11 +675,12 @@ impl PyLazyFrame {
                     post_opt_callback(&lambda, root, lp_arena, expr_arena, None)
                 })
             } else {
-                ldf.collect_with_engine(engine.0).map(|r| match r {
-                    QueryResult::Single(df) => df,
-                    // TODO: Should return query results
-                    QueryResult::Multiple(_) => DataFrame::empty(),
-                })
+                ldf.collect_with_engine_options(engine.0, streaming_options)
+                    .map(|r| match r {
+                        QueryResult::Single(df) => df,
+                        // TODO: Should return query results
+                        QueryResult::Multiple(_) => DataFrame::empty(),
+                    })
             }
         })
     }
@@ -678,7 +751,7 @@ impl PyLazyFrame {
     #[cfg(feature = "parquet")]
     #[pyo3(signature = (
         target, sink_options, compression, compression_level, statistics, row_group_size, data_page_size,
-        metadata, arrow_schema
+        metadata, arrow_schema, bloom_filters=None
     ))]
     fn sink_parquet(
         &self,
@@ -692,8 +765,14 @@ impl PyLazyFrame {
         data_page_size: Option<usize>,
         metadata: Wrap<Option<KeyValueMetadata>>,
         arrow_schema: Option<Wrap<ArrowSchema>>,
+        bloom_filters: Option<Vec<(PyBackedStr, Option<u64>, f64)>>,
     ) -> PyResult<PyLazyFrame> {
         let compression = parse_parquet_compression(compression, compression_level)?;
+        let bloom_filters = bloom_filters
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, ndv, fpp)| ((&*name).into(), BloomFilterOptions { ndv, fpp }))
+            .collect();
 
         let options = ParquetWriteOptions {
             compression,
@@ -703,6 +782,8 @@ impl PyLazyFrame {
             key_value_metadata: metadata.0,
             arrow_schema: arrow_schema.map(|x| Arc::new(x.0)),
             compat_level: None,
+            bloom_filters,
+            encryption: None,
         };
 
         let target = target.extract_file_sink_destination()?;
@@ -763,7 +844,7 @@ impl PyLazyFrame {
         target, sink_options, include_bom, compression, compression_level, check_extension,
         include_header, separator, line_terminator, quote_char, batch_size, datetime_format,
         date_format, time_format, float_scientific, float_precision, decimal_comma, null_value,
-        quote_style
+        quote_style, nested_as_json=false
     ))]
     fn sink_csv(
         &self,
@@ -787,6 +868,7 @@ impl PyLazyFrame {
         decimal_comma: bool,
         null_value: Option<Wrap<PlSmallStr>>,
         quote_style: Option<Wrap<QuoteStyle>>,
+        nested_as_json: bool,
     ) -> PyResult<PyLazyFrame> {
         let quote_style = quote_style.map_or(QuoteStyle::default(), |wrap| wrap.0);
         let null_value = null_value
@@ -805,6 +887,7 @@ impl PyLazyFrame {
             null: null_value,
             line_terminator: line_terminator.0,
             quote_style,
+            nested_as_json,
         };
 
         let options = CsvWriterOptions {
@@ -863,6 +946,43 @@ impl PyLazyFrame {
         .map_err(Into::into)
     }
 
+    #[cfg(feature = "avro")]
+    #[pyo3(signature = (target, compression, name, namespace, block_size, sink_options))]
+    fn sink_avro(
+        &self,
+        py: Python<'_>,
+        target: PyFileSinkDestination,
+        compression: Wrap<Option<polars::io::avro::AvroCodec>>,
+        name: String,
+        namespace: Option<String>,
+        block_size: Option<usize>,
+        sink_options: PySinkOptions,
+    ) -> PyResult<PyLazyFrame> {
+        let options = polars::io::avro::AvroWriterOptions {
+            codec: compression.0,
+            name: name.into(),
+            namespace: namespace.map(Into::into),
+            block_size,
+        };
+
+        let target = target.extract_file_sink_destination()?;
+        let unified_sink_args = sink_options.extract_unified_sink_args(target.cloud_scheme())?;
+
+        py.enter_polars(|| {
+            self.ldf
+                .read()
+                .clone()
+                .sink(
+                    target,
+                    FileWriteFormat::Avro(Arc::new(options)),
+                    unified_sink_args,
+                )
+                .into()
+        })
+        .map(Into::into)
+        .map_err(Into::into)
+    }
+
     #[pyo3(signature = (function, maintain_order, chunk_size))]
     pub fn sink_batches(
         &self,
@@ -1422,6 +1542,33 @@ impl PyLazyFrame {
         .into()
     }
 
+    #[cfg(feature = "pivot")]
+    #[pyo3(signature = (on, index, values, agg, maintain_order, separator, column_naming, max_on_columns))]
+    fn pivot_deferred(
+        &self,
+        on: PySelector,
+        index: PySelector,
+        values: PySelector,
+        agg: PyExpr,
+        maintain_order: bool,
+        separator: String,
+        column_naming: Wrap<PivotColumnNaming>,
+        max_on_columns: Option<usize>,
+    ) -> Self {
+        let ldf = self.ldf.read().clone();
+        ldf.pivot_deferred(
+            on.inner,
+            index.inner,
+            values.inner,
+            agg.inner,
+            maintain_order,
+            separator.into(),
+            column_naming.0,
+            max_on_columns,
+        )
+        .into()
+    }
+
     #[cfg(feature = "pivot")]
     #[pyo3(signature = (on, index, value_name, variable_name))]
     fn unpivot(
@@ -11,7 +11,7 @@ pub mod visitor;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::PyInProcessQuery;
 use parking_lot::RwLock;
-use polars::prelude::{Engine, LazyFrame, OptFlags};
+use polars::prelude::{Engine, LazyFrame, OptFlags, SpillPolicy};
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::pybacked::PyBackedStr;
@@ -79,3 +79,15 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<Engine> {
         Ok(Wrap(parsed))
     }
 }
+
+impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<SpillPolicy> {
+    type Error = PyErr;
+
+    fn extract(ob: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        let parsed = ob
+            .extract::<PyBackedStr>()?
+            .parse()
+            .map_err(PyValueError::new_err)?;
+        Ok(Wrap(parsed))
+    }
+}
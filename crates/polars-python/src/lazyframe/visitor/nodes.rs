@@ -41,6 +41,8 @@ fn scan_type_to_pyobject(
         },
         #[cfg(feature = "ipc")]
         FileScanIR::Ipc { .. } => Err(PyNotImplementedError::new_err("ipc scan")),
+        #[cfg(feature = "avro")]
+        FileScanIR::Avro => Err(PyNotImplementedError::new_err("avro scan")),
         #[cfg(feature = "json")]
         FileScanIR::NDJson { options, .. } => {
             let options = serde_json::to_string(options)
@@ -49,6 +51,8 @@ fn scan_type_to_pyobject(
         },
         #[cfg(feature = "scan_lines")]
         FileScanIR::Lines { name } => Ok(("lines", name.as_str()).into_py_any(py)?),
+        #[cfg(feature = "fwf")]
+        FileScanIR::Fwf { .. } => Err(PyNotImplementedError::new_err("fwf scan")),
         FileScanIR::ExpandedPaths { name } => {
             Ok(("expanded-paths", name.as_str()).into_py_any(py)?)
         },
@@ -658,6 +662,7 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<Py<PyAny>> {
                     streamable: _,
                     fmt_str: _,
                 } => return Err(PyNotImplementedError::new_err("opaque rust mapfunction")),
+                FunctionIR::Sample(_) => return Err(PyNotImplementedError::new_err("sample")),
                 FunctionIR::Unnest { columns, separator } => (
                     "unnest",
                     columns.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
@@ -10,17 +10,22 @@ use polars_plan::prelude::*;
 use polars_utils::aliases::{PlHashSet, PlIndexSet};
 use polars_utils::format_pl_smallstr;
 use sqlparser::ast::{
-    BinaryOperator as SQLBinaryOperator, CreateTable, CreateTableLikeKind, CreateTableOptions,
+    BinaryOperator as SQLBinaryOperator, CreateTable, CreateTableLikeKind, CreateTableOptions, Cte,
     Delete, Distinct, ExcludeSelectItem, Expr as SQLExpr, Fetch, FromTable, FunctionArg,
-    GroupByExpr, HiveDistributionStyle, HiveFormat, Ident, JoinConstraint, JoinOperator,
-    LimitClause, NamedWindowDefinition, NamedWindowExpr, ObjectName, ObjectType, OrderBy,
-    OrderByKind, Query, RenameSelectItem, Select, SelectFlavor, SelectItem,
+    GroupByExpr, GroupByWithModifier, HiveDistributionStyle, HiveFormat, Ident, JoinConstraint,
+    JoinOperator, LimitClause, NamedWindowDefinition, NamedWindowExpr, ObjectName, ObjectType,
+    OrderBy, OrderByKind, Query, RenameSelectItem, Select, SelectFlavor, SelectItem,
     SelectItemQualifiedWildcardKind, SetExpr, SetOperator, SetQuantifier, Statement, TableAlias,
-    TableFactor, TableWithJoins, Truncate, UnaryOperator as SQLUnaryOperator, Value as SQLValue,
-    ValueWithSpan, Values, Visit, WildcardAdditionalOptions, WindowSpec,
+    TableFactor, TableSample, TableSampleKind, TableSampleMethod, TableSampleSeed, TableSampleUnit,
+    TableWithJoins, Truncate, UnaryOperator as SQLUnaryOperator, Value as SQLValue, ValueWithSpan,
+    Values, Visit, WildcardAdditionalOptions, WindowSpec,
 };
+#[cfg(feature = "pivot")]
+use sqlparser::ast::{ExprWithAlias, NullInclusion, PivotValueSource};
 use sqlparser::dialect::GenericDialect;
+use sqlparser::keywords::Keyword;
 use sqlparser::parser::{Parser, ParserOptions};
+use sqlparser::tokenizer::{Token, TokenWithSpan, Tokenizer};
 
 use crate::function_registry::{DefaultFunctionRegistry, FunctionRegistry};
 use crate::sql_expr::{
@@ -28,7 +33,7 @@ use crate::sql_expr::{
 };
 use crate::sql_visitors::{
     QualifyExpression, TableIdentifierCollector, check_for_ambiguous_column_refs,
-    expr_has_window_functions, expr_refers_to_table,
+    expr_has_window_functions, expr_refers_to_table, set_expr_refers_to_relation,
 };
 use crate::table_functions::PolarsTableFunctions;
 use crate::types::map_sql_dtype_to_polars;
@@ -152,6 +157,14 @@ pub(crate) enum FilterMode {
     RemoveTrue,
 }
 
+/// Default maximum number of iterations of a recursive CTE.
+const DEFAULT_RECURSIVE_CTE_LIMIT: usize = 1000;
+
+/// Column holding the grouping id of an aggregation over grouping sets.
+const GROUPING_ID: &str = "__POLARS_GROUPING_ID";
+/// Prefix of the placeholder columns of `GROUPING(...)` calls.
+const GROUPING_CALL_PREFIX: &str = "__POLARS_GROUPING_CALL_";
+
 /// The SQLContext is the main entry point for executing SQL queries.
 #[derive(Clone)]
 pub struct SQLContext {
@@ -164,6 +177,8 @@ pub struct SQLContext {
     table_aliases: PlHashMap<String, String>,
     joined_aliases: PlHashMap<String, PlHashMap<String, String>>,
     pub(crate) named_windows: PlHashMap<String, WindowSpec>,
+    grouping_calls: Vec<Vec<Expr>>,
+    recursive_cte_limit: usize,
 }
 
 impl Default for SQLContext {
@@ -177,6 +192,8 @@ impl Default for SQLContext {
             named_windows: Default::default(),
             lp_arena: Default::default(),
             expr_arena: Default::default(),
+            grouping_calls: Default::default(),
+            recursive_cte_limit: DEFAULT_RECURSIVE_CTE_LIMIT,
         }
     }
 }
@@ -243,18 +260,7 @@ impl SQLContext {
     /// # }
     ///```
     pub fn execute(&mut self, query: &str) -> PolarsResult<LazyFrame> {
-        let mut parser = Parser::new(&GenericDialect);
-        parser = parser.with_options(ParserOptions {
-            trailing_commas: true,
-            ..Default::default()
-        });
-
-        let ast = parser
-            .try_with_sql(query)
-            .map_err(to_sql_interface_err)?
-            .parse_statements()
-            .map_err(to_sql_interface_err)?;
-
+        let ast = parse_sql_statements(query)?;
         polars_ensure!(ast.len() == 1, SQLInterface: "one (and only one) statement can be parsed at a time");
         let res = self.execute_statement(ast.first().unwrap())?;
 
@@ -269,6 +275,7 @@ impl SQLContext {
         self.table_aliases.clear();
         self.joined_aliases.clear();
         self.named_windows.clear();
+        self.grouping_calls.clear();
 
         Ok(res)
     }
@@ -280,6 +287,14 @@ impl SQLContext {
         self
     }
 
+    /// Set the maximum number of times the recursive term of a `WITH RECURSIVE` CTE is
+    /// evaluated; queries that don't reach a fixpoint within the limit raise an error.
+    /// Defaults to 1000.
+    pub fn with_recursive_cte_limit(mut self, limit: usize) -> Self {
+        self.recursive_cte_limit = limit;
+        self
+    }
+
     /// Get the function registry of the SQLContext
     pub fn registry(&self) -> &Arc<dyn FunctionRegistry> {
         &self.function_registry
@@ -298,6 +313,7 @@ impl SQLContext {
             table_map: Arc::new(RwLock::new(self.table_map.read().unwrap().clone())),
             named_windows: self.named_windows.clone(),
             cte_map: self.cte_map.clone(),
+            recursive_cte_limit: self.recursive_cte_limit,
 
             ..Default::default()
         }
@@ -885,20 +901,141 @@ impl SQLContext {
 
     fn register_ctes(&mut self, query: &Query) -> PolarsResult<()> {
         if let Some(with) = &query.with {
-            if with.recursive {
-                polars_bail!(SQLInterface: "recursive CTEs are not supported")
-            }
             for cte in &with.cte_tables {
                 // Note: isolate CTE execution to prevent context state leakage
                 let cte_name = cte.alias.name.value.clone();
-                let mut lf = self.execute_isolated(|ctx| ctx.execute_query(&cte.query))?;
-                lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+                let lf =
+                    if with.recursive && set_expr_refers_to_relation(&cte.query.body, &cte_name) {
+                        self.execute_isolated(|ctx| ctx.execute_recursive_cte(cte))?
+                    } else {
+                        let lf = self.execute_isolated(|ctx| ctx.execute_query(&cte.query))?;
+                        self.rename_columns_from_table_alias(lf, &cte.alias)?
+                    };
                 self.register_cte(&cte_name, lf);
             }
         }
         Ok(())
     }
 
+    /// Evaluate a recursive CTE as an iterative fixpoint.
+    ///
+    /// The anchor term is evaluated once; the recursive term is then evaluated repeatedly, with
+    /// the CTE name bound to the rows produced by the previous iteration, until it produces no
+    /// new rows. With `UNION` (as opposed to `UNION ALL`) rows that were already produced are
+    /// discarded, so cyclic data also reaches a fixpoint.
+    ///
+    /// Planning only resolves the schema of the recursive term; the iterations run when the
+    /// resulting frame is executed.
+    fn execute_recursive_cte(&mut self, cte: &Cte) -> PolarsResult<LazyFrame> {
+        let cte_name = cte.alias.name.value.as_str();
+        let query = &cte.query;
+        let SetExpr::SetOperation {
+            op: SetOperator::Union,
+            set_quantifier,
+            left,
+            right,
+        } = query.body.as_ref()
+        else {
+            polars_bail!(
+                SQLInterface: "recursive CTE '{}' must have the form '<anchor> UNION [ALL] <recursive term>'",
+                cte_name
+            )
+        };
+        let distinct = match set_quantifier {
+            SetQuantifier::All => false,
+            SetQuantifier::Distinct | SetQuantifier::None => true,
+            _ => polars_bail!(
+                SQLInterface: "'UNION {}' is not supported in recursive CTE '{}'",
+                set_quantifier, cte_name
+            ),
+        };
+        polars_ensure!(
+            !set_expr_refers_to_relation(left, cte_name),
+            SQLInterface: "the anchor term of recursive CTE '{}' cannot reference itself",
+            cte_name
+        );
+        self.register_ctes(query)?;
+
+        let anchor = self.execute_isolated(|ctx| ctx.process_query(left, query))?;
+        let mut anchor = self.rename_columns_from_table_alias(anchor, &cte.alias)?;
+        let schema = self.get_frame_schema(&mut anchor)?;
+        if distinct {
+            anchor = anchor.unique_stable(None, UniqueKeepStrategy::First);
+        }
+
+        // Plan the recursive term against an empty working table to validate it and to resolve
+        // the casts that combine it with the anchor term.
+        let mut rf = self.execute_isolated(|ctx| {
+            ctx.register_cte(cte_name, DataFrame::empty_with_schema(&schema).lazy());
+            ctx.process_query(right, query)
+        })?;
+        let rf_schema = self.get_frame_schema(&mut rf)?;
+        polars_ensure!(
+            rf_schema.len() == schema.len(),
+            SQLInterface: "the recursive term of CTE '{}' must return the same number of columns as its anchor term ({}); found {}",
+            cte_name, schema.len(), rf_schema.len()
+        );
+
+        // SQL combines the terms positionally, under the names and types of the anchor.
+        let exprs: Vec<_> = rf_schema
+            .iter_names()
+            .zip(schema.iter())
+            .map(|(name, (anchor_name, dtype))| {
+                col(name.clone())
+                    .strict_cast(dtype.clone())
+                    .alias(anchor_name.clone())
+            })
+            .collect();
+
+        let ctx = self.isolated();
+        let cte_name = cte_name.to_string();
+        let recursive_term = right.as_ref().clone();
+        let cte_query = query.as_ref().clone();
+        let limit = self.recursive_cte_limit;
+
+        let iterate = move |mut result: DataFrame| -> PolarsResult<DataFrame> {
+            let mut working_table = result.clone();
+
+            let mut n_iterations = 0;
+            while working_table.height() > 0 {
+                polars_ensure!(
+                    n_iterations < limit,
+                    SQLInterface: "recursive CTE '{}' did not complete within {} iterations (the limit can be raised with `SQLContext::with_recursive_cte_limit`)",
+                    cte_name, limit
+                );
+                n_iterations += 1;
+
+                let rf = ctx.isolated().execute_isolated(|ctx| {
+                    ctx.register_cte(&cte_name, working_table.clone().lazy());
+                    ctx.process_query(&recursive_term, &cte_query)
+                })?;
+                let new_rows = rf.select(exprs.clone()).collect()?;
+
+                working_table = if distinct {
+                    let n_rows = result.height();
+                    result = concat([result.lazy(), new_rows.lazy()], UnionArgs::default())?
+                        .unique_stable(None, UniqueKeepStrategy::First)
+                        .collect()?;
+                    result.slice(n_rows as i64, usize::MAX)
+                } else {
+                    result.vstack_mut(&new_rows)?;
+                    new_rows
+                };
+            }
+            result.rechunk_mut_par();
+            Ok(result)
+        };
+
+        let lf = anchor.map(
+            iterate,
+            AllowedOptimizations::empty(),
+            None,
+            Some("RECURSIVE CTE"),
+        );
+        let lf = self.process_order_by(lf, &query.order_by, None)?;
+        self.process_limit_offset(lf, &query.limit_clause, &query.fetch)
+    }
+
     fn register_named_windows(
         &mut self,
         named_windows: &[NamedWindowDefinition],
@@ -1116,12 +1253,12 @@ impl SQLContext {
             name: _,
             alias: _,
             args: _,
+            sample: _,
 
             // Unsupported dialect-specific modifiers
             ref index_hints,
             ref json_path,
             ref partitions,
-            ref sample,
             ref version,
             ref with_hints,
             with_ordinality,
@@ -1134,7 +1271,6 @@ impl SQLContext {
         polars_ensure!(index_hints.is_empty(), SQLInterface: "table index hints are not supported");
         polars_ensure!(json_path.is_none(), SQLInterface: "table JSON path access is not supported");
         polars_ensure!(partitions.is_empty(), SQLInterface: "table `PARTITION` selection is not supported");
-        polars_ensure!(sample.is_none(), SQLInterface: "table `SAMPLE` clause is not supported");
         polars_ensure!(version.is_none(), SQLInterface: "table version (time-travel) qualifiers are not supported");
         polars_ensure!(with_hints.is_empty(), SQLInterface: "table `WITH (...)` hints are not supported");
 
@@ -1465,33 +1601,78 @@ impl SQLContext {
 
         // Check for "GROUP BY ..." (after determining projections)
         let mut group_by_keys: Vec<Expr> = Vec::new();
+        let mut grouping_sets: Option<Vec<Vec<usize>>> = None;
         match &select_stmt.group_by {
             // Standard "GROUP BY x, y, z" syntax (also recognising ordinal values)
             GroupByExpr::Expressions(group_by_exprs, modifiers) => {
-                if !modifiers.is_empty() {
-                    polars_bail!(SQLInterface: "GROUP BY does not support CUBE, ROLLUP, or TOTALS modifiers")
-                }
                 // Translate the group expressions, resolving ordinal values and SELECT aliases
-                group_by_keys = group_by_exprs
-                    .iter()
-                    .map(|e| match e {
-                        SQLExpr::Identifier(ident) => {
-                            resolve_select_alias(&ident.value, &projections, &schema).map_or_else(
-                                || {
-                                    self.expr_or_ordinal(
-                                        e,
-                                        &projections,
-                                        None,
-                                        Some(&schema),
-                                        "GROUP BY",
-                                    )
-                                },
-                                Ok,
-                            )
+                let mut translate_key = |e: &SQLExpr| match e {
+                    SQLExpr::Identifier(ident) => resolve_select_alias(
+                        &ident.value,
+                        &projections,
+                        &schema,
+                    )
+                    .map_or_else(
+                        || self.expr_or_ordinal(e, &projections, None, Some(&schema), "GROUP BY"),
+                        Ok,
+                    ),
+                    _ => self.expr_or_ordinal(e, &projections, None, Some(&schema), "GROUP BY"),
+                };
+                let has_grouping_sets = group_by_exprs.iter().any(|e| {
+                    matches!(
+                        e,
+                        SQLExpr::Rollup(_) | SQLExpr::Cube(_) | SQLExpr::GroupingSets(_)
+                    )
+                });
+                if !has_grouping_sets && modifiers.is_empty() {
+                    group_by_keys = group_by_exprs
+                        .iter()
+                        .map(translate_key)
+                        .collect::<PolarsResult<_>>()?
+                } else {
+                    // Expand ROLLUP, CUBE and GROUPING SETS into grouping sets (the cross
+                    // product of the sets of the individual GROUP BY elements), and collect
+                    // the distinct keys of all sets.
+                    let sql_sets = match modifiers.as_slice() {
+                        [] => expand_grouping_sets(group_by_exprs),
+                        [GroupByWithModifier::Rollup | GroupByWithModifier::Cube]
+                            if !has_grouping_sets =>
+                        {
+                            let items: Vec<_> =
+                                group_by_exprs.iter().map(|e| vec![e.clone()]).collect();
+                            if matches!(modifiers[0], GroupByWithModifier::Rollup) {
+                                rollup_sets(&items)
+                            } else {
+                                cube_sets(&items)
+                            }
                         },
-                        _ => self.expr_or_ordinal(e, &projections, None, Some(&schema), "GROUP BY"),
-                    })
-                    .collect::<PolarsResult<_>>()?
+                        _ => polars_bail!(
+                            SQLInterface: "GROUP BY does not support the {} modifier{}",
+                            modifiers.iter().map(|m| m.to_string()).collect::<Vec<_>>().join(" "),
+                            if has_grouping_sets { " with ROLLUP, CUBE or GROUPING SETS" } else { "" }
+                        ),
+                    };
+                    let mut sets = Vec::with_capacity(sql_sets.len());
+                    for sql_set in &sql_sets {
+                        let mut set = Vec::with_capacity(sql_set.len());
+                        for e in sql_set {
+                            let key = translate_key(e)?;
+                            let idx =
+                                group_by_keys
+                                    .iter()
+                                    .position(|k| *k == key)
+                                    .unwrap_or_else(|| {
+                                        group_by_keys.push(key);
+                                        group_by_keys.len() - 1
+                                    });
+                            if !set.contains(&idx) {
+                                set.push(idx);
+                            }
+                        }
+                        sets.push(set);
+                    }
+                    grouping_sets = Some(sets);
+                }
             },
             // "GROUP BY ALL" syntax; automatically adds expressions that do not contain
             // nested agg/window funcs to the group key (also ignores literals).
@@ -1539,6 +1720,9 @@ impl SQLContext {
             if select_stmt.having.is_some() {
                 polars_bail!(SQLSyntax: "HAVING clause not valid outside of GROUP BY; found:\n{:?}", select_stmt.having);
             };
+            if !self.grouping_calls.is_empty() {
+                polars_bail!(SQLSyntax: "GROUPING is not valid outside of GROUP BY");
+            }
 
             // Final/selected cols, accounting for 'SELECT *' modifiers
             let mut retained_cols = Vec::with_capacity(projections.len());
@@ -1635,12 +1819,18 @@ impl SQLContext {
             };
             lf
         } else {
-            let having = select_stmt
+            let mut having = select_stmt
                 .having
                 .as_ref()
                 .map(|expr| parse_sql_expr(expr, self, Some(&schema)))
                 .transpose()?;
-            lf = self.process_group_by(lf, &group_by_keys, &projections, having)?;
+            if !self.grouping_calls.is_empty() {
+                // GROUPING(...) over a plain GROUP BY aggregates a single grouping set
+                grouping_sets.get_or_insert_with(|| vec![(0..group_by_keys.len()).collect()]);
+                self.resolve_grouping_calls(&group_by_keys, &mut projections, &mut having)?;
+                Arc::make_mut(&mut schema).with_column(GROUPING_ID.into(), DataType::UInt32);
+            }
+            lf = self.process_group_by(lf, &group_by_keys, grouping_sets, &projections, having)?;
             lf = self.process_order_by(lf, &query.order_by, None)?;
 
             // Drop any extra columns (eg: added to maintain ORDER BY access to original cols)
@@ -2138,24 +2328,32 @@ impl SQLContext {
     fn get_table(&mut self, relation: &TableFactor) -> PolarsResult<(String, LazyFrame)> {
         match relation {
             TableFactor::Table {
-                name, alias, args, ..
+                name,
+                alias,
+                args,
+                sample,
+                ..
             } => {
                 self.validate_table_factor(relation)?;
-                if let Some(args) = args {
-                    return self.execute_table_function(name, alias, &args.args);
-                }
-                let tbl_name = name.0.first().unwrap().as_ident().unwrap().value.as_str();
-                if let Some(lf) = self.get_table_from_current_scope(tbl_name) {
+                let (name, lf) = if let Some(args) = args {
+                    self.execute_table_function(name, alias, &args.args)?
+                } else {
+                    let tbl_name = name.0.first().unwrap().as_ident().unwrap().value.as_str();
+                    let Some(lf) = self.get_table_from_current_scope(tbl_name) else {
+                        polars_bail!(SQLInterface: "relation '{}' was not found", tbl_name);
+                    };
                     match alias {
                         Some(alias) => {
                             self.table_aliases
                                 .insert(alias.name.value.clone(), tbl_name.to_string());
-                            Ok((alias.name.value.clone(), lf))
+                            (alias.name.value.clone(), lf)
                         },
-                        None => Ok((tbl_name.to_string(), lf)),
+                        None => (tbl_name.to_string(), lf),
                     }
-                } else {
-                    polars_bail!(SQLInterface: "relation '{}' was not found", tbl_name);
+                };
+                match sample {
+                    Some(sample) => Ok((name, lf.sample(sql_sample_options(sample)?))),
+                    None => Ok((name, lf)),
                 }
             },
             TableFactor::Derived {
@@ -2165,13 +2363,15 @@ impl SQLContext {
                 sample,
             } => {
                 polars_ensure!(!(*lateral), SQLInterface: "`LATERAL` clause is not supported");
-                polars_ensure!(sample.is_none(), SQLInterface: "table `SAMPLE` clause is not supported");
+                let sample = sample.as_ref().map(sql_sample_options).transpose()?;
 
                 // Execute the subquery in isolation so that outer join state
                 // doesn't leak into it and cause spurious ambiguous-column errors
+                let mut lf = self.execute_isolated(|ctx| ctx.execute_query_no_ctes(subquery))?;
+                if let Some(sample) = sample {
+                    lf = lf.sample(sample);
+                }
                 if let Some(alias) = alias {
-                    let mut lf =
-                        self.execute_isolated(|ctx| ctx.execute_query_no_ctes(subquery))?;
                     lf = self.rename_columns_from_table_alias(lf, alias)?;
                     self.table_map
                         .write()
@@ -2179,7 +2379,6 @@ impl SQLContext {
                         .insert(alias.name.value.clone(), lf.clone());
                     Ok((alias.name.value.clone(), lf))
                 } else {
-                    let lf = self.execute_isolated(|ctx| ctx.execute_query_no_ctes(subquery))?;
                     Ok(("".to_string(), lf))
                 }
             },
@@ -2259,11 +2458,268 @@ impl SQLContext {
                     None => Ok(("".to_string(), lf)),
                 }
             },
+            #[cfg(feature = "pivot")]
+            TableFactor::Pivot {
+                table,
+                aggregate_functions,
+                value_column,
+                value_source,
+                default_on_null,
+                alias,
+            } => {
+                let (_, lf) = self.get_table(table)?;
+                let lf = self.execute_pivot(
+                    lf,
+                    aggregate_functions,
+                    value_column,
+                    value_source,
+                    default_on_null.as_ref(),
+                )?;
+                self.register_table_operator_result(lf, alias)
+            },
+            #[cfg(feature = "pivot")]
+            TableFactor::Unpivot {
+                table,
+                value,
+                name,
+                columns,
+                null_inclusion,
+                alias,
+            } => {
+                let (_, lf) = self.get_table(table)?;
+                let lf = self.execute_unpivot(lf, value, name, columns, null_inclusion)?;
+                self.register_table_operator_result(lf, alias)
+            },
             // Support bare table, optionally with an alias, for now
             _ => polars_bail!(SQLInterface: "not yet implemented: {}", relation),
         }
     }
 
+    /// Register the result of a table operator (eg: PIVOT) under its alias, if any.
+    #[cfg(feature = "pivot")]
+    fn register_table_operator_result(
+        &mut self,
+        lf: LazyFrame,
+        alias: &Option<TableAlias>,
+    ) -> PolarsResult<(String, LazyFrame)> {
+        if let Some(alias) = alias {
+            let lf = self.rename_columns_from_table_alias(lf, alias)?;
+            self.table_map
+                .write()
+                .unwrap()
+                .insert(alias.name.value.clone(), lf.clone());
+            Ok((alias.name.value.clone(), lf))
+        } else {
+            Ok(("".to_string(), lf))
+        }
+    }
+
+    /// Lower `PIVOT (<agg>, ... FOR <col> IN (<value> [AS <alias>], ...))` to one
+    /// [`LazyFrame::pivot`] per aggregate, indexed on all the columns that are neither pivoted
+    /// nor aggregated.
+    ///
+    /// Each value becomes a column named after its alias (or the value itself); if there are
+    /// several aggregates, or the aggregate is aliased, the aggregate alias (or function name)
+    /// is appended as `<value>_<aggregate>`.
+    #[cfg(feature = "pivot")]
+    fn execute_pivot(
+        &mut self,
+        mut lf: LazyFrame,
+        aggregate_functions: &[ExprWithAlias],
+        value_column: &[SQLExpr],
+        value_source: &PivotValueSource,
+        default_on_null: Option<&SQLExpr>,
+    ) -> PolarsResult<LazyFrame> {
+        use polars_core::frame::PivotColumnNaming;
+
+        let schema = self.get_frame_schema(&mut lf)?;
+        let on = match value_column {
+            [e] => sql_column_name(e)?,
+            _ => polars_bail!(
+                SQLInterface: "PIVOT supports a single FOR column; found {}",
+                value_column.len()
+            ),
+        };
+        let on_dtype = schema.try_get(&on)?.clone();
+        let PivotValueSource::List(pivot_values) = value_source else {
+            polars_bail!(SQLInterface: "PIVOT requires an explicit list of values; found IN ({})", value_source)
+        };
+        polars_ensure!(!pivot_values.is_empty(), SQLSyntax: "PIVOT requires at least one value");
+
+        // Materialise the (literal) values, they are the `on` columns of the pivot
+        let on_values = DataFrame::empty()
+            .lazy()
+            .select(
+                pivot_values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| {
+                        Ok(parse_sql_expr(&v.expr, self, Some(&schema))?
+                            .strict_cast(on_dtype.clone())
+                            .alias(format!("{i}")))
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?,
+            )
+            .collect()?;
+        let mut on_column = Series::new_empty(on.clone(), &on_dtype);
+        for c in on_values.columns() {
+            on_column.append(c.as_materialized_series())?;
+        }
+        let on_titles = on_column.cast(&DataType::String)?;
+        let value_names: Vec<(PlSmallStr, PlSmallStr)> = on_titles
+            .str()?
+            .iter()
+            .zip(pivot_values)
+            .map(|(title, v)| {
+                let title = PlSmallStr::from_str(title.unwrap_or("null"));
+                let name = match &v.alias {
+                    Some(alias) => PlSmallStr::from_str(&alias.value),
+                    None => title.clone(),
+                };
+                (title, name)
+            })
+            .collect();
+        let on_columns = Arc::new(DataFrame::new_infer_height(vec![on_column.into_column()])?);
+
+        // Resolve the aggregates, and the column each of them aggregates
+        let mut aggs = Vec::with_capacity(aggregate_functions.len());
+        for agg in aggregate_functions {
+            let expr = parse_sql_expr(&agg.expr, self, Some(&schema))?;
+            let mut leaves = expr_to_leaf_column_names(&expr);
+            leaves.sort_unstable();
+            leaves.dedup();
+            let [value] = leaves.as_slice() else {
+                polars_bail!(SQLSyntax: "PIVOT aggregate must reference exactly one column; found {}", agg.expr)
+            };
+            let value = value.clone();
+            let suffix = match (&agg.alias, &agg.expr) {
+                (Some(alias), _) => Some(alias.value.clone()),
+                (None, SQLExpr::Function(f)) if aggregate_functions.len() > 1 => {
+                    Some(f.name.to_string().to_lowercase())
+                },
+                (None, _) if aggregate_functions.len() > 1 => {
+                    polars_bail!(SQLSyntax: "PIVOT aggregate {} requires an alias", agg.expr)
+                },
+                _ => None,
+            };
+            // The pivot aggregates the values of each group as the element
+            let expr = expr.map_expr(|e| match e {
+                Expr::Column(name) if name == value => element(),
+                e => e,
+            });
+            aggs.push((value, expr, suffix));
+        }
+
+        // Index on all the remaining columns, or on a constant if there are none
+        let mut index: Vec<PlSmallStr> = schema
+            .iter_names()
+            .filter(|name| **name != on && !aggs.iter().any(|(value, _, _)| value == *name))
+            .cloned()
+            .collect();
+        let tmp_index = index
+            .is_empty()
+            .then(|| PlSmallStr::from_static("__POLARS_SQL_PIVOT_INDEX"));
+        if let Some(name) = &tmp_index {
+            lf = lf.with_column(lit(true).alias(name.clone()));
+            index.push(name.clone());
+        }
+
+        let mut output_names = Vec::with_capacity(aggs.len() * value_names.len());
+        let mut pivots = Vec::with_capacity(aggs.len());
+        for (i, (value, agg, suffix)) in aggs.into_iter().enumerate() {
+            let (titles, names): (Vec<_>, Vec<_>) = value_names
+                .iter()
+                .map(|(title, name)| {
+                    let name = match &suffix {
+                        Some(suffix) => format_pl_smallstr!("{name}_{suffix}"),
+                        None => name.clone(),
+                    };
+                    (title.clone(), name)
+                })
+                .unzip();
+            let pivot = lf
+                .clone()
+                .pivot(
+                    by_name([on.clone()], true, false),
+                    on_columns.clone(),
+                    by_name(index.clone(), true, false),
+                    by_name([value], true, false),
+                    agg,
+                    true,
+                    PlSmallStr::from_static("_"),
+                    PivotColumnNaming::Auto,
+                )
+                .rename(titles, names.clone(), true);
+            // All pivots share the same (ordered) index, keep it from the first one only
+            pivots.push(if i == 0 {
+                pivot
+            } else {
+                pivot.select([by_name(names.clone(), true, false).as_expr()])
+            });
+            output_names.extend(names);
+        }
+        let mut lf = if pivots.len() == 1 {
+            pivots.pop().unwrap()
+        } else {
+            concat_lf_horizontal(pivots, HConcatOptions::default())?
+        };
+        if let Some(name) = tmp_index {
+            lf = lf.drop(by_name([name], true, false));
+        }
+
+        if let Some(default) = default_on_null {
+            let default = parse_sql_expr(default, self, None)?;
+            lf = lf.with_columns([by_name(output_names, true, false)
+                .as_expr()
+                .fill_null(default)]);
+        }
+        Ok(lf)
+    }
+
+    /// Lower `UNPIVOT [INCLUDE | EXCLUDE NULLS] (<value> FOR <name> IN (<col> [AS <alias>], ...))`
+    /// to an unpivot of the listed columns, keeping all others as the index.
+    #[cfg(feature = "pivot")]
+    fn execute_unpivot(
+        &mut self,
+        lf: LazyFrame,
+        value: &SQLExpr,
+        name: &Ident,
+        columns: &[ExprWithAlias],
+        null_inclusion: &Option<NullInclusion>,
+    ) -> PolarsResult<LazyFrame> {
+        let value_name = sql_column_name(value)?;
+        polars_ensure!(!columns.is_empty(), SQLSyntax: "UNPIVOT requires at least one column");
+
+        // Aliased columns are renamed first, so that the alias becomes the unpivoted name
+        let mut on = Vec::with_capacity(columns.len());
+        let (mut existing, mut new) = (vec![], vec![]);
+        for c in columns {
+            let col_name = sql_column_name(&c.expr)?;
+            match &c.alias {
+                Some(alias) => {
+                    let alias = PlSmallStr::from_str(&alias.value);
+                    existing.push(col_name);
+                    new.push(alias.clone());
+                    on.push(alias);
+                },
+                None => on.push(col_name),
+            }
+        }
+        let on = by_name(on, true, false);
+        let mut lf = lf.rename(existing, new, true).unpivot(UnpivotArgsDSL {
+            on: Some(on.clone()),
+            index: all() - on,
+            variable_name: Some(PlSmallStr::from_str(&name.value)),
+            value_name: Some(value_name.clone()),
+        });
+
+        // Nulls are excluded by default
+        if !matches!(null_inclusion, Some(NullInclusion::IncludeNulls)) {
+            lf = lf.filter(col(value_name).is_not_null());
+        }
+        Ok(lf)
+    }
+
     fn execute_table_function(
         &mut self,
         name: &ObjectName,
@@ -2369,13 +2825,86 @@ impl SQLContext {
         ))
     }
 
+    /// Register a `GROUPING(...)` call, returning a placeholder that is resolved against the
+    /// GROUP BY keys by [`Self::resolve_grouping_calls`].
+    pub(crate) fn register_grouping_call(&mut self, args: Vec<Expr>) -> Expr {
+        let name = format_pl_smallstr!("{GROUPING_CALL_PREFIX}{}", self.grouping_calls.len());
+        self.grouping_calls.push(args);
+        col(name)
+    }
+
+    /// Replace the `GROUPING(...)` placeholders in the projections and HAVING clause with an
+    /// expression over the grouping id of the aggregation.
+    fn resolve_grouping_calls(
+        &mut self,
+        group_by_keys: &[Expr],
+        projections: &mut Vec<Expr>,
+        having: &mut Option<Expr>,
+    ) -> PolarsResult<()> {
+        let n_keys = group_by_keys.len();
+        let mut resolved = PlHashMap::with_capacity(self.grouping_calls.len());
+        for (i, args) in std::mem::take(&mut self.grouping_calls)
+            .into_iter()
+            .enumerate()
+        {
+            polars_ensure!(
+                args.len() < u32::BITS as usize,
+                SQLSyntax: "GROUPING supports at most {} arguments; found {}", u32::BITS - 1, args.len()
+            );
+            let n_args = args.len();
+            let mut expr: Option<Expr> = None;
+            for (j, arg) in args.iter().enumerate() {
+                let Some(key_idx) = group_by_keys
+                    .iter()
+                    .position(|k| k == arg || strip_outer_alias(k) == *arg)
+                else {
+                    polars_bail!(SQLSyntax: "GROUPING argument {:?} is not a GROUP BY key", arg)
+                };
+                // bit `n_keys - 1 - key_idx` of the grouping id is set if the key is aggregated
+                let bit = col(GROUPING_ID)
+                    .and(lit(1u32 << (n_keys - 1 - key_idx)))
+                    .neq(lit(0u32))
+                    .cast(DataType::UInt32)
+                    * lit(1u32 << (n_args - 1 - j));
+                expr = Some(match expr {
+                    Some(e) => e + bit,
+                    None => bit,
+                });
+            }
+            let name = format_pl_smallstr!("{GROUPING_CALL_PREFIX}{i}");
+            resolved.insert(name, expr.unwrap());
+        }
+        let replace = |e: Expr| match &e {
+            Expr::Column(name) => resolved.get(name).cloned().unwrap_or(e),
+            _ => e,
+        };
+        for p in projections.iter_mut() {
+            *p = match &*p {
+                // an unaliased GROUPING call is named "grouping"
+                Expr::Column(name) if resolved.contains_key(name) => {
+                    resolved[name].clone().alias("grouping")
+                },
+                _ => p.clone().map_expr(replace),
+            };
+        }
+        if let Some(having) = having {
+            *having = having.clone().map_expr(replace);
+        }
+        Ok(())
+    }
+
     fn process_group_by(
         &mut self,
         mut lf: LazyFrame,
         group_by_keys: &[Expr],
+        grouping_sets: Option<Vec<Vec<usize>>>,
         projections: &[Expr],
         having: Option<Expr>,
     ) -> PolarsResult<LazyFrame> {
+        if grouping_sets.is_some() {
+            // Expose the grouping id to `GROUPING(...)`; it is computed by the aggregation.
+            lf = lf.with_column(lit(0u32).alias(GROUPING_ID));
+        }
         let schema_before = self.get_frame_schema(&mut lf)?;
         let group_by_keys_schema =
             expressions_to_schema(group_by_keys, &schema_before, |duplicate_name: &str| {
@@ -2538,7 +3067,14 @@ impl SQLContext {
         };
 
         // Apply HAVING filter after aggregation
-        let mut aggregated = lf.group_by(group_by_keys).agg(&aggregation_projection);
+        let mut aggregated = match grouping_sets {
+            Some(sets) => lf
+                .group_by(group_by_keys)
+                .grouping_sets(sets)?
+                .with_grouping_id(GROUPING_ID)
+                .agg(&aggregation_projection),
+            None => lf.group_by(group_by_keys).agg(&aggregation_projection),
+        };
         if let Some(filter_expr) = having_filter {
             aggregated = aggregated.filter(filter_expr);
         }
@@ -2897,6 +3433,65 @@ fn is_simple_col_ref(expr: &Expr, col_name: &PlSmallStr) -> bool {
     }
 }
 
+/// Expand the elements of a GROUP BY clause into grouping sets: the cross product of the sets
+/// of each element, where a plain expression is a single set of itself.
+fn expand_grouping_sets(group_by_exprs: &[SQLExpr]) -> Vec<Vec<SQLExpr>> {
+    let mut sets = vec![vec![]];
+    for e in group_by_exprs {
+        let element_sets = match e {
+            SQLExpr::Rollup(items) => rollup_sets(items),
+            SQLExpr::Cube(items) => cube_sets(items),
+            SQLExpr::GroupingSets(items) => items.clone(),
+            e => vec![vec![e.clone()]],
+        };
+        sets = sets
+            .iter()
+            .flat_map(|set| {
+                element_sets.iter().map(move |element_set| {
+                    let mut set = set.clone();
+                    set.extend(element_set.iter().cloned());
+                    set
+                })
+            })
+            .collect();
+    }
+    sets
+}
+
+/// The grouping sets of `ROLLUP(e1, ..., en)`, where every element can be a composite group.
+fn rollup_sets(items: &[Vec<SQLExpr>]) -> Vec<Vec<SQLExpr>> {
+    (0..=items.len())
+        .rev()
+        .map(|n| items[..n].iter().flatten().cloned().collect())
+        .collect()
+}
+
+/// The grouping sets of `CUBE(e1, ..., en)`: every subset of the elements, from the full set
+/// to the empty set.
+fn cube_sets(items: &[Vec<SQLExpr>]) -> Vec<Vec<SQLExpr>> {
+    let n = items.len();
+    (0..1usize << n)
+        .map(|id| {
+            (0..n)
+                .filter(|i| id & (1 << (n - 1 - i)) == 0)
+                .flat_map(|i| items[i].iter().cloned())
+                .collect()
+        })
+        .collect()
+}
+
+/// The column name of a (possibly qualified) SQL column reference.
+#[cfg(feature = "pivot")]
+fn sql_column_name(e: &SQLExpr) -> PolarsResult<PlSmallStr> {
+    match e {
+        SQLExpr::Identifier(ident) => Ok(PlSmallStr::from_str(&ident.value)),
+        SQLExpr::CompoundIdentifier(idents) if !idents.is_empty() => {
+            Ok(PlSmallStr::from_str(&idents.last().unwrap().value))
+        },
+        _ => polars_bail!(SQLSyntax: "expected a column name; found {}", e),
+    }
+}
+
 /// Strip the outer alias from an expression (if present) for expression equality comparison.
 fn strip_outer_alias(expr: &Expr) -> Expr {
     if let Expr::Alias(inner, _) = expr {
@@ -3307,6 +3902,170 @@ fn extract_join_predicates(
     )
 }
 
+fn parse_sql_statements(query: &str) -> PolarsResult<Vec<Statement>> {
+    let mut tokens = Tokenizer::new(&GenericDialect, query)
+        .tokenize_with_location()
+        .map_err(to_sql_interface_err)?;
+    parse_reservoir_sample_method(&mut tokens)?;
+
+    Parser::new(&GenericDialect)
+        .with_options(ParserOptions {
+            trailing_commas: true,
+            ..Default::default()
+        })
+        .with_tokens_with_locations(tokens)
+        .parse_statements()
+        .map_err(to_sql_interface_err)
+}
+
+/// `sqlparser` doesn't know the `RESERVOIR` sampling method, so we parse
+/// `{TABLESAMPLE | SAMPLE} RESERVOIR (<n> [ROWS])` here and hand it on as the equivalent
+/// `(<n> ROWS)`: a row count without a method is a fixed-size sample, which is what we implement
+/// with reservoir sampling. A reservoir sample of a percentage is rejected.
+fn parse_reservoir_sample_method(tokens: &mut Vec<TokenWithSpan>) -> PolarsResult<()> {
+    let next_non_whitespace = |tokens: &[TokenWithSpan], mut i: usize| {
+        while matches!(tokens.get(i).map(|t| &t.token), Some(Token::Whitespace(_))) {
+            i += 1;
+        }
+        i
+    };
+    let token_at = |tokens: &[TokenWithSpan], i: usize| tokens.get(i).map(|t| t.token.clone());
+    let is_keyword = |token: &Option<Token>, keyword: Keyword| matches!(token, Some(Token::Word(w)) if w.keyword == keyword);
+
+    let mut i = 0;
+    while i < tokens.len() {
+        let Token::Word(w) = &tokens[i].token else {
+            i += 1;
+            continue;
+        };
+        if !matches!(w.keyword, Keyword::TABLESAMPLE | Keyword::SAMPLE) {
+            i += 1;
+            continue;
+        }
+
+        let method = next_non_whitespace(tokens, i + 1);
+        let is_reservoir = matches!(
+            token_at(tokens, method),
+            Some(Token::Word(w)) if w.quote_style.is_none() && w.value.eq_ignore_ascii_case("reservoir")
+        );
+        let lparen = next_non_whitespace(tokens, method + 1);
+        if !is_reservoir || token_at(tokens, lparen) != Some(Token::LParen) {
+            i = method;
+            continue;
+        }
+
+        let quantity = next_non_whitespace(tokens, lparen + 1);
+        let Some(Token::Number(n, _)) = token_at(tokens, quantity) else {
+            polars_bail!(SQLSyntax: "`RESERVOIR` sampling requires a number of rows")
+        };
+        let mut rparen = next_non_whitespace(tokens, quantity + 1);
+        let unit = token_at(tokens, rparen);
+        if is_keyword(&unit, Keyword::ROWS) {
+            rparen = next_non_whitespace(tokens, rparen + 1);
+        } else if is_keyword(&unit, Keyword::PERCENT) || unit == Some(Token::Mod) {
+            polars_bail!(
+                SQLInterface: "`RESERVOIR` sampling requires a number of rows, not a percentage; found {}%", n
+            )
+        }
+        polars_ensure!(
+            token_at(tokens, rparen) == Some(Token::RParen),
+            SQLSyntax: "expected ')' after the `RESERVOIR` sample size {}", n
+        );
+
+        // `RESERVOIR (<n> [ROWS])` -> `(<n> ROWS)`
+        let span = tokens[method].span;
+        let rows = Token::make_keyword("ROWS");
+        tokens.splice(
+            method..=rparen,
+            [
+                Token::LParen,
+                Token::Number(n, false),
+                Token::Whitespace(sqlparser::tokenizer::Whitespace::Space),
+                rows,
+                Token::RParen,
+            ]
+            .into_iter()
+            .map(|token| TokenWithSpan::new(token, span)),
+        );
+        i = method + 5;
+    }
+    Ok(())
+}
+
+/// Convert a `TABLESAMPLE`/`SAMPLE` clause into [`SampleOptions`].
+///
+/// A quantity in `ROWS` takes a fixed-size (reservoir) sample, anything else is a percentage
+/// that is applied per row (`BERNOULLI`, `ROW` or no method) or per block (`SYSTEM`, `BLOCK`).
+fn sql_sample_options(sample: &TableSampleKind) -> PolarsResult<SampleOptions> {
+    let (TableSampleKind::BeforeTableAlias(sample) | TableSampleKind::AfterTableAlias(sample)) =
+        sample;
+    let TableSample {
+        modifier: _,
+        name,
+        quantity,
+        seed,
+        bucket,
+        offset,
+    } = sample.as_ref();
+
+    polars_ensure!(bucket.is_none(), SQLInterface: "`BUCKET` sampling is not supported");
+    polars_ensure!(offset.is_none(), SQLInterface: "`OFFSET` in a `SAMPLE` clause is not supported");
+    let Some(quantity) = quantity else {
+        polars_bail!(SQLInterface: "`SAMPLE` clause requires a sample size");
+    };
+    let value = match &quantity.value {
+        SQLExpr::Value(ValueWithSpan {
+            value: SQLValue::Number(n, _),
+            ..
+        }) => n,
+        other => {
+            polars_bail!(SQLInterface: "`SAMPLE` size must be a number literal; found {}", other)
+        },
+    };
+
+    let options = match quantity.unit {
+        Some(TableSampleUnit::Rows) => {
+            polars_ensure!(
+                !matches!(name, Some(TableSampleMethod::System | TableSampleMethod::Block)),
+                SQLInterface: "`SYSTEM` sampling requires a percentage, not a number of rows"
+            );
+            let n = value.parse::<usize>().map_err(
+                |_| polars_err!(SQLInterface: "`SAMPLE` row count must be a non-negative integer; found {}", value),
+            )?;
+            SampleOptions::reservoir(n)
+        },
+        Some(TableSampleUnit::Percent) | None => {
+            let percentage = value
+                .parse::<f64>()
+                .map_err(|_| polars_err!(SQLInterface: "invalid `SAMPLE` percentage: {}", value))?;
+            polars_ensure!(
+                (0.0..=100.0).contains(&percentage),
+                SQLInterface: "`SAMPLE` percentage must be between 0 and 100; found {}", value
+            );
+            let fraction = percentage / 100.0;
+            match name {
+                Some(TableSampleMethod::System | TableSampleMethod::Block) => {
+                    SampleOptions::system(fraction)
+                },
+                Some(TableSampleMethod::Bernoulli | TableSampleMethod::Row) | None => {
+                    SampleOptions::bernoulli(fraction)
+                },
+            }
+        },
+    };
+
+    let seed = match seed {
+        Some(TableSampleSeed { value, .. }) => match value {
+            SQLValue::Number(n, _) => Some(n.parse::<u64>().map_err(
+                |_| polars_err!(SQLInterface: "`REPEATABLE` seed must be a non-negative integer; found {}", n),
+            )?),
+            other => polars_bail!(SQLInterface: "`REPEATABLE` seed must be an integer literal; found {}", other),
+        },
+        None => None,
+    };
+    Ok(options.with_seed(seed))
+}
+
 /// Extract table identifiers referenced in a SQL query; uses a visitor to
 /// collect all table names that appear in FROM clauses, JOINs, TABLE refs
 /// in set operations, and subqueries.
@@ -3315,17 +4074,7 @@ pub fn extract_table_identifiers(
     include_schema: bool,
     unique: bool,
 ) -> PolarsResult<Vec<String>> {
-    let mut parser = Parser::new(&GenericDialect);
-    parser = parser.with_options(ParserOptions {
-        trailing_commas: true,
-        ..Default::default()
-    });
-    let ast = parser
-        .try_with_sql(query)
-        .map_err(to_sql_interface_err)?
-        .parse_statements()
-        .map_err(to_sql_interface_err)?;
-
+    let ast = parse_sql_statements(query)?;
     let mut collector = TableIdentifierCollector {
         include_schema,
         ..Default::default()
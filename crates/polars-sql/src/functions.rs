@@ -587,6 +587,13 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT FIRST(col1) FROM df;
     /// ```
     First,
+    /// SQL 'grouping' function.
+    /// Returns a bit mask of the given GROUP BY keys, where a bit is set if the key is
+    /// aggregated in the grouping set of the row (see ROLLUP, CUBE and GROUPING SETS).
+    /// ```sql
+    /// SELECT col1, col2, SUM(col3), GROUPING(col1, col2) FROM df GROUP BY ROLLUP(col1, col2);
+    /// ```
+    Grouping,
     /// SQL 'last' function.
     /// Returns the last element of the grouping.
     /// ```sql
@@ -783,6 +790,35 @@ pub(crate) enum PolarsSQLFunctions {
     /// ```
     #[cfg(feature = "rank")]
     DenseRank,
+    /// SQL 'percent_rank' function.
+    /// Returns the relative rank of each row within a window partition, computed as
+    /// `(rank - 1) / (partition rows - 1)`; the first row (and its peers) get 0.
+    /// ```sql
+    /// SELECT PERCENT_RANK() OVER (PARTITION BY col1 ORDER BY col2) FROM df;
+    /// ```
+    #[cfg(feature = "rank")]
+    PercentRank,
+    /// SQL 'cume_dist' function.
+    /// Returns the fraction of partition rows that sort before or are peers of the current row.
+    /// ```sql
+    /// SELECT CUME_DIST() OVER (PARTITION BY col1 ORDER BY col2) FROM df;
+    /// ```
+    #[cfg(feature = "rank")]
+    CumeDist,
+    /// SQL 'ntile' function.
+    /// Divides the rows of a window partition into `n` buckets of (nearly) equal size,
+    /// numbered from 1; earlier buckets receive the extra rows when the division is uneven.
+    /// ```sql
+    /// SELECT NTILE(4) OVER (ORDER BY col1) FROM df;
+    /// ```
+    Ntile,
+    /// SQL 'nth_value' function.
+    /// Returns the value of the expression at the n-th row (1-indexed) of the window frame,
+    /// or NULL if the frame has fewer than `n` rows.
+    /// ```sql
+    /// SELECT NTH_VALUE(col1, 2) OVER (PARTITION BY category ORDER BY id) FROM df;
+    /// ```
+    NthValue,
 
     // ----
     // Column selection
@@ -841,6 +877,7 @@ impl PolarsSQLFunctions {
             "covar",
             "covar_pop",
             "covar_samp",
+            "cume_dist",
             "date",
             "date_part",
             "degrees",
@@ -851,6 +888,7 @@ impl PolarsSQLFunctions {
             "first_value",
             "floor",
             "greatest",
+            "grouping",
             "if",
             "ifnull",
             "initcap",
@@ -874,8 +912,11 @@ impl PolarsSQLFunctions {
             "quantile_disc",
             "min",
             "mod",
+            "nth_value",
+            "ntile",
             "nullif",
             "octet_length",
+            "percent_rank",
             "pi",
             "pow",
             "power",
@@ -1032,6 +1073,7 @@ impl PolarsSQLFunctions {
             "covar_pop" => Self::CovarPop,
             "covar_samp" | "covar" => Self::CovarSamp,
             "first" => Self::First,
+            "grouping" => Self::Grouping,
             "last" => Self::Last,
             "max" => Self::Max,
             "median" => Self::Median,
@@ -1063,11 +1105,17 @@ impl PolarsSQLFunctions {
             // Window functions
             // ----
             #[cfg(feature = "rank")]
+            "cume_dist" => Self::CumeDist,
+            #[cfg(feature = "rank")]
             "dense_rank" => Self::DenseRank,
             "first_value" => Self::FirstValue,
             "last_value" => Self::LastValue,
             "lag" => Self::Lag,
             "lead" => Self::Lead,
+            "nth_value" => Self::NthValue,
+            "ntile" => Self::Ntile,
+            #[cfg(feature = "rank")]
+            "percent_rank" => Self::PercentRank,
             #[cfg(feature = "rank")]
             "rank" => Self::Rank,
             "row_number" => Self::RowNumber,
@@ -1604,6 +1652,7 @@ impl SQLFunctionVisitor<'_> {
             CovarPop => self.visit_binary(|a, b| polars_lazy::dsl::cov(a, b, 0)),
             CovarSamp => self.visit_binary(|a, b| polars_lazy::dsl::cov(a, b, 1)),
             First => self.visit_unary(Expr::first),
+            Grouping => self.visit_grouping(),
             Last => self.visit_unary(Expr::last),
             Max => self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max),
             Median => self.visit_unary(Expr::median),
@@ -1772,6 +1821,10 @@ impl SQLFunctionVisitor<'_> {
                 };
                 self.apply_window_spec(rank_expr, &self.func.over)
             },
+            #[cfg(feature = "rank")]
+            PercentRank | CumeDist => self.visit_relative_rank(matches!(function_name, CumeDist)),
+            Ntile => self.visit_ntile(),
+            NthValue => self.visit_nth_value(),
             RowNumber => {
                 let args = extract_args(function)?;
                 if !args.is_empty() {
@@ -1825,6 +1878,168 @@ impl SQLFunctionVisitor<'_> {
         }.and_then(|e| self.apply_window_spec(e, &self.func.over))
     }
 
+    /// Parse a positive integer literal argument (such as the `n` of NTILE or NTH_VALUE).
+    fn parse_positive_int_arg(&mut self, sql_expr: &SQLExpr) -> PolarsResult<i64> {
+        match parse_sql_expr(sql_expr, self.ctx, self.active_schema)? {
+            Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(n))) if n > 0 => Ok(n as i64),
+            other => {
+                polars_bail!(SQLSyntax: "{} expects a positive integer (found {:?})", self.func.name, other)
+            },
+        }
+    }
+
+    /// PERCENT_RANK and CUME_DIST, derived from the min (resp. max) rank of each row.
+    #[cfg(feature = "rank")]
+    fn visit_relative_rank(&mut self, cume_dist: bool) -> PolarsResult<Expr> {
+        let func_name = if cume_dist {
+            "CUME_DIST"
+        } else {
+            "PERCENT_RANK"
+        };
+        let args = extract_args(self.func)?;
+        if !args.is_empty() {
+            polars_bail!(SQLSyntax: "{} expects 0 arguments (found {})", func_name, args.len());
+        }
+        let window_spec = match &self.func.over {
+            Some(window_type) => self.resolve_window_spec(window_type)?,
+            None => polars_bail!(SQLSyntax: "{} requires an OVER clause with ORDER BY", func_name),
+        };
+        if window_spec.order_by.is_empty() {
+            polars_bail!(SQLSyntax: "{} requires an OVER clause with ORDER BY", func_name);
+        }
+        let (order_exprs, all_desc) = self.parse_order_by_in_window(&window_spec.order_by)?;
+        let order_key = if order_exprs.len() == 1 {
+            order_exprs[0].clone()
+        } else {
+            as_struct(order_exprs)
+        };
+        let rank = order_key
+            .rank(
+                RankOptions {
+                    method: if cume_dist {
+                        RankMethod::Max
+                    } else {
+                        RankMethod::Min
+                    },
+                    descending: all_desc,
+                },
+                None,
+            )
+            .cast(DataType::Float64);
+        let n_rows = len().cast(DataType::Float64);
+
+        let expr = if cume_dist {
+            rank / n_rows
+        } else {
+            when(n_rows.clone().gt(lit(1.0)))
+                .then((rank - lit(1.0)) / (n_rows - lit(1.0)))
+                .otherwise(lit(0.0))
+        };
+        self.apply_window_spec(expr, &self.func.over)
+    }
+
+    fn visit_ntile(&mut self) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        let n_buckets = match args.as_slice() {
+            [FunctionArgExpr::Expr(sql_expr)] => self.parse_positive_int_arg(sql_expr)?,
+            _ => polars_bail!(SQLSyntax: "NTILE expects 1 argument (found {})", args.len()),
+        };
+        if self.func.over.is_none() {
+            polars_bail!(SQLSyntax: "NTILE requires an OVER clause");
+        }
+
+        // With `q, r = divmod(n_rows, n_buckets)`, the first `r` buckets hold `q + 1` rows
+        // and the remaining buckets hold `q` rows.
+        let idx = int_range(lit(0i64), len(), 1, DataType::Int64);
+        let n_rows = len().cast(DataType::Int64);
+        let q = n_rows.clone().floor_div(lit(n_buckets));
+        let r = n_rows % lit(n_buckets);
+        let large_bucket_size = q.clone() + lit(1i64);
+        let bucket = when(idx.clone().lt(r.clone() * large_bucket_size.clone()))
+            .then(idx.clone().floor_div(large_bucket_size))
+            .otherwise((idx - r).floor_div(q));
+        self.apply_window_spec(bucket + lit(1i64), &self.func.over)
+    }
+
+    /// NTH_VALUE, honouring the window frame:
+    /// - no ORDER BY, or `UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING`: the whole partition.
+    /// - `ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW` (the default with ORDER BY): NULL
+    ///   until the current row is the n-th row of the partition.
+    /// - `RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW`: as above, but peers of the
+    ///   current row are part of the frame.
+    fn visit_nth_value(&mut self) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        let (expr, n) = match args.as_slice() {
+            [FunctionArgExpr::Expr(sql_expr), FunctionArgExpr::Expr(n)] => {
+                let expr = parse_sql_expr(sql_expr, self.ctx, self.active_schema)?;
+                (expr, self.parse_positive_int_arg(n)?)
+            },
+            _ => polars_bail!(SQLSyntax: "NTH_VALUE expects 2 arguments (found {})", args.len()),
+        };
+        let window_spec = match &self.func.over {
+            Some(window_type) => self.resolve_window_spec(window_type)?,
+            None => polars_bail!(SQLSyntax: "NTH_VALUE requires an OVER clause"),
+        };
+
+        let nth = expr.get(lit(n - 1), true);
+        let frame = window_spec
+            .window_frame
+            .as_ref()
+            .map(|f| (f.units, &f.start_bound, &f.end_bound));
+        let nth_expr = match frame {
+            _ if window_spec.order_by.is_empty() => nth,
+            Some((
+                _,
+                WindowFrameBound::Preceding(None),
+                Some(WindowFrameBound::Following(None)),
+            )) => nth,
+            None
+            | Some((
+                WindowFrameUnits::Rows,
+                WindowFrameBound::Preceding(None),
+                None | Some(WindowFrameBound::CurrentRow),
+            )) => {
+                let idx = int_range(lit(0i64), len(), 1, DataType::Int64);
+                when(idx.gt_eq(lit(n - 1)))
+                    .then(nth)
+                    .otherwise(lit(LiteralValue::untyped_null()))
+            },
+            #[cfg(feature = "rank")]
+            Some((
+                WindowFrameUnits::Range,
+                WindowFrameBound::Preceding(None),
+                None | Some(WindowFrameBound::CurrentRow),
+            )) => {
+                // The frame ends at the last peer of the current row, which is its max rank.
+                let (order_exprs, all_desc) =
+                    self.parse_order_by_in_window(&window_spec.order_by)?;
+                let order_key = if order_exprs.len() == 1 {
+                    order_exprs[0].clone()
+                } else {
+                    as_struct(order_exprs)
+                };
+                let frame_end = order_key.rank(
+                    RankOptions {
+                        method: RankMethod::Max,
+                        descending: all_desc,
+                    },
+                    None,
+                );
+                when(frame_end.gt_eq(lit(n)))
+                    .then(nth)
+                    .otherwise(lit(LiteralValue::untyped_null()))
+            },
+            Some((units, start, end)) => polars_bail!(
+                SQLInterface:
+                "NTH_VALUE does not support the '{} BETWEEN {} AND {}' window frame",
+                units,
+                start,
+                end.as_ref().map_or("CURRENT ROW".to_string(), |b| b.to_string())
+            ),
+        };
+        self.over_window_spec(nth_expr, &window_spec)
+    }
+
     fn visit_udf(&mut self, func_name: &str) -> PolarsResult<Expr> {
         let args = extract_args(self.func)?
             .into_iter()
@@ -2234,6 +2449,22 @@ impl SQLFunctionVisitor<'_> {
         }
     }
 
+    fn visit_grouping(&mut self) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        if args.is_empty() {
+            polars_bail!(SQLSyntax: "GROUPING expects at least one argument");
+        }
+        let mut expr_args = Vec::with_capacity(args.len());
+        for arg in args {
+            if let FunctionArgExpr::Expr(sql_expr) = arg {
+                expr_args.push(self.parse_sql_arg(sql_expr)?);
+            } else {
+                return self.not_supported_error();
+            };
+        }
+        Ok(self.ctx.register_grouping_call(expr_args))
+    }
+
     fn visit_count(&mut self) -> PolarsResult<Expr> {
         let (args, is_distinct) = extract_args_distinct(self.func)?;
 
@@ -2392,7 +2623,12 @@ impl SQLFunctionVisitor<'_> {
         };
         let window_spec = self.resolve_window_spec(window_type)?;
         self.validate_window_frame(&window_spec.window_frame)?;
+        self.over_window_spec(expr, &window_spec)
+    }
 
+    /// Apply the partitioning and ordering of a window spec to `expr`, without validating the
+    /// window frame (for functions that interpret the frame themselves).
+    fn over_window_spec(&mut self, expr: Expr, window_spec: &WindowSpec) -> PolarsResult<Expr> {
         let partition_by = if window_spec.partition_by.is_empty() {
             None
         } else {
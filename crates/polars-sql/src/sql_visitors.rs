@@ -239,6 +239,14 @@ impl SQLVisitor for TableIdentifierCollector {
     }
 }
 
+/// Check if a query body references the relation `name` (eg: in a FROM or JOIN clause).
+pub(crate) fn set_expr_refers_to_relation(set_expr: &SetExpr, name: &str) -> bool {
+    let mut collector = TableIdentifierCollector::default();
+    collector.collect_from_set_expr(set_expr);
+    let _ = set_expr.visit(&mut collector);
+    collector.tables.iter().any(|t| t == name)
+}
+
 // ---------------------------------------------------------------------------
 // WindowFunctionFinder
 // ---------------------------------------------------------------------------
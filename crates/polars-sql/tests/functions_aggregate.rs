@@ -185,3 +185,102 @@ fn test_corr_group_by() {
 
     assert_eq!(expected, actual, "expected {expected:?}, got {actual:?}");
 }
+
+fn create_df_sales() -> LazyFrame {
+    df! {
+        "region" => ["north", "north", "south", "south", "south"],
+        "year" => [2024, 2025, 2024, 2024, 2025],
+        "sales" => [10, 20, 5, 15, 30],
+    }
+    .unwrap()
+    .lazy()
+}
+
+#[test]
+fn test_group_by_rollup() {
+    let mut ctx = SQLContext::new();
+    ctx.register("df", create_df_sales());
+    let sql = r#"
+    SELECT
+        region,
+        year,
+        SUM(sales) AS total,
+        GROUPING(region, year) AS g
+    FROM df
+    GROUP BY ROLLUP(region, year)
+    ORDER BY g, region NULLS LAST, year NULLS LAST"#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+
+    let expected = df! {
+        "region" => [Some("north"), Some("north"), Some("south"), Some("south"), Some("north"), Some("south"), None],
+        "year" => [Some(2024), Some(2025), Some(2024), Some(2025), None, None, None],
+        "total" => [10, 20, 20, 30, 30, 50, 80],
+        "g" => [0u32, 0, 0, 0, 1, 1, 3],
+    }
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected {expected:?}, got {actual:?}"
+    );
+
+    // MySQL-style modifier
+    let sql = r#"
+    SELECT region, year, SUM(sales) AS total
+    FROM df
+    GROUP BY region, year WITH ROLLUP"#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert_eq!(actual.height(), 7);
+}
+
+#[test]
+fn test_group_by_cube_and_grouping_sets() {
+    let mut ctx = SQLContext::new();
+    ctx.register("df", create_df_sales());
+
+    let sql = r#"
+    SELECT region, year, SUM(sales) AS total
+    FROM df
+    GROUP BY CUBE(region, year)"#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    // (region, year), (region), (year), ()
+    assert_eq!(actual.height(), 4 + 2 + 2 + 1);
+
+    let sql = r#"
+    SELECT year, SUM(sales) AS total, GROUPING(year) AS g
+    FROM df
+    GROUP BY GROUPING SETS ((year), ())
+    HAVING SUM(sales) > 30
+    ORDER BY year NULLS LAST"#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "year" => [Some(2025), None],
+        "total" => [50, 80],
+        "g" => [0u32, 1],
+    }
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected {expected:?}, got {actual:?}"
+    );
+
+    // plain keys are part of every grouping set
+    let sql = r#"
+    SELECT region, year, SUM(sales) AS total
+    FROM df
+    GROUP BY region, ROLLUP(year)
+    ORDER BY region, year NULLS LAST"#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "region" => ["north", "north", "north", "south", "south", "south"],
+        "year" => [Some(2024), Some(2025), None, Some(2024), Some(2025), None],
+        "total" => [10, 20, 30, 20, 30, 50],
+    }
+    .unwrap();
+    assert!(
+        actual.equals_missing(&expected),
+        "expected {expected:?}, got {actual:?}"
+    );
+
+    let sql = "SELECT GROUPING(region) FROM df";
+    assert!(ctx.execute(sql).is_err());
+}
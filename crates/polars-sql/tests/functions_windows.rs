@@ -223,3 +223,75 @@ fn test_lead_lag_without_order_by() {
         );
     }
 }
+
+#[test]
+fn test_ntile_nth_value() {
+    let mut ctx = SQLContext::new();
+    ctx.register("df", create_df());
+    let actual = ctx
+        .execute(
+            "
+      SELECT
+          a,
+          b,
+          NTILE(2) OVER (PARTITION BY a ORDER BY b) AS ntile,
+          NTH_VALUE(b, 2) OVER (PARTITION BY a ORDER BY b) AS nth,
+          NTH_VALUE(b, 2) OVER (
+            PARTITION BY a ORDER BY b
+            ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING
+          ) AS nth_partition
+      FROM df
+      ",
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+
+    let expected = df! {
+        "a" => [1, 1, 1, 2, 2, 3],
+        "b" => ["a", "b", "c", "a", "b", "c"],
+        "ntile" => [1i64, 1, 2, 1, 2, 1],
+        "nth" => [None, Some("b"), Some("b"), None, Some("b"), None],
+        "nth_partition" => [Some("b"), Some("b"), Some("b"), Some("b"), Some("b"), None],
+    }
+    .unwrap();
+    assert_eq!(expected, actual);
+
+    ensure_error("a, NTILE(0) OVER (ORDER BY b) as c", "positive integer");
+    ensure_error("a, NTH_VALUE(b, 2) as c", "requires an OVER clause");
+}
+
+#[test]
+#[cfg(feature = "rank")]
+fn test_percent_rank_cume_dist() {
+    let mut ctx = SQLContext::new();
+    ctx.register("df", create_df());
+    let actual = ctx
+        .execute(
+            "
+      SELECT
+          a,
+          PERCENT_RANK() OVER (PARTITION BY a ORDER BY b) AS pct,
+          CUME_DIST() OVER (PARTITION BY a ORDER BY b) AS cume,
+          PERCENT_RANK() OVER (ORDER BY a) AS pct_ties
+      FROM df
+      ",
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+
+    let expected = df! {
+        "a" => [1, 1, 1, 2, 2, 3],
+        "pct" => [0.0, 0.5, 1.0, 0.0, 1.0, 0.0],
+        "cume" => [1.0 / 3.0, 2.0 / 3.0, 1.0, 0.5, 1.0, 1.0],
+        "pct_ties" => [0.0, 0.0, 0.0, 0.6, 0.6, 1.0],
+    }
+    .unwrap();
+    assert_eq!(expected, actual);
+
+    ensure_error(
+        "a, PERCENT_RANK() OVER (PARTITION BY a) as c",
+        "requires an OVER clause with ORDER BY",
+    );
+}
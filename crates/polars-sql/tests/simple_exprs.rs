@@ -859,6 +859,91 @@ fn test_cte_values() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_recursive_cte_counter() -> PolarsResult<()> {
+    let sql = r#"
+        WITH RECURSIVE t(n) AS (
+          SELECT 1
+          UNION ALL
+          SELECT n + 1 FROM t WHERE n < 10
+        )
+        SELECT n FROM t ORDER BY n
+    "#;
+    let mut context = SQLContext::new();
+    let df = context.execute(sql)?.collect()?;
+    let expected = df! { "n" => (1..=10).collect::<Vec<i32>>() }?;
+    assert!(df.equals(&expected));
+
+    Ok(())
+}
+
+#[test]
+fn test_recursive_cte_hierarchy() -> PolarsResult<()> {
+    let employees = df! {
+        "id" => [1, 2, 3, 4, 5],
+        "manager_id" => [None, Some(1), Some(1), Some(2), Some(4)],
+        "name" => ["alice", "bob", "carol", "dave", "eve"],
+    }?;
+    let mut context = SQLContext::new();
+    context.register("employees", employees.lazy());
+
+    let sql = r#"
+        WITH RECURSIVE reports AS (
+          SELECT id, name, 0 AS depth FROM employees WHERE manager_id IS NULL
+          UNION ALL
+          SELECT e.id, e.name, r.depth + 1
+          FROM employees e JOIN reports r ON e.manager_id = r.id
+        )
+        SELECT id, name, depth FROM reports ORDER BY id
+    "#;
+    let df = context.execute(sql)?.collect()?;
+    let expected = df! {
+        "id" => [1, 2, 3, 4, 5],
+        "name" => ["alice", "bob", "carol", "dave", "eve"],
+        "depth" => [0, 1, 1, 2, 3],
+    }?;
+    assert!(df.equals(&expected));
+
+    Ok(())
+}
+
+#[test]
+fn test_recursive_cte_union_distinct() -> PolarsResult<()> {
+    // the edges form a cycle; UNION discards rows that were already produced
+    let edges = df! {
+        "src" => [1, 2, 3, 3],
+        "dst" => [2, 3, 1, 4],
+    }?;
+    let mut context = SQLContext::new();
+    context.register("edges", edges.lazy());
+
+    let sql = r#"
+        WITH RECURSIVE reachable(node) AS (
+          SELECT 1
+          UNION
+          SELECT dst FROM edges JOIN reachable ON edges.src = reachable.node
+        )
+        SELECT node FROM reachable ORDER BY node
+    "#;
+    let df = context.execute(sql)?.collect()?;
+    let expected = df! { "node" => [1, 2, 3, 4] }?;
+    assert!(df.equals(&expected));
+
+    // with UNION ALL the same query never reaches a fixpoint; the iterations only run once the
+    // query is collected, so planning and schema resolution succeed
+    let sql = sql.replace("UNION", "UNION ALL");
+    let mut context = context.with_recursive_cte_limit(50);
+    let mut lf = context.execute(&sql)?;
+    assert_eq!(lf.collect_schema()?.len(), 1);
+    let err = lf.collect().unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("did not complete within 50 iterations")
+    );
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "ipc")]
 fn test_group_by_2() -> PolarsResult<()> {
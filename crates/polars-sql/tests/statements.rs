@@ -654,3 +654,143 @@ fn test_join_non_equi_range() {
         "expected = {expected:?}\nactual={actual:?}"
     );
 }
+
+#[cfg(feature = "pivot")]
+fn create_quarterly_sales_ctx() -> SQLContext {
+    let sales = df! {
+        "region" => ["north", "north", "south", "south", "south"],
+        "quarter" => ["Q1", "Q2", "Q1", "Q1", "Q2"],
+        "amount" => [10, 20, 5, 15, 30],
+    }
+    .unwrap();
+    let ctx = SQLContext::new();
+    ctx.register("sales", sales.lazy());
+    ctx
+}
+
+#[test]
+#[cfg(feature = "pivot")]
+fn test_pivot() {
+    let mut ctx = create_quarterly_sales_ctx();
+    let sql = r#"
+        SELECT * FROM sales
+        PIVOT (SUM(amount) FOR quarter IN ('Q1', 'Q2'))
+        ORDER BY region
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "region" => ["north", "south"],
+        "Q1" => [10, 20],
+        "Q2" => [20, 30],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    // aliased values and aggregates, with a default for missing combinations
+    let sql = r#"
+        SELECT * FROM sales
+        PIVOT (
+          SUM(amount) AS total, MAX(amount) AS top
+          FOR quarter IN ('Q1' AS q1, 'Q3' AS q3)
+          DEFAULT ON NULL (0)
+        ) AS p
+        ORDER BY region
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "region" => ["north", "south"],
+        "q1_total" => [10, 20],
+        "q3_total" => [0, 0],
+        "q1_top" => [10, 15],
+        "q3_top" => [0, 0],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+}
+
+#[test]
+#[cfg(feature = "pivot")]
+fn test_unpivot() {
+    let wide = df! {
+        "region" => ["north", "south"],
+        "q1" => [Some(10), Some(20)],
+        "q2" => [Some(20), None],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("wide", wide.lazy());
+
+    let sql = r#"
+        SELECT * FROM wide
+        UNPIVOT (amount FOR quarter IN (q1 AS "Q1", q2 AS "Q2"))
+        ORDER BY region, quarter
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+        "region" => ["north", "north", "south"],
+        "quarter" => ["Q1", "Q2", "Q1"],
+        "amount" => [10, 20, 20],
+    }
+    .unwrap();
+    assert!(
+        actual.equals(&expected),
+        "expected = {expected:?}\nactual={actual:?}"
+    );
+
+    let sql = r#"
+        SELECT * FROM wide
+        UNPIVOT INCLUDE NULLS (amount FOR quarter IN (q1, q2))
+    "#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert_eq!(actual.height(), 4);
+    assert_eq!(actual.column("amount").unwrap().null_count(), 1);
+}
+
+#[test]
+fn test_table_sample() {
+    let df = df! {
+        "n" => (0..1000).collect::<Vec<i64>>(),
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("df", df.lazy());
+
+    let sql = "SELECT * FROM df TABLESAMPLE RESERVOIR (25 ROWS) REPEATABLE (42)";
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    assert_eq!(actual.height(), 25);
+    let again = ctx.execute(sql).unwrap().collect().unwrap();
+    assert!(actual.equals(&again));
+
+    for (sql, expected) in [
+        ("SELECT * FROM df TABLESAMPLE BERNOULLI (100)", 1000),
+        ("SELECT * FROM df TABLESAMPLE SYSTEM (0)", 0),
+        ("SELECT * FROM df AS t TABLESAMPLE (5 ROWS)", 5),
+        ("SELECT * FROM df TABLESAMPLE RESERVOIR (5000 ROWS)", 1000),
+        ("SELECT * FROM df SAMPLE reservoir (10)", 10),
+    ] {
+        let actual = ctx.execute(sql).unwrap().collect().unwrap();
+        assert_eq!(actual.height(), expected, "{sql}");
+    }
+
+    let actual = ctx
+        .execute("SELECT * FROM df TABLESAMPLE BERNOULLI (50) REPEATABLE (1)")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert!((300..700).contains(&actual.height()));
+
+    for sql in [
+        "SELECT * FROM df TABLESAMPLE BERNOULLI (150)",
+        "SELECT * FROM df TABLESAMPLE SYSTEM (10 ROWS)",
+        "SELECT * FROM df TABLESAMPLE RESERVOIR (10 PERCENT)",
+        "SELECT * FROM df TABLESAMPLE RESERVOIR (10%)",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
@@ -3,10 +3,12 @@ use std::sync::Arc;
 use crossbeam_channel::Sender;
 use parking_lot::Mutex;
 use polars_async::executor;
+pub use polars_config::StreamingExecutionOptions;
 use polars_core::frame::DataFrame;
 use polars_core::runtime::ASYNC;
 use polars_error::PolarsResult;
 use polars_expr::state::ExecutionState;
+use polars_ooc::{QueryMemoryContext, memory_manager};
 use polars_utils::aliases::PlHashSet;
 use polars_utils::relaxed_cell::RelaxedCell;
 use polars_utils::reuse_vec::reuse_vec;
@@ -25,11 +27,19 @@ pub struct StreamingExecutionState {
     /// The ExecutionState passed to any non-streaming operations.
     pub in_memory_exec_state: ExecutionState,
 
+    /// The memory budget and spill policy of this query.
+    pub query_memory: Arc<QueryMemoryContext>,
+
     query_tasks_send: Sender<JoinHandle<PolarsResult<()>>>,
     subphase_tasks_send: Sender<JoinHandle<PolarsResult<()>>>,
 }
 
 impl StreamingExecutionState {
+    /// Whether either the process or this query exceeds its memory budget.
+    pub fn is_over_memory_budget(&self) -> bool {
+        memory_manager().is_over_budget() || self.query_memory.is_over_budget()
+    }
+
     /// Spawns a task which is awaited at the end of the query.
     #[allow(unused)]
     pub fn spawn_query_task<F: Future<Output = PolarsResult<()>> + Send + 'static>(&self, fut: F) {
@@ -298,16 +308,61 @@ fn run_subgraph(
     Ok(())
 }
 
+/// Attaches the spill contexts of all nodes to the query and records their memory usage in the
+/// metrics of the node they belong to.
+fn track_node_memory(
+    graph: &Graph,
+    query_memory: &QueryMemoryContext,
+    metrics: Option<&Mutex<GraphMetrics>>,
+) {
+    for (node_key, node) in graph.nodes.iter() {
+        let spill_contexts = node.compute.spill_contexts();
+        if spill_contexts.is_empty() {
+            continue;
+        }
+
+        let mut resident_bytes = 0;
+        let mut peak_resident_bytes = 0;
+        let mut spilled_bytes = 0;
+        for ctx in spill_contexts {
+            query_memory.attach(ctx.clone());
+            if metrics.is_some() {
+                ctx.release_dropped();
+                let stats = ctx.stats();
+                resident_bytes += stats.resident_bytes();
+                peak_resident_bytes += stats.peak_resident_bytes();
+                spilled_bytes += stats.spilled_bytes();
+            }
+        }
+
+        if let Some(m) = metrics {
+            m.lock()
+                .update_memory(node_key, resident_bytes, peak_resident_bytes, spilled_bytes);
+        }
+    }
+}
+
 pub fn execute_graph(
     graph: &mut Graph,
     metrics: Option<Arc<Mutex<GraphMetrics>>>,
+    options: StreamingExecutionOptions,
 ) -> PolarsResult<SparseSecondaryMap<GraphNodeKey, DataFrame>> {
     let (query_tasks_send, query_tasks_recv) = crossbeam_channel::unbounded();
     let (subphase_tasks_send, subphase_tasks_recv) = crossbeam_channel::unbounded();
 
+    if let Some(budget) = options.memory_budget_bytes
+        && polars_core::config::verbose()
+    {
+        eprintln!(
+            "polars-stream: query memory budget: {budget} bytes, spill policy: {}",
+            options.spill_policy
+        );
+    }
+
     let state = StreamingExecutionState {
         num_pipelines: polars_config::config().max_threads(),
         in_memory_exec_state: ExecutionState::default(),
+        query_memory: QueryMemoryContext::new(options.memory_budget_bytes, options.spill_policy),
         query_tasks_send,
         subphase_tasks_send,
     };
@@ -331,6 +386,7 @@ pub fn execute_graph(
             eprintln!("polars-stream: updating graph state");
         }
         graph.update_all_states(&state, metrics.as_deref())?;
+        track_node_memory(graph, &state.query_memory, metrics.as_deref());
 
         if let Some(m) = metrics.as_ref() {
             m.lock().flush(&graph.pipes);
@@ -368,6 +424,7 @@ pub fn execute_graph(
             &state,
             metrics.clone(),
         )?;
+        track_node_memory(graph, &state.query_memory, metrics.as_deref());
         ASYNC.block_in_place_on(async {
             // TODO: track this in metrics.
             while let Ok(handle) = subphase_tasks_recv.try_recv() {
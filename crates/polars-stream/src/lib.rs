@@ -3,10 +3,11 @@ mod skeleton;
 
 use std::sync::LazyLock;
 
-pub use skeleton::{run_query, visualize_physical_plan};
+pub use skeleton::{run_query, run_query_with_options, visualize_physical_plan};
 
 mod execute;
 pub use dispatch::build_streaming_query_executor;
+pub use execute::StreamingExecutionOptions;
 pub(crate) mod expression;
 mod graph;
 pub use graph::{GraphNodeKey, LogicalPipe, LogicalPipeKey};
@@ -31,6 +31,14 @@ pub struct NodeMetrics {
     pub io_total_bytes_requested: u64,
     pub io_total_bytes_received: u64,
     pub io_total_bytes_sent: u64,
+    pub io_total_row_groups_skipped: u64,
+
+    /// Estimated bytes of spillable data this node holds in memory.
+    pub memory_resident_bytes: u64,
+    /// Highest value `memory_resident_bytes` reached in any of the spill contexts of this node.
+    pub memory_peak_resident_bytes: u64,
+    /// Bytes of this node which are currently spilled.
+    pub memory_spilled_bytes: u64,
 
     pub state_update_in_progress: bool,
     pub num_running_tasks: u32,
@@ -53,6 +61,7 @@ impl NodeMetrics {
         self.io_total_bytes_requested += io_metrics.bytes_requested.load();
         self.io_total_bytes_received += io_metrics.bytes_received.load();
         self.io_total_bytes_sent += io_metrics.bytes_sent.load();
+        self.io_total_row_groups_skipped += io_metrics.row_groups_skipped.load();
     }
 
     fn reset_io_metrics(&mut self) {
@@ -60,6 +69,13 @@ impl NodeMetrics {
         self.io_total_bytes_requested = 0;
         self.io_total_bytes_received = 0;
         self.io_total_bytes_sent = 0;
+        self.io_total_row_groups_skipped = 0;
+    }
+
+    fn update_memory(&mut self, resident_bytes: u64, peak_resident_bytes: u64, spilled_bytes: u64) {
+        self.memory_resident_bytes = resident_bytes;
+        self.memory_peak_resident_bytes = self.memory_peak_resident_bytes.max(peak_resident_bytes);
+        self.memory_spilled_bytes = spilled_bytes;
     }
 
     fn start_state_update(&mut self) {
@@ -129,6 +145,20 @@ impl GraphMetrics {
         self.node_metrics[key].stop_state_update(time, is_done);
     }
 
+    pub fn update_memory(
+        &mut self,
+        key: GraphNodeKey,
+        resident_bytes: u64,
+        peak_resident_bytes: u64,
+        spilled_bytes: u64,
+    ) {
+        self.node_metrics
+            .entry(key)
+            .unwrap()
+            .or_default()
+            .update_memory(resident_bytes, peak_resident_bytes, spilled_bytes);
+    }
+
     pub fn flush(&mut self, pipes: &SlotMap<LogicalPipeKey, LogicalPipe>) {
         for (key, in_progress_task_metrics) in self.in_progress_task_metrics.iter_mut() {
             let this_node_metrics = self.node_metrics.entry(key).unwrap().or_default();
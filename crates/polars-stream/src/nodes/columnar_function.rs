@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use polars_core::schema::Schema;
+use polars_ooc::SpillContext;
 use polars_plan::dsl::ColumnsUdf;
 use polars_plan::plans::FunctionArgMap;
 use polars_utils::itertools::Itertools;
@@ -123,6 +124,15 @@ impl ComputeNode for ColumnarFunctionNode {
         matches!(self, Self::Sink { .. })
     }
 
+    fn spill_contexts(&self) -> Vec<Arc<dyn SpillContext>> {
+        match self {
+            Self::Sink { sink_nodes, .. } => {
+                sink_nodes.iter().flat_map(|n| n.spill_contexts()).collect()
+            },
+            Self::Source(_) | Self::Done => Vec::new(),
+        }
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
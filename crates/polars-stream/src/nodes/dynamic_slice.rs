@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use polars_core::schema::Schema;
+use polars_ooc::SpillContext;
 
 use super::compute_node_prelude::*;
 use crate::nodes::in_memory_sink::InMemorySinkNode;
@@ -79,6 +80,18 @@ impl ComputeNode for DynamicSliceNode {
         Ok(())
     }
 
+    fn spill_contexts(&self) -> Vec<Arc<dyn SpillContext>> {
+        match self {
+            Self::GatheringParams { offset, length } => {
+                let mut out = offset.spill_contexts();
+                out.extend(length.spill_contexts());
+                out
+            },
+            Self::Streaming(node) => node.spill_contexts(),
+            Self::Negative(node) => node.spill_contexts(),
+        }
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use polars_core::prelude::*;
+use polars_ooc::SpillContext;
 use polars_ops::frame::gather::GatherDf;
 
 use super::compute_node_prelude::*;
@@ -76,6 +77,13 @@ impl ComputeNode for GatherNode {
         matches!(self.state, GatherState::Sink(_))
     }
 
+    fn spill_contexts(&self) -> Vec<Arc<dyn SpillContext>> {
+        match &self.state {
+            GatherState::Sink(sink_node) => sink_node.spill_contexts(),
+            GatherState::Gather(_) | GatherState::Done => Vec::new(),
+        }
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
@@ -1,22 +1,24 @@
+use std::borrow::Cow;
 use std::sync::Arc;
 
 use polars_async::executor;
-use polars_core::prelude::{IntoColumn, PlHashSet, PlRandomState};
+use polars_config::config;
+use polars_core::prelude::{DataType, IntoColumn, PlHashSet, PlRandomState};
 use polars_core::runtime::{ASYNC, RAYON};
 use polars_core::schema::Schema;
+use polars_core::series::Series;
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 use polars_expr::groups::Grouper;
-use polars_expr::hash_keys::HashKeys;
+use polars_expr::hash_keys::{HashKeys, HashKeysLayout};
 use polars_expr::hot_groups::{HotGrouper, new_hash_hot_grouper};
 use polars_expr::reduce::GroupedReduction;
-use polars_ooc::{MostRecentSpillContext, SpillFrame};
+use polars_ooc::{MostRecentSpillContext, SpillContext, SpillFrame};
 use polars_utils::cardinality_sketch::CardinalitySketch;
 use polars_utils::hashing::HashPartitioner;
 use polars_utils::itertools::Itertools;
 use polars_utils::pl_str::PlSmallStr;
-use polars_utils::reuse_vec::reuse_vec;
 use polars_utils::sparse_init_vec::SparseInitVec;
-use polars_utils::{IdxSize, UnitVec};
+use polars_utils::{IdxSize, UnitVec, format_pl_smallstr};
 use rayon::prelude::*;
 use tokio::sync::mpsc::{Receiver, channel};
 
@@ -30,12 +32,51 @@ const DEFAULT_HOT_TABLE_SIZE: usize = 4;
 #[cfg(not(debug_assertions))]
 const DEFAULT_HOT_TABLE_SIZE: usize = 4096;
 
+// Bounds on the recursive repartitioning of partitions whose aggregation state
+// is estimated to exceed the memory budget.
+const MAX_REPARTITION_DEPTH: usize = 4;
+const MAX_SUB_PARTITIONS: usize = 64;
+
 struct PreAgg {
     keys: HashKeys,
     reduction_idxs: UnitVec<usize>,
     reductions: Vec<Box<dyn GroupedReduction>>,
 }
 
+/// The rows of an evicted pre-aggregate belonging to a single partition, with
+/// the keys and encoded reduction states stored in a spillable DataFrame.
+struct SpilledPreAgg {
+    keys_layout: HashKeysLayout,
+    reduction_idxs: UnitVec<usize>,
+    frame: SpillFrame,
+}
+
+impl SpilledPreAgg {
+    async fn load(self, grouped_reductions: &[Box<dyn GroupedReduction>]) -> PolarsResult<PreAgg> {
+        let df = self.frame.into_df().await?;
+        let (key_cols, state_cols) = df.columns().split_at(self.keys_layout.num_columns());
+        let keys = HashKeys::from_columns(&self.keys_layout, key_cols)?;
+        let reductions = self
+            .reduction_idxs
+            .iter()
+            .zip(state_cols)
+            .map(|(r, c)| {
+                let state = c.binary()?.rechunk();
+                grouped_reductions[*r].decode_state(state.downcast_as_array())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(PreAgg {
+            keys,
+            reduction_idxs: self.reduction_idxs,
+            reductions,
+        })
+    }
+}
+
+fn should_spill_pre_aggs(state: &StreamingExecutionState) -> bool {
+    config().force_group_by_spill() || state.is_over_memory_budget()
+}
+
 struct LocalGroupBySinkState {
     hot_grouper_per_input: Vec<Box<dyn HotGrouper>>,
     hot_grouped_reductions: Vec<Box<dyn GroupedReduction>>,
@@ -56,6 +97,10 @@ struct LocalGroupBySinkState {
     pre_aggs: Vec<PreAgg>,
     pre_agg_idxs_values_per_p: Vec<Vec<IdxSize>>,
     pre_agg_idxs_offsets_per_p: Vec<usize>,
+
+    // Evicted pre-aggregates stored while under memory pressure, already split
+    // per partition.
+    spilled_pre_aggs_per_p: Vec<Vec<SpilledPreAgg>>,
 }
 
 impl LocalGroupBySinkState {
@@ -82,6 +127,8 @@ impl LocalGroupBySinkState {
             pre_aggs: Vec::new(),
             pre_agg_idxs_values_per_p: vec![Vec::new(); num_partitions],
             pre_agg_idxs_offsets_per_p: vec![0; num_partitions],
+
+            spilled_pre_aggs_per_p: (0..num_partitions).map(|_| Vec::new()).collect(),
         }
     }
 
@@ -99,6 +146,67 @@ impl LocalGroupBySinkState {
         self.add_pre_agg(hash_keys, reduction_idxs, reductions, partitioner);
     }
 
+    /// Like flush_evictions, but stores the evicted pre-aggregates in a
+    /// spillable frame per partition. Keeps them in memory instead if the
+    /// state of one of the reductions can't be encoded.
+    async fn spill_evictions(
+        &mut self,
+        input_idx: usize,
+        reduction_idxs: &[usize],
+        partitioner: &HashPartitioner,
+        spill_ctx: &MostRecentSpillContext,
+    ) {
+        let hash_keys = self.hot_grouper_per_input[input_idx].take_evicted_keys();
+        let reductions = reduction_idxs
+            .iter()
+            .map(|r| self.hot_grouped_reductions[*r].take_evictions())
+            .collect_vec();
+        let Some(states) = reductions
+            .iter()
+            .map(|r| r.encode_state())
+            .collect::<Option<Vec<_>>>()
+        else {
+            self.add_pre_agg(hash_keys, reduction_idxs, reductions, partitioner);
+            return;
+        };
+        drop(reductions);
+
+        let mut idxs_per_p = vec![Vec::new(); partitioner.num_partitions()];
+        hash_keys.gen_idxs_per_partition(
+            partitioner,
+            &mut idxs_per_p,
+            &mut self.sketch_per_p,
+            true,
+        );
+        let height = hash_keys.len();
+        let (keys_layout, mut columns) = hash_keys.to_columns();
+        for (i, state) in states.into_iter().enumerate() {
+            let name = format_pl_smallstr!("__POLARS_GROUP_BY_STATE_{i}");
+            let state = unsafe {
+                Series::from_chunks_and_dtype_unchecked(
+                    name,
+                    vec![state.boxed()],
+                    &DataType::Binary,
+                )
+            };
+            columns.push(state.into_column());
+        }
+        let df = unsafe { DataFrame::new_unchecked(height, columns) };
+
+        for (p, idxs) in idxs_per_p.iter().enumerate() {
+            if idxs.is_empty() {
+                continue;
+            }
+            let p_df = unsafe { df.take_slice_unchecked_impl(idxs, false) };
+            let frame = SpillFrame::new(p_df, spill_ctx).await;
+            self.spilled_pre_aggs_per_p[p].push(SpilledPreAgg {
+                keys_layout: keys_layout.clone(),
+                reduction_idxs: UnitVec::from_slice(reduction_idxs),
+                frame,
+            });
+        }
+    }
+
     fn add_pre_agg(
         &mut self,
         hash_keys: HashKeys,
@@ -235,13 +343,25 @@ impl GroupBySinkState {
                         }
                     }
 
-                    // If we have too many evicted rows, flush them.
+                    // If we have too many evicted rows, flush them. Under memory
+                    // pressure we store them such that they can be spilled.
                     if hot_grouper.num_evictions() >= get_ideal_morsel_size() {
-                        local.flush_evictions(
-                            input_idx,
-                            &reductions_per_input[input_idx],
-                            &partitioner,
-                        );
+                        if should_spill_pre_aggs(state) {
+                            local
+                                .spill_evictions(
+                                    input_idx,
+                                    &reductions_per_input[input_idx],
+                                    &partitioner,
+                                    spill_ctx,
+                                )
+                                .await;
+                        } else {
+                            local.flush_evictions(
+                                input_idx,
+                                &reductions_per_input[input_idx],
+                                &partitioner,
+                            );
+                        }
                     }
                 }
                 Ok(())
@@ -249,7 +369,13 @@ impl GroupBySinkState {
         }
     }
 
-    fn combine_locals(&mut self) -> PolarsResult<Vec<GroupByPartition>> {
+    fn combine_locals(
+        &mut self,
+        key_schema: &Schema,
+        output_schema: &Schema,
+        state: &StreamingExecutionState,
+        spill_ctx: &MostRecentSpillContext,
+    ) -> PolarsResult<Vec<DataFrame>> {
         // Finalize pre-aggregations.
         RAYON.install(|| {
             self.locals
@@ -298,13 +424,38 @@ impl GroupBySinkState {
         }
         let (drop_q_send, drop_q_recv) = async_channel::bounded(self.locals.len());
         let num_partitions = self.locals[0].sketch_per_p.len();
-        let output_per_partition: SparseInitVec<GroupByPartition> =
+
+        // The spilled pre-aggregates are already split per partition, so each
+        // partition simply takes ownership of its own.
+        let mut spilled_pre_aggs_per_p = (0..num_partitions).map(|_| Vec::new()).collect_vec();
+        for l in &mut self.locals {
+            let l_spilled_per_p = core::mem::take(&mut l.spilled_pre_aggs_per_p);
+            for (p_spilled, l_spilled) in spilled_pre_aggs_per_p.iter_mut().zip(l_spilled_per_p) {
+                p_spilled.extend(l_spilled);
+            }
+        }
+
+        let output_per_partition: SparseInitVec<Vec<DataFrame>> =
             SparseInitVec::with_capacity(num_partitions);
         let locals = &self.locals;
-        let grouper_template = &self.grouper;
-        let reductions_per_input = &self.reductions_per_input;
-        let grouped_reductions_template = &self.grouped_reductions;
-        let grouped_reduction_cols = &self.grouped_reduction_cols;
+        let memory_budget = config()
+            .ooc_memory_budget_bytes()
+            .min(state.query_memory.budget_bytes().unwrap_or(u64::MAX));
+        let ctx = &PartitionContext {
+            grouper: &*self.grouper,
+            grouped_reductions: &self.grouped_reductions,
+            reductions_per_input: &self.reductions_per_input,
+            grouped_reduction_cols: &self.grouped_reduction_cols,
+            key_schema,
+            output_schema,
+            spill_ctx,
+            // A rough estimate: the hash, group index and keys in the hash
+            // table plus the reduction states.
+            est_bytes_per_group: 16 * (1 + key_schema.len() + self.grouped_reductions.len()),
+            // All partitions are aggregated concurrently, sharing the budget.
+            memory_budget: usize::try_from(memory_budget).unwrap_or(usize::MAX) / num_partitions,
+            force_repartition: config().force_group_by_spill(),
+        };
 
         executor::task_scope(|s| {
             // Wrap in outer Arc to move to each thread, performing the
@@ -312,7 +463,7 @@ impl GroupBySinkState {
             let arc_morsels_per_local = Arc::new(morsels_per_local);
             let arc_pre_aggs_per_local = Arc::new(pre_aggs_per_local);
             let mut join_handles = Vec::new();
-            for p in 0..num_partitions {
+            for (p, p_spilled) in spilled_pre_aggs_per_p.into_iter().enumerate() {
                 let arc_morsels_per_local = Arc::clone(&arc_morsels_per_local);
                 let arc_pre_aggs_per_local = Arc::clone(&arc_pre_aggs_per_local);
                 let drop_q_send = drop_q_send.clone();
@@ -329,122 +480,139 @@ impl GroupBySinkState {
                     for l in locals {
                         sketch.combine(&l.sketch_per_p[p]);
                     }
-
-                    // Allocate grouper and reductions.
                     let est_num_groups = sketch.estimate() * 5 / 4;
-                    let mut p_grouper = grouper_template.new_empty();
-                    let mut p_reductions = grouped_reductions_template
-                        .iter()
-                        .map(|gr| gr.new_empty())
-                        .collect_vec();
-                    p_grouper.reserve(est_num_groups);
-                    for r in &mut p_reductions {
-                        r.reserve(est_num_groups);
-                    }
 
-                    // Insert morsels.
-                    let mut skip_drop_attempt = false;
-                    let mut group_idxs = Vec::new();
-                    let mut in_cols = Vec::new();
-                    for (l, l_morsels) in locals.iter().zip(morsels_per_local) {
-                        // Try to help with dropping.
-                        if !skip_drop_attempt {
-                            drop(drop_q_recv.try_recv());
+                    let dfs = if ctx.should_repartition(est_num_groups, 0) {
+                        let mut inputs = Vec::new();
+                        for (l, l_morsels) in locals.iter().zip(&morsels_per_local) {
+                            for (i, morsel) in l_morsels.iter().enumerate() {
+                                let (input_idx, seq_id, keys, frame) = morsel;
+                                let start = l.morsel_idxs_offsets_per_p[i * num_partitions + p];
+                                let stop =
+                                    l.morsel_idxs_offsets_per_p[(i + 1) * num_partitions + p];
+                                inputs.push(PartitionInput::Morsel {
+                                    input_idx: *input_idx,
+                                    seq_id: *seq_id,
+                                    keys,
+                                    frame,
+                                    subset: &l.morsel_idxs_values_per_p[p][start..stop],
+                                });
+                            }
                         }
+                        for (l, l_pre_aggs) in locals.iter().zip(&pre_aggs_per_local) {
+                            for (i, pre_agg) in l_pre_aggs.iter().enumerate() {
+                                let start = l.pre_agg_idxs_offsets_per_p[i * num_partitions + p];
+                                let stop =
+                                    l.pre_agg_idxs_offsets_per_p[(i + 1) * num_partitions + p];
+                                inputs.push(PartitionInput::PreAgg {
+                                    pre_agg,
+                                    subset: Cow::Borrowed(
+                                        &l.pre_agg_idxs_values_per_p[p][start..stop],
+                                    ),
+                                });
+                            }
+                        }
+                        inputs.extend(p_spilled.into_iter().map(PartitionInput::Spilled));
 
-                        for (i, morsel) in l_morsels.iter().enumerate() {
-                            let (input_idx, seq_id, keys, sf) = morsel;
-                            let morsel_df = sf.get().await;
-                            unsafe {
-                                let p_morsel_idxs_start =
-                                    l.morsel_idxs_offsets_per_p[i * num_partitions + p];
-                                let p_morsel_idxs_stop =
-                                    l.morsel_idxs_offsets_per_p[(i + 1) * num_partitions + p];
-                                let p_morsel_idxs = &l.morsel_idxs_values_per_p[p]
-                                    [p_morsel_idxs_start..p_morsel_idxs_stop];
+                        let dfs = aggregate_repartitioned(inputs, ctx, p, est_num_groups).await?;
 
-                                group_idxs.clear();
-                                p_grouper.insert_keys_subset(
-                                    keys,
-                                    p_morsel_idxs,
-                                    Some(&mut group_idxs),
-                                );
-
-                                for red_idx in &reductions_per_input[*input_idx] {
-                                    let cols = &grouped_reduction_cols[*red_idx];
-                                    let reduction = &mut p_reductions[*red_idx];
-                                    for col in cols {
-                                        in_cols.push(morsel_df.column(col).unwrap());
-                                    }
-                                    reduction.resize(p_grouper.num_groups());
-                                    reduction.update_groups_subset(
-                                        &in_cols,
-                                        p_morsel_idxs,
-                                        &group_idxs,
+                        for l in morsels_per_local {
+                            if let Some(l) = Arc::into_inner(l) {
+                                drop(drop_q_send.try_send(ToDrop::A(l)));
+                            }
+                        }
+                        for l in pre_aggs_per_local {
+                            if let Some(l) = Arc::into_inner(l) {
+                                drop(drop_q_send.try_send(ToDrop::B(l)));
+                            }
+                        }
+                        dfs
+                    } else {
+                        let mut partition = GroupByPartition::new(ctx, est_num_groups);
+
+                        // Insert morsels.
+                        let mut skip_drop_attempt = false;
+                        for (l, l_morsels) in locals.iter().zip(morsels_per_local) {
+                            // Try to help with dropping.
+                            if !skip_drop_attempt {
+                                drop(drop_q_recv.try_recv());
+                            }
+
+                            for (i, morsel) in l_morsels.iter().enumerate() {
+                                let (input_idx, seq_id, keys, sf) = morsel;
+                                let morsel_df = sf.get().await?;
+                                unsafe {
+                                    let p_morsel_idxs_start =
+                                        l.morsel_idxs_offsets_per_p[i * num_partitions + p];
+                                    let p_morsel_idxs_stop =
+                                        l.morsel_idxs_offsets_per_p[(i + 1) * num_partitions + p];
+                                    let p_morsel_idxs = &l.morsel_idxs_values_per_p[p]
+                                        [p_morsel_idxs_start..p_morsel_idxs_stop];
+                                    partition.insert_morsel(
+                                        ctx,
+                                        *input_idx,
                                         *seq_id,
+                                        keys,
+                                        &morsel_df,
+                                        p_morsel_idxs,
                                     )?;
-                                    in_cols = reuse_vec(in_cols);
                                 }
                             }
-                        }
-                        in_cols = reuse_vec(in_cols);
-
-                        if let Some(l) = Arc::into_inner(l_morsels) {
-                            // If we're the last thread to process this set of morsels we're probably
-                            // falling behind the rest, since the drop can be quite expensive we skip
-                            // a drop attempt hoping someone else will pick up the slack.
-                            drop(drop_q_send.try_send(ToDrop::A(l)));
-                            skip_drop_attempt = true;
-                        } else {
-                            skip_drop_attempt = false;
-                        }
-                    }
 
-                    // Insert pre-aggregates.
-                    for (l, l_pre_aggs) in locals.iter().zip(pre_aggs_per_local) {
-                        // Try to help with dropping.
-                        if !skip_drop_attempt {
-                            drop(drop_q_recv.try_recv());
+                            if let Some(l) = Arc::into_inner(l_morsels) {
+                                // If we're the last thread to process this set of morsels we're probably
+                                // falling behind the rest, since the drop can be quite expensive we skip
+                                // a drop attempt hoping someone else will pick up the slack.
+                                drop(drop_q_send.try_send(ToDrop::A(l)));
+                                skip_drop_attempt = true;
+                            } else {
+                                skip_drop_attempt = false;
+                            }
                         }
 
-                        for (i, key_pre_aggs) in l_pre_aggs.iter().enumerate() {
-                            let PreAgg {
-                                keys,
-                                reduction_idxs: r_idxs,
-                                reductions: pre_aggs,
-                            } = key_pre_aggs;
-                            unsafe {
-                                let p_pre_agg_idxs_start =
-                                    l.pre_agg_idxs_offsets_per_p[i * num_partitions + p];
-                                let p_pre_agg_idxs_stop =
-                                    l.pre_agg_idxs_offsets_per_p[(i + 1) * num_partitions + p];
-                                let p_pre_agg_idxs = &l.pre_agg_idxs_values_per_p[p]
-                                    [p_pre_agg_idxs_start..p_pre_agg_idxs_stop];
+                        // Insert pre-aggregates.
+                        for (l, l_pre_aggs) in locals.iter().zip(pre_aggs_per_local) {
+                            // Try to help with dropping.
+                            if !skip_drop_attempt {
+                                drop(drop_q_recv.try_recv());
+                            }
 
-                                group_idxs.clear();
-                                p_grouper.insert_keys_subset(
-                                    keys,
-                                    p_pre_agg_idxs,
-                                    Some(&mut group_idxs),
-                                );
-                                for (pre_agg, r_idx) in pre_aggs.iter().zip(r_idxs.iter()) {
-                                    let r = &mut p_reductions[*r_idx];
-                                    r.resize(p_grouper.num_groups());
-                                    r.combine_subset(&**pre_agg, p_pre_agg_idxs, &group_idxs)?;
+                            for (i, pre_agg) in l_pre_aggs.iter().enumerate() {
+                                unsafe {
+                                    let p_pre_agg_idxs_start =
+                                        l.pre_agg_idxs_offsets_per_p[i * num_partitions + p];
+                                    let p_pre_agg_idxs_stop =
+                                        l.pre_agg_idxs_offsets_per_p[(i + 1) * num_partitions + p];
+                                    let p_pre_agg_idxs = &l.pre_agg_idxs_values_per_p[p]
+                                        [p_pre_agg_idxs_start..p_pre_agg_idxs_stop];
+                                    partition.insert_pre_agg(pre_agg, p_pre_agg_idxs)?;
                                 }
                             }
+
+                            if let Some(l) = Arc::into_inner(l_pre_aggs) {
+                                // If we're the last thread to process this set of morsels we're probably
+                                // falling behind the rest, since the drop can be quite expensive we skip
+                                // a drop attempt hoping someone else will pick up the slack.
+                                drop(drop_q_send.try_send(ToDrop::B(l)));
+                                skip_drop_attempt = true;
+                            } else {
+                                skip_drop_attempt = false;
+                            }
                         }
 
-                        if let Some(l) = Arc::into_inner(l_pre_aggs) {
-                            // If we're the last thread to process this set of morsels we're probably
-                            // falling behind the rest, since the drop can be quite expensive we skip
-                            // a drop attempt hoping someone else will pick up the slack.
-                            drop(drop_q_send.try_send(ToDrop::B(l)));
-                            skip_drop_attempt = true;
-                        } else {
-                            skip_drop_attempt = false;
+                        // Insert spilled pre-aggregates, all their rows belong to this partition.
+                        let mut all_idxs = Vec::new();
+                        for spilled in p_spilled {
+                            let pre_agg = spilled.load(ctx.grouped_reductions).await?;
+                            all_idxs.clear();
+                            all_idxs.extend(0..pre_agg.keys.len() as IdxSize);
+                            unsafe {
+                                partition.insert_pre_agg(&pre_agg, &all_idxs)?;
+                            }
                         }
-                    }
+
+                        vec![partition.into_df(key_schema, output_schema)?]
+                    };
 
                     // We're done, help others out by doing drops.
                     drop(drop_q_send); // So we don't deadlock trying to receive from ourselves.
@@ -452,16 +620,7 @@ impl GroupBySinkState {
                         drop(to_drop);
                     }
 
-                    output_per_partition
-                        .try_set(
-                            p,
-                            GroupByPartition {
-                                grouper: p_grouper,
-                                grouped_reductions: p_reductions,
-                            },
-                        )
-                        .ok()
-                        .unwrap();
+                    output_per_partition.try_set(p, dfs).ok().unwrap();
 
                     PolarsResult::Ok(())
                 }));
@@ -492,16 +651,357 @@ impl GroupBySinkState {
                 .for_each(drop);
         });
 
-        Ok(output_per_partition.try_assume_init().ok().unwrap())
+        Ok(output_per_partition
+            .try_assume_init()
+            .ok()
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .collect())
     }
 }
 
+/// Everything needed to aggregate the rows of a partition.
+struct PartitionContext<'a> {
+    grouper: &'a dyn Grouper,
+    grouped_reductions: &'a [Box<dyn GroupedReduction>],
+    reductions_per_input: &'a [Vec<usize>],
+    grouped_reduction_cols: &'a [Vec<PlSmallStr>],
+    key_schema: &'a Schema,
+    output_schema: &'a Schema,
+    // Where the rows of sub-partitions are stored until they're aggregated.
+    spill_ctx: &'a MostRecentSpillContext,
+    est_bytes_per_group: usize,
+    // The memory budget for the aggregation state of a single partition.
+    memory_budget: usize,
+    force_repartition: bool,
+}
+
+impl PartitionContext<'_> {
+    fn should_repartition(&self, est_num_groups: usize, depth: usize) -> bool {
+        // A single group can't be split any further.
+        if est_num_groups <= 1 || depth >= MAX_REPARTITION_DEPTH {
+            return false;
+        }
+        let est_bytes = est_num_groups.saturating_mul(self.est_bytes_per_group);
+        est_bytes > self.memory_budget || (self.force_repartition && depth == 0)
+    }
+
+    fn num_sub_partitions(&self, est_num_groups: usize) -> usize {
+        let est_bytes = est_num_groups.saturating_mul(self.est_bytes_per_group);
+        est_bytes
+            .div_ceil(self.memory_budget.max(1))
+            .clamp(2, MAX_SUB_PARTITIONS)
+    }
+}
+
+/// A source of rows for a partition that is aggregated in multiple passes.
+enum PartitionInput<'a> {
+    /// A subset of the rows of a cold morsel.
+    Morsel {
+        input_idx: usize,
+        seq_id: u64,
+        keys: &'a HashKeys,
+        frame: &'a SpillFrame,
+        subset: &'a [IdxSize],
+    },
+    /// The rows of a cold morsel split off into their own frame.
+    SplitMorsel {
+        input_idx: usize,
+        seq_id: u64,
+        keys: HashKeys,
+        frame: SpillFrame,
+    },
+    PreAgg {
+        pre_agg: &'a PreAgg,
+        subset: Cow<'a, [IdxSize]>,
+    },
+    Spilled(SpilledPreAgg),
+}
+
+impl<'a> PartitionInput<'a> {
+    /// Splits the rows of this input over the sub-partitions of partitioner,
+    /// pushing the rows of each non-empty sub-partition to its inputs. Rows
+    /// stored in frames are loaded once and stored in a new spillable frame
+    /// per sub-partition.
+    async fn split(
+        self,
+        ctx: &PartitionContext<'_>,
+        partitioner: &HashPartitioner,
+        sketches: &mut [CardinalitySketch],
+        inputs_per_sp: &mut [Vec<PartitionInput<'a>>],
+    ) -> PolarsResult<()> {
+        let mut idxs_per_sp = vec![Vec::new(); partitioner.num_partitions()];
+        let mut assign = |idx, opt_h| {
+            let sp = sub_partition(partitioner, opt_h);
+            sketches[sp].insert(opt_h.unwrap_or(0));
+            idxs_per_sp[sp].push(idx);
+        };
+        match self {
+            Self::Morsel {
+                input_idx,
+                seq_id,
+                keys,
+                frame,
+                subset,
+            } => {
+                // SAFETY: the subsets of the partition inputs are in-bounds.
+                unsafe { keys.for_each_hash_subset(subset, &mut assign) };
+                let df = frame.get().await?;
+                split_morsel(
+                    ctx,
+                    input_idx,
+                    seq_id,
+                    keys,
+                    &df,
+                    idxs_per_sp,
+                    inputs_per_sp,
+                )
+                .await;
+            },
+            Self::SplitMorsel {
+                input_idx,
+                seq_id,
+                keys,
+                frame,
+            } => {
+                keys.for_each_hash(&mut assign);
+                let df = frame.into_df().await?;
+                split_morsel(
+                    ctx,
+                    input_idx,
+                    seq_id,
+                    &keys,
+                    &df,
+                    idxs_per_sp,
+                    inputs_per_sp,
+                )
+                .await;
+            },
+            Self::PreAgg { pre_agg, subset } => {
+                // SAFETY: the subsets of the partition inputs are in-bounds.
+                unsafe { pre_agg.keys.for_each_hash_subset(&subset, &mut assign) };
+                for (sp, idxs) in idxs_per_sp.into_iter().enumerate() {
+                    if !idxs.is_empty() {
+                        inputs_per_sp[sp].push(Self::PreAgg {
+                            pre_agg,
+                            subset: Cow::Owned(idxs),
+                        });
+                    }
+                }
+            },
+            Self::Spilled(spilled) => {
+                // Only the keys are needed to split, the states stay encoded.
+                let df = spilled.frame.into_df().await?;
+                let key_cols = &df.columns()[..spilled.keys_layout.num_columns()];
+                HashKeys::from_columns(&spilled.keys_layout, key_cols)?.for_each_hash(&mut assign);
+                for (sp, idxs) in idxs_per_sp.into_iter().enumerate() {
+                    if idxs.is_empty() {
+                        continue;
+                    }
+                    // SAFETY: the idxs were generated from the rows of df.
+                    let sp_df = unsafe { df.take_slice_unchecked_impl(&idxs, false) };
+                    inputs_per_sp[sp].push(Self::Spilled(SpilledPreAgg {
+                        keys_layout: spilled.keys_layout.clone(),
+                        reduction_idxs: spilled.reduction_idxs.clone(),
+                        frame: SpillFrame::new(sp_df, ctx.spill_ctx).await,
+                    }));
+                }
+            },
+        }
+        Ok(())
+    }
+
+    /// Aggregates the rows of this input into partition.
+    async fn insert_into(
+        self,
+        ctx: &PartitionContext<'_>,
+        partition: &mut GroupByPartition,
+    ) -> PolarsResult<()> {
+        // SAFETY: the subsets of the partition inputs are in-bounds, the rows
+        // of split inputs all belong to the partition.
+        unsafe {
+            match self {
+                Self::Morsel {
+                    input_idx,
+                    seq_id,
+                    keys,
+                    frame,
+                    subset,
+                } => {
+                    let df = frame.get().await?;
+                    partition.insert_morsel(ctx, input_idx, seq_id, keys, &df, subset)
+                },
+                Self::SplitMorsel {
+                    input_idx,
+                    seq_id,
+                    keys,
+                    frame,
+                } => {
+                    let df = frame.into_df().await?;
+                    let all_idxs = (0..keys.len() as IdxSize).collect_vec();
+                    partition.insert_morsel(ctx, input_idx, seq_id, &keys, &df, &all_idxs)
+                },
+                Self::PreAgg { pre_agg, subset } => partition.insert_pre_agg(pre_agg, &subset),
+                Self::Spilled(spilled) => {
+                    let pre_agg = spilled.load(ctx.grouped_reductions).await?;
+                    let all_idxs = (0..pre_agg.keys.len() as IdxSize).collect_vec();
+                    partition.insert_pre_agg(&pre_agg, &all_idxs)
+                },
+            }
+        }
+    }
+}
+
+/// Stores the rows of a morsel of each sub-partition in their own frame.
+async fn split_morsel(
+    ctx: &PartitionContext<'_>,
+    input_idx: usize,
+    seq_id: u64,
+    keys: &HashKeys,
+    df: &DataFrame,
+    idxs_per_sp: Vec<Vec<IdxSize>>,
+    inputs_per_sp: &mut [Vec<PartitionInput<'_>>],
+) {
+    for (sp, idxs) in idxs_per_sp.into_iter().enumerate() {
+        if idxs.is_empty() {
+            continue;
+        }
+        // SAFETY: the idxs were generated from the rows of keys and df.
+        let (sp_keys, sp_df) = unsafe {
+            (
+                keys.gather_unchecked(&idxs),
+                df.take_slice_unchecked_impl(&idxs, false),
+            )
+        };
+        inputs_per_sp[sp].push(PartitionInput::SplitMorsel {
+            input_idx,
+            seq_id,
+            keys: sp_keys,
+            frame: SpillFrame::new(sp_df, ctx.spill_ctx).await,
+        });
+    }
+}
+
+fn sub_partition(partitioner: &HashPartitioner, opt_h: Option<u64>) -> usize {
+    opt_h.map_or(partitioner.null_partition(), |h| {
+        partitioner.hash_to_partition(h)
+    })
+}
+
+/// Aggregates a partition whose estimated aggregation state exceeds the
+/// memory budget. The partition is recursively split into sub-partitions,
+/// each using a differently seeded partitioner, until every sub-partition is
+/// estimated to fit. Every split reads each input once, storing the rows of
+/// each sub-partition in a spillable frame of its own. The sub-partitions are
+/// then aggregated one at a time.
+async fn aggregate_repartitioned(
+    inputs: Vec<PartitionInput<'_>>,
+    ctx: &PartitionContext<'_>,
+    p: usize,
+    est_num_groups: usize,
+) -> PolarsResult<Vec<DataFrame>> {
+    let mut out = Vec::new();
+    let mut todo = vec![(inputs, 0, est_num_groups)];
+    while let Some((inputs, depth, est_num_groups)) = todo.pop() {
+        if ctx.should_repartition(est_num_groups, depth) {
+            let num_sub_partitions = ctx.num_sub_partitions(est_num_groups);
+            if polars_core::config::verbose() {
+                eprintln!(
+                    "[group-by]: repartitioning partition {p} (depth {depth}, ~{est_num_groups} groups) into {num_sub_partitions} sub-partitions"
+                );
+            }
+            let partitioner = HashPartitioner::new(num_sub_partitions, depth as u64 + 1);
+            let mut sketches = vec![CardinalitySketch::new(); num_sub_partitions];
+            let mut inputs_per_sp = (0..num_sub_partitions).map(|_| Vec::new()).collect_vec();
+            for input in inputs {
+                input
+                    .split(ctx, &partitioner, &mut sketches, &mut inputs_per_sp)
+                    .await?;
+            }
+            for (sp_inputs, sketch) in inputs_per_sp.into_iter().zip(&sketches) {
+                todo.push((sp_inputs, depth + 1, sketch.estimate() * 5 / 4));
+            }
+            continue;
+        }
+
+        let mut partition = GroupByPartition::new(ctx, est_num_groups);
+        for input in inputs {
+            input.insert_into(ctx, &mut partition).await?;
+        }
+        out.push(partition.into_df(ctx.key_schema, ctx.output_schema)?);
+    }
+    Ok(out)
+}
+
 struct GroupByPartition {
     grouper: Box<dyn Grouper>,
     grouped_reductions: Vec<Box<dyn GroupedReduction>>,
+    group_idxs: Vec<IdxSize>,
 }
 
 impl GroupByPartition {
+    fn new(ctx: &PartitionContext<'_>, est_num_groups: usize) -> Self {
+        let mut grouper = ctx.grouper.new_empty();
+        let mut grouped_reductions = ctx
+            .grouped_reductions
+            .iter()
+            .map(|gr| gr.new_empty())
+            .collect_vec();
+        grouper.reserve(est_num_groups);
+        for r in &mut grouped_reductions {
+            r.reserve(est_num_groups);
+        }
+        Self {
+            grouper,
+            grouped_reductions,
+            group_idxs: Vec::new(),
+        }
+    }
+
+    /// # Safety
+    /// The subset must be in-bounds for the keys and the DataFrame.
+    unsafe fn insert_morsel(
+        &mut self,
+        ctx: &PartitionContext<'_>,
+        input_idx: usize,
+        seq_id: u64,
+        keys: &HashKeys,
+        df: &DataFrame,
+        subset: &[IdxSize],
+    ) -> PolarsResult<()> {
+        self.group_idxs.clear();
+        self.grouper
+            .insert_keys_subset(keys, subset, Some(&mut self.group_idxs));
+
+        let mut in_cols = Vec::new();
+        for red_idx in &ctx.reductions_per_input[input_idx] {
+            let cols = &ctx.grouped_reduction_cols[*red_idx];
+            let reduction = &mut self.grouped_reductions[*red_idx];
+            for col in cols {
+                in_cols.push(df.column(col).unwrap());
+            }
+            reduction.resize(self.grouper.num_groups());
+            reduction.update_groups_subset(&in_cols, subset, &self.group_idxs, seq_id)?;
+            in_cols.clear();
+        }
+        Ok(())
+    }
+
+    /// # Safety
+    /// The subset must be in-bounds for the pre-aggregate.
+    unsafe fn insert_pre_agg(&mut self, pre_agg: &PreAgg, subset: &[IdxSize]) -> PolarsResult<()> {
+        self.group_idxs.clear();
+        self.grouper
+            .insert_keys_subset(&pre_agg.keys, subset, Some(&mut self.group_idxs));
+        for (r_pre_agg, r_idx) in pre_agg.reductions.iter().zip(pre_agg.reduction_idxs.iter()) {
+            let r = &mut self.grouped_reductions[*r_idx];
+            r.resize(self.grouper.num_groups());
+            r.combine_subset(&**r_pre_agg, subset, &self.group_idxs)?;
+        }
+        Ok(())
+    }
+
     fn into_df(self, key_schema: &Schema, output_schema: &Schema) -> PolarsResult<DataFrame> {
         let mut out = self.grouper.get_keys_in_group_order(key_schema);
         let out_names = output_schema.iter_names().skip(out.width());
@@ -623,14 +1123,12 @@ impl ComputeNode for GroupByNode {
                 else {
                     unreachable!()
                 };
-                let partitions = sink.combine_locals()?;
-                let dfs = RAYON.install(|| {
-                    partitions
-                        .into_par_iter()
-                        .map(|p| p.into_df(&self.key_schema, &self.output_schema))
-                        .collect::<Result<Vec<_>, _>>()
-                })?;
-
+                let dfs = sink.combine_locals(
+                    &self.key_schema,
+                    &self.output_schema,
+                    state,
+                    &self.spill_ctx,
+                )?;
                 let df = accumulate_dataframes_vertical_unchecked(dfs);
                 let source = InMemorySourceNode::new(Arc::new(df), MorselSeq::new(0));
                 self.state = GroupByState::Source(source);
@@ -664,6 +1162,10 @@ impl ComputeNode for GroupByNode {
         Ok(())
     }
 
+    fn spill_contexts(&self) -> Vec<Arc<dyn SpillContext>> {
+        vec![self.spill_ctx.clone()]
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use polars_core::schema::Schema;
+use polars_ooc::SpillContext;
 use polars_plan::plans::DataFrameUdf;
 
 use super::compute_node_prelude::*;
@@ -76,6 +77,13 @@ impl ComputeNode for InMemoryMapNode {
         matches!(self, Self::Sink { .. })
     }
 
+    fn spill_contexts(&self) -> Vec<Arc<dyn SpillContext>> {
+        match self {
+            Self::Sink { sink_node, .. } => sink_node.spill_contexts(),
+            Self::Source(_) | Self::Done => Vec::new(),
+        }
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use parking_lot::Mutex;
 use polars_core::schema::Schema;
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
-use polars_ooc::{LeastRecentSpillContext, SpillFrame};
+use polars_ooc::{LeastRecentSpillContext, SpillContext, SpillFrame};
 
 use super::compute_node_prelude::*;
 use crate::utils::in_memory_linearize::linearize;
@@ -51,6 +51,10 @@ impl ComputeNode for InMemorySinkNode {
         true
     }
 
+    fn spill_contexts(&self) -> Vec<Arc<dyn SpillContext>> {
+        vec![self.spill_ctx.clone()]
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
@@ -85,10 +89,10 @@ impl ComputeNode for InMemorySinkNode {
         if spillframes.is_empty() {
             Ok(Some(DataFrame::empty_with_schema(&self.schema)))
         } else {
-            let dfs: Vec<_> = spillframes
+            let dfs = spillframes
                 .into_iter()
                 .map(|sf| sf.into_df_blocking())
-                .collect();
+                .collect::<PolarsResult<Vec<_>>>()?;
             Ok(Some(accumulate_dataframes_vertical_unchecked(dfs)))
         }
     }
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use polars_async::executor;
+use polars_error::PolarsResult;
+use polars_io::avro::AvroBlockEncoder;
+use tokio::io::AsyncWriteExt as _;
+
+use crate::nodes::io_sinks::components::sink_morsel::SinkMorselPermit;
+use crate::nodes::io_sinks::writers::interface::FileOpenTaskHandle;
+
+pub struct IOWriter {
+    pub file: FileOpenTaskHandle,
+    pub encoded_block_rx: tokio::sync::mpsc::Receiver<(
+        executor::AbortOnDropHandle<PolarsResult<Vec<u8>>>,
+        SinkMorselPermit,
+    )>,
+    pub encoder: Arc<AvroBlockEncoder>,
+}
+
+impl IOWriter {
+    pub async fn run(self) -> PolarsResult<()> {
+        let IOWriter {
+            file,
+            mut encoded_block_rx,
+            encoder,
+        } = self;
+
+        let (writable, sync_on_close) = file.await?;
+        let mut writer = writable.try_into_async_writeable()?;
+
+        // The header is written even if there are no blocks, so that an empty input still
+        // produces a valid file.
+        let mut header = vec![];
+        encoder.write_header(&mut header)?;
+        writer.write_all(&header).await?;
+
+        while let Some((handle, permit)) = encoded_block_rx.recv().await {
+            let encoded_blocks = handle.await?;
+            writer.write_all(&encoded_blocks).await?;
+            drop(permit);
+        }
+
+        writer.close(sync_on_close).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+use std::sync::Arc;
+
+use polars_async::executor::{self, TaskPriority};
+use polars_async::primitives::connector;
+use polars_core::prelude::CompatLevel;
+use polars_core::runtime::ASYNC;
+use polars_error::PolarsResult;
+use polars_io::avro::{AvroBlockEncoder, AvroWriterOptions};
+use polars_utils::IdxSize;
+use polars_utils::index::NonZeroIdxSize;
+
+use crate::morsel::get_ideal_morsel_size;
+use crate::nodes::io_sinks::components::par_utils::rechunk_par;
+use crate::nodes::io_sinks::components::sink_morsel::{SinkMorsel, SinkMorselPermit};
+use crate::nodes::io_sinks::components::size::{
+    NonZeroRowCountAndSize, RowCountAndSize, TakeableRowsProvider,
+};
+use crate::nodes::io_sinks::writers::interface::{
+    FileOpenTaskHandle, FileWriterStarter, ideal_sink_morsel_size_env,
+};
+use crate::utils::tokio_handle_ext;
+
+mod io_writer;
+
+pub struct AvroWriterStarter {
+    pub options: Arc<AvroWriterOptions>,
+    pub encoder: Arc<AvroBlockEncoder>,
+}
+
+impl FileWriterStarter for AvroWriterStarter {
+    fn writer_name(&self) -> &str {
+        "avro"
+    }
+
+    fn takeable_rows_provider(&self) -> TakeableRowsProvider {
+        // Every morsel is written as a single block, so a configured block size must be exact.
+        if let Some(block_size) = self.options.block_size
+            && block_size > 0
+        {
+            return TakeableRowsProvider {
+                max_size: NonZeroRowCountAndSize::new(RowCountAndSize {
+                    num_rows: IdxSize::try_from(block_size).unwrap_or(IdxSize::MAX),
+                    num_bytes: u64::MAX,
+                })
+                .unwrap(),
+                byte_size_min_rows: NonZeroIdxSize::new(1).unwrap(),
+                allow_non_max_size: false,
+            };
+        }
+
+        let (num_rows, num_bytes) = ideal_sink_morsel_size_env();
+
+        TakeableRowsProvider {
+            max_size: NonZeroRowCountAndSize::new(RowCountAndSize {
+                num_rows: num_rows
+                    .unwrap_or(get_ideal_morsel_size().try_into().unwrap_or(IdxSize::MAX)),
+                num_bytes: num_bytes.unwrap_or(8 * 1024 * 1024),
+            })
+            .unwrap(),
+            byte_size_min_rows: NonZeroIdxSize::new(256).unwrap(),
+            allow_non_max_size: true,
+        }
+    }
+
+    fn start_file_writer(
+        &self,
+        mut morsel_rx: connector::Receiver<SinkMorsel>,
+        file: FileOpenTaskHandle,
+        num_pipelines: std::num::NonZeroUsize,
+    ) -> PolarsResult<executor::JoinHandle<PolarsResult<()>>> {
+        let (encoded_block_tx, encoded_block_rx) = tokio::sync::mpsc::channel::<(
+            executor::AbortOnDropHandle<PolarsResult<Vec<u8>>>,
+            SinkMorselPermit,
+        )>(num_pipelines.get());
+
+        // Every file gets its own sync marker.
+        let encoder = Arc::new(self.encoder.with_new_sync_marker());
+
+        let io_handle = tokio_handle_ext::AbortOnDropHandle(
+            ASYNC.spawn(
+                io_writer::IOWriter {
+                    file,
+                    encoded_block_rx,
+                    encoder: Arc::clone(&encoder),
+                }
+                .run(),
+            ),
+        );
+
+        let encode_handle = executor::spawn(TaskPriority::High, async move {
+            while let Ok(morsel) = morsel_rx.recv().await {
+                let (df, morsel_permit) = morsel.into_inner();
+                let encoder = Arc::clone(&encoder);
+
+                let handle = executor::AbortOnDropHandle::new(executor::spawn(
+                    TaskPriority::High,
+                    async move {
+                        let mut df = df;
+                        rechunk_par(unsafe { df.columns_mut_retain_schema() }).await;
+
+                        let mut out = vec![];
+                        for chunk in df.iter_chunks(CompatLevel::oldest(), false) {
+                            encoder.encode_block(&chunk, &mut out)?;
+                        }
+                        PolarsResult::Ok(out)
+                    },
+                ));
+
+                if encoded_block_tx
+                    .send((handle, morsel_permit))
+                    .await
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        });
+
+        Ok(executor::spawn(TaskPriority::Low, async move {
+            io_handle.await.unwrap()?;
+            encode_handle.await;
+            Ok(())
+        }))
+    }
+}
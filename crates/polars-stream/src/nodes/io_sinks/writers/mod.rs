@@ -7,6 +7,8 @@ use polars_utils::IdxSize;
 
 use crate::nodes::io_sinks::writers::interface::FileWriterStarter;
 
+#[cfg(feature = "avro")]
+mod avro;
 #[cfg(feature = "csv")]
 mod csv;
 pub mod interface;
@@ -82,11 +84,21 @@ pub fn create_file_writer_starter(
                 initialized_state: Default::default(),
             },
         ) as _,
+        #[cfg(feature = "avro")]
+        FileWriteFormat::Avro(options) => {
+            use polars_io::avro::AvroBlockEncoder;
+
+            Arc::new(crate::nodes::io_sinks::writers::avro::AvroWriterStarter {
+                options: Arc::clone(options),
+                encoder: Arc::new(AvroBlockEncoder::try_new(file_schema.as_ref(), options)?),
+            }) as _
+        },
         #[cfg(not(any(
             feature = "parquet",
             feature = "ipc",
             feature = "csv",
-            feature = "json"
+            feature = "json",
+            feature = "avro"
         )))]
         _ => panic!("no enum variants on FileType (hint: missing feature flags?)"),
     })
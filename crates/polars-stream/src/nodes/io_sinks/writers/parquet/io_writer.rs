@@ -6,6 +6,7 @@ use polars_buffer::Buffer;
 use polars_error::PolarsResult;
 use polars_io::parquet::write::BatchedWriter;
 use polars_io::prelude::KeyValueMetadata;
+use polars_parquet::parquet::encryption::FileEncryptionProperties;
 use polars_parquet::write::{Encoding, FileWriter, SchemaDescriptor, WriteOptions};
 
 use crate::nodes::io_sinks::writers::interface::FileOpenTaskHandle;
@@ -20,6 +21,7 @@ pub struct IOWriter {
     pub write_options: WriteOptions,
     pub encodings: Buffer<Vec<Encoding>>,
     pub key_value_metadata: Option<KeyValueMetadata>,
+    pub encryption: Option<FileEncryptionProperties>,
     pub num_leaf_columns: usize,
 }
 
@@ -33,19 +35,25 @@ impl IOWriter {
             write_options,
             encodings,
             key_value_metadata,
+            encryption,
             num_leaf_columns,
         } = self;
 
         let (mut file, sync_on_close) = file.await?;
         let mut buffered_file = file.as_buffered();
 
+        let mut file_writer = FileWriter::new_with_parquet_schema(
+            &mut *buffered_file,
+            Arc::unwrap_or_clone(arrow_schema),
+            Arc::unwrap_or_clone(schema_descriptor),
+            write_options,
+        );
+        if let Some(encryption) = encryption {
+            file_writer = file_writer.with_encryption(encryption)?;
+        }
+
         let mut parquet_writer = BatchedWriter::new(
-            std::sync::Mutex::new(FileWriter::new_with_parquet_schema(
-                &mut *buffered_file,
-                Arc::unwrap_or_clone(arrow_schema),
-                Arc::unwrap_or_clone(schema_descriptor),
-                write_options,
-            )),
+            std::sync::Mutex::new(file_writer),
             encodings,
             write_options,
             false,
@@ -56,10 +64,11 @@ impl IOWriter {
             let EncodedRowGroup {
                 num_rows,
                 data,
+                bloom_filters,
                 morsel_permit,
             } = handle.await?;
             assert_eq!(data.len(), num_leaf_columns);
-            parquet_writer.write_row_group(num_rows as u64, &data)?;
+            parquet_writer.write_row_group(num_rows as u64, &data, bloom_filters)?;
             drop(data);
             drop(morsel_permit);
         }
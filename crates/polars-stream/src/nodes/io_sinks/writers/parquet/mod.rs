@@ -6,9 +6,11 @@ use polars_async::primitives::connector;
 use polars_buffer::Buffer;
 use polars_core::runtime::ASYNC;
 use polars_error::PolarsResult;
-use polars_io::prelude::{ParquetWriteOptions, get_encodings};
+use polars_io::prelude::{ParquetWriteOptions, get_bloom_filter_options, get_encodings};
+use polars_parquet::parquet::encryption::FileEncryptionProperties;
 use polars_parquet::write::{
-    CompressedPage, Encoding, SchemaDescriptor, Version, WriteOptions, to_parquet_schema,
+    BloomFilterOptions, CompressedPage, Encoding, SchemaDescriptor, Version, WriteOptions,
+    to_parquet_schema,
 };
 use polars_utils::IdxSize;
 use polars_utils::index::NonZeroIdxSize;
@@ -36,11 +38,16 @@ pub struct ParquetWriterStarter {
 pub struct InitializedState {
     encodings: Buffer<Vec<Encoding>>,
     schema_descriptor: Arc<SchemaDescriptor>,
+    bloom_filters: Arc<[Option<BloomFilterOptions>]>,
+    /// The keys are retrieved once for all files.
+    encryption: Option<FileEncryptionProperties>,
 }
 
 struct EncodedRowGroup {
     num_rows: usize,
     data: Vec<Vec<CompressedPage>>,
+    /// Bloom filter bitset per leaf column, empty if no bloom filters are written.
+    bloom_filters: Vec<Option<Vec<u8>>>,
     morsel_permit: SinkMorselPermit,
 }
 
@@ -84,16 +91,29 @@ impl FileWriterStarter for ParquetWriterStarter {
         let InitializedState {
             encodings,
             schema_descriptor,
+            bloom_filters,
+            encryption,
         } = {
             let mut initialized_state = self.initialized_state.lock().unwrap();
 
             if initialized_state.is_none() {
                 let schema_descriptor = Arc::new(to_parquet_schema(&self.arrow_schema)?);
                 let encodings = get_encodings(&self.arrow_schema);
+                let bloom_filters: Arc<[_]> =
+                    get_bloom_filter_options(&self.arrow_schema, &self.options.bloom_filters)?
+                        .into();
+                let encryption = self
+                    .options
+                    .encryption
+                    .as_ref()
+                    .map(|e| e.file_encryption_properties(&self.arrow_schema))
+                    .transpose()?;
 
                 *initialized_state = Some(InitializedState {
                     encodings,
                     schema_descriptor,
+                    bloom_filters,
+                    encryption,
                 })
             };
 
@@ -125,6 +145,7 @@ impl FileWriterStarter for ParquetWriterStarter {
                     write_options,
                     encodings: Buffer::clone(&encodings),
                     key_value_metadata,
+                    encryption,
                     num_leaf_columns,
                 }
                 .run(),
@@ -141,6 +162,7 @@ impl FileWriterStarter for ParquetWriterStarter {
                 schema_descriptor,
                 write_options,
                 encodings,
+                bloom_filters,
                 num_leaf_columns,
             }
             .run(),
@@ -10,7 +10,8 @@ use polars_error::PolarsResult;
 use polars_parquet::parquet::error::ParquetResult;
 use polars_parquet::read::ParquetError;
 use polars_parquet::write::{
-    CompressedPage, Compressor, Encoding, SchemaDescriptor, WriteOptions, array_to_columns,
+    BloomFilterOptions, CompressedPage, Compressor, Encoding, SchemaDescriptor, WriteOptions,
+    array_to_bloom_filters, array_to_columns,
 };
 use polars_utils::UnitVec;
 
@@ -26,6 +27,7 @@ pub struct RowGroupEncoder {
     pub schema_descriptor: Arc<SchemaDescriptor>,
     pub write_options: WriteOptions,
     pub encodings: Buffer<Vec<Encoding>>,
+    pub bloom_filters: Arc<[Option<BloomFilterOptions>]>,
     pub num_leaf_columns: usize,
 }
 
@@ -38,13 +40,17 @@ impl RowGroupEncoder {
             schema_descriptor,
             write_options,
             encodings,
+            bloom_filters,
             num_leaf_columns,
         } = self;
 
+        let write_bloom_filters = bloom_filters.iter().any(Option::is_some);
+
         while let Ok(morsel) = morsel_rx.recv().await {
             let arrow_schema = Arc::clone(&arrow_schema);
             let schema_descriptor = Arc::clone(&schema_descriptor);
             let encodings = Buffer::clone(&encodings);
+            let bloom_filters = Arc::clone(&bloom_filters);
 
             let row_group_encode_handle =
                 executor::AbortOnDropHandle::new(executor::spawn(TaskPriority::High, async move {
@@ -52,6 +58,11 @@ impl RowGroupEncoder {
                     let num_rows = df.height();
 
                     let mut data: Vec<Vec<CompressedPage>> = Vec::with_capacity(num_leaf_columns);
+                    let mut bloom_filter_bitsets: Vec<Option<Vec<u8>>> = if write_bloom_filters {
+                        Vec::with_capacity(num_leaf_columns)
+                    } else {
+                        Vec::new()
+                    };
 
                     for fut in parallelize_first_to_local(
                         TaskPriority::High,
@@ -59,6 +70,7 @@ impl RowGroupEncoder {
                             let arrow_schema = Arc::clone(&arrow_schema);
                             let schema_descriptor = Arc::clone(&schema_descriptor);
                             let encodings = Buffer::clone(&encodings);
+                            let bloom_filters = Arc::clone(&bloom_filters);
 
                             async move {
                                 let parquet_type = &schema_descriptor.fields()[i];
@@ -70,6 +82,17 @@ impl RowGroupEncoder {
                                         true,
                                     )?;
 
+                                let bloom_filter_bitsets: UnitVec<Option<Vec<u8>>> =
+                                    match &bloom_filters[i] {
+                                        Some(options) => {
+                                            array_to_bloom_filters(array.as_ref(), options).into()
+                                        },
+                                        None if write_bloom_filters => {
+                                            std::iter::repeat_n(None, encodings.len()).collect()
+                                        },
+                                        None => UnitVec::new(),
+                                    };
+
                                 let mut data: UnitVec<Vec<CompressedPage>> =
                                     UnitVec::with_capacity(num_leaf_columns);
 
@@ -96,16 +119,19 @@ impl RowGroupEncoder {
                                     data.push(compressed_pages)
                                 }
 
-                                PolarsResult::Ok(data)
+                                PolarsResult::Ok((data, bloom_filter_bitsets))
                             }
                         }),
                     ) {
-                        data.extend(fut.await?);
+                        let (column_data, column_bloom_filter_bitsets) = fut.await?;
+                        data.extend(column_data);
+                        bloom_filter_bitsets.extend(column_bloom_filter_bitsets);
                     }
 
                     Ok(EncodedRowGroup {
                         num_rows,
                         data,
+                        bloom_filters: bloom_filter_bitsets,
                         morsel_permit,
                     })
                 }));
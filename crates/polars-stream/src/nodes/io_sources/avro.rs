@@ -0,0 +1,385 @@
+use std::ops::Range;
+use std::sync::{Arc, OnceLock};
+
+use async_trait::async_trait;
+use polars_async::executor::{self, JoinHandle, TaskPriority};
+use polars_buffer::Buffer;
+use polars_core::config;
+use polars_core::runtime::ASYNC;
+use polars_core::schema::SchemaRef;
+use polars_error::{PolarsResult, polars_ensure, polars_err};
+use polars_io::avro::{AvroBlock, AvroFileMetadata, MAX_BLOCK_HEADER_SIZE, read_block_header};
+use polars_io::cloud::CloudOptions;
+use polars_io::cloud::concurrency_config::FetchConfig;
+use polars_io::metrics::IOMetrics;
+use polars_io::utils::byte_source::{ByteSource, DynByteSource, DynByteSourceBuilder};
+use polars_io::utils::slice::SplitSlicePosition;
+use polars_plan::dsl::ScanSource;
+use polars_utils::IdxSize;
+use polars_utils::slice_enum::Slice;
+
+use crate::metrics::OptIOMetrics;
+use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
+use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
+use crate::nodes::io_sources::multi_scan::reader_interface::output::{
+    FileReaderOutputRecv, FileReaderOutputSend,
+};
+use crate::nodes::io_sources::multi_scan::reader_interface::{
+    BeginReadArgs, FileReader, FileReaderCallbacks, Projection, calc_row_position_after_slice,
+};
+
+/// The minimum number of bytes fetched at once while reading blocks.
+const FETCH_SIZE: usize = 8 * 1024 * 1024;
+
+#[derive(Default)]
+pub struct AvroReaderBuilder {
+    pub io_metrics: OnceLock<Arc<IOMetrics>>,
+}
+
+impl std::fmt::Debug for AvroReaderBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AvroReaderBuilder").finish()
+    }
+}
+
+impl FileReaderBuilder for AvroReaderBuilder {
+    fn reader_name(&self) -> &str {
+        "avro"
+    }
+
+    fn reader_capabilities(&self) -> ReaderCapabilities {
+        ReaderCapabilities::PRE_SLICE
+    }
+
+    fn set_io_metrics(&self, io_metrics: Arc<IOMetrics>) {
+        self.io_metrics.set(io_metrics).ok().unwrap()
+    }
+
+    fn build_file_reader(
+        &self,
+        source: ScanSource,
+        cloud_options: Option<Arc<CloudOptions>>,
+        _scan_source_idx: usize,
+    ) -> Box<dyn FileReader> {
+        let byte_source_builder = if source.is_cloud_url() || polars_config::config().force_async()
+        {
+            DynByteSourceBuilder::ObjectStore(FetchConfig::streaming())
+        } else {
+            DynByteSourceBuilder::Mmap
+        };
+
+        Box::new(AvroFileReader {
+            scan_source: source,
+            cloud_options,
+            byte_source_builder,
+            io_metrics: OptIOMetrics(self.io_metrics.get().cloned()),
+            verbose: config::verbose(),
+            init_data: None,
+        }) as Box<dyn FileReader>
+    }
+}
+
+struct AvroFileReader {
+    scan_source: ScanSource,
+    cloud_options: Option<Arc<CloudOptions>>,
+    byte_source_builder: DynByteSourceBuilder,
+    io_metrics: OptIOMetrics,
+    verbose: bool,
+    init_data: Option<InitializedState>,
+}
+
+#[derive(Clone)]
+struct InitializedState {
+    byte_source: Arc<DynByteSource>,
+    file_size: usize,
+    metadata: Arc<AvroFileMetadata>,
+}
+
+/// Walks the blocks of a file in order. The block headers are spread throughout
+/// the file, so instead of fetching all of it, the bytes are fetched in windows
+/// of at least [`FETCH_SIZE`] bytes while reading blocks, and only the header
+/// is fetched for blocks that are skipped.
+struct BlockFetcher {
+    byte_source: Arc<DynByteSource>,
+    file_size: usize,
+    window: Buffer<u8>,
+    window_start: usize,
+    pos: usize,
+}
+
+impl BlockFetcher {
+    fn new(byte_source: Arc<DynByteSource>, file_size: usize, data_offset: usize) -> Self {
+        Self {
+            byte_source,
+            file_size,
+            window: Buffer::new(),
+            window_start: 0,
+            pos: data_offset,
+        }
+    }
+
+    /// Make sure `range` is fetched. If it isn't and `prefetch` is set, at
+    /// least [`FETCH_SIZE`] bytes are fetched starting from `range.start`.
+    async fn fetch(&mut self, range: Range<usize>, prefetch: bool) -> PolarsResult<()> {
+        let window_end = self.window_start + self.window.len();
+        if self.window_start <= range.start && range.end <= window_end {
+            return Ok(());
+        }
+
+        let start = range.start;
+        let end = if prefetch {
+            range.end.max(start + FETCH_SIZE).min(self.file_size)
+        } else {
+            range.end
+        };
+        let byte_source = self.byte_source.clone();
+        self.window = ASYNC
+            .spawn(async move { byte_source.get_range(start..end).await })
+            .await
+            .unwrap()?;
+        self.window_start = start;
+        Ok(())
+    }
+
+    /// Read the header of the next block, returns `None` at the end of the file.
+    async fn next_block(&mut self, prefetch: bool) -> PolarsResult<Option<AvroBlock>> {
+        if self.pos >= self.file_size {
+            return Ok(None);
+        }
+
+        let header_end = (self.pos + MAX_BLOCK_HEADER_SIZE).min(self.file_size);
+        self.fetch(self.pos..header_end, prefetch).await?;
+        let block = read_block_header(&self.window[self.pos - self.window_start..], self.pos)?;
+        polars_ensure!(
+            block.byte_range.end <= self.file_size,
+            ComputeError: "corrupt avro file: block out of bounds"
+        );
+        self.pos = block.byte_range.end;
+        Ok(Some(block))
+    }
+
+    async fn block_bytes(&mut self, block: &AvroBlock) -> PolarsResult<Buffer<u8>> {
+        self.fetch(block.byte_range.clone(), true).await?;
+        let start = block.byte_range.start - self.window_start;
+        Ok(self
+            .window
+            .clone()
+            .sliced(start..start + block.byte_range.len()))
+    }
+}
+
+#[async_trait]
+impl FileReader for AvroFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        if self.init_data.is_some() {
+            return Ok(());
+        }
+
+        let scan_source = self.scan_source.clone();
+        let byte_source_builder = self.byte_source_builder.clone();
+        let cloud_options = self.cloud_options.clone();
+        let io_metrics = self.io_metrics.clone();
+
+        // Only the header is read here, the blocks are located while reading.
+        let (byte_source, file_size, metadata) = ASYNC
+            .spawn(async move {
+                let byte_source = scan_source
+                    .as_scan_source_ref()
+                    .to_dyn_byte_source(
+                        &byte_source_builder,
+                        cloud_options.as_deref(),
+                        io_metrics.0,
+                    )
+                    .await?;
+                let file_size = byte_source.get_size().await?;
+                let metadata = AvroFileMetadata::try_from_byte_source(&byte_source).await?;
+                PolarsResult::Ok((byte_source, file_size, metadata))
+            })
+            .await
+            .unwrap()?;
+
+        if self.verbose {
+            eprintln!(
+                "[AvroFileReader]: file_size: {}, data_offset: {}",
+                file_size, metadata.data_offset
+            );
+        }
+
+        self.init_data = Some(InitializedState {
+            byte_source: Arc::new(byte_source),
+            file_size,
+            metadata: Arc::new(metadata),
+        });
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let InitializedState {
+            byte_source,
+            file_size,
+            metadata,
+        } = self.init_data.clone().unwrap();
+
+        let BeginReadArgs {
+            projection: Projection::Plain(projected_schema),
+            row_index: None,
+            pre_slice,
+            predicate: None,
+            cast_columns_policy: _,
+            num_pipelines,
+            disable_morsel_split,
+            last_morsel_pipelines: _,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args
+        else {
+            panic!("unsupported args: {:?}", &args)
+        };
+
+        debug_assert!(!matches!(pre_slice, Some(Slice::Negative { .. })));
+
+        // Handle callbacks that are ready now.
+        if let Some(file_schema_tx) = file_schema_tx {
+            _ = file_schema_tx.send(Arc::new(metadata.pl_schema()));
+        }
+
+        let projection: Arc<[bool]> = metadata
+            .schema
+            .iter_names()
+            .map(|name| projected_schema.contains(name))
+            .collect();
+
+        let slice_range: Range<usize> = pre_slice
+            .clone()
+            .map_or(0..usize::MAX, Range::<usize>::from);
+
+        if self.verbose {
+            eprintln!(
+                "[AvroFileReader]: project: {} / {}, pre_slice: {:?}",
+                projection.iter().filter(|x| **x).count(),
+                projection.len(),
+                pre_slice,
+            );
+        }
+
+        let (decode_send, mut decode_recv) = tokio::sync::mpsc::channel(num_pipelines.max(1));
+        let (mut morsel_send, morsel_recv) = FileReaderOutputSend::new_serial();
+
+        // Task: Decode dispatch.
+        // Spawns a decode task for every block that overlaps with the slice. The
+        // row count callbacks need the headers of the remaining blocks as well.
+        let decode_dispatch_task = executor::spawn(TaskPriority::High, async move {
+            let mut fetcher = BlockFetcher::new(byte_source, file_size, metadata.data_offset);
+            let mut row_offset: usize = 0;
+            let mut decoding = true;
+
+            loop {
+                // Once decoding is done we only continue to count the rows.
+                if !decoding
+                    && n_rows_in_file_tx.is_none()
+                    && (row_offset >= slice_range.end || row_position_on_end_tx.is_none())
+                {
+                    break;
+                }
+
+                // Blocks before the slice are skipped without fetching them.
+                let prefetch = decoding && row_offset >= slice_range.start;
+                let Some(block) = fetcher.next_block(prefetch).await? else {
+                    break;
+                };
+                let n_rows = block.n_rows;
+                let position = SplitSlicePosition::split_slice_at_file(
+                    row_offset,
+                    n_rows,
+                    slice_range.clone(),
+                );
+                row_offset += n_rows;
+
+                match position {
+                    SplitSlicePosition::Overlapping(rows_offset, rows_len) if decoding => {
+                        let block_bytes = fetcher.block_bytes(&block).await?;
+                        let metadata = metadata.clone();
+                        let projection = projection.clone();
+                        let decode_fut = executor::spawn(TaskPriority::High, async move {
+                            let df = metadata.decode_block(&block_bytes, &projection)?;
+                            PolarsResult::Ok(df.slice(rows_offset as i64, rows_len))
+                        });
+                        decoding = decode_send.send(decode_fut).await.is_ok();
+                    },
+                    SplitSlicePosition::After => decoding = false,
+                    _ => {},
+                }
+            }
+            drop(decode_send);
+
+            let n_rows_in_file = IdxSize::try_from(row_offset)
+                .map_err(|_| polars_err!(bigidx, ctx = "avro file", size = row_offset))?;
+            if let Some(n_rows_in_file_tx) = n_rows_in_file_tx {
+                _ = n_rows_in_file_tx.send(n_rows_in_file);
+            }
+            if let Some(row_position_on_end_tx) = row_position_on_end_tx {
+                // If we stopped early this is the end of the slice.
+                _ = row_position_on_end_tx
+                    .send(calc_row_position_after_slice(n_rows_in_file, pre_slice));
+            }
+
+            PolarsResult::Ok(())
+        });
+
+        // Task: Distributor.
+        // Sends the decoded blocks in order, split into morsels.
+        let distribute_task = executor::spawn(TaskPriority::High, async move {
+            let mut morsel_seq = MorselSeq::default();
+            // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+            let source_token = SourceToken::new();
+            let ideal_morsel_size = get_ideal_morsel_size();
+
+            while let Some(decode_fut) = decode_recv.recv().await {
+                let df = decode_fut.await?;
+                let morsel_size = if disable_morsel_split {
+                    df.height().max(1)
+                } else {
+                    ideal_morsel_size
+                };
+
+                let mut offset = 0;
+                while offset < df.height() {
+                    let morsel_df = df.slice(offset as i64, morsel_size);
+                    offset += morsel_size;
+
+                    if morsel_send
+                        .send_morsel(Morsel::new(morsel_df, morsel_seq, source_token.clone()))
+                        .await
+                        .is_err()
+                    {
+                        return Ok(());
+                    }
+                    morsel_seq = morsel_seq.successor();
+                }
+            }
+
+            PolarsResult::Ok(())
+        });
+
+        let handle = executor::spawn(TaskPriority::Low, async move {
+            distribute_task.await?;
+            decode_dispatch_task.await
+        });
+
+        Ok((morsel_recv, handle))
+    }
+
+    async fn file_schema(&mut self) -> PolarsResult<SchemaRef> {
+        let metadata = &self.init_data.as_ref().unwrap().metadata;
+        Ok(Arc::new(metadata.pl_schema()))
+    }
+}
@@ -3,18 +3,21 @@ use std::sync::Arc;
 
 use polars_async::primitives::wait_group::WaitGroup;
 use polars_core::config;
+use polars_core::frame::DataFrame;
 use polars_io::cloud::CloudOptions;
 use polars_io::cloud::concurrency_config::FetchConfig;
 #[cfg(feature = "csv")]
 use polars_io::metrics::IOMetrics;
 use polars_io::prelude::CsvReadOptions;
 use polars_plan::dsl::ScanSource;
+use polars_plan::prelude::PlanCallback;
 use polars_utils::relaxed_cell::RelaxedCell;
 
 use super::{CsvFileReader, DynByteSourceBuilder};
 use crate::nodes::io_sources::multi_scan::reader_interface::FileReader;
 use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
 use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
+use crate::nodes::io_sources::shared::rejects::RejectsReporter;
 
 pub struct CsvReaderBuilder {
     pub options: Arc<CsvReadOptions>,
@@ -22,6 +25,8 @@ pub struct CsvReaderBuilder {
     pub prefetch_semaphore: std::sync::OnceLock<Arc<tokio::sync::Semaphore>>,
     pub shared_prefetch_wait_group_slot: Arc<std::sync::Mutex<Option<WaitGroup>>>,
     pub io_metrics: std::sync::OnceLock<Arc<IOMetrics>>,
+    /// Callback receiving the rows that could not be read.
+    pub rejects: Option<PlanCallback<DataFrame, ()>>,
 }
 
 impl std::fmt::Debug for CsvReaderBuilder {
@@ -42,7 +47,8 @@ impl FileReaderBuilder for CsvReaderBuilder {
     fn reader_capabilities(&self) -> ReaderCapabilities {
         use ReaderCapabilities as RC;
 
-        if self.options.parse_options.comment_prefix.is_some() {
+        // Rejected rows don't count as rows, so row positions are only known after parsing.
+        if self.options.parse_options.comment_prefix.is_some() || self.rejects.is_some() {
             RC::empty()
         } else {
             RC::PRE_SLICE
@@ -93,6 +99,14 @@ impl FileReaderBuilder for CsvReaderBuilder {
         let scan_source = source;
         let verbose = config::verbose();
         let options = self.options.clone();
+        let rejects = self.rejects.clone().map(|callback| RejectsReporter {
+            callback,
+            path: scan_source
+                .as_scan_source_ref()
+                .to_include_path_name()
+                .into(),
+            eol_char: options.parse_options.eol_char,
+        });
 
         let byte_source_builder =
             if scan_source.is_cloud_url() || polars_config::config().force_async() {
@@ -116,6 +130,7 @@ impl FileReaderBuilder for CsvReaderBuilder {
             },
             init_data: None,
             io_metrics: OptIOMetrics(self.io_metrics.get().cloned()),
+            rejects,
         };
 
         Box::new(reader) as Box<dyn FileReader>
@@ -5,23 +5,27 @@ use polars_core::prelude::Field;
 use polars_core::schema::{SchemaExt, SchemaRef};
 use polars_error::{PolarsResult, polars_bail, polars_warn};
 use polars_io::prelude::_csv_read_internal::{
-    NullValuesCompiled, cast_columns, prepare_csv_schema, read_chunk,
+    NullValuesCompiled, cast_columns, prepare_csv_schema, read_chunk, read_chunk_with_rejects,
 };
 use polars_io::prelude::builder::validate_utf8;
 use polars_io::prelude::{CsvEncoding, CsvParseOptions, CsvReadOptions};
+use polars_utils::pl_str::PlSmallStr;
 
 use super::{NO_SLICE, SLICE_ENDED};
 use crate::nodes::compute_node_prelude::*;
+use crate::nodes::io_sources::shared::rejects::RejectsReporter;
 
 #[derive(Default)]
 pub(super) struct ChunkReader {
     reader_schema: SchemaRef,
     parse_options: Arc<CsvParseOptions>,
     fields_to_cast: Vec<Field>,
+    json_columns: Arc<[PlSmallStr]>,
     ignore_errors: bool,
     projection: Vec<usize>,
     null_values: Option<NullValuesCompiled>,
     validate_utf8: bool,
+    rejects: Option<RejectsReporter>,
 }
 
 impl ChunkReader {
@@ -29,9 +33,14 @@ impl ChunkReader {
         options: Arc<CsvReadOptions>,
         mut reader_schema: SchemaRef,
         projection: Vec<usize>,
+        rejects: Option<RejectsReporter>,
     ) -> PolarsResult<Self> {
         let mut fields_to_cast: Vec<Field> = options.fields_to_cast.clone();
-        prepare_csv_schema(&mut reader_schema, &mut fields_to_cast)?;
+        prepare_csv_schema(
+            &mut reader_schema,
+            &mut fields_to_cast,
+            options.json_columns.as_deref(),
+        )?;
 
         let parse_options = options.parse_options.clone();
 
@@ -50,10 +59,12 @@ impl ChunkReader {
             reader_schema,
             parse_options,
             fields_to_cast,
+            json_columns: options.json_columns.clone().unwrap_or_default(),
             ignore_errors: options.ignore_errors,
             projection,
             null_values,
             validate_utf8,
+            rejects,
         })
     }
 
@@ -65,7 +76,28 @@ impl ChunkReader {
         n_lines: usize,
         slice: (usize, usize),
         chunk_row_offset: usize,
+        // Number of physical lines before the chunk, only tracked with rejects.
+        chunk_line_offset: usize,
     ) -> PolarsResult<(DataFrame, usize)> {
+        if let Some(rejects) = &self.rejects {
+            // Rejects disable slice pushdown.
+            assert_eq!(slice, NO_SLICE);
+
+            let (df, rejected) = read_chunk_with_rejects(
+                chunk,
+                &self.parse_options,
+                &self.reader_schema,
+                &self.projection,
+                self.null_values.as_ref(),
+                &self.fields_to_cast,
+                &self.json_columns,
+            )?;
+            rejects.report(chunk, chunk_line_offset, rejected)?;
+
+            let height = df.height();
+            return Ok((df, height));
+        }
+
         if self.validate_utf8 && !validate_utf8(chunk) {
             polars_bail!(ComputeError: "invalid utf-8 sequence")
         }
@@ -113,7 +145,13 @@ impl ChunkReader {
             df = df.slice(i64::try_from(slice.0).unwrap(), slice.1);
         }
 
-        cast_columns(&mut df, &self.fields_to_cast, false, self.ignore_errors)?;
+        cast_columns(
+            &mut df,
+            &self.fields_to_cast,
+            &self.json_columns,
+            false,
+            self.ignore_errors,
+        )?;
 
         Ok((df, height))
     }
@@ -22,6 +22,9 @@ pub(super) struct LineBatch {
     pub(super) slice: (usize, usize),
     /// Position of this chunk relative to the start of the file according to CountLines.
     pub(super) row_offset: usize,
+    /// Number of physical lines in the file before this chunk. Only tracked if the source was
+    /// given a starting line offset, 0 otherwise.
+    pub(super) line_offset: usize,
     pub(super) morsel_seq: MorselSeq,
 }
 
@@ -32,6 +35,8 @@ pub(super) struct LineBatchSource {
     pub(super) line_batch_tx: distributor_channel::Sender<LineBatch>,
     pub(super) pre_slice: Option<Slice>,
     pub(super) needs_full_row_count: bool,
+    /// Number of physical lines before `base_leftover`, if line offsets should be tracked.
+    pub(super) line_offset: Option<usize>,
     pub(super) use_async_prefetch: bool,
     pub(super) verbose: bool,
 }
@@ -60,6 +65,7 @@ impl LineBatchSource {
             self.line_counter,
             self.pre_slice,
             self.needs_full_row_count,
+            self.line_offset,
             use_l2_prefetch,
         );
         let mut line_batch_tx = self.line_batch_tx;
@@ -95,6 +101,7 @@ impl LineBatchSource {
                     self.line_counter,
                     self.pre_slice,
                     self.needs_full_row_count,
+                    self.line_offset,
                     use_l2_prefetch,
                 );
 
@@ -123,6 +130,7 @@ struct LineBatchProducer {
     needs_full_row_count: bool,
     use_prefetch_l2: bool,
     row_offset: usize,
+    line_offset: Option<usize>,
     morsel_seq: MorselSeq,
     n_rows_skipped: usize,
     read_size: usize,
@@ -136,6 +144,7 @@ impl LineBatchProducer {
         line_counter: CountLines,
         pre_slice: Option<Slice>,
         needs_full_row_count: bool,
+        line_offset: Option<usize>,
         use_prefetch_l2: bool,
     ) -> Self {
         let global_slice = if let Some(pre_slice) = pre_slice {
@@ -157,6 +166,7 @@ impl LineBatchProducer {
             needs_full_row_count,
             use_prefetch_l2,
             row_offset: 0,
+            line_offset,
             morsel_seq: MorselSeq::default(),
             n_rows_skipped: 0,
             read_size: ByteSourceReader::<ReaderSource>::initial_read_size(),
@@ -203,6 +213,15 @@ impl LineBatchProducer {
             let prev_row_offset = self.row_offset;
             self.row_offset += n_lines;
 
+            let batch_line_offset = if let Some(line_offset) = &mut self.line_offset {
+                let prev_line_offset = *line_offset;
+                *line_offset +=
+                    memchr::memchr_iter(self.line_counter.eol_char(), &batch_slice).count();
+                prev_line_offset
+            } else {
+                0
+            };
+
             let slice = if let Some(global_slice) = &self.global_slice {
                 match SplitSlicePosition::split_slice_at_file(
                     prev_row_offset,
@@ -234,6 +253,7 @@ impl LineBatchProducer {
                 n_lines,
                 slice,
                 row_offset: self.row_offset,
+                line_offset: batch_line_offset,
                 morsel_seq: self.morsel_seq,
             };
 
@@ -33,6 +33,7 @@ use polars_utils::slice_enum::Slice;
 use super::multi_scan::reader_interface::output::FileReaderOutputRecv;
 use super::multi_scan::reader_interface::{BeginReadArgs, FileReader, FileReaderCallbacks};
 use super::shared::chunk_data_fetch::ChunkDataFetcher;
+use super::shared::rejects::RejectsReporter;
 use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
 use crate::morsel::SourceToken;
 use crate::nodes::TaskPriority;
@@ -56,6 +57,7 @@ struct CsvFileReader {
     pub chunk_prefetch_sync: ChunkPrefetchSync,
     pub init_data: Option<InitializedState>,
     pub io_metrics: OptIOMetrics,
+    pub rejects: Option<RejectsReporter>,
 }
 
 pub(crate) struct ChunkPrefetchSync {
@@ -278,6 +280,7 @@ impl FileReader for CsvFileReader {
         };
 
         let options = self.options.clone();
+        let rejects = self.rejects.clone();
         let needs_full_row_count = n_rows_in_file_tx.is_some();
         let concurrency_strategy = self.byte_source_builder.concurrency_strategy().cloned();
         let chunk_size = self.byte_source_builder.chunk_size();
@@ -295,7 +298,8 @@ impl FileReader for CsvFileReader {
         // Because StreamBufReader uses `blocking_recv`, this runs on tokio's elastic blocking pool.
         let infer_schema_handle =
             tokio_handle_ext::AbortOnDropHandle(ASYNC.spawn_blocking(move || {
-                let mut reader = ByteSourceReader::try_new(reader_source, compression)?;
+                let mut reader = ByteSourceReader::try_new(reader_source, compression)?
+                    .with_transcoder(options.parse_options.encoding.transcoder());
                 let result = read_until_start_and_infer_schema(
                     &options,
                     Some(projected_schema.clone()),
@@ -303,15 +307,18 @@ impl FileReader for CsvFileReader {
                     None,
                     &mut reader,
                 )
-                .map(|(inferred_schema, base_leftover)| {
-                    (
-                        inferred_schema,
-                        base_leftover,
-                        reader,
-                        options,
-                        projected_schema,
-                    )
-                });
+                .map(
+                    |(inferred_schema, base_leftover, n_lines_before_leftover)| {
+                        (
+                            inferred_schema,
+                            base_leftover,
+                            n_lines_before_leftover,
+                            reader,
+                            options,
+                            projected_schema,
+                        )
+                    },
+                );
                 _ = infer_schema_tx.send(result);
                 PolarsResult::Ok(())
             }));
@@ -324,14 +331,20 @@ impl FileReader for CsvFileReader {
                     |_| polars_err!(ComputeError: "CSV pre-read task panicked or was dropped"),
                 )?;
 
-                let (inferred_schema, base_leftover, reader, options, projected_schema) =
-                    match pre_read_result {
-                        Ok(v) => v,
-                        Err(e) => {
-                            _ = chunk_reader_tx.send(Err(e.clone()));
-                            return Err(e);
-                        },
-                    };
+                let (
+                    inferred_schema,
+                    base_leftover,
+                    n_lines_before_leftover,
+                    reader,
+                    options,
+                    projected_schema,
+                ) = match pre_read_result {
+                    Ok(v) => v,
+                    Err(e) => {
+                        _ = chunk_reader_tx.send(Err(e.clone()));
+                        return Err(e);
+                    },
+                };
 
                 let used_schema = Arc::new(inferred_schema);
 
@@ -367,9 +380,10 @@ impl FileReader for CsvFileReader {
                 let comment_prefix = options.parse_options.comment_prefix.clone();
 
                 let line_counter = CountLines::new(quote_char, eol_char, comment_prefix);
+                let line_offset = rejects.is_some().then_some(n_lines_before_leftover);
 
                 let chunk_reader_result =
-                    ChunkReader::try_new(options, used_schema, projection).map(Arc::new);
+                    ChunkReader::try_new(options, used_schema, projection, rejects).map(Arc::new);
                 _ = chunk_reader_tx.send(chunk_reader_result.clone());
 
                 match chunk_reader_result {
@@ -381,6 +395,7 @@ impl FileReader for CsvFileReader {
                             line_batch_tx,
                             pre_slice,
                             needs_full_row_count,
+                            line_offset,
                             use_async_prefetch,
                             verbose,
                         }
@@ -417,6 +432,7 @@ impl FileReader for CsvFileReader {
                         n_lines,
                         slice,
                         row_offset,
+                        line_offset,
                         morsel_seq,
                     }) = line_batch_rx.recv().await
                     {
@@ -430,6 +446,7 @@ impl FileReader for CsvFileReader {
                             n_lines,
                             (offset, len),
                             row_offset,
+                            line_offset,
                         )?;
 
                         n_rows_processed = n_rows_processed.saturating_add(n_rows_in_chunk);
@@ -458,6 +475,7 @@ impl FileReader for CsvFileReader {
                             n_lines,
                             slice,
                             row_offset: _,
+                            line_offset: _,
                             morsel_seq: _,
                         }) = line_batch_rx.recv().await
                         {
@@ -1,8 +1,12 @@
 pub mod multi_scan;
 
+#[cfg(feature = "avro")]
+pub mod avro;
 pub mod batch;
 #[cfg(feature = "csv")]
 pub mod csv;
+#[cfg(feature = "fwf")]
+pub mod fwf;
 #[cfg(feature = "ipc")]
 pub mod ipc;
 #[cfg(feature = "scan_lines")]
@@ -3,17 +3,21 @@ use std::sync::Arc;
 
 use polars_async::primitives::wait_group::WaitGroup;
 use polars_core::config;
+use polars_core::frame::DataFrame;
+use polars_core::schema::SchemaRef;
 use polars_io::cloud::CloudOptions;
 use polars_io::cloud::concurrency_config::FetchConfig;
 #[cfg(feature = "json")]
 use polars_io::metrics::IOMetrics;
 use polars_plan::dsl::{NDJsonReadOptions, ScanSource};
+use polars_plan::prelude::PlanCallback;
 use polars_utils::relaxed_cell::RelaxedCell;
 
 use super::{DynByteSourceBuilder, FileReader, NDJsonFileReader};
 use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
 use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
-use crate::nodes::io_sources::ndjson::chunk_reader::ChunkReaderBuilder;
+use crate::nodes::io_sources::ndjson::chunk_reader::{ChunkReaderBuilder, NDJsonRejects};
+use crate::nodes::io_sources::shared::rejects::RejectsReporter;
 
 pub struct NDJsonReaderBuilder {
     pub options: Arc<NDJsonReadOptions>,
@@ -21,6 +25,9 @@ pub struct NDJsonReaderBuilder {
     pub prefetch_semaphore: std::sync::OnceLock<Arc<tokio::sync::Semaphore>>,
     pub shared_prefetch_wait_group_slot: Arc<std::sync::Mutex<Option<WaitGroup>>>,
     pub io_metrics: std::sync::OnceLock<Arc<IOMetrics>>,
+    /// Callback receiving the lines that could not be parsed, along with the schema they are
+    /// validated against.
+    pub rejects: Option<(PlanCallback<DataFrame, ()>, SchemaRef)>,
 }
 
 impl std::fmt::Debug for NDJsonReaderBuilder {
@@ -46,7 +53,15 @@ impl FileReaderBuilder for NDJsonReaderBuilder {
     }
 
     fn reader_capabilities(&self) -> ReaderCapabilities {
-        ndjson_reader_capabilities()
+        // Rejected lines don't count as rows, so row positions are only known after parsing.
+        if self.rejects.is_some() {
+            ReaderCapabilities::empty()
+        } else if self.options.record_pointer.is_some() {
+            // JSON documents cannot be read in reverse.
+            ReaderCapabilities::ROW_INDEX | ReaderCapabilities::PRE_SLICE
+        } else {
+            ndjson_reader_capabilities()
+        }
     }
 
     fn set_execution_state(&self, execution_state: &crate::execute::StreamingExecutionState) {
@@ -91,8 +106,24 @@ impl FileReaderBuilder for NDJsonReaderBuilder {
         use crate::nodes::io_sources::ndjson::ChunkPrefetchSync;
 
         let scan_source = source;
+        let rejects = self
+            .rejects
+            .clone()
+            .map(|(callback, file_schema)| NDJsonRejects {
+                reporter: RejectsReporter {
+                    callback,
+                    path: scan_source
+                        .as_scan_source_ref()
+                        .to_include_path_name()
+                        .into(),
+                    eol_char: b'\n',
+                },
+                file_schema,
+            });
         let chunk_reader_builder = ChunkReaderBuilder::NDJson {
             ignore_errors: self.options.ignore_errors,
+            rejects,
+            record_pointer: self.options.record_pointer.clone(),
         };
         let verbose = config::verbose();
 
@@ -1,19 +1,38 @@
+#[cfg(feature = "fwf")]
+use std::sync::Arc;
+
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
+use polars_io::json::records::parse_json_records;
 use polars_io::ndjson;
-use polars_io::prelude::{is_json_line, parse_ndjson};
-#[cfg(feature = "scan_lines")]
+use polars_io::prelude::{is_json_line, parse_ndjson, parse_ndjson_with_rejects};
+#[cfg(feature = "fwf")]
+use polars_io::scan_fwf::{FwfReadOptions, parse_fwf_chunk};
 use polars_utils::pl_str::PlSmallStr;
 
 use crate::nodes::compute_node_prelude::*;
+use crate::nodes::io_sources::shared::rejects::RejectsReporter;
+
+#[derive(Clone)]
+pub struct NDJsonRejects {
+    pub reporter: RejectsReporter,
+    /// Lines are validated against the projected schema, or against this schema if nothing is
+    /// projected.
+    pub file_schema: SchemaRef,
+}
 
 #[derive(Clone)]
 pub enum ChunkReaderBuilder {
     NDJson {
         ignore_errors: bool,
+        rejects: Option<NDJsonRejects>,
+        /// JSON pointer to the record array, if the file is a single JSON document.
+        record_pointer: Option<PlSmallStr>,
     },
     #[cfg(feature = "scan_lines")]
     Lines,
+    #[cfg(feature = "fwf")]
+    Fwf { options: Arc<FwfReadOptions> },
 }
 
 #[derive(Clone)]
@@ -22,20 +41,34 @@ pub enum ChunkReader {
     NDJson {
         projected_schema: SchemaRef,
         ignore_errors: bool,
+        rejects: Option<NDJsonRejects>,
+        /// Whether the lines are records split from a JSON document.
+        records: bool,
     },
     #[cfg(feature = "scan_lines")]
     Lines {
         /// If this is `None` we are projecting 0-width morsels.
         projection: Option<PlSmallStr>,
     },
+    #[cfg(feature = "fwf")]
+    Fwf {
+        options: Arc<FwfReadOptions>,
+        projected_schema: SchemaRef,
+    },
 }
 
 impl ChunkReaderBuilder {
     pub(super) fn build(&self, projected_schema: SchemaRef) -> ChunkReader {
         match self {
-            Self::NDJson { ignore_errors } => ChunkReader::NDJson {
+            Self::NDJson {
+                ignore_errors,
+                rejects,
+                record_pointer,
+            } => ChunkReader::NDJson {
                 projected_schema,
                 ignore_errors: *ignore_errors,
+                rejects: rejects.clone(),
+                records: record_pointer.is_some(),
             },
             #[cfg(feature = "scan_lines")]
             Self::Lines => {
@@ -52,6 +85,41 @@ impl ChunkReaderBuilder {
 
                 ChunkReader::Lines { projection }
             },
+            #[cfg(feature = "fwf")]
+            Self::Fwf { options } => ChunkReader::Fwf {
+                options: options.clone(),
+                projected_schema,
+            },
+        }
+    }
+
+    /// Whether chunks must carry the number of lines preceding them.
+    pub(super) fn needs_line_offsets(&self) -> bool {
+        match self {
+            Self::NDJson { rejects, .. } => rejects.is_some(),
+            #[cfg(feature = "scan_lines")]
+            Self::Lines => false,
+            #[cfg(feature = "fwf")]
+            Self::Fwf { .. } => false,
+        }
+    }
+
+    /// Number of lines to skip at the start of the file before counting rows.
+    pub(super) fn n_leading_lines_to_skip(&self) -> usize {
+        match self {
+            #[cfg(feature = "fwf")]
+            Self::Fwf { options } => options.skip_lines,
+            _ => 0,
+        }
+    }
+
+    /// JSON pointer to the record array, if the file is a single JSON document that is converted
+    /// to lines of records before being chunked.
+    pub(super) fn record_pointer(&self) -> Option<&PlSmallStr> {
+        match self {
+            Self::NDJson { record_pointer, .. } => record_pointer.as_ref(),
+            #[cfg(any(feature = "scan_lines", feature = "fwf"))]
+            _ => None,
         }
     }
 
@@ -60,18 +128,46 @@ impl ChunkReaderBuilder {
             Self::NDJson { .. } => is_json_line,
             #[cfg(feature = "scan_lines")]
             Self::Lines { .. } => |_: &[u8]| true,
+            #[cfg(feature = "fwf")]
+            Self::Fwf { .. } => |_: &[u8]| true,
         }
     }
 }
 
 impl ChunkReader {
-    pub(super) fn read_chunk(&self, chunk: &[u8]) -> PolarsResult<DataFrame> {
+    /// `line_offset` is the number of lines in the file before `chunk`. It is only tracked when
+    /// [`ChunkReaderBuilder::needs_line_offsets`] is set.
+    pub(super) fn read_chunk(&self, chunk: &[u8], line_offset: usize) -> PolarsResult<DataFrame> {
         match self {
             Self::NDJson {
                 projected_schema,
-                ignore_errors,
+                ignore_errors: _,
+                rejects: Some(rejects),
+                records: _,
             } => {
+                // Without projected columns, the lines are still validated against the file schema.
+                let schema = if projected_schema.is_empty() {
+                    &rejects.file_schema
+                } else {
+                    projected_schema
+                };
+                let (df, rejected) = parse_ndjson_with_rejects(chunk, schema)?;
+                rejects.reporter.report(chunk, line_offset, rejected)?;
                 if projected_schema.is_empty() {
+                    Ok(DataFrame::empty_with_height(df.height()))
+                } else {
+                    Ok(df)
+                }
+            },
+            Self::NDJson {
+                projected_schema,
+                ignore_errors,
+                rejects: None,
+                records,
+            } => {
+                if *records {
+                    parse_json_records(chunk, projected_schema, *ignore_errors)
+                } else if projected_schema.is_empty() {
                     Ok(DataFrame::empty_with_height(ndjson::count_rows(chunk)))
                 } else {
                     parse_ndjson(chunk, None, projected_schema, *ignore_errors)
@@ -92,6 +188,19 @@ impl ChunkReader {
 
                 Ok(out)
             },
+            #[cfg(feature = "fwf")]
+            Self::Fwf {
+                options,
+                projected_schema,
+            } => {
+                if projected_schema.is_empty() {
+                    Ok(DataFrame::empty_with_height(
+                        polars_io::scan_fwf::count_lines(chunk),
+                    ))
+                } else {
+                    parse_fwf_chunk(chunk, options, projected_schema)
+                }
+            },
         }
     }
 }
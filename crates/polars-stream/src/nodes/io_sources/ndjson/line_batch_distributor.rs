@@ -5,9 +5,11 @@ use polars_async::primitives::distributor_channel;
 use polars_buffer::Buffer;
 use polars_core::runtime::ASYNC;
 use polars_error::PolarsResult;
+use polars_io::json::records::JsonRecordSplitter;
 use polars_io::utils::compression::{ByteSourceReader, SupportedCompression};
 use polars_io::utils::stream_buf_reader::ReaderSource;
 use polars_utils::mem::prefetch::prefetch_l2;
+use polars_utils::pl_str::PlSmallStr;
 
 use super::line_batch_processor::LineBatch;
 use crate::utils::tokio_handle_ext;
@@ -18,10 +20,18 @@ pub(super) struct LineBatchDistributor {
     pub(super) reader: ReaderSource,
     pub(super) reverse: bool,
     pub(super) row_skipper: RowSkipper,
+    /// Lines at the start of the file that are skipped before `row_skipper` counts rows. Only
+    /// supported when not reading in reverse.
+    pub(super) n_leading_lines_to_skip: usize,
+    /// If set, the input is a JSON document whose records at this pointer are distributed as
+    /// lines. Only supported when not reading in reverse.
+    pub(super) record_pointer: Option<PlSmallStr>,
     pub(super) line_batch_distribute_tx: distributor_channel::Sender<LineBatch>,
     pub(super) compression: Option<SupportedCompression>,
     pub(super) uncompressed_file_size_hint: Option<usize>,
     pub(super) use_async_prefetch: bool,
+    /// Count the lines preceding each batch.
+    pub(super) track_line_offsets: bool,
     pub(super) verbose: bool,
 }
 
@@ -49,8 +59,11 @@ impl LineBatchDistributor {
             reader,
             self.reverse,
             self.row_skipper,
+            self.n_leading_lines_to_skip,
+            self.record_pointer.as_deref(),
             self.uncompressed_file_size_hint,
             use_prefetch_l2,
+            self.track_line_offsets,
             self.verbose,
         )?;
 
@@ -71,10 +84,13 @@ impl LineBatchDistributor {
             reader: reader_source,
             reverse,
             row_skipper,
+            n_leading_lines_to_skip,
+            record_pointer,
             line_batch_distribute_tx: mut line_batch_tx,
             compression,
             uncompressed_file_size_hint,
             use_async_prefetch: _,
+            track_line_offsets,
             verbose,
         } = self;
 
@@ -92,8 +108,11 @@ impl LineBatchDistributor {
                     reader,
                     reverse,
                     row_skipper,
+                    n_leading_lines_to_skip,
+                    record_pointer.as_deref(),
                     uncompressed_file_size_hint,
                     use_prefetch_l2,
+                    track_line_offsets,
                     verbose,
                 )?;
 
@@ -118,12 +137,16 @@ struct LineBatchProducer {
     reader: ByteSourceReader<ReaderSource>,
     reverse: bool,
     row_skipper: RowSkipper,
+    leading_line_skipper: RowSkipper,
+    record_splitter: Option<JsonRecordSplitter>,
     use_prefetch_l2: bool,
     fixed_read_size: Option<NonZeroUsize>,
     full_input_opt: Option<(Buffer<u8>, usize)>,
     prev_leftover: Buffer<u8>,
     read_size: usize,
     chunk_idx: usize,
+    /// Number of lines consumed so far, if tracked.
+    n_lines_consumed: Option<usize>,
     finished: bool,
 }
 
@@ -132,10 +155,29 @@ impl LineBatchProducer {
         mut reader: ByteSourceReader<ReaderSource>,
         reverse: bool,
         row_skipper: RowSkipper,
+        n_leading_lines_to_skip: usize,
+        record_pointer: Option<&str>,
         uncompressed_file_size_hint: Option<usize>,
         use_prefetch_l2: bool,
+        track_line_offsets: bool,
         verbose: bool,
     ) -> PolarsResult<Self> {
+        // Line offsets and leading lines are counted from the start of the file.
+        assert!(!(track_line_offsets && reverse));
+        assert!(!(n_leading_lines_to_skip > 0 && reverse));
+        assert!(!(record_pointer.is_some() && reverse));
+
+        let record_splitter = record_pointer
+            .map(JsonRecordSplitter::try_new)
+            .transpose()?;
+
+        let leading_line_skipper = RowSkipper {
+            cfg_n_rows_to_skip: n_leading_lines_to_skip,
+            n_rows_skipped: 0,
+            reverse,
+            is_line: |_| true,
+        };
+
         let fixed_read_size = std::env::var("POLARS_FORCE_NDJSON_READ_SIZE")
             .map(|x| {
                 x.parse::<NonZeroUsize>().ok().unwrap_or_else(|| {
@@ -178,12 +220,15 @@ impl LineBatchProducer {
             reader,
             reverse,
             row_skipper,
+            leading_line_skipper,
+            record_splitter,
             use_prefetch_l2,
             fixed_read_size,
             full_input_opt,
             prev_leftover: Buffer::new(),
             read_size,
             chunk_idx: 0,
+            n_lines_consumed: track_line_offsets.then_some(0),
             finished: false,
         })
     }
@@ -193,6 +238,10 @@ impl LineBatchProducer {
             return Ok(None);
         }
 
+        if self.record_splitter.is_some() {
+            return self.next_record_batch();
+        }
+
         loop {
             let (mem_slice, bytes_read) = if self.reverse {
                 let (full_input, offset) = self.full_input_opt.as_mut().unwrap();
@@ -226,7 +275,9 @@ impl LineBatchProducer {
                 is_eof,
                 self.reverse,
                 &mut self.chunk_idx,
+                &mut self.leading_line_skipper,
                 &mut self.row_skipper,
+                &mut self.n_lines_consumed,
             );
 
             if is_eof {
@@ -260,6 +311,55 @@ impl LineBatchProducer {
         }
     }
 
+    /// Converts the next slice of a JSON document to lines of records and batches those.
+    fn next_record_batch(&mut self) -> PolarsResult<Option<LineBatch>> {
+        let record_splitter = self.record_splitter.as_mut().unwrap();
+
+        loop {
+            let (mem_slice, bytes_read) = self.reader.read_next_slice(
+                &Buffer::new(),
+                self.read_size,
+                Some(self.read_size),
+            )?;
+
+            if self.use_prefetch_l2 {
+                prefetch_l2(&mem_slice);
+            }
+
+            let mut records = Vec::new();
+            record_splitter.push(&mem_slice, &mut records)?;
+
+            if bytes_read == 0 {
+                record_splitter.finish()?;
+                self.finished = true;
+            } else if record_splitter.is_done() {
+                // Anything after the record array is not read.
+                self.finished = true;
+            }
+
+            if self.read_size < ByteSourceReader::<ReaderSource>::ideal_read_size()
+                && self.fixed_read_size.is_none()
+            {
+                self.read_size *= 4;
+            }
+
+            // The records are always complete lines.
+            let (batch, _) = process_chunk(
+                Buffer::from_vec(records),
+                true,
+                false,
+                &mut self.chunk_idx,
+                &mut self.leading_line_skipper,
+                &mut self.row_skipper,
+                &mut self.n_lines_consumed,
+            );
+
+            if batch.is_some() || self.finished {
+                return Ok(batch);
+            }
+        }
+    }
+
     fn n_rows_skipped(&self) -> usize {
         self.row_skipper.n_rows_skipped
     }
@@ -271,7 +371,9 @@ fn process_chunk(
     is_eof: bool,
     reverse: bool,
     chunk_idx: &mut usize,
+    leading_line_skipper: &mut RowSkipper,
     row_skipper: &mut RowSkipper,
+    n_lines_consumed: &mut Option<usize>,
 ) -> (Option<LineBatch>, Option<usize>) {
     let len = chunk.len();
     if len == 0 {
@@ -299,12 +401,23 @@ fn process_chunk(
 
         // Since this path is only executed if at least one line is found or EOF, we guarantee that
         // `skip_rows` will always make progress.
-        let batch_chunk = row_skipper.skip_rows(line_chunk);
+        let batch_chunk = row_skipper.skip_rows(leading_line_skipper.skip_rows(line_chunk.clone()));
+
+        let line_offset = if let Some(n_lines) = n_lines_consumed {
+            // Not reversed, so the skipped rows are at the start of `line_chunk`.
+            let skipped = &line_chunk[..line_chunk.len() - batch_chunk.len()];
+            let line_offset = *n_lines + memchr::memchr_iter(LF, skipped).count();
+            *n_lines += memchr::memchr_iter(LF, &line_chunk).count();
+            line_offset
+        } else {
+            0
+        };
 
         if !batch_chunk.is_empty() {
             let batch = LineBatch {
                 bytes: batch_chunk,
                 chunk_idx: *chunk_idx,
+                line_offset,
             };
             *chunk_idx += 1;
             Some(batch)
@@ -493,10 +493,13 @@ impl FileReader for NDJsonFileReader {
                 reader: reader_source,
                 reverse: is_negative_slice,
                 row_skipper,
+                n_leading_lines_to_skip: self.chunk_reader_builder.n_leading_lines_to_skip(),
+                record_pointer: self.chunk_reader_builder.record_pointer().cloned(),
                 line_batch_distribute_tx,
                 compression,
                 uncompressed_file_size_hint,
                 use_async_prefetch,
+                track_line_offsets: self.chunk_reader_builder.needs_line_offsets(),
                 verbose,
             }
             .run(),
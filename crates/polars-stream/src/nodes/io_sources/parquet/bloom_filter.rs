@@ -0,0 +1,188 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow::bitmap::Bitmap;
+use polars_core::prelude::*;
+use polars_io::predicates::{ScanIOPredicate, SpecializedColumnPredicate};
+use polars_io::prelude::FileMetadata;
+use polars_io::utils::byte_source::{ByteSource, DynByteSource};
+use polars_parquet::parquet::bloom_filter::{deserialize, hash_byte, hash_native, is_in_set};
+use polars_parquet::parquet::schema::types::PhysicalType;
+
+use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
+
+/// A column whose pushed-down predicate only holds for a known set of values.
+struct BloomFilterProbe<'a> {
+    column_name: &'a PlSmallStr,
+    values: &'a [Scalar],
+}
+
+/// Probes the bloom filters of the column chunks in `row_group_slice` for columns that have an
+/// equality or `is_in` predicate, and returns a mask of the row groups that can be skipped
+/// because they contain none of the values.
+///
+/// Row groups that are already set in `skip_mask` are not probed.
+pub(super) async fn calculate_row_group_bloom_filter_skip_mask(
+    row_group_slice: Range<usize>,
+    use_statistics: bool,
+    predicate: Option<&ScanIOPredicate>,
+    metadata: &Arc<FileMetadata>,
+    projected_arrow_fields: &[ArrowFieldProjection],
+    byte_source: &DynByteSource,
+    skip_mask: Option<&Bitmap>,
+    verbose: bool,
+) -> PolarsResult<Option<Bitmap>> {
+    if !use_statistics {
+        return Ok(None);
+    }
+
+    let Some(predicate) = predicate else {
+        return Ok(None);
+    };
+
+    // Every column predicate is a conjunction of terms of the full predicate, so a row group
+    // where a column predicate matches no row can be skipped entirely.
+    let probes: Vec<BloomFilterProbe> = projected_arrow_fields
+        .iter()
+        .filter_map(|projection| {
+            // The values of mapped columns don't have the physical representation of the file.
+            let ArrowFieldProjection::Plain(field) = projection else {
+                return None;
+            };
+            let (_, specialized) = predicate.column_predicates.predicates.get(&field.name)?;
+
+            let values = match specialized.as_ref()? {
+                SpecializedColumnPredicate::Equal(value) => std::slice::from_ref(value),
+                SpecializedColumnPredicate::EqualOneOf(values) => values.as_ref(),
+                _ => return None,
+            };
+
+            // Nulls are not inserted into bloom filters.
+            if values.iter().any(|v| v.is_null()) {
+                return None;
+            }
+
+            Some(BloomFilterProbe {
+                column_name: &field.name,
+                values,
+            })
+        })
+        .collect();
+
+    if probes.is_empty() {
+        return Ok(None);
+    }
+
+    let row_groups = &metadata.row_groups[row_group_slice.clone()];
+
+    // (row group, probe) -> bloom filter byte range.
+    let mut probe_ranges: Vec<(usize, usize, Range<usize>)> = Vec::new();
+    let mut probe_hashes: Vec<Option<Vec<u64>>> = vec![None; probes.len()];
+
+    for (rg_idx, rg) in row_groups.iter().enumerate() {
+        if skip_mask.is_some_and(|m| m.get_bit(rg_idx)) {
+            continue;
+        }
+
+        for (probe_idx, probe) in probes.iter().enumerate() {
+            let Some(mut columns) = rg.columns_under_root_iter(probe.column_name) else {
+                continue;
+            };
+            let (Some(column), None) = (columns.next(), columns.next()) else {
+                continue;
+            };
+            // Bloom filters of encrypted columns are encrypted modules.
+            if column.is_encrypted() {
+                continue;
+            }
+            let (Some(offset), Some(length)) =
+                (column.bloom_filter_offset(), column.bloom_filter_length())
+            else {
+                continue;
+            };
+
+            if probe_hashes[probe_idx].is_none() {
+                let Some(hashes) = probe
+                    .values
+                    .iter()
+                    .map(|v| scalar_to_bloom_filter_hash(v, column.physical_type()))
+                    .collect::<Option<Vec<u64>>>()
+                else {
+                    continue;
+                };
+                probe_hashes[probe_idx] = Some(hashes);
+            }
+
+            let offset = offset as usize;
+            probe_ranges.push((rg_idx, probe_idx, offset..offset + length as usize));
+        }
+    }
+
+    if probe_ranges.is_empty() {
+        return Ok(None);
+    }
+
+    let mut ranges: Vec<Range<usize>> = probe_ranges.iter().map(|(_, _, r)| r.clone()).collect();
+    let bytes_map = byte_source.get_ranges(&mut ranges).await?;
+
+    let mut skip = vec![false; row_groups.len()];
+    let mut bitset = vec![];
+
+    for (rg_idx, probe_idx, range) in probe_ranges {
+        if skip[rg_idx] {
+            continue;
+        }
+
+        let bytes = bytes_map.get(&range.start).unwrap();
+        deserialize(&bytes[..range.len()], &mut bitset)?;
+
+        if bitset.is_empty() {
+            continue;
+        }
+
+        let hashes = probe_hashes[probe_idx].as_ref().unwrap();
+        skip[rg_idx] = !hashes.iter().any(|h| is_in_set(&bitset, *h));
+    }
+
+    let skip_row_group_mask = Bitmap::from_iter(skip);
+
+    if verbose {
+        eprintln!(
+            "[ParquetFileReader]: Bloom filter pushdown: \
+            skipping {} / {} row groups",
+            skip_row_group_mask.set_bits(),
+            row_groups.len(),
+        );
+    }
+
+    Ok(Some(skip_row_group_mask))
+}
+
+/// Hashes `scalar` as it is stored in a parquet column of `physical_type`. Returns `None` if the
+/// value can't be represented by that physical type.
+fn scalar_to_bloom_filter_hash(scalar: &Scalar, physical_type: PhysicalType) -> Option<u64> {
+    use AnyValue as A;
+
+    let scalar = scalar.clone().to_physical();
+
+    // casts below MUST match the casts done when the pages are written.
+    Some(match (physical_type, scalar.value()) {
+        (PhysicalType::Int32, A::UInt8(v)) => hash_native(*v as i32),
+        (PhysicalType::Int32, A::UInt16(v)) => hash_native(*v as i32),
+        (PhysicalType::Int32, A::UInt32(v)) => hash_native(*v as i32),
+        (PhysicalType::Int32, A::Int8(v)) => hash_native(*v as i32),
+        (PhysicalType::Int32, A::Int16(v)) => hash_native(*v as i32),
+        (PhysicalType::Int32, A::Int32(v)) => hash_native(*v),
+        (PhysicalType::Int64, A::UInt64(v)) => hash_native(*v as i64),
+        (PhysicalType::Int64, A::Int64(v)) => hash_native(*v),
+        (PhysicalType::ByteArray, A::String(v)) => hash_byte(v.as_bytes()),
+        (PhysicalType::ByteArray, A::StringOwned(v)) => hash_byte(v.as_bytes()),
+        (PhysicalType::ByteArray | PhysicalType::FixedLenByteArray(_), A::Binary(v)) => {
+            hash_byte(v)
+        },
+        (PhysicalType::ByteArray | PhysicalType::FixedLenByteArray(_), A::BinaryOwned(v)) => {
+            hash_byte(v)
+        },
+        _ => return None,
+    })
+}
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use arrow::bitmap::Bitmap;
 use polars_async::executor;
 use polars_core::frame::DataFrame;
 use polars_core::runtime::ASYNC;
@@ -13,6 +14,8 @@ use super::row_group_decode::RowGroupDecoder;
 use super::{AsyncTaskData, ParquetReadImpl};
 use crate::morsel::{Morsel, SourceToken, get_ideal_morsel_size};
 use crate::nodes::io_sources::multi_scan::reader_interface::output::FileReaderOutputSend;
+use crate::nodes::io_sources::parquet::bloom_filter::calculate_row_group_bloom_filter_skip_mask;
+use crate::nodes::io_sources::parquet::page_index::calculate_row_group_page_index_row_masks;
 use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
 use crate::nodes::io_sources::parquet::statistics::calculate_row_group_pred_pushdown_skip_mask;
 use crate::nodes::{MorselSeq, TaskPriority};
@@ -84,6 +87,7 @@ impl ParquetReadImpl {
             tokio::sync::mpsc::channel(row_group_prefetch_size);
 
         let row_index = self.row_index.clone();
+        let io_metrics = self.io_metrics.clone();
 
         let pipeline_budget = self.pipeline_budget.clone();
 
@@ -146,7 +150,9 @@ impl ParquetReadImpl {
                 }
             }
 
-            let row_group_mask = calculate_row_group_pred_pushdown_skip_mask(
+            let row_index_name = row_index.as_ref().map(|ri| ri.name.clone());
+
+            let mut row_group_mask = calculate_row_group_pred_pushdown_skip_mask(
                 row_group_slice.clone(),
                 use_statistics,
                 predicate.as_ref(),
@@ -157,6 +163,55 @@ impl ParquetReadImpl {
             )
             .await?;
 
+            if let Some(bloom_filter_mask) = calculate_row_group_bloom_filter_skip_mask(
+                row_group_slice.clone(),
+                use_statistics,
+                predicate.as_ref(),
+                &metadata,
+                &projected_arrow_fields,
+                &byte_source,
+                row_group_mask.as_ref(),
+                verbose,
+            )
+            .await?
+            {
+                row_group_mask = Some(match row_group_mask {
+                    Some(mask) => &mask | &bloom_filter_mask,
+                    None => bloom_filter_mask,
+                });
+            }
+
+            let page_row_masks = calculate_row_group_page_index_row_masks(
+                row_group_slice.clone(),
+                use_statistics,
+                predicate.as_ref(),
+                &metadata,
+                projected_arrow_fields.clone(),
+                row_index_name,
+                &byte_source,
+                row_group_mask.as_ref(),
+                verbose,
+            )
+            .await?
+            .unwrap_or_default();
+
+            // Row groups where no page can match are skipped entirely.
+            let page_index_mask = page_row_masks
+                .iter()
+                .map(|m| m.as_ref().is_some_and(|m| m.set_bits() == 0))
+                .collect::<Bitmap>();
+
+            if page_index_mask.set_bits() > 0 {
+                row_group_mask = Some(match row_group_mask {
+                    Some(mask) => &mask | &page_index_mask,
+                    None => page_index_mask,
+                });
+            }
+
+            if let Some(mask) = row_group_mask.as_ref() {
+                io_metrics.add_row_groups_skipped(mask.set_bits() as u64);
+            }
+
             let mut row_group_data_fetcher = RowGroupDataFetcher {
                 projection: projected_arrow_fields.clone(),
                 is_full_projection,
@@ -167,6 +222,7 @@ impl ParquetReadImpl {
                 byte_source,
                 row_group_slice,
                 row_group_mask,
+                page_row_masks: page_row_masks.into(),
                 row_offset,
             };
 
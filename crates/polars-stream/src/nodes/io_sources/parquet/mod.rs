@@ -11,7 +11,7 @@ use polars_error::{PolarsResult, polars_err};
 use polars_io::RowIndex;
 use polars_io::cloud::CloudOptions;
 use polars_io::predicates::ScanIOPredicate;
-use polars_io::prelude::{FileMetadata, ParquetOptions};
+use polars_io::prelude::{FileMetadata, ParquetKeyRetriever, ParquetOptions};
 use polars_io::utils::byte_source::{BufferByteSource, DynByteSource, DynByteSourceBuilder};
 use polars_parquet::read::schema::infer_schema_with_options;
 use polars_plan::dsl::ScanSource;
@@ -33,9 +33,11 @@ use crate::nodes::io_sources::parquet::projection::{
 use crate::nodes::{TaskPriority, io_sources};
 use crate::utils::tokio_handle_ext;
 
+mod bloom_filter;
 pub mod builder;
 pub mod init;
 mod metadata_utils;
+mod page_index;
 mod projection;
 mod row_group_data_fetch;
 mod row_group_decode;
@@ -122,9 +124,17 @@ impl FileReader for ParquetFileReader {
                 byte_source = Arc::new(DynByteSource::Buffer(BufferByteSource(full_bytes)));
             }
 
-            Arc::new(polars_parquet::parquet::read::deserialize_metadata(
-                metadata_bytes,
-            )?)
+            let decryption = self
+                .config
+                .decryption
+                .as_ref()
+                .map(ParquetKeyRetriever::decryption_properties);
+            Arc::new(
+                polars_parquet::parquet::read::deserialize_metadata_with_decryption(
+                    metadata_bytes,
+                    decryption.as_ref(),
+                )?,
+            )
         };
 
         let file_schema = Arc::new(infer_schema_with_options(&file_metadata, &None)?);
@@ -345,6 +355,7 @@ impl FileReader for ParquetFileReader {
             verbose,
             memory_prefetch_func,
             row_index,
+            io_metrics: self.io_metrics.clone(),
 
             pipeline_budget: self.row_group_prefetch_sync.pipeline_budget.clone(),
             rg_prefetch_prev_all_spawned: Option::take(
@@ -438,6 +449,7 @@ struct ParquetReadImpl {
     verbose: bool,
     memory_prefetch_func: fn(&[u8]) -> (),
     row_index: Option<RowIndex>,
+    io_metrics: OptIOMetrics,
 
     pipeline_budget: PipelineBudget,
     rg_prefetch_prev_all_spawned: Option<WaitGroup>,
@@ -0,0 +1,252 @@
+use std::ops::Range;
+
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_async::executor::{self, TaskPriority};
+use polars_core::prelude::*;
+use polars_io::predicates::ScanIOPredicate;
+use polars_io::prelude::FileMetadata;
+use polars_io::utils::byte_source::{ByteSource, DynByteSource};
+use polars_parquet::parquet::read::deserialize_page_index;
+use polars_parquet::read::statistics::deserialize_page_statistics;
+
+use super::statistics::StatisticsColumns;
+use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
+
+/// Location of the page index of a projected column in a row group.
+struct PageIndexLocation {
+    /// Index into the row group slice.
+    row_group_idx: usize,
+    /// Index into the projected fields.
+    projection_idx: usize,
+    column_index: Range<usize>,
+    offset_index: Range<usize>,
+}
+
+/// Evaluates the skip batch predicate against the page index (`ColumnIndex` / `OffsetIndex`) of
+/// every live column in `row_group_slice` and returns, for each row group, a mask of the rows that
+/// can match the predicate. Rows are only masked out at data-page granularity.
+///
+/// The mask is `None` for row groups where every row can match. Row groups that are already set
+/// in `skip_mask` are not evaluated.
+#[allow(clippy::too_many_arguments)]
+pub(super) async fn calculate_row_group_page_index_row_masks(
+    row_group_slice: Range<usize>,
+    use_statistics: bool,
+    predicate: Option<&ScanIOPredicate>,
+    metadata: &Arc<FileMetadata>,
+    projected_arrow_fields: Arc<[ArrowFieldProjection]>,
+    row_index_name: Option<PlSmallStr>,
+    byte_source: &DynByteSource,
+    skip_mask: Option<&Bitmap>,
+    verbose: bool,
+) -> PolarsResult<Option<Vec<Option<Bitmap>>>> {
+    if !use_statistics {
+        return Ok(None);
+    }
+
+    let Some(predicate) = predicate else {
+        return Ok(None);
+    };
+
+    let Some(sbp) = predicate.skip_batch_predicate.as_ref() else {
+        return Ok(None);
+    };
+
+    let row_groups = &metadata.row_groups[row_group_slice.clone()];
+    let mut locations = Vec::new();
+
+    for (row_group_idx, rg) in row_groups.iter().enumerate() {
+        if skip_mask.is_some_and(|m| m.get_bit(row_group_idx)) {
+            continue;
+        }
+
+        for (projection_idx, projection) in projected_arrow_fields.iter().enumerate() {
+            let arrow_field = projection.arrow_field();
+
+            if !predicate.live_columns.contains(projection.output_name())
+                || arrow_field.dtype().is_nested()
+            {
+                continue;
+            }
+
+            let Some(mut columns) = rg.columns_under_root_iter(&arrow_field.name) else {
+                continue;
+            };
+            let (Some(column), None) = (columns.next(), columns.next()) else {
+                continue;
+            };
+            // The page index of encrypted columns consists of encrypted modules.
+            if column.is_encrypted() {
+                continue;
+            }
+            let (
+                Some(column_index_offset),
+                Some(column_index_length),
+                Some(offset_index_offset),
+                Some(offset_index_length),
+            ) = (
+                column.column_index_offset(),
+                column.column_index_length(),
+                column.offset_index_offset(),
+                column.offset_index_length(),
+            )
+            else {
+                continue;
+            };
+
+            let column_index_offset = column_index_offset as usize;
+            let offset_index_offset = offset_index_offset as usize;
+
+            locations.push(PageIndexLocation {
+                row_group_idx,
+                projection_idx,
+                column_index: column_index_offset
+                    ..column_index_offset + column_index_length as usize,
+                offset_index: offset_index_offset
+                    ..offset_index_offset + offset_index_length as usize,
+            });
+        }
+    }
+
+    if locations.is_empty() {
+        return Ok(None);
+    }
+
+    let mut ranges = locations
+        .iter()
+        .flat_map(|l| [l.column_index.clone(), l.offset_index.clone()])
+        .collect::<Vec<_>>();
+    let bytes_map = byte_source.get_ranges(&mut ranges).await?;
+
+    let sbp = sbp.clone();
+    let num_row_groups = row_groups.len();
+    let metadata = metadata.clone();
+    let live_columns = predicate.live_columns.clone();
+
+    // Note: We are spawning here onto the computational async runtime because the caller is being run
+    // on a tokio async thread.
+    let row_masks = executor::spawn(TaskPriority::High, async move {
+        let row_groups = &metadata.row_groups[row_group_slice];
+        let mut row_masks: Vec<Option<Bitmap>> = vec![None; row_groups.len()];
+
+        for location in locations {
+            let rg = &row_groups[location.row_group_idx];
+            let num_rows = rg.num_rows();
+            let projection = &projected_arrow_fields[location.projection_idx];
+            let arrow_field = projection.arrow_field();
+            let column = rg
+                .columns_under_root_iter(&arrow_field.name)
+                .unwrap()
+                .next()
+                .unwrap();
+
+            let column_index = bytes_map.get(&location.column_index.start).unwrap();
+            let offset_index = bytes_map.get(&location.offset_index.start).unwrap();
+            let page_index = deserialize_page_index(
+                &column_index[..location.column_index.len()],
+                &offset_index[..location.offset_index.len()],
+            )?;
+
+            let page_row_ranges = page_index.page_row_ranges(num_rows).collect::<Vec<_>>();
+            let num_pages = page_row_ranges.len();
+
+            // Don't trust an index that doesn't cover the row group.
+            if page_row_ranges.first().is_none_or(|r| r.start != 0)
+                || page_row_ranges.iter().any(|r| r.start > r.end)
+            {
+                continue;
+            }
+
+            let Some(statistics) =
+                deserialize_page_statistics(arrow_field, column, &page_index, num_rows)?
+            else {
+                continue;
+            };
+            let mut statistics = Some(statistics);
+
+            let lengths: Vec<IdxSize> =
+                page_row_ranges.iter().map(|r| r.len() as IdxSize).collect();
+
+            let mut columns = Vec::with_capacity(1 + live_columns.len() * 3);
+            columns.push(Column::new("len".into(), lengths));
+
+            // Only the statistics of this column are known at the granularity of its pages.
+            for (i, other) in projected_arrow_fields.iter().enumerate() {
+                let c = other.output_name();
+
+                if !live_columns.contains(c) {
+                    continue;
+                }
+
+                let mut statistics = if i == location.projection_idx {
+                    StatisticsColumns::from_arrow_statistics(
+                        statistics.take().unwrap(),
+                        arrow_field,
+                    )?
+                } else {
+                    StatisticsColumns::new_null(
+                        &DataType::from_arrow_field(other.arrow_field()),
+                        num_pages,
+                    )
+                };
+
+                statistics.min = other.apply_transform(statistics.min)?;
+                statistics.max = other.apply_transform(statistics.max)?;
+
+                let statistics = statistics.with_base_column_name(c);
+
+                columns.extend([statistics.min, statistics.max, statistics.null_count]);
+            }
+
+            if let Some(row_index_name) = &row_index_name {
+                let statistics = StatisticsColumns::new_null(&DataType::IDX_DTYPE, num_pages)
+                    .with_base_column_name(row_index_name);
+
+                columns.extend([statistics.min, statistics.max, statistics.null_count]);
+            }
+
+            let statistics_df = DataFrame::new(num_pages, columns)?;
+            let skip_page_mask = sbp.evaluate_with_stat_df(&statistics_df)?;
+
+            if skip_page_mask.set_bits() == 0 {
+                continue;
+            }
+
+            let mut column_row_mask = MutableBitmap::with_capacity(num_rows);
+            for (range, skip) in page_row_ranges.iter().zip(skip_page_mask.iter()) {
+                column_row_mask.extend_constant(range.len(), !skip);
+            }
+            let column_row_mask = column_row_mask.freeze();
+
+            let row_mask = &mut row_masks[location.row_group_idx];
+            *row_mask = Some(match row_mask.take() {
+                Some(row_mask) => &row_mask & &column_row_mask,
+                None => column_row_mask,
+            });
+        }
+
+        PolarsResult::Ok(row_masks)
+    })
+    .await?;
+
+    if verbose {
+        let (num_rows_read, num_rows_total) = row_masks
+            .iter()
+            .zip(row_groups)
+            .enumerate()
+            .filter(|(i, _)| !skip_mask.is_some_and(|m| m.get_bit(*i)))
+            .fold((0, 0), |(read, total), (_, (row_mask, rg))| {
+                let num_rows = rg.num_rows();
+                let num_rows_read = row_mask.as_ref().map_or(num_rows, |m| m.set_bits());
+                (read + num_rows_read, total + num_rows)
+            });
+
+        eprintln!(
+            "[ParquetFileReader]: Page index pushdown: \
+            reading {} / {} rows of {} row groups",
+            num_rows_read, num_rows_total, num_row_groups,
+        );
+    }
+
+    Ok(Some(row_masks))
+}
@@ -1,3 +1,4 @@
+use std::collections::VecDeque;
 use std::ops::Range;
 use std::sync::Arc;
 
@@ -5,11 +6,12 @@ use polars_buffer::Buffer;
 use polars_core::prelude::PlHashMap;
 use polars_core::runtime::ASYNC;
 use polars_core::series::IsSorted;
-use polars_core::utils::arrow::bitmap::Bitmap;
+use polars_core::utils::arrow::bitmap::{Bitmap, BitmapBuilder};
 use polars_error::PolarsResult;
 use polars_io::predicates::ScanIOPredicate;
 use polars_io::prelude::{FileMetadata, create_sorting_map};
 use polars_io::utils::byte_source::{ByteSource, DynByteSource};
+use polars_parquet::parquet::read::deserialize_offset_index;
 use polars_parquet::read::RowGroupMetadata;
 use polars_utils::pl_str::PlSmallStr;
 
@@ -23,6 +25,19 @@ pub(super) struct RowGroupData {
     pub(super) slice: Option<(usize, usize)>,
     pub(super) row_group_metadata: RowGroupMetadata,
     pub(super) sorting_map: Vec<(usize, IsSorted)>,
+    /// Rows that can match the predicate according to the page index, `None` if all rows can.
+    pub(super) row_mask: Option<Bitmap>,
+    /// Column chunks of which only the data pages with rows in `row_mask` were fetched, keyed by
+    /// the start of their byte range.
+    pub(super) sparse_column_chunks: PlHashMap<usize, SparseColumnChunk>,
+}
+
+/// A column chunk of which only the data pages with rows in the page index row mask were fetched.
+pub(super) struct SparseColumnChunk {
+    /// The dictionary page of the column chunk, if any, followed by the fetched data pages.
+    pub(super) bytes: Buffer<u8>,
+    /// The part of the row mask that covers the rows of the fetched data pages.
+    pub(super) row_mask: Bitmap,
 }
 
 pub(super) struct RowGroupDataFetcher {
@@ -37,6 +52,8 @@ pub(super) struct RowGroupDataFetcher {
 
     pub(super) row_group_slice: Range<usize>,
     pub(super) row_group_mask: Option<Bitmap>,
+    /// Page index row masks of the row groups in `row_group_slice`. May be empty.
+    pub(super) page_row_masks: VecDeque<Option<Bitmap>>,
 
     pub(super) row_offset: usize,
 }
@@ -109,6 +126,8 @@ impl RowGroupDataFetcher {
                 None
             };
 
+            let row_mask = self.page_row_masks.pop_front().flatten();
+
             if let Some(row_group_mask) = self.row_group_mask.as_mut() {
                 let do_skip = row_group_mask.get_bit(0);
                 row_group_mask.slice(1, self.row_group_slice.len());
@@ -126,6 +145,7 @@ impl RowGroupDataFetcher {
 
             let handle = ASYNC.spawn(async move {
                 let row_group_metadata = &metadata.row_groups[idx];
+                let mut sparse_column_chunks = PlHashMap::new();
                 let fetched_bytes =
                     if let DynByteSource::Buffer(mem_slice) = current_byte_source.as_ref() {
                         // Skip byte range calculation for `no_prefetch`.
@@ -157,19 +177,55 @@ impl RowGroupDataFetcher {
                             offset: 0,
                             buffer: mem_slice,
                         }
-                    } else if !is_full_projection {
+                    } else if !is_full_projection || row_mask.is_some() {
                         let mut ranges = get_row_group_byte_ranges_for_projection(
                             row_group_metadata,
                             &mut projection.iter().map(|x| &x.arrow_field().name),
                         )
                         .collect::<Vec<_>>();
 
+                        // The row mask is dropped by the decoder if there is a slice.
+                        let sparse_plans = match &row_mask {
+                            Some(row_mask) if slice.is_none() => {
+                                plan_sparse_column_chunks(
+                                    current_byte_source.as_ref(),
+                                    row_group_metadata,
+                                    &projection,
+                                    row_mask,
+                                )
+                                .await?
+                            },
+                            _ => vec![],
+                        };
+
+                        for plan in &sparse_plans {
+                            ranges.retain(|r| r.start != plan.column_start);
+                            ranges.extend(plan.fetch_ranges.iter().cloned());
+                        }
+
                         let n_ranges = ranges.len();
 
-                        let bytes_map = current_byte_source.get_ranges(&mut ranges).await?;
+                        let mut bytes_map = current_byte_source.get_ranges(&mut ranges).await?;
 
                         assert_eq!(bytes_map.len(), n_ranges);
 
+                        for plan in sparse_plans {
+                            let mut bytes =
+                                Vec::with_capacity(plan.fetch_ranges.iter().map(|r| r.len()).sum());
+                            for range in &plan.fetch_ranges {
+                                let fetched = bytes_map.remove(&range.start).unwrap();
+                                bytes.extend_from_slice(&fetched[..range.len()]);
+                            }
+
+                            sparse_column_chunks.insert(
+                                plan.column_start,
+                                SparseColumnChunk {
+                                    bytes: Buffer::from(bytes),
+                                    row_mask: plan.row_mask,
+                                },
+                            );
+                        }
+
                         FetchedBytes::BytesMap(bytes_map)
                     } else {
                         // We still prefer `get_ranges()` over a single `get_range()` for downloading
@@ -198,6 +254,8 @@ impl RowGroupDataFetcher {
                     // @TODO: Remove clone
                     row_group_metadata: row_group_metadata.clone(),
                     sorting_map,
+                    row_mask,
+                    sparse_column_chunks,
                 })
             });
 
@@ -209,6 +267,114 @@ impl RowGroupDataFetcher {
     }
 }
 
+/// The byte ranges to fetch for a column chunk of which only some data pages are needed.
+struct SparseColumnChunkPlan {
+    column_start: usize,
+    /// The dictionary page, followed by the needed data pages with adjacent pages merged.
+    fetch_ranges: Vec<Range<usize>>,
+    row_mask: Bitmap,
+}
+
+/// Uses the offset index of the projected flat columns to determine which of their data pages
+/// have rows in `row_mask`. Columns for which every page is needed, or that have no usable offset
+/// index, are left out and fetched in full.
+async fn plan_sparse_column_chunks(
+    byte_source: &DynByteSource,
+    row_group_metadata: &RowGroupMetadata,
+    projection: &[ArrowFieldProjection],
+    row_mask: &Bitmap,
+) -> PolarsResult<Vec<SparseColumnChunkPlan>> {
+    let num_rows = row_group_metadata.num_rows();
+
+    let columns = projection
+        .iter()
+        .filter(|p| !p.arrow_field().dtype().is_nested())
+        .filter_map(|p| {
+            let mut columns = row_group_metadata.columns_under_root_iter(&p.arrow_field().name)?;
+            let (Some(column), None) = (columns.next(), columns.next()) else {
+                return None;
+            };
+            // The offset index and pages of encrypted columns are encrypted modules.
+            if column.is_encrypted() {
+                return None;
+            }
+            let offset = column.offset_index_offset()? as usize;
+            let length = column.offset_index_length()? as usize;
+            Some((column, offset..offset + length))
+        })
+        .collect::<Vec<_>>();
+
+    if columns.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut ranges = columns.iter().map(|(_, r)| r.clone()).collect::<Vec<_>>();
+    let bytes_map = byte_source.get_ranges(&mut ranges).await?;
+
+    let mut plans = Vec::with_capacity(columns.len());
+
+    for (column, offset_index_range) in columns {
+        let offset_index = bytes_map.get(&offset_index_range.start).unwrap();
+        let locations = deserialize_offset_index(&offset_index[..offset_index_range.len()])?;
+
+        let byte_range = column.byte_range();
+        let column_range = byte_range.start as usize..byte_range.end as usize;
+
+        // Don't trust an index that doesn't cover the row group or lies outside the column chunk.
+        if locations.first().is_none_or(|l| l.first_row_index != 0)
+            || locations
+                .last()
+                .is_some_and(|l| l.first_row_index > num_rows)
+            || locations.iter().any(|l| {
+                l.offset < column_range.start
+                    || l.offset + l.compressed_page_size > column_range.end
+            })
+        {
+            continue;
+        }
+
+        let mut fetch_ranges = Vec::new();
+        let dictionary_range = column_range.start..locations[0].offset;
+        if !dictionary_range.is_empty() {
+            fetch_ranges.push(dictionary_range);
+        }
+
+        let mut compacted_row_mask = BitmapBuilder::new();
+        let mut num_skipped_pages = 0;
+
+        for (i, location) in locations.iter().enumerate() {
+            let row_end = locations.get(i + 1).map_or(num_rows, |l| l.first_row_index);
+            let page_rows = location.first_row_index..row_end;
+            let page_row_mask = row_mask.clone().sliced(page_rows.start, page_rows.len());
+
+            if page_row_mask.set_bits() == 0 {
+                num_skipped_pages += 1;
+                continue;
+            }
+
+            compacted_row_mask.extend_from_bitmap(&page_row_mask);
+
+            let page_range = location.offset..location.offset + location.compressed_page_size;
+            match fetch_ranges.last_mut() {
+                Some(last) if last.end == page_range.start => last.end = page_range.end,
+                _ => fetch_ranges.push(page_range),
+            }
+        }
+
+        if num_skipped_pages == 0 || num_skipped_pages == locations.len() {
+            continue;
+        }
+
+        plans.push(SparseColumnChunkPlan {
+            column_start: column_range.start,
+            fetch_ranges,
+            row_mask: compacted_row_mask.freeze(),
+        });
+    }
+
+    Ok(plans)
+}
+
 pub(super) enum FetchedBytes {
     Buffer { buffer: Buffer<u8>, offset: usize },
     BytesMap(PlHashMap<usize, Buffer<u8>>),
@@ -46,8 +46,16 @@ impl RowGroupDecoder {
             slice.0 == 0 && slice.1 >= row_group_data.row_group_metadata.num_rows()
         });
 
+        // A slice takes precedence over the page index row mask.
+        if row_group_data.slice.is_some() {
+            row_group_data.row_mask = None;
+        }
+
+        // Prefiltering decodes every page of the predicate columns, so it is not used when the
+        // page index allows skipping pages.
         if self.use_prefiltered.is_some()
             && row_group_data.slice.is_none()
+            && row_group_data.row_mask.is_none()
             && !self.predicate_field_indices.is_empty()
         {
             self.row_group_data_to_df_prefiltered(row_group_data).await
@@ -74,21 +82,29 @@ impl RowGroupDecoder {
         assert!(slice_range.end <= row_group_data.row_group_metadata.num_rows());
 
         if let Some(s) = self.materialize_row_index(row_group_data.as_ref(), slice_range.clone())? {
+            let s = match &row_group_data.row_mask {
+                Some(row_mask) => s.filter(&BooleanChunked::from_bitmap(
+                    PlSmallStr::EMPTY,
+                    row_mask.clone(),
+                ))?,
+                None => s,
+            };
             out_columns.push(s);
         }
 
+        // Decoding with a mask skips the pages that have no rows in the mask.
+        let filter = match &row_group_data.row_mask {
+            Some(row_mask) => Filter::Mask(row_mask.clone()),
+            None => Filter::Range(slice_range.clone()),
+        };
+        let projection_height = filter.num_rows(row_group_data.row_group_metadata.num_rows());
+
         let mut decoded_cols = Vec::with_capacity(row_group_data.row_group_metadata.n_columns());
-        self.decode_projected_columns(
-            &mut decoded_cols,
-            &row_group_data,
-            Some(polars_parquet::read::Filter::Range(slice_range.clone())),
-        )
-        .await?;
+        self.decode_projected_columns(&mut decoded_cols, &row_group_data, Some(filter))
+            .await?;
 
         drop(row_group_data);
 
-        let projection_height = slice_range.len();
-
         out_columns.extend(decoded_cols);
 
         let df = unsafe { DataFrame::new_unchecked(projection_height, out_columns) };
@@ -251,34 +267,66 @@ fn decode_column(
         ));
     };
 
-    let columns_to_deserialize = iter
-        .map(|col_md| {
-            let byte_range = col_md.byte_range();
+    let columns = iter.collect::<Vec<_>>();
 
-            (
-                col_md,
-                row_group_data
-                    .fetched_bytes
-                    .get_range(byte_range.start as usize..byte_range.end as usize),
-            )
-        })
-        .collect::<Vec<_>>();
+    // Flat columns of which only the pages with rows in the mask were fetched are decoded with
+    // the part of the mask that covers those pages.
+    let sparse_column_chunk = match (&filter, columns.as_slice()) {
+        (Some(Filter::Mask(_)), [col_md]) => row_group_data
+            .sparse_column_chunks
+            .get(&(col_md.byte_range().start as usize)),
+        _ => None,
+    };
 
     let skip_num_rows_check = matches!(filter, Some(Filter::Predicate(_)));
 
-    let (arrays, pred_true_mask) = polars_io::prelude::_internal::to_deserializer(
-        columns_to_deserialize,
-        arrow_field.clone(),
-        filter,
-    )?;
+    // Nested columns are decoded in full and filtered afterwards, same as in
+    // `decode_column_prefiltered`.
+    let (filter, post_filter_mask) = match filter {
+        Some(Filter::Mask(mask)) if arrow_field.dtype.is_nested() => (None, Some(mask)),
+        filter => (filter, None),
+    };
 
-    if !skip_num_rows_check {
-        let num_rows = arrays.iter().map(|array| array.len()).sum::<usize>();
-        assert_eq!(num_rows, expected_num_rows);
-    }
+    let (arrays, pred_true_mask) = if let Some(sparse) = sparse_column_chunk {
+        polars_io::prelude::_internal::to_sparse_deserializer(
+            columns[0],
+            sparse.bytes.clone(),
+            sparse.row_mask.len(),
+            arrow_field.clone(),
+            Some(Filter::Mask(sparse.row_mask.clone())),
+        )?
+    } else {
+        let columns_to_deserialize = columns
+            .into_iter()
+            .map(|col_md| {
+                let byte_range = col_md.byte_range();
+
+                (
+                    col_md,
+                    row_group_data
+                        .fetched_bytes
+                        .get_range(byte_range.start as usize..byte_range.end as usize),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        polars_io::prelude::_internal::to_deserializer(
+            columns_to_deserialize,
+            arrow_field.clone(),
+            filter,
+        )?
+    };
 
     let mut series = Series::try_from((arrow_field, arrays))?;
 
+    if let Some(mask) = post_filter_mask {
+        series = series.filter(&BooleanChunked::from_bitmap(PlSmallStr::EMPTY, mask))?;
+    }
+
+    if !skip_num_rows_check {
+        assert_eq!(series.len(), expected_num_rows);
+    }
+
     if let Some(col_idxs) = row_group_data
         .row_group_metadata
         .columns_idxs_under_root_iter(&arrow_field.name)
@@ -15,14 +15,14 @@ use polars_utils::format_pl_smallstr;
 
 use crate::nodes::io_sources::parquet::projection::ArrowFieldProjection;
 
-struct StatisticsColumns {
-    min: Column,
-    max: Column,
-    null_count: Column,
+pub(super) struct StatisticsColumns {
+    pub(super) min: Column,
+    pub(super) max: Column,
+    pub(super) null_count: Column,
 }
 
 impl StatisticsColumns {
-    fn new_null(dtype: &DataType, height: usize) -> Self {
+    pub(super) fn new_null(dtype: &DataType, height: usize) -> Self {
         Self {
             min: Column::full_null(PlSmallStr::EMPTY, height, dtype),
             max: Column::full_null(PlSmallStr::EMPTY, height, dtype),
@@ -30,7 +30,7 @@ impl StatisticsColumns {
         }
     }
 
-    fn from_arrow_statistics(
+    pub(super) fn from_arrow_statistics(
         statistics: ArrowColumnStatisticsArrays,
         field: &ArrowField,
     ) -> PolarsResult<Self> {
@@ -60,7 +60,7 @@ impl StatisticsColumns {
         })
     }
 
-    fn with_base_column_name(self, base_column_name: &str) -> Self {
+    pub(super) fn with_base_column_name(self, base_column_name: &str) -> Self {
         let b = base_column_name;
 
         let min = self.min.with_name(format_pl_smallstr!("{b}_min"));
@@ -0,0 +1,60 @@
+use polars_core::prelude::{Column, DataFrame};
+use polars_error::PolarsResult;
+use polars_io::utils::rejects::RejectedRow;
+use polars_plan::prelude::PlanCallback;
+use polars_utils::pl_str::PlSmallStr;
+
+/// Reports rows that a reader diverted instead of failing the scan.
+#[derive(Clone)]
+pub struct RejectsReporter {
+    pub callback: PlanCallback<DataFrame, ()>,
+    pub path: PlSmallStr,
+    pub eol_char: u8,
+}
+
+impl RejectsReporter {
+    /// Passes the rejected rows of `chunk` to the callback.
+    ///
+    /// `line_offset` is the number of lines in the file before `chunk`, and `rejected` must be
+    /// ordered by position.
+    pub fn report(
+        &self,
+        chunk: &[u8],
+        line_offset: usize,
+        rejected: Vec<RejectedRow>,
+    ) -> PolarsResult<()> {
+        if rejected.is_empty() {
+            return Ok(());
+        }
+
+        let height = rejected.len();
+        let mut lines = Vec::with_capacity(height);
+        let mut raw = Vec::with_capacity(height);
+        let mut errors = Vec::with_capacity(height);
+
+        let mut line = line_offset;
+        let mut position = 0;
+
+        for RejectedRow { range, error } in rejected {
+            debug_assert!(range.start >= position);
+            line += memchr::memchr_iter(self.eol_char, &chunk[position..range.start]).count();
+            position = range.start;
+
+            lines.push((line + 1) as u64);
+            raw.push(String::from_utf8_lossy(&chunk[range]).into_owned());
+            errors.push(error.to_string());
+        }
+
+        let df = DataFrame::new(
+            height,
+            vec![
+                Column::new("path".into(), vec![self.path.as_str(); height]),
+                Column::new("line".into(), lines),
+                Column::new("raw".into(), raw),
+                Column::new("error".into(), errors),
+            ],
+        )?;
+
+        self.callback.call(df)
+    }
+}
@@ -4,6 +4,7 @@ use arrow::array::builder::ShareStrategy;
 use polars_core::frame::builder::DataFrameBuilder;
 use polars_core::schema::Schema;
 use polars_error::polars_warn;
+use polars_ooc::SpillContext;
 use polars_ops::frame::{JoinArgs, JoinBuildSide, MaintainOrderJoin};
 use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
@@ -134,6 +135,13 @@ impl ComputeNode for CrossJoinNode {
         Ok(())
     }
 
+    fn spill_contexts(&self) -> Vec<Arc<dyn SpillContext>> {
+        match &self.state {
+            CrossJoinState::Build(sink_node) => sink_node.spill_contexts(),
+            CrossJoinState::Probe(_) | CrossJoinState::Done => Vec::new(),
+        }
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
@@ -1,9 +1,11 @@
+use std::borrow::Cow;
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use arrow::array::builder::ShareStrategy;
+use parking_lot::Mutex;
 use polars_async::executor;
 use polars_async::primitives::wait_group::WaitGroup;
 use polars_core::config;
@@ -13,7 +15,7 @@ use polars_core::runtime::{ASYNC, RAYON};
 use polars_core::schema::{Schema, SchemaExt};
 use polars_expr::hash_keys::HashKeys;
 use polars_expr::idx_table::{IdxTable, new_idx_table};
-use polars_ooc::{MostRecentSpillContext, SpillFrame};
+use polars_ooc::{MostRecentSpillContext, SpillContext, SpillFrame};
 use polars_ops::frame::{JoinArgs, JoinBuildSide, JoinType, MaintainOrderJoin};
 use polars_ops::series::coalesce_columns;
 use polars_utils::cardinality_sketch::CardinalitySketch;
@@ -32,6 +34,16 @@ use crate::morsel::{SourceToken, get_ideal_morsel_size};
 use crate::nodes::compute_node_prelude::*;
 use crate::nodes::in_memory_source::InMemorySourceNode;
 
+// Seed of the partitioner used to split both sides of a grace hash join. It must
+// differ from the seed of the partitioner used for the hash tables, otherwise
+// all keys of a grace partition end up in the same hash table partition. Grace
+// partitions which are split again use the seed plus their depth.
+const GRACE_PARTITIONER_SEED: u64 = 1;
+
+// The maximum number of times a grace partition is split into smaller ones.
+// Splitting doesn't help if a partition is large due to a few frequent keys.
+const MAX_GRACE_DEPTH: usize = 3;
+
 struct EquiJoinParams {
     left_is_build: Option<bool>,
     preserve_order_build: bool,
@@ -68,6 +80,57 @@ impl EquiJoinParams {
             self.args.how == JoinType::Left || self.args.how == JoinType::Full
         }
     }
+
+    /// How the build side may switch to a grace hash join, or None if we may
+    /// not fall back to a grace hash join.
+    fn grace_switch(&self) -> Option<GraceSwitch> {
+        // Joining the partitions one at a time doesn't preserve any order.
+        if self.preserve_order_build || self.preserve_order_probe {
+            return None;
+        }
+        let num_partitions = polars_config::config().join_grace_partitions();
+        (num_partitions > 0).then(|| {
+            GraceSwitch::new(
+                HashPartitioner::new(num_partitions as usize, GRACE_PARTITIONER_SEED),
+                polars_config::config().force_grace_join(),
+            )
+        })
+    }
+
+    fn build_payload_selector(&self) -> &[Option<PlSmallStr>] {
+        if self.left_is_build.unwrap() {
+            &self.left_payload_select
+        } else {
+            &self.right_payload_select
+        }
+    }
+
+    fn build_payload_schema(&self) -> &Arc<Schema> {
+        if self.left_is_build.unwrap() {
+            &self.left_payload_schema
+        } else {
+            &self.right_payload_schema
+        }
+    }
+}
+
+/// The partitioner used to split the build side into grace partitions once it
+/// no longer fits in memory, and whether we have done so.
+struct GraceSwitch {
+    partitioner: HashPartitioner,
+    active: RelaxedCell<bool>,
+    /// Switch right away rather than under memory pressure.
+    force: bool,
+}
+
+impl GraceSwitch {
+    fn new(partitioner: HashPartitioner, force: bool) -> Self {
+        Self {
+            partitioner,
+            active: RelaxedCell::from(false),
+            force,
+        }
+    }
 }
 
 /// A payload selector contains for each column whether that column should be
@@ -194,16 +257,68 @@ async fn select_keys(
     ))
 }
 
-fn select_payload(df: DataFrame, selector: &[Option<PlSmallStr>]) -> DataFrame {
-    let height = df.height();
+fn select_payload(df: &DataFrame, selector: &[Option<PlSmallStr>]) -> DataFrame {
     let new_cols = df
-        .into_columns()
-        .into_iter()
+        .columns()
+        .iter()
         .zip(selector)
-        .filter_map(|(c, name)| Some(c.with_name(name.clone()?)))
+        .filter_map(|(c, name)| Some(c.clone().with_name(name.clone()?)))
         .collect();
 
-    unsafe { DataFrame::new_unchecked(height, new_cols) }
+    unsafe { DataFrame::new_unchecked(df.height(), new_cols) }
+}
+
+/// Computes the frame stored for a morsel sunk into the build side, along with
+/// its hashed keys. The stored frame is the payload, followed by the key
+/// columns if `keep_keys` is set, such that it can be split into grace
+/// partitions and joined again without the rest of the morsel.
+///
+/// If `input_has_keys` is set the morsel is such a stored frame already.
+async fn build_frame_and_keys(
+    df: DataFrame,
+    input_has_keys: bool,
+    keep_keys: bool,
+    key_selectors: &[StreamExpr],
+    params: &EquiJoinParams,
+    state: &ExecutionState,
+) -> PolarsResult<(DataFrame, HashKeys)> {
+    let height = df.height();
+    let (mut frame, key_columns) = if input_has_keys {
+        let (payload, keys) = df.columns().split_at(params.build_payload_schema().len());
+        let payload = unsafe { DataFrame::new_unchecked(height, payload.to_vec()) };
+        (payload, keys.to_vec())
+    } else {
+        let mut key_columns = Vec::with_capacity(key_selectors.len());
+        for (i, selector) in key_selectors.iter().enumerate() {
+            let key = selector.evaluate(&df, state).await?.into_column();
+            key_columns.push(key.with_name(format_pl_smallstr!("__POLARS_GRACE_KEYCOL_{i}")));
+        }
+        let payload = select_payload(&df, params.build_payload_selector());
+        (payload, key_columns)
+    };
+
+    let keys = unsafe { DataFrame::new_unchecked_with_broadcast(height, key_columns)? };
+    let hash_keys = HashKeys::from_df(
+        &keys,
+        params.random_state.clone(),
+        params.args.nulls_equal,
+        false,
+    );
+    if keep_keys {
+        unsafe { frame.hstack_mut_unchecked(keys.columns()) };
+    }
+    Ok((frame, hash_keys))
+}
+
+/// The payload of a frame stored by the build side, without the key columns
+/// which follow it if the frame may have to be split into grace partitions.
+fn build_payload(df: &DataFrame, payload_schema: &Schema) -> Cow<'_, DataFrame> {
+    if df.width() == payload_schema.len() {
+        Cow::Borrowed(df)
+    } else {
+        let payload = df.columns()[..payload_schema.len()].to_vec();
+        Cow::Owned(unsafe { DataFrame::new_unchecked(df.height(), payload) })
+    }
 }
 
 fn estimate_cardinality(
@@ -385,53 +500,26 @@ impl SampleState {
             "equi-join-left-sample".into(),
             core::mem::take(&mut self.left),
             MorselSeq::default(),
+            &state.query_memory,
         );
         let mut sampled_probe_morsels = BufferedStream::new(
             "equi-join-right-sample".into(),
             core::mem::take(&mut self.right),
             MorselSeq::default(),
+            &state.query_memory,
         );
         if !left_is_build {
             core::mem::swap(&mut sampled_build_morsels, &mut sampled_probe_morsels);
         }
 
-        let partitioner = HashPartitioner::new(state.num_pipelines, 0);
         let mut build_state = BuildState::new(
             state.num_pipelines,
             state.num_pipelines,
             sampled_probe_morsels,
+            params.grace_switch(),
+            false,
         );
-
-        // Simulate the sample build morsels flowing into the build side.
-        if !sampled_build_morsels.is_empty() {
-            executor::task_scope(|scope| {
-                let mut join_handles = Vec::new();
-                let receivers = sampled_build_morsels
-                    .reinsert(state.num_pipelines, None, scope, &mut join_handles)
-                    .unwrap();
-
-                for (local_builder, recv) in build_state.local_builders.iter_mut().zip(receivers) {
-                    join_handles.push(scope.spawn_task(
-                        TaskPriority::High,
-                        BuildState::partition_and_sink(
-                            recv,
-                            local_builder,
-                            partitioner.clone(),
-                            params,
-                            state,
-                            spill_ctx,
-                        ),
-                    ));
-                }
-
-                ASYNC.block_in_place_on(async move {
-                    for handle in join_handles {
-                        handle.await?;
-                    }
-                    PolarsResult::Ok(())
-                })
-            })?;
-        }
+        build_state.sink_buffered(&sampled_build_morsels, params, state, spill_ctx)?;
 
         Ok(Some(build_state))
     }
@@ -440,8 +528,13 @@ impl SampleState {
 #[derive(Default)]
 struct LocalBuilder {
     // The complete list of morsels and their computed hashes seen by this builder.
+    // Only the payload is kept, followed by the key columns if we may switch to
+    // a grace hash join, see `build_frame_and_keys`.
     morsels: Vec<(MorselSeq, SpillFrame, HashKeys)>,
 
+    // The morsels seen by this builder after switching to a grace hash join.
+    grace: LocalGracePartitions,
+
     // A cardinality sketch per partition for the keys seen by this builder.
     sketch_per_p: Vec<CardinalitySketch>,
 
@@ -456,6 +549,13 @@ struct LocalBuilder {
 struct BuildState {
     local_builders: Vec<LocalBuilder>,
     sampled_probe_morsels: BufferedStream,
+
+    // Set if we may switch to a grace hash join.
+    grace: Option<GraceSwitch>,
+
+    // Whether the sunk morsels are frames of a grace partition, which carry
+    // their key columns already.
+    input_has_keys: bool,
 }
 
 impl BuildState {
@@ -463,10 +563,14 @@ impl BuildState {
         num_pipelines: usize,
         num_partitions: usize,
         sampled_probe_morsels: BufferedStream,
+        grace: Option<GraceSwitch>,
+        input_has_keys: bool,
     ) -> Self {
+        let num_grace_partitions = grace.as_ref().map_or(0, |g| g.partitioner.num_partitions());
         let local_builders = (0..num_pipelines)
             .map(|_| LocalBuilder {
                 morsels: Vec::new(),
+                grace: LocalGracePartitions::new(num_grace_partitions),
                 sketch_per_p: vec![CardinalitySketch::default(); num_partitions],
                 morsel_idxs_values_per_p: vec![Vec::new(); num_partitions],
                 morsel_idxs_offsets_per_p: vec![0; num_partitions],
@@ -475,40 +579,106 @@ impl BuildState {
         Self {
             local_builders,
             sampled_probe_morsels,
+            grace,
+            input_has_keys,
         }
     }
 
+    /// Simulates the given buffered morsels flowing into the build side.
+    fn sink_buffered(
+        &mut self,
+        morsels: &BufferedStream,
+        params: &EquiJoinParams,
+        state: &StreamingExecutionState,
+        spill_ctx: &MostRecentSpillContext,
+    ) -> PolarsResult<()> {
+        if morsels.is_empty() {
+            return Ok(());
+        }
+
+        let partitioner = HashPartitioner::new(state.num_pipelines, 0);
+        executor::task_scope(|scope| {
+            let mut join_handles = Vec::new();
+            let receivers = morsels
+                .reinsert(state.num_pipelines, None, scope, &mut join_handles)
+                .unwrap();
+
+            for (local_builder, recv) in self.local_builders.iter_mut().zip(receivers) {
+                join_handles.push(scope.spawn_task(
+                    TaskPriority::High,
+                    BuildState::partition_and_sink(
+                        recv,
+                        local_builder,
+                        partitioner.clone(),
+                        self.grace.as_ref(),
+                        self.input_has_keys,
+                        params,
+                        state,
+                        spill_ctx,
+                    ),
+                ));
+            }
+
+            ASYNC.block_in_place_on(async move {
+                for handle in join_handles {
+                    handle.await?;
+                }
+                PolarsResult::Ok(())
+            })
+        })
+    }
+
     async fn partition_and_sink(
         mut recv: PortReceiver,
         local: &mut LocalBuilder,
         partitioner: HashPartitioner,
+        grace: Option<&GraceSwitch>,
+        input_has_keys: bool,
         params: &EquiJoinParams,
         state: &StreamingExecutionState,
         spill_ctx: &MostRecentSpillContext,
     ) -> PolarsResult<()> {
         let track_unmatchable = params.emit_unmatched_build();
-        let (key_selectors, payload_selector);
-        if params.left_is_build.unwrap() {
-            payload_selector = &params.left_payload_select;
-            key_selectors = &params.left_key_selectors;
+        let key_selectors = if params.left_is_build.unwrap() {
+            &params.left_key_selectors
         } else {
-            payload_selector = &params.right_payload_select;
-            key_selectors = &params.right_key_selectors;
+            &params.right_key_selectors
         };
 
         while let Ok(morsel) = recv.recv().await {
-            // Compute hashed keys and payload. We must rechunk the payload for
-            // later gathers.
-            let hash_keys = select_keys(
-                morsel.df(),
+            // Compute hashed keys. We must rechunk the morsel for later gathers.
+            let seq = morsel.seq();
+            let (mut df, hash_keys) = build_frame_and_keys(
+                morsel.into_df(),
+                input_has_keys,
+                grace.is_some(),
                 key_selectors,
                 params,
                 &state.in_memory_exec_state,
             )
             .await?;
-            let mut payload = select_payload(morsel.df().clone(), payload_selector);
-            payload.rechunk_mut();
 
+            if let Some(grace) = grace {
+                // Once the build side no longer fits in memory we stop building
+                // and split the remainder into grace partitions.
+                if !grace.active.load()
+                    && (grace.force || state.is_over_memory_budget())
+                    && !grace.active.swap(true)
+                    && config::verbose()
+                {
+                    eprintln!("[equi-join]: switching to grace hash join");
+                }
+
+                if grace.active.load() {
+                    local
+                        .grace
+                        .insert(&df, &hash_keys, &grace.partitioner, spill_ctx)
+                        .await;
+                    continue;
+                }
+            }
+
+            df.rechunk_mut();
             hash_keys.gen_idxs_per_partition(
                 &partitioner,
                 &mut local.morsel_idxs_values_per_p,
@@ -519,27 +689,100 @@ impl BuildState {
             local
                 .morsel_idxs_offsets_per_p
                 .extend(local.morsel_idxs_values_per_p.iter().map(|vp| vp.len()));
-            let sf = SpillFrame::new(payload, spill_ctx).await;
-            local.morsels.push((morsel.seq(), sf, hash_keys));
+            let sf = SpillFrame::new(df, spill_ctx).await;
+            local.morsels.push((seq, sf, hash_keys));
         }
         Ok(())
     }
 
-    fn finalize_ordered(&mut self, params: &EquiJoinParams, table: &dyn IdxTable) -> ProbeState {
-        let track_unmatchable = params.emit_unmatched_build();
-        let payload_schema = if params.left_is_build.unwrap() {
-            &params.left_payload_schema
-        } else {
-            &params.right_payload_schema
+    /// Whether we should join the build side partition-wise with a grace hash
+    /// join rather than building a single hash table.
+    fn should_use_grace(&self) -> bool {
+        self.grace.as_ref().is_some_and(|g| g.active.load())
+            || self.grace.is_some()
+                && self.local_builders.iter().any(|l| {
+                    l.morsels
+                        .iter()
+                        .any(|(_seq, sf, _keys)| sf.try_get().is_none())
+                })
+    }
+
+    /// Splits all morsels seen by the build side into grace partitions.
+    fn finalize_grace(
+        &mut self,
+        params: &EquiJoinParams,
+        state: &StreamingExecutionState,
+        spill_ctx: &MostRecentSpillContext,
+    ) -> PolarsResult<GraceState> {
+        let partitioner = self.grace.as_ref().unwrap().partitioner.clone();
+
+        // Morsels which were sunk before switching still have to be split.
+        executor::task_scope(|scope| {
+            let mut join_handles = Vec::new();
+            for local in self.local_builders.iter_mut() {
+                let partitioner = &partitioner;
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    for (_seq, sf, hash_keys) in core::mem::take(&mut local.morsels) {
+                        let df = sf.into_df().await?;
+                        local
+                            .grace
+                            .insert(&df, &hash_keys, partitioner, spill_ctx)
+                            .await;
+                    }
+                    PolarsResult::Ok(())
+                }));
+            }
+
+            ASYNC.block_in_place_on(async move {
+                for handle in join_handles {
+                    handle.await?;
+                }
+                PolarsResult::Ok(())
+            })
+        })?;
+
+        let mut build_per_p: Vec<Vec<SpillFrame>> = (0..partitioner.num_partitions())
+            .map(|_| Vec::new())
+            .collect();
+        for local in &mut self.local_builders {
+            for (p, frames) in local.grace.frames_per_p.iter_mut().enumerate() {
+                build_per_p[p].append(frames);
+            }
+        }
+
+        let mut grace_state = GraceState {
+            local_probes: (0..state.num_pipelines)
+                .map(|_| LocalGracePartitions::new(partitioner.num_partitions()))
+                .collect(),
+            partitioner,
+            build_per_p,
+            pending: Vec::new(),
+            depth: 0,
         };
 
+        // The sampled probe morsels won't flow through a port, split them now.
+        let sampled_probe_morsels = core::mem::take(&mut self.sampled_probe_morsels);
+        grace_state.sink_buffered_probe(&sampled_probe_morsels, params, state, spill_ctx)?;
+        Ok(grace_state)
+    }
+
+    fn finalize_ordered(
+        &mut self,
+        params: &EquiJoinParams,
+        table: &dyn IdxTable,
+    ) -> PolarsResult<ProbeState> {
+        let track_unmatchable = params.emit_unmatched_build();
+        let payload_schema = params.build_payload_schema();
+
         let num_partitions = self.local_builders[0].sketch_per_p.len();
         let local_builders = &self.local_builders;
         let probe_tables: SparseInitVec<ProbeTable> = SparseInitVec::with_capacity(num_partitions);
+        let first_error: Mutex<Option<PolarsError>> = Mutex::new(None);
 
         RAYON.scope(|s| {
             for p in 0..num_partitions {
                 let probe_tables = &probe_tables;
+                let first_error = &first_error;
                 s.spawn(move |_| {
                     // TODO: every thread does an identical linearize, we can do a single parallel one.
                     let mut kmerge = BinaryHeap::with_capacity(local_builders.len());
@@ -584,7 +827,14 @@ impl BuildState {
                             }
 
                             let (_mseq, sf, keys) = l.morsels.get_unchecked(idx_in_l);
-                            let payload = sf.get_blocking();
+                            let df = match sf.get_blocking() {
+                                Ok(df) => df,
+                                Err(e) => {
+                                    first_error.lock().get_or_insert(e);
+                                    return;
+                                },
+                            };
+                            let payload = build_payload(&df, payload_schema);
                             let p_morsel_idxs_start =
                                 l.morsel_idxs_offsets_per_p[idx_in_l * num_partitions + p];
                             let p_morsel_idxs_stop =
@@ -616,21 +866,26 @@ impl BuildState {
             }
         });
 
-        ProbeState {
+        if let Some(e) = first_error.into_inner() {
+            return Err(e);
+        }
+
+        Ok(ProbeState {
             table_per_partition: probe_tables.try_assume_init().ok().unwrap(),
             max_seq_sent: MorselSeq::default(),
             sampled_probe_morsels: core::mem::take(&mut self.sampled_probe_morsels),
             unordered_morsel_seq: AtomicU64::new(0),
-        }
+            unordered_seq_offset: MorselSeq::default(),
+        })
     }
 
-    fn finalize_unordered(&mut self, params: &EquiJoinParams, table: &dyn IdxTable) -> ProbeState {
+    fn finalize_unordered(
+        &mut self,
+        params: &EquiJoinParams,
+        table: &dyn IdxTable,
+    ) -> PolarsResult<ProbeState> {
         let track_unmatchable = params.emit_unmatched_build();
-        let payload_schema = if params.left_is_build.unwrap() {
-            &params.left_payload_schema
-        } else {
-            &params.right_payload_schema
-        };
+        let payload_schema = params.build_payload_schema();
 
         // To reduce maximum memory usage we want to drop the morsels
         // as soon as they're processed, so we move into Arcs. The drops might
@@ -689,7 +944,8 @@ impl BuildState {
 
                         for (i, morsel) in l_morsels.iter().enumerate() {
                             let (_mseq, sf, keys) = morsel;
-                            let payload = sf.get().await;
+                            let df = sf.get().await?;
+                            let payload = build_payload(&df, payload_schema);
                             unsafe {
                                 let p_morsel_idxs_start =
                                     l.morsel_idxs_offsets_per_p[i * num_partitions + p];
@@ -734,6 +990,7 @@ impl BuildState {
                         )
                         .ok()
                         .unwrap();
+                    PolarsResult::Ok(())
                 }));
             }
 
@@ -746,17 +1003,19 @@ impl BuildState {
 
             ASYNC.block_in_place_on(async move {
                 for handle in join_handles {
-                    handle.await;
+                    handle.await?;
                 }
-            });
-        });
+                PolarsResult::Ok(())
+            })
+        })?;
 
-        ProbeState {
+        Ok(ProbeState {
             table_per_partition: probe_tables.try_assume_init().ok().unwrap(),
             max_seq_sent: MorselSeq::default(),
             sampled_probe_morsels: core::mem::take(&mut self.sampled_probe_morsels),
             unordered_morsel_seq: AtomicU64::new(0),
-        }
+            unordered_seq_offset: MorselSeq::default(),
+        })
     }
 }
 
@@ -771,8 +1030,10 @@ struct ProbeState {
     max_seq_sent: MorselSeq,
     sampled_probe_morsels: BufferedStream,
 
-    // For unordered joins we relabel output morsels to speed up the linearizer.
+    // For unordered joins we relabel output morsels to speed up the linearizer,
+    // numbering them from the offset onwards.
     unordered_morsel_seq: AtomicU64,
+    unordered_seq_offset: MorselSeq,
 }
 
 impl ProbeState {
@@ -782,6 +1043,7 @@ impl ProbeState {
         mut send: PortSender,
         partitions: &[ProbeTable],
         unordered_morsel_seq: &AtomicU64,
+        unordered_seq_offset: MorselSeq,
         partitioner: HashPartitioner,
         params: &EquiJoinParams,
         state: &StreamingExecutionState,
@@ -829,7 +1091,7 @@ impl ProbeState {
 
             let hash_keys =
                 select_keys(&df, key_selectors, params, &state.in_memory_exec_state).await?;
-            let mut payload = select_payload(df, payload_selector);
+            let mut payload = select_payload(&df, payload_selector);
             let mut payload_rechunked = false; // We don't eagerly rechunk because there might be no matches.
             let mut total_matches = 0;
 
@@ -855,7 +1117,8 @@ impl ProbeState {
                         let out_seq = if params.preserve_order_probe {
                             in_seq
                         } else {
-                            MorselSeq::new(unordered_morsel_seq.fetch_add(1, Ordering::Relaxed))
+                            unordered_seq_offset
+                                .offset_by_u64(unordered_morsel_seq.fetch_add(1, Ordering::Relaxed))
                         };
                         max_seq = out_seq;
                         Morsel::new(out_df, out_seq, src_token.clone())
@@ -1188,9 +1451,259 @@ impl EmitUnmatchedState {
     }
 }
 
+/// The morsels of one side of a grace hash join seen by a single pipeline,
+/// split into spillable frames per grace partition.
+#[derive(Default)]
+struct LocalGracePartitions {
+    frames_per_p: Vec<Vec<SpillFrame>>,
+    idxs_per_p: Vec<Vec<IdxSize>>,
+}
+
+impl LocalGracePartitions {
+    fn new(num_partitions: usize) -> Self {
+        Self {
+            frames_per_p: (0..num_partitions).map(|_| Vec::new()).collect(),
+            idxs_per_p: vec![Vec::new(); num_partitions],
+        }
+    }
+
+    async fn insert(
+        &mut self,
+        df: &DataFrame,
+        hash_keys: &HashKeys,
+        partitioner: &HashPartitioner,
+        spill_ctx: &MostRecentSpillContext,
+    ) {
+        // We keep the rows with null keys, they might still have to be emitted
+        // as unmatched rows.
+        hash_keys.gen_idxs_per_partition(partitioner, &mut self.idxs_per_p, &mut [], true);
+        for (idxs, frames) in self.idxs_per_p.iter_mut().zip(&mut self.frames_per_p) {
+            if idxs.is_empty() {
+                continue;
+            }
+            let p_df = unsafe { df.take_slice_unchecked_impl(idxs, false) };
+            frames.push(SpillFrame::new(p_df, spill_ctx).await);
+            idxs.clear();
+        }
+    }
+}
+
+/// State of a grace hash join. Both sides are split into the same partitions
+/// by key hash and stored as spillable frames, after which the pairs of
+/// partitions are joined one at a time, such that only the hash table of a
+/// single partition has to fit in memory. A partition whose build side still
+/// doesn't fit is split again, up to a depth of [`MAX_GRACE_DEPTH`].
+struct GraceState {
+    partitioner: HashPartitioner,
+    build_per_p: Vec<Vec<SpillFrame>>,
+    local_probes: Vec<LocalGracePartitions>,
+    // The pairs of partitions that still have to be joined, the next one last.
+    pending: Vec<GracePartition>,
+    // How many times the partitions of this state have been split before.
+    depth: usize,
+}
+
+struct GracePartition {
+    build: Vec<SpillFrame>,
+    probe: Vec<SpillFrame>,
+    depth: usize,
+}
+
+impl GraceState {
+    async fn partition_probe(
+        mut recv: PortReceiver,
+        local: &mut LocalGracePartitions,
+        partitioner: &HashPartitioner,
+        params: &EquiJoinParams,
+        state: &StreamingExecutionState,
+        spill_ctx: &MostRecentSpillContext,
+    ) -> PolarsResult<()> {
+        let key_selectors = if params.left_is_build.unwrap() {
+            &params.right_key_selectors
+        } else {
+            &params.left_key_selectors
+        };
+
+        while let Ok(morsel) = recv.recv().await {
+            let df = morsel.into_df();
+            if df.height() == 0 {
+                continue;
+            }
+            let hash_keys =
+                select_keys(&df, key_selectors, params, &state.in_memory_exec_state).await?;
+            local.insert(&df, &hash_keys, partitioner, spill_ctx).await;
+        }
+        Ok(())
+    }
+
+    /// Simulates the given buffered morsels flowing into the probe side.
+    fn sink_buffered_probe(
+        &mut self,
+        morsels: &BufferedStream,
+        params: &EquiJoinParams,
+        state: &StreamingExecutionState,
+        spill_ctx: &MostRecentSpillContext,
+    ) -> PolarsResult<()> {
+        if morsels.is_empty() {
+            return Ok(());
+        }
+
+        executor::task_scope(|scope| {
+            let mut join_handles = Vec::new();
+            let receivers = morsels
+                .reinsert(state.num_pipelines, None, scope, &mut join_handles)
+                .unwrap();
+
+            for (local, recv) in self.local_probes.iter_mut().zip(receivers) {
+                join_handles.push(scope.spawn_task(
+                    TaskPriority::High,
+                    GraceState::partition_probe(
+                        recv,
+                        local,
+                        &self.partitioner,
+                        params,
+                        state,
+                        spill_ctx,
+                    ),
+                ));
+            }
+
+            ASYNC.block_in_place_on(async move {
+                for handle in join_handles {
+                    handle.await?;
+                }
+                PolarsResult::Ok(())
+            })
+        })
+    }
+
+    /// Gathers the probe side partitions of all pipelines and queues the
+    /// pairs of partitions to be joined.
+    fn finalize_probe(&mut self) {
+        let mut probe_per_p: Vec<Vec<SpillFrame>> = (0..self.partitioner.num_partitions())
+            .map(|_| Vec::new())
+            .collect();
+        for local in core::mem::take(&mut self.local_probes) {
+            for (p, mut frames) in local.frames_per_p.into_iter().enumerate() {
+                probe_per_p[p].append(&mut frames);
+            }
+        }
+
+        // If all build rows ended up in a single partition splitting again with
+        // another seed won't help either, the keys are most likely identical.
+        let build_per_p = core::mem::take(&mut self.build_per_p);
+        let num_nonempty = build_per_p.iter().filter(|f| !f.is_empty()).count();
+        let depth = if num_nonempty <= 1 {
+            MAX_GRACE_DEPTH
+        } else {
+            self.depth
+        };
+        let partitions = build_per_p
+            .into_iter()
+            .zip(probe_per_p)
+            .map(|(build, probe)| GracePartition {
+                build,
+                probe,
+                depth,
+            });
+        self.pending.extend(partitions.rev());
+    }
+
+    /// Builds the hash table for the next pair of partitions that can produce
+    /// output, returning the state that joins them, or None if all partitions
+    /// are done.
+    fn next_partition(
+        &mut self,
+        next_seq: MorselSeq,
+        params: &EquiJoinParams,
+        table: &dyn IdxTable,
+        state: &StreamingExecutionState,
+        spill_ctx: &MostRecentSpillContext,
+    ) -> PolarsResult<Option<EquiJoinState>> {
+        while let Some(partition) = self.pending.pop() {
+            let GracePartition {
+                build: build_frames,
+                probe: probe_frames,
+                depth,
+            } = partition;
+            let can_emit = if build_frames.is_empty() {
+                !probe_frames.is_empty() && params.emit_unmatched_probe()
+            } else {
+                !probe_frames.is_empty() || params.emit_unmatched_build()
+            };
+            if !can_emit {
+                continue;
+            }
+
+            if config::verbose() {
+                eprintln!(
+                    "[equi-join]: joining grace partition at depth {depth}, build frames: {}, probe frames: {}",
+                    build_frames.len(),
+                    probe_frames.len()
+                );
+            }
+
+            // Allow splitting this partition again if its build side doesn't
+            // fit in memory either, using a different seed than its parent.
+            let grace = (depth < MAX_GRACE_DEPTH).then(|| {
+                GraceSwitch::new(
+                    HashPartitioner::new(
+                        self.partitioner.num_partitions(),
+                        GRACE_PARTITIONER_SEED + depth as u64 + 1,
+                    ),
+                    false,
+                )
+            });
+            let probe_is_empty = probe_frames.is_empty();
+            let mut build_state = BuildState::new(
+                state.num_pipelines,
+                state.num_pipelines,
+                BufferedStream::from_spill_frames(probe_frames, MorselSeq::default()),
+                grace,
+                true,
+            );
+            let build_frames =
+                BufferedStream::from_spill_frames(build_frames, MorselSeq::default());
+            build_state.sink_buffered(&build_frames, params, state, spill_ctx)?;
+            drop(build_frames);
+
+            if build_state.should_use_grace() {
+                if config::verbose() {
+                    eprintln!("[equi-join]: splitting grace partition at depth {depth} again");
+                }
+                let mut sub_grace = build_state.finalize_grace(params, state, spill_ctx)?;
+                sub_grace.depth = depth + 1;
+                sub_grace.finalize_probe();
+                self.pending.append(&mut sub_grace.pending);
+                continue;
+            }
+
+            let mut probe_state = build_state.finalize_unordered(params, table)?;
+            if probe_is_empty {
+                return Ok(Some(EquiJoinState::EmitUnmatchedBuild(
+                    EmitUnmatchedState {
+                        partitions: core::mem::take(&mut probe_state.table_per_partition),
+                        active_partition_idx: 0,
+                        offset_in_active_p: 0,
+                        morsel_seq: next_seq,
+                    },
+                )));
+            }
+
+            // Continue numbering the output after the previous partitions.
+            probe_state.max_seq_sent = next_seq;
+            probe_state.unordered_seq_offset = next_seq;
+            return Ok(Some(EquiJoinState::Probe(probe_state)));
+        }
+
+        Ok(None)
+    }
+}
+
 enum EquiJoinState {
     Sample(SampleState),
     Build(BuildState),
+    GracePartitionProbe,
     Probe(ProbeState),
     EmitUnmatchedBuild(EmitUnmatchedState),
     EmitUnmatchedBuildInOrder(InMemorySourceNode),
@@ -1202,6 +1715,7 @@ pub struct EquiJoinNode {
     params: EquiJoinParams,
     table: Box<dyn IdxTable>,
     spill_ctx: Arc<MostRecentSpillContext>,
+    grace: Option<GraceState>,
 }
 
 impl EquiJoinNode {
@@ -1270,41 +1784,67 @@ impl EquiJoinNode {
             &args,
         )?;
 
-        let state = if left_is_build.is_some() {
+        let left_payload_schema = Arc::new(select_schema(&left_input_schema, &left_payload_select));
+        let right_payload_schema =
+            Arc::new(select_schema(&right_input_schema, &right_payload_select));
+        let params = EquiJoinParams {
+            left_is_build,
+            preserve_order_build,
+            preserve_order_probe,
+            left_key_schema,
+            left_key_selectors,
+            right_key_schema,
+            right_key_selectors,
+            left_payload_select,
+            right_payload_select,
+            left_payload_schema,
+            right_payload_schema,
+            args,
+            random_state: PlRandomState::default(),
+            sample_limit,
+        };
+
+        let state = if params.left_is_build.is_some() {
             EquiJoinState::Build(BuildState::new(
                 num_pipelines,
                 num_pipelines,
                 BufferedStream::default(),
+                params.grace_switch(),
+                false,
             ))
         } else {
             EquiJoinState::Sample(SampleState::default())
         };
 
-        let left_payload_schema = Arc::new(select_schema(&left_input_schema, &left_payload_select));
-        let right_payload_schema =
-            Arc::new(select_schema(&right_input_schema, &right_payload_select));
         Ok(Self {
             state,
-            params: EquiJoinParams {
-                left_is_build,
-                preserve_order_build,
-                preserve_order_probe,
-                left_key_schema,
-                left_key_selectors,
-                right_key_schema,
-                right_key_selectors,
-                left_payload_select,
-                right_payload_select,
-                left_payload_schema,
-                right_payload_schema,
-                args,
-                random_state: PlRandomState::default(),
-                sample_limit,
-            },
+            params,
             table: new_idx_table(unique_key_schema),
             spill_ctx: MostRecentSpillContext::new("equi-join".into()),
+            grace: None,
         })
     }
+
+    /// Finishes the active pair of grace partitions, moving on to the next one
+    /// if there is any.
+    fn finish_partition(
+        &mut self,
+        next_seq: MorselSeq,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        let next_state = match &mut self.grace {
+            Some(grace) => grace.next_partition(
+                next_seq,
+                &self.params,
+                &*self.table,
+                state,
+                &self.spill_ctx,
+            )?,
+            None => None,
+        };
+        self.state = next_state.unwrap_or(EquiJoinState::Done);
+        Ok(())
+    }
 }
 
 impl ComputeNode for EquiJoinNode {
@@ -1347,12 +1887,27 @@ impl ComputeNode for EquiJoinNode {
         // If we are building and the build input is done, transition to probing.
         if let EquiJoinState::Build(build_state) = &mut self.state {
             if recv[build_idx] == PortState::Done {
-                let probe_state = if self.params.preserve_order_build {
-                    build_state.finalize_ordered(&self.params, &*self.table)
+                if build_state.should_use_grace() {
+                    let grace = build_state.finalize_grace(&self.params, state, &self.spill_ctx)?;
+                    self.grace = Some(grace);
+                    self.state = EquiJoinState::GracePartitionProbe;
                 } else {
-                    build_state.finalize_unordered(&self.params, &*self.table)
-                };
-                self.state = EquiJoinState::Probe(probe_state);
+                    let probe_state = if self.params.preserve_order_build {
+                        build_state.finalize_ordered(&self.params, &*self.table)?
+                    } else {
+                        build_state.finalize_unordered(&self.params, &*self.table)?
+                    };
+                    self.state = EquiJoinState::Probe(probe_state);
+                }
+            }
+        }
+
+        // If we are splitting the probe side into grace partitions and the
+        // probe input is done, start joining the partitions.
+        if let EquiJoinState::GracePartitionProbe = &self.state {
+            if recv[probe_idx] == PortState::Done {
+                self.grace.as_mut().unwrap().finalize_probe();
+                self.finish_partition(MorselSeq::default(), state)?;
             }
         }
 
@@ -1378,7 +1933,8 @@ impl ComputeNode for EquiJoinNode {
                         });
                     }
                 } else {
-                    self.state = EquiJoinState::Done;
+                    let next_seq = probe_state.max_seq_sent.successor();
+                    self.finish_partition(next_seq, state)?;
                 }
             }
         }
@@ -1386,7 +1942,8 @@ impl ComputeNode for EquiJoinNode {
         // Finally, check if we are done emitting unmatched keys.
         if let EquiJoinState::EmitUnmatchedBuild(emit_state) = &mut self.state {
             if emit_state.active_partition_idx >= emit_state.partitions.len() {
-                self.state = EquiJoinState::Done;
+                let next_seq = emit_state.morsel_seq;
+                self.finish_partition(next_seq, state)?;
             }
         }
 
@@ -1417,6 +1974,13 @@ impl ComputeNode for EquiJoinNode {
                     recv[probe_idx] = PortState::Blocked;
                 }
             },
+            EquiJoinState::GracePartitionProbe => {
+                send[0] = PortState::Blocked;
+                recv[build_idx] = PortState::Done;
+                if recv[probe_idx] != PortState::Done {
+                    recv[probe_idx] = PortState::Ready;
+                }
+            },
             EquiJoinState::Probe(probe_state) => {
                 if recv[probe_idx] != PortState::Done {
                     core::mem::swap(&mut send[0], &mut recv[probe_idx]);
@@ -1455,10 +2019,16 @@ impl ComputeNode for EquiJoinNode {
     fn is_memory_intensive_pipeline_blocker(&self) -> bool {
         matches!(
             self.state,
-            EquiJoinState::Sample { .. } | EquiJoinState::Build { .. }
+            EquiJoinState::Sample { .. }
+                | EquiJoinState::Build { .. }
+                | EquiJoinState::GracePartitionProbe
         )
     }
 
+    fn spill_contexts(&self) -> Vec<Arc<dyn SpillContext>> {
+        vec![self.spill_ctx.clone()]
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
@@ -1531,6 +2101,28 @@ impl ComputeNode for EquiJoinNode {
                             recv,
                             local_builder,
                             partitioner.clone(),
+                            build_state.grace.as_ref(),
+                            build_state.input_has_keys,
+                            &self.params,
+                            state,
+                            &self.spill_ctx,
+                        ),
+                    ));
+                }
+            },
+            EquiJoinState::GracePartitionProbe => {
+                assert!(send_ports[0].is_none());
+                assert!(recv_ports[build_idx].is_none());
+                let grace = self.grace.as_mut().unwrap();
+                let receivers = recv_ports[probe_idx].take().unwrap().parallel();
+
+                for (local, recv) in grace.local_probes.iter_mut().zip(receivers) {
+                    join_handles.push(scope.spawn_task(
+                        TaskPriority::High,
+                        GraceState::partition_probe(
+                            recv,
+                            local,
+                            &grace.partitioner,
                             &self.params,
                             state,
                             &self.spill_ctx,
@@ -1563,6 +2155,7 @@ impl ComputeNode for EquiJoinNode {
                                 send,
                                 &probe_state.table_per_partition,
                                 &probe_state.unordered_morsel_seq,
+                                probe_state.unordered_seq_offset,
                                 partitioner.clone(),
                                 &self.params,
                                 state,
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use polars_core::schema::Schema;
+use polars_ooc::SpillContext;
 
 use crate::nodes::compute_node_prelude::*;
 use crate::nodes::in_memory_sink::InMemorySinkNode;
@@ -91,6 +92,17 @@ impl ComputeNode for InMemoryJoinNode {
         matches!(self.state, InMemoryJoinState::Sink { .. })
     }
 
+    fn spill_contexts(&self) -> Vec<Arc<dyn SpillContext>> {
+        match &self.state {
+            InMemoryJoinState::Sink { left, right } => {
+                let mut out = left.spill_contexts();
+                out.extend(right.spill_contexts());
+                out
+            },
+            InMemoryJoinState::Source(_) | InMemoryJoinState::Done => Vec::new(),
+        }
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
@@ -5,7 +5,7 @@ use polars_async::executor::{JoinHandle, TaskPriority, TaskScope};
 use polars_async::primitives::wait_group::WaitGroup;
 use polars_core::runtime::RAYON;
 use polars_error::PolarsResult;
-use polars_ooc::{MostRecentSpillContext, SpillFrame};
+use polars_ooc::{MostRecentSpillContext, QueryMemoryContext, SpillFrame};
 use polars_utils::itertools::Itertools;
 use polars_utils::pl_str::PlSmallStr;
 use rayon::prelude::*;
@@ -37,10 +37,16 @@ struct BufferedStream {
 }
 
 impl BufferedStream {
-    pub fn new(name: PlSmallStr, morsels: Vec<Morsel>, start_offset: MorselSeq) -> Self {
+    pub fn new(
+        name: PlSmallStr,
+        morsels: Vec<Morsel>,
+        start_offset: MorselSeq,
+        query_memory: &QueryMemoryContext,
+    ) -> Self {
         // Relabel so we can insert into parallel streams later.
         let mut seq = start_offset;
         let ctx = MostRecentSpillContext::new(name);
+        query_memory.attach(ctx.clone());
         let queue = ArrayQueue::new(morsels.len().max(1));
         for morsel in morsels {
             let sf = SpillFrame::new_blocking(morsel.into_df(), &*ctx);
@@ -55,6 +61,23 @@ impl BufferedStream {
         }
     }
 
+    /// Creates a stream from frames which were already made spillable. They
+    /// must stay registered to a spill context that outlives this stream.
+    pub fn from_spill_frames(frames: Vec<SpillFrame>, start_offset: MorselSeq) -> Self {
+        let mut seq = start_offset;
+        let queue = ArrayQueue::new(frames.len().max(1));
+        for sf in frames {
+            queue.push((sf, seq)).unwrap();
+            seq = seq.successor();
+        }
+
+        Self {
+            morsels: queue,
+            post_buffer_offset: seq,
+            _spill_ctx: None,
+        }
+    }
+
     pub fn is_empty(&self) -> bool {
         self.morsels.is_empty()
     }
@@ -86,7 +109,7 @@ impl BufferedStream {
                     let Some((sf, seq)) = self.morsels.pop() else {
                         break;
                     };
-                    let df = sf.into_df().await;
+                    let df = sf.into_df().await?;
                     let mut morsel = Morsel::new(df, seq, source_token.clone());
                     morsel.set_consume_token(wait_group.token());
                     if new_send.send(morsel).await.is_err() {
@@ -6,6 +6,7 @@ use polars_async::executor::{JoinHandle, TaskPriority, TaskScope};
 use polars_async::primitives::wait_group::{WaitGroup, WaitToken};
 use polars_core::frame::builder::DataFrameBuilder;
 use polars_core::prelude::*;
+use polars_ooc::SpillContext;
 use polars_ops::frame::{_finish_join, IEJoinOptions, InequalityOperator, JoinArgs, JoinBuildSide};
 use polars_ops::series::{SearchSortedSide, search_sorted};
 
@@ -231,6 +232,13 @@ impl ComputeNode for RangeJoinNode {
         Ok(())
     }
 
+    fn spill_contexts(&self) -> Vec<Arc<dyn SpillContext>> {
+        match &self.state {
+            RangeJoinState::Build(sink_node) => sink_node.spill_contexts(),
+            RangeJoinState::Probe(_) | RangeJoinState::Done => Vec::new(),
+        }
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
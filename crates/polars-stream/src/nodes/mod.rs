@@ -38,10 +38,13 @@ pub mod rle;
 pub mod rle_id;
 #[cfg(feature = "dynamic_group_by")]
 pub mod rolling_group_by;
+pub mod sample;
 pub mod select;
 pub mod shift;
 pub mod simple_projection;
+pub mod sort;
 pub mod sorted_group_by;
+pub mod sorted_over;
 pub mod sorted_unique;
 pub mod streaming_slice;
 #[cfg(any(
@@ -69,7 +72,10 @@ mod compute_node_prelude {
     pub use crate::pipe::{PortReceiver, PortSender, RecvPort, SendPort};
 }
 
+use std::sync::Arc;
+
 use compute_node_prelude::*;
+use polars_ooc::SpillContext;
 
 use crate::execute::StreamingExecutionState;
 use crate::metrics::NodeMetricsRegistrator;
@@ -115,6 +121,12 @@ pub trait ComputeNode: Send {
 
     fn set_phase_metrics_registrator(&mut self, _metrics_builder: NodeMetricsRegistrator) {}
 
+    /// The spill contexts this node (in its current state) keeps its spillable data in. They are
+    /// attached to the memory budget of the query and reported in the metrics of this node.
+    fn spill_contexts(&self) -> Vec<Arc<dyn SpillContext>> {
+        Vec::new()
+    }
+
     /// Called once after the last execution phase to extract output from
     /// in-memory nodes.
     fn get_output(&mut self) -> PolarsResult<Option<DataFrame>> {
@@ -2,7 +2,7 @@ use std::collections::VecDeque;
 use std::sync::Arc;
 
 use polars_async::primitives::wait_group::WaitGroup;
-use polars_ooc::{MostRecentSpillContext, SpillFrame};
+use polars_ooc::{MostRecentSpillContext, SpillContext, SpillFrame};
 use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
 
 use super::compute_node_prelude::*;
@@ -102,6 +102,16 @@ impl ComputeNode for MultiplexerNode {
         Ok(())
     }
 
+    fn spill_contexts(&self) -> Vec<Arc<dyn SpillContext>> {
+        self.buffers
+            .iter()
+            .filter_map(|b| match b {
+                BufferedStream::Open(_, ctx) => Some(ctx.clone() as Arc<dyn SpillContext>),
+                BufferedStream::Closed => None,
+            })
+            .collect()
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
@@ -207,7 +217,7 @@ impl ComputeNode for MultiplexerNode {
                 join_handles.push(scope.spawn_task(TaskPriority::High, async move {
                     // First we try to flush all the old buffered data.
                     while let Some((sf, seq)) = buf.pop_back() {
-                        let df = sf.into_df().await;
+                        let df = sf.into_df().await?;
                         let mut morsel = Morsel::new(df, seq, buffered_source_token.clone());
                         morsel.set_consume_token(wait_group.token());
                         if sender.send(morsel).await.is_err() {
@@ -229,7 +239,7 @@ impl ComputeNode for MultiplexerNode {
 
                     // Then send along data from the multiplexer.
                     while let Some((sf, seq, source_token)) = rx.recv().await {
-                        let df = sf.into_df().await;
+                        let df = sf.into_df().await?;
                         let mut morsel = Morsel::new(df, seq, source_token);
                         morsel.set_consume_token(wait_group.token());
                         if sender.send(morsel).await.is_err() {
@@ -2,7 +2,7 @@ use std::collections::VecDeque;
 use std::sync::Arc;
 
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
-use polars_ooc::{MostRecentSpillContext, SpillFrame};
+use polars_ooc::{MostRecentSpillContext, SpillContext, SpillFrame};
 
 use super::compute_node_prelude::*;
 use crate::nodes::in_memory_source::InMemorySourceNode;
@@ -84,11 +84,11 @@ impl ComputeNode for NegativeSliceNode {
                 if buffer.total_len == 0 {
                     self.state = Done;
                 } else {
-                    let dfs: Vec<_> = buffer
+                    let dfs = buffer
                         .frames
                         .drain(..)
                         .map(|sf| sf.into_df_blocking())
-                        .collect();
+                        .collect::<PolarsResult<Vec<_>>>()?;
                     let mut df = accumulate_dataframes_vertical_unchecked(dfs);
                     let clamped_start = signed_start_offset.max(0);
                     let len = (signed_stop_offset - clamped_start).max(0) as usize;
@@ -116,6 +116,10 @@ impl ComputeNode for NegativeSliceNode {
         Ok(())
     }
 
+    fn spill_contexts(&self) -> Vec<Arc<dyn SpillContext>> {
+        vec![self.spill_ctx.clone()]
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use polars_async::primitives::wait_group::WaitGroup;
 use polars_core::schema::Schema;
+use polars_ooc::SpillContext;
 
 use super::compute_node_prelude::*;
 use crate::morsel::{SourceToken, get_ideal_morsel_size};
@@ -77,6 +78,17 @@ impl ComputeNode for RepeatNode {
         Ok(())
     }
 
+    fn spill_contexts(&self) -> Vec<Arc<dyn SpillContext>> {
+        match self {
+            Self::GatheringParams { value, repeats } => {
+                let mut out = value.spill_contexts();
+                out.extend(repeats.spill_contexts());
+                out
+            },
+            Self::Repeating { .. } => Vec::new(),
+        }
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
@@ -0,0 +1,262 @@
+use std::sync::Arc;
+
+use polars_core::prelude::{BooleanChunked, IdxCa, IdxSize};
+use polars_core::random::get_global_random_u64;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_plan::dsl::{SampleMethod, SampleOptions};
+use polars_utils::pl_str::PlSmallStr;
+use rand::prelude::*;
+
+use super::compute_node_prelude::*;
+use crate::nodes::in_memory_source::InMemorySourceNode;
+
+/// Samples every morsel independently, either row by row (Bernoulli) or by keeping or dropping
+/// the morsel as a whole (system).
+pub struct SampleFilterNode {
+    options: SampleOptions,
+    seed: u64,
+}
+
+impl SampleFilterNode {
+    pub fn new(options: SampleOptions) -> Self {
+        assert!(!matches!(options.method, SampleMethod::Reservoir { .. }));
+        let seed = options.seed.unwrap_or_else(get_global_random_u64);
+        Self { options, seed }
+    }
+}
+
+/// The SplitMix64 output function, a bijective mixer of its input.
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9e3779b97f4a7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+/// Derives the seed of a morsel from the node seed, the morsel's sequence id and its index among
+/// the morsels with that sequence id, such that neighbouring morsels get unrelated seeds.
+fn morsel_seed(seed: u64, seq: u64, idx: u64) -> u64 {
+    splitmix64(splitmix64(splitmix64(seed) ^ seq) ^ idx)
+}
+
+impl ComputeNode for SampleFilterNode {
+    fn name(&self) -> &str {
+        "sample"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+        recv.swap_with_slice(send);
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        let receivers = recv_ports[0].take().unwrap().parallel();
+        let senders = send_ports[0].take().unwrap().parallel();
+
+        for (mut recv, mut send) in receivers.into_iter().zip(senders) {
+            let slf = &*self;
+            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                let mut prev_seq = None;
+                let mut idx_in_seq = 0;
+                while let Ok(morsel) = recv.recv().await {
+                    // Derive the seed from the sequence id so the result doesn't depend on
+                    // which pipeline happened to receive the morsel.
+                    let seq = morsel.seq().to_u64();
+                    idx_in_seq = if prev_seq == Some(seq) {
+                        idx_in_seq + 1
+                    } else {
+                        0
+                    };
+                    prev_seq = Some(seq);
+                    let seed = Some(morsel_seed(slf.seed, seq, idx_in_seq));
+                    let morsel = morsel.try_map(|df| match slf.options.method {
+                        SampleMethod::Bernoulli { fraction } => df.sample_bernoulli(fraction, seed),
+                        SampleMethod::System { fraction } => df.sample_system(fraction, seed),
+                        SampleMethod::Reservoir { .. } => unreachable!(),
+                    })?;
+                    if send.send(morsel).await.is_err() {
+                        break;
+                    }
+                }
+
+                Ok(())
+            }));
+        }
+    }
+}
+
+/// Keeps a uniform random sample of `n` rows.
+///
+/// Every row is assigned a random key and the rows with the `n` smallest keys are kept. Once `n`
+/// rows are buffered, rows with a key above the current `n`-th smallest key can never be part of
+/// the sample and are dropped on arrival, so at most `2 * n` rows are held in memory.
+struct Reservoir {
+    n: usize,
+    rng: SmallRng,
+    frames: Vec<DataFrame>,
+    /// The keys of the buffered rows, in the same order as the rows in `frames`.
+    keys: Vec<u64>,
+    threshold: u64,
+}
+
+impl Reservoir {
+    fn insert(&mut self, df: DataFrame) -> PolarsResult<()> {
+        let mut keys: Vec<u64> = (0..df.height()).map(|_| self.rng.next_u64()).collect();
+        let df = if self.threshold < u64::MAX {
+            let mask: BooleanChunked = keys.iter().map(|k| *k < self.threshold).collect();
+            keys.retain(|k| *k < self.threshold);
+            df.filter(&mask)?
+        } else {
+            df
+        };
+
+        if df.height() > 0 {
+            self.frames.push(df);
+            self.keys.extend(keys);
+        }
+
+        if self.keys.len() >= 2 * self.n {
+            self.compact();
+        }
+        Ok(())
+    }
+
+    /// Only keep the `n` rows with the smallest keys, maintaining their order.
+    fn compact(&mut self) {
+        if self.keys.len() <= self.n {
+            return;
+        }
+
+        let mut idx: Vec<IdxSize> = (0..self.keys.len() as IdxSize).collect();
+        idx.select_nth_unstable_by_key(self.n - 1, |i| self.keys[*i as usize]);
+        idx.truncate(self.n);
+        idx.sort_unstable();
+
+        self.keys = idx.iter().map(|i| self.keys[*i as usize]).collect();
+        self.threshold = self.keys.iter().copied().max().unwrap();
+
+        let df = accumulate_dataframes_vertical_unchecked(self.frames.drain(..));
+        let idx = IdxCa::from_vec(PlSmallStr::EMPTY, idx);
+        // SAFETY: the indices are within bounds.
+        self.frames.push(unsafe { df.take_unchecked(&idx) });
+    }
+}
+
+enum ReservoirSampleState {
+    Buffering(Reservoir),
+    Source(InMemorySourceNode),
+    Done,
+}
+
+pub struct ReservoirSampleNode {
+    state: ReservoirSampleState,
+}
+
+impl ReservoirSampleNode {
+    pub fn new(n: usize, seed: Option<u64>) -> Self {
+        let seed = seed.unwrap_or_else(get_global_random_u64);
+        let state = if n == 0 {
+            ReservoirSampleState::Done
+        } else {
+            ReservoirSampleState::Buffering(Reservoir {
+                n,
+                rng: SmallRng::seed_from_u64(seed),
+                frames: Vec::new(),
+                keys: Vec::new(),
+                threshold: u64::MAX,
+            })
+        };
+        Self { state }
+    }
+}
+
+impl ComputeNode for ReservoirSampleNode {
+    fn name(&self) -> &str {
+        "reservoir-sample"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        use ReservoirSampleState::*;
+
+        if send[0] == PortState::Done {
+            self.state = Done;
+        }
+
+        if recv[0] == PortState::Done
+            && let Buffering(reservoir) = &mut self.state
+        {
+            reservoir.compact();
+            if reservoir.frames.is_empty() {
+                self.state = Done;
+            } else {
+                let df = accumulate_dataframes_vertical_unchecked(reservoir.frames.drain(..));
+                self.state = Source(InMemorySourceNode::new(Arc::new(df), MorselSeq::default()));
+            }
+        }
+
+        match &mut self.state {
+            Buffering(_) => {
+                recv[0] = PortState::Ready;
+                send[0] = PortState::Blocked;
+            },
+            Source(node) => {
+                recv[0] = PortState::Done;
+                node.update_state(&mut [], send, state)?;
+            },
+            Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        match &mut self.state {
+            ReservoirSampleState::Buffering(reservoir) => {
+                // Serial, so the keys only depend on the seed and the order of the input.
+                let mut recv = recv_ports[0].take().unwrap().serial();
+                assert!(send_ports[0].is_none());
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    while let Ok(morsel) = recv.recv().await {
+                        reservoir.insert(morsel.into_df())?;
+                    }
+
+                    Ok(())
+                }));
+            },
+            ReservoirSampleState::Source(in_memory_source_node) => {
+                assert!(recv_ports[0].is_none());
+                in_memory_source_node.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
+            ReservoirSampleState::Done => unreachable!(),
+        }
+    }
+}
@@ -4,7 +4,7 @@ use std::sync::Arc;
 use polars_async::primitives::wait_group::WaitGroup;
 use polars_core::prelude::*;
 use polars_core::schema::Schema;
-use polars_ooc::{MostRecentSpillContext, SpillFrame};
+use polars_ooc::{MostRecentSpillContext, SpillContext, SpillFrame};
 
 use super::compute_node_prelude::*;
 use crate::morsel::{SourceToken, get_ideal_morsel_size};
@@ -64,7 +64,7 @@ impl ShiftState {
             } else {
                 let src = self.frames.front_mut().unwrap();
                 let len = self.rows_received - self.rows_sent;
-                let mut src_df = src.get_mut().await;
+                let mut src_df = src.get_mut().await?;
                 let (head, tail) = src_df.split_at(len as i64);
                 *src_df = tail;
                 df = head;
@@ -251,6 +251,18 @@ impl ComputeNode for ShiftNode {
         Ok(())
     }
 
+    fn spill_contexts(&self) -> Vec<Arc<dyn SpillContext>> {
+        match self {
+            Self::GatheringParams { offset, fill, .. } => {
+                let mut out = offset.spill_contexts();
+                out.extend(fill.iter().flat_map(|f| f.spill_contexts()));
+                out
+            },
+            Self::Shifting(shift_state) => vec![shift_state.spill_ctx.clone()],
+            Self::Done => Vec::new(),
+        }
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
@@ -0,0 +1,602 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::sync::Arc;
+
+use arrow::array::{Array, BinaryArray};
+use parking_lot::Mutex;
+use polars_async::primitives::wait_group::WaitGroup;
+use polars_core::prelude::row_encode::_get_rows_encoded_ca;
+use polars_core::prelude::*;
+use polars_core::schema::Schema;
+use polars_core::series::IsSorted;
+use polars_core::utils::{accumulate_dataframes_vertical_unchecked, slice_offsets};
+use polars_ooc::{MostRecentSpillContext, SpillContext, SpillFormat, SpillFrame};
+use polars_utils::IdxSize;
+use polars_utils::pl_str::unique_column_name;
+
+use super::compute_node_prelude::*;
+use crate::expression::StreamExpr;
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
+
+/// The (estimated) amount of input each pipeline buffers before sorting it into a run.
+const RUN_SIZE_BYTES: usize = 64 * 1024 * 1024;
+
+/// The number of ideal morsels each pipeline buffers at most before sorting them into a run.
+const RUN_SIZE_MORSELS: usize = 64;
+
+/// The maximum number of runs that are merged at once. If there are more runs
+/// they are first merged into fewer, longer runs.
+const MAX_MERGE_FAN_IN: usize = 32;
+
+/// A sorted run, split into blocks which can be spilled independently.
+type Run = VecDeque<SpillFrame>;
+
+fn key_array(df: &DataFrame, key_name: &PlSmallStr) -> BinaryArray<i64> {
+    let ca = df.column(key_name).unwrap().binary_offset().unwrap();
+    ca.rechunk().downcast_as_array().clone()
+}
+
+/// Gathers the rows of `df` in the order of `idx`, in blocks of at most
+/// `get_ideal_morsel_size()` rows.
+///
+/// # Safety
+/// The indices must be in-bounds.
+unsafe fn gather_into_blocks(df: &DataFrame, idx: &[IdxSize]) -> Vec<DataFrame> {
+    // Gather every block separately so that each block owns its memory and
+    // can be released on its own once spilled.
+    idx.chunks(get_ideal_morsel_size())
+        .map(|chunk| unsafe { df.take_slice_unchecked(chunk) })
+        .collect()
+}
+
+/// Sorts the DataFrame on its row-encoded key column, returning the result in
+/// blocks of at most `get_ideal_morsel_size()` rows.
+fn sort_into_blocks(df: DataFrame, key_name: &PlSmallStr) -> Vec<DataFrame> {
+    let keys = key_array(&df, key_name);
+    let mut idx: Vec<IdxSize> = (0..df.height() as IdxSize).collect();
+    // SAFETY: the indices are in-bounds.
+    unsafe {
+        idx.sort_unstable_by(|a, b| {
+            keys.value_unchecked(*a as usize)
+                .cmp(keys.value_unchecked(*b as usize))
+        });
+        gather_into_blocks(&df, &idx)
+    }
+}
+
+/// Merges sorted key arrays, returning the merged order as indices into the
+/// arrays concatenated vertically. Ties are taken from the earlier array first.
+fn merge_order(keys: &[BinaryArray<i64>]) -> Vec<IdxSize> {
+    let mut offsets = Vec::with_capacity(keys.len());
+    let mut total_len = 0;
+    for k in keys {
+        offsets.push(total_len);
+        total_len += k.len();
+    }
+
+    let mut order = Vec::with_capacity(total_len);
+    let mut heap = BinaryHeap::with_capacity(keys.len());
+    for (i, k) in keys.iter().enumerate() {
+        if !k.is_empty() {
+            heap.push(Reverse((k.value(0), i, 0)));
+        }
+    }
+    while let Some(Reverse((_, i, mut row))) = heap.pop() {
+        // Keep taking from the same array for as long as it holds the
+        // smallest key, which is cheaper than going through the heap.
+        loop {
+            order.push((offsets[i] + row) as IdxSize);
+            row += 1;
+            if row == keys[i].len() {
+                break;
+            }
+            let key = keys[i].value(row);
+            if let Some(Reverse((top, j, _))) = heap.peek()
+                && (key, i) > (*top, *j)
+            {
+                heap.push(Reverse((key, i, row)));
+                break;
+            }
+        }
+    }
+    order
+}
+
+async fn push_blocks(run: &mut Run, blocks: Vec<DataFrame>, spill_ctx: &MostRecentSpillContext) {
+    for block in blocks {
+        run.push_back(SpillFrame::new(block, spill_ctx).await);
+    }
+}
+
+struct RunCursor {
+    run: Run,
+    block: DataFrame,
+    keys: BinaryArray<i64>,
+    offset: usize,
+}
+
+/// Merges sorted runs, only keeping the current block of every run in memory.
+///
+/// Every call to [`RunMerger::next_batch`] merges all rows that are at most the
+/// smallest last key of the current blocks. Those rows must precede every row
+/// that has not been loaded yet.
+struct RunMerger {
+    cursors: Vec<RunCursor>,
+    key_name: PlSmallStr,
+}
+
+impl RunMerger {
+    fn new(runs: Vec<Run>, key_name: PlSmallStr) -> Self {
+        let cursors = runs
+            .into_iter()
+            .map(|run| RunCursor {
+                run,
+                block: DataFrame::empty(),
+                keys: BinaryArray::new_empty(ArrowDataType::LargeBinary),
+                offset: 0,
+            })
+            .collect();
+        Self { cursors, key_name }
+    }
+
+    async fn next_batch(&mut self) -> PolarsResult<Option<Vec<DataFrame>>> {
+        // Load the next block of every cursor that was fully consumed.
+        let mut i = 0;
+        while i < self.cursors.len() {
+            let cursor = &mut self.cursors[i];
+            if cursor.offset < cursor.block.height() {
+                i += 1;
+            } else if let Some(sf) = cursor.run.pop_front() {
+                cursor.block = sf.into_df().await?;
+                cursor.keys = key_array(&cursor.block, &self.key_name);
+                cursor.offset = 0;
+            } else {
+                self.cursors.swap_remove(i);
+            }
+        }
+
+        let Some(bound) = self
+            .cursors
+            .iter()
+            .map(|c| c.keys.value(c.keys.len() - 1))
+            .min()
+        else {
+            return Ok(None);
+        };
+        let bound = bound.to_vec();
+
+        let mut parts = Vec::with_capacity(self.cursors.len());
+        let mut part_keys = Vec::with_capacity(self.cursors.len());
+        for cursor in self.cursors.iter_mut() {
+            let (mut lo, mut hi) = (cursor.offset, cursor.block.height());
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+                if cursor.keys.value(mid) <= bound.as_slice() {
+                    lo = mid + 1;
+                } else {
+                    hi = mid;
+                }
+            }
+            if lo > cursor.offset {
+                let len = lo - cursor.offset;
+                parts.push(cursor.block.slice(cursor.offset as i64, len));
+                part_keys.push(cursor.keys.clone().sliced(cursor.offset, len));
+                cursor.offset = lo;
+            }
+        }
+
+        if parts.len() == 1 {
+            return Ok(Some(parts));
+        }
+        let order = merge_order(&part_keys);
+        let df = accumulate_dataframes_vertical_unchecked(parts);
+        // SAFETY: the merge order is a permutation of the rows of df.
+        Ok(Some(unsafe { gather_into_blocks(&df, &order) }))
+    }
+}
+
+/// Merges all runs at once if none of their blocks were spilled.
+struct InMemoryMerger {
+    df: DataFrame,
+    order: Vec<IdxSize>,
+    offset: usize,
+}
+
+impl InMemoryMerger {
+    /// Returns `None` if any block of the runs was spilled.
+    async fn try_new(runs: &mut Vec<Run>, key_name: &PlSmallStr) -> PolarsResult<Option<Self>> {
+        if !runs.iter().flatten().all(|sf| sf.try_get().is_some()) {
+            return Ok(None);
+        }
+
+        let mut run_dfs = Vec::with_capacity(runs.len());
+        for run in runs.drain(..) {
+            let mut blocks = Vec::with_capacity(run.len());
+            for sf in run {
+                // This only unspills if the block got spilled since the check above.
+                blocks.push(sf.into_df().await?);
+            }
+            run_dfs.push(accumulate_dataframes_vertical_unchecked(blocks));
+        }
+
+        let keys: Vec<_> = run_dfs.iter().map(|df| key_array(df, key_name)).collect();
+        let order = merge_order(&keys);
+        // Gathering from a single chunk is much cheaper.
+        let mut df = accumulate_dataframes_vertical_unchecked(run_dfs);
+        df.rechunk_mut();
+        Ok(Some(Self {
+            df,
+            order,
+            offset: 0,
+        }))
+    }
+
+    fn next_batch(&mut self, skip: &mut usize) -> Option<Vec<DataFrame>> {
+        // Skip rows without gathering them.
+        let n = (*skip).min(self.order.len() - self.offset);
+        self.offset += n;
+        *skip -= n;
+
+        let len = get_ideal_morsel_size().min(self.order.len() - self.offset);
+        if len == 0 {
+            return None;
+        }
+        let idx = &self.order[self.offset..self.offset + len];
+        self.offset += len;
+        // SAFETY: the merge order is a permutation of the rows of df.
+        Some(vec![unsafe { self.df.take_slice_unchecked(idx) }])
+    }
+}
+
+enum Merger {
+    InMemory(InMemoryMerger),
+    Runs(RunMerger),
+}
+
+struct MergeState {
+    runs: Vec<Run>,
+    merger: Option<Merger>,
+    queue: VecDeque<DataFrame>,
+    /// Rows still to skip respectively to send, after resolving the slice.
+    skip: usize,
+    remaining: usize,
+    seq: MorselSeq,
+    sent_any: bool,
+    exhausted: bool,
+}
+
+impl MergeState {
+    async fn next_output(
+        &mut self,
+        key_name: &PlSmallStr,
+        spill_ctx: &MostRecentSpillContext,
+    ) -> PolarsResult<Option<DataFrame>> {
+        if self.merger.is_none()
+            && self.remaining > 0
+            && let Some(merger) = InMemoryMerger::try_new(&mut self.runs, key_name).await?
+        {
+            self.merger = Some(Merger::InMemory(merger));
+        }
+
+        if self.merger.is_none() && self.remaining > 0 {
+            let mut runs = std::mem::take(&mut self.runs);
+            while runs.len() > MAX_MERGE_FAN_IN {
+                let mut merged = Vec::with_capacity(runs.len().div_ceil(MAX_MERGE_FAN_IN));
+                let mut iter = runs.into_iter().peekable();
+                while iter.peek().is_some() {
+                    let group = iter.by_ref().take(MAX_MERGE_FAN_IN).collect();
+                    let mut merger = RunMerger::new(group, key_name.clone());
+                    let mut run = Run::new();
+                    while let Some(blocks) = merger.next_batch().await? {
+                        push_blocks(&mut run, blocks, spill_ctx).await;
+                    }
+                    merged.push(run);
+                }
+                runs = merged;
+            }
+            self.merger = Some(Merger::Runs(RunMerger::new(runs, key_name.clone())));
+        }
+
+        while self.remaining > 0 {
+            let Some(mut df) = self.queue.pop_front() else {
+                let blocks = match self.merger.as_mut().unwrap() {
+                    Merger::InMemory(merger) => merger.next_batch(&mut self.skip),
+                    Merger::Runs(merger) => merger.next_batch().await?,
+                };
+                match blocks {
+                    Some(blocks) => self.queue.extend(blocks),
+                    None => break,
+                }
+                continue;
+            };
+
+            if self.skip > 0 {
+                let n = self.skip.min(df.height());
+                df = df.slice(n as i64, usize::MAX);
+                self.skip -= n;
+            }
+            if df.height() == 0 {
+                continue;
+            }
+            df = df.slice(0, self.remaining);
+            self.remaining -= df.height();
+            df.drop_in_place(key_name).unwrap();
+            return Ok(Some(df));
+        }
+
+        self.exhausted = true;
+        self.queue.clear();
+        self.merger = None;
+        Ok(None)
+    }
+}
+
+enum SortState {
+    Sink,
+    Source(MergeState),
+    Done,
+}
+
+/// An out-of-core sort.
+///
+/// Every pipeline sorts its input into runs on the row-encoded sort keys. The
+/// runs are split into blocks that are registered with the memory manager so
+/// they can be spilled to disk under memory pressure. Once all input is
+/// received the runs are merged, loading only a single block per run at a
+/// time.
+pub struct SortNode {
+    state: SortState,
+    output_schema: Arc<Schema>,
+    key_selectors: Vec<StreamExpr>,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+    slice: Option<(i64, usize)>,
+    limit: Option<usize>,
+    /// The index of the output column that is the first sort key, if any, so
+    /// we can mark it as sorted.
+    sorted_column: Option<(usize, IsSorted)>,
+    key_name: PlSmallStr,
+    runs: Mutex<Vec<Run>>,
+    spill_ctx: Arc<MostRecentSpillContext>,
+}
+
+impl SortNode {
+    pub fn new(
+        output_schema: Arc<Schema>,
+        key_selectors: Vec<StreamExpr>,
+        sort_options: &SortMultipleOptions,
+        slice: Option<(i64, usize)>,
+        first_key_column: Option<usize>,
+    ) -> Self {
+        let broadcast = |v: &[bool]| {
+            if v.len() == 1 {
+                vec![v[0]; key_selectors.len()]
+            } else {
+                v.to_vec()
+            }
+        };
+        let descending = broadcast(&sort_options.descending);
+        let nulls_last = broadcast(&sort_options.nulls_last);
+        assert!(descending.len() == key_selectors.len());
+        assert!(nulls_last.len() == key_selectors.len());
+        // Lowering replaces maintain_order by a row index sort key.
+        assert!(!sort_options.maintain_order);
+
+        #[allow(clippy::unnecessary_cast)]
+        let limit = sort_options.limit.map(|l| l as usize);
+        Self {
+            state: SortState::Sink,
+            output_schema,
+            key_selectors,
+            descending,
+            nulls_last,
+            slice,
+            limit,
+            sorted_column: first_key_column.map(|idx| {
+                let sorted = if sort_options.descending[0] {
+                    IsSorted::Descending
+                } else {
+                    IsSorted::Ascending
+                };
+                (idx, sorted)
+            }),
+            key_name: unique_column_name(),
+            runs: Mutex::default(),
+            // Runs are spilled as raw row-encoded buffers rather than IPC.
+            spill_ctx: MostRecentSpillContext::new_with_format("sort".into(), SpillFormat::Row),
+        }
+    }
+
+    fn start_merge(&mut self) -> MergeState {
+        let runs = std::mem::take(self.runs.get_mut());
+        let total_len: usize = runs.iter().flatten().map(|sf| sf.height()).sum();
+        let (skip, mut remaining) = match self.slice {
+            Some((offset, len)) => slice_offsets(offset, len, total_len),
+            None => (0, total_len),
+        };
+        if let Some(limit) = self.limit {
+            remaining = remaining.min(limit);
+        }
+
+        MergeState {
+            runs,
+            merger: None,
+            queue: VecDeque::new(),
+            skip,
+            remaining,
+            seq: MorselSeq::default(),
+            sent_any: false,
+            exhausted: false,
+        }
+    }
+
+    async fn add_key_column(
+        &self,
+        mut df: DataFrame,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<DataFrame> {
+        let mut keys = Vec::with_capacity(self.key_selectors.len());
+        for selector in &self.key_selectors {
+            keys.push(selector.evaluate(&df, &state.in_memory_exec_state).await?);
+        }
+        let keys = _get_rows_encoded_ca(
+            self.key_name.clone(),
+            &keys,
+            &self.descending,
+            &self.nulls_last,
+            false,
+        )?;
+        df.with_column(keys.into_column())?;
+        Ok(df)
+    }
+
+    async fn flush_run(&self, buffer: &mut Vec<DataFrame>) {
+        if buffer.is_empty() {
+            return;
+        }
+        let df = accumulate_dataframes_vertical_unchecked(buffer.drain(..));
+        let mut run = Run::new();
+        push_blocks(
+            &mut run,
+            sort_into_blocks(df, &self.key_name),
+            &self.spill_ctx,
+        )
+        .await;
+        self.runs.lock().push(run);
+    }
+}
+
+impl ComputeNode for SortNode {
+    fn name(&self) -> &str {
+        "sort"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        if send[0] == PortState::Done {
+            self.state = SortState::Done;
+        }
+
+        if recv[0] == PortState::Done && matches!(self.state, SortState::Sink) {
+            self.state = SortState::Source(self.start_merge());
+        }
+
+        if let SortState::Source(merge) = &self.state
+            && merge.exhausted
+        {
+            self.state = SortState::Done;
+        }
+
+        match &self.state {
+            SortState::Sink => {
+                recv[0] = PortState::Ready;
+                send[0] = PortState::Blocked;
+            },
+            SortState::Source(_) => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Ready;
+            },
+            SortState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, SortState::Sink)
+    }
+
+    fn spill_contexts(&self) -> Vec<Arc<dyn SpillContext>> {
+        vec![self.spill_ctx.clone()]
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        match &mut self.state {
+            SortState::Sink => {
+                assert!(send_ports[0].is_none());
+                let receivers = recv_ports[0].take().unwrap().parallel();
+                for mut recv in receivers {
+                    let slf = &*self;
+                    join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                        let mut buffer = Vec::new();
+                        let mut buffered_bytes = 0;
+                        let mut buffered_rows = 0;
+                        let max_run_rows = RUN_SIZE_MORSELS * get_ideal_morsel_size();
+                        while let Ok(morsel) = recv.recv().await {
+                            let df = morsel.into_df();
+                            if df.height() == 0 {
+                                continue;
+                            }
+                            let df = slf.add_key_column(df, state).await?;
+                            buffered_bytes += df.estimated_size();
+                            buffered_rows += df.height();
+                            buffer.push(df);
+                            if buffered_bytes >= RUN_SIZE_BYTES || buffered_rows >= max_run_rows {
+                                slf.flush_run(&mut buffer).await;
+                                buffered_bytes = 0;
+                                buffered_rows = 0;
+                            }
+                        }
+                        slf.flush_run(&mut buffer).await;
+                        Ok(())
+                    }));
+                }
+            },
+            SortState::Source(merge) => {
+                assert!(recv_ports[0].is_none());
+                let mut send = send_ports[0].take().unwrap().serial();
+                let key_name = &self.key_name;
+                let spill_ctx = &*self.spill_ctx;
+                let output_schema = &self.output_schema;
+                let sorted_column = self.sorted_column;
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    let source_token = SourceToken::new();
+                    let wait_group = WaitGroup::default();
+                    loop {
+                        let mut df = match merge.next_output(key_name, spill_ctx).await? {
+                            Some(df) => df,
+                            // Always send at least one morsel, some nodes rely on it.
+                            None if !merge.sent_any => DataFrame::empty_with_schema(output_schema),
+                            None => break,
+                        };
+                        merge.sent_any = true;
+                        if let Some((idx, sorted)) = sorted_column {
+                            // SAFETY: we don't change the length or names of the columns.
+                            unsafe { df.columns_mut_retain_schema()[idx].set_sorted_flag(sorted) };
+                        }
+
+                        let mut morsel = Morsel::new(df, merge.seq, source_token.clone());
+                        merge.seq = merge.seq.successor();
+                        morsel.set_consume_token(wait_group.token());
+                        if send.send(morsel).await.is_err() {
+                            break;
+                        }
+                        wait_group.wait().await;
+                        if merge.exhausted || source_token.stop_requested() {
+                            break;
+                        }
+                    }
+
+                    Ok(())
+                }));
+            },
+            SortState::Done => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,222 @@
+use std::sync::Arc;
+
+use polars_async::executor::{JoinHandle, TaskPriority, TaskScope};
+use polars_async::primitives::distributor_channel::distributor_channel;
+use polars_async::primitives::wait_group::WaitGroup;
+use polars_core::frame::DataFrame;
+use polars_core::schema::Schema;
+use polars_error::{PolarsError, PolarsResult};
+use polars_expr::state::ExecutionState;
+use polars_ops::series::rle_lengths;
+use polars_utils::IdxSize;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::ComputeNode;
+use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
+use crate::execute::StreamingExecutionState;
+use crate::expression::StreamExpr;
+use crate::graph::PortState;
+use crate::morsel::{Morsel, MorselSeq, SourceToken};
+use crate::pipe::{RecvPort, SendPort};
+
+/// Evaluates window expressions on an input where the rows of every partition are contiguous,
+/// e.g. because the input is sorted by the partition keys.
+///
+/// Rows are buffered until the partition keys change, after which all completed partitions are
+/// evaluated at once. Only the output columns of the window expressions are sent.
+pub struct SortedOver {
+    /// Contains only the rows of the last, possibly incomplete, partition.
+    buf_df: DataFrame,
+
+    seq: MorselSeq,
+
+    keys: Arc<[PlSmallStr]>,
+    exprs: Arc<[(PlSmallStr, StreamExpr)]>,
+}
+
+impl SortedOver {
+    pub fn new(
+        keys: Arc<[PlSmallStr]>,
+        exprs: Arc<[(PlSmallStr, StreamExpr)]>,
+        input_schema: Arc<Schema>,
+    ) -> Self {
+        let buf_df = DataFrame::empty_with_arc_schema(input_schema);
+        Self {
+            buf_df,
+            seq: MorselSeq::default(),
+            keys,
+            exprs,
+        }
+    }
+
+    /// Returns the offset at which the last partition of `df` starts.
+    fn last_partition_start(
+        keys: &[PlSmallStr],
+        lengths: &mut Vec<IdxSize>,
+        df: &DataFrame,
+    ) -> PolarsResult<usize> {
+        let mut start = 0;
+        for key in keys {
+            rle_lengths(df.column(key)?, lengths)?;
+            start = start.max(df.height() - *lengths.last().unwrap() as usize);
+        }
+        Ok(start)
+    }
+
+    async fn evaluate_one(
+        exprs: &[(PlSmallStr, StreamExpr)],
+        state: &ExecutionState,
+        df: DataFrame,
+    ) -> PolarsResult<DataFrame> {
+        let mut columns = Vec::with_capacity(exprs.len());
+        for (name, expr) in exprs.iter() {
+            let column = expr.evaluate(&df, state).await?;
+            columns.push(column.with_name(name.clone()));
+        }
+        DataFrame::new(df.height(), columns)
+    }
+}
+
+impl ComputeNode for SortedOver {
+    fn name(&self) -> &str {
+        "sorted-over"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        if send[0] == PortState::Done {
+            recv[0] = PortState::Done;
+            std::mem::take(&mut self.buf_df);
+        } else if recv[0] == PortState::Done {
+            if self.buf_df.height() == 0 {
+                send[0] = PortState::Done;
+            } else {
+                send[0] = PortState::Ready;
+            }
+        } else {
+            recv.swap_with_slice(send);
+        }
+
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+
+        let Some(recv) = recv_ports[0].take() else {
+            // We no longer have to receive data. The last partition is complete now.
+            assert!(self.buf_df.height() > 0);
+            let mut send = send_ports[0].take().unwrap().serial();
+            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                let mut exec_state = state.in_memory_exec_state.split();
+                exec_state.remove_cache_window_flag();
+                let df =
+                    Self::evaluate_one(&self.exprs, &exec_state, std::mem::take(&mut self.buf_df))
+                        .await?;
+
+                _ = send
+                    .send(Morsel::new(df, self.seq.successor(), SourceToken::new()))
+                    .await;
+
+                Ok(())
+            }));
+            return;
+        };
+
+        let mut recv = recv.serial();
+        let send = send_ports[0].take().unwrap().parallel();
+
+        let (mut distributor, rxs) =
+            distributor_channel::<Morsel>(send.len(), *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+
+        // Worker tasks.
+        //
+        // These evaluate the window expressions on completed partitions.
+        join_handles.extend(rxs.into_iter().zip(send).map(|(mut rx, mut tx)| {
+            let wg = WaitGroup::default();
+            let exprs = self.exprs.clone();
+            let mut exec_state = state.in_memory_exec_state.split();
+            // Every morsel holds different partitions, so the groups can't be reused.
+            exec_state.remove_cache_window_flag();
+            scope.spawn_task(TaskPriority::High, async move {
+                while let Ok(mut morsel) = rx.recv().await {
+                    morsel = morsel
+                        .async_try_map::<PolarsError, _, _>(async |df| {
+                            Self::evaluate_one(&exprs, &exec_state, df).await
+                        })
+                        .await?;
+                    morsel.set_consume_token(wg.token());
+
+                    if tx.send(morsel).await.is_err() {
+                        break;
+                    }
+                    wg.wait().await;
+                }
+
+                Ok(())
+            })
+        }));
+
+        // Distributor task.
+        //
+        // This splits off the completed partitions.
+        join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+            let mut lengths = Vec::new();
+
+            while let Ok(morsel) = recv.recv().await {
+                let (df, seq, source_token, wait_token) = morsel.into_inner();
+                self.seq = seq;
+                drop(wait_token);
+
+                if df.height() == 0 {
+                    continue;
+                }
+
+                // The buffer only holds rows of a single partition, so it suffices to look at its
+                // last row to find where the new rows complete it.
+                let buf_height = self.buf_df.height();
+                let start = if buf_height == 0 {
+                    Self::last_partition_start(&self.keys, &mut lengths, &df)?
+                } else {
+                    let mut probe = self.buf_df.slice(buf_height as i64 - 1, 1);
+                    probe.vstack_mut_owned(df.clone())?;
+                    match Self::last_partition_start(&self.keys, &mut lengths, &probe)? {
+                        0 => 0,
+                        start => buf_height - 1 + start,
+                    }
+                };
+
+                self.buf_df.vstack_mut_owned(df)?;
+                if start == 0 {
+                    continue;
+                }
+
+                let df;
+                (df, self.buf_df) = self.buf_df.split_at(start as i64);
+
+                if distributor
+                    .send(Morsel::new(df, seq, source_token))
+                    .await
+                    .is_err()
+                {
+                    break;
+                }
+            }
+
+            Ok(())
+        }));
+    }
+}
@@ -8,6 +8,7 @@ use polars_core::prelude::*;
 use polars_core::schema::Schema;
 use polars_core::utils::accumulate_dataframes_vertical;
 use polars_core::with_match_physical_numeric_polars_type;
+use polars_ooc::SpillContext;
 use polars_plan::plans::{DynamicPred, PredicateExpr, TrivialPredicateExpr};
 use polars_utils::IdxSize;
 use polars_utils::priority::Priority;
@@ -649,6 +650,13 @@ impl ComputeNode for TopKNode {
         Ok(())
     }
 
+    fn spill_contexts(&self) -> Vec<Arc<dyn SpillContext>> {
+        match &self.state {
+            TopKState::WaitingForK(sink_node) => sink_node.spill_contexts(),
+            TopKState::Sink { .. } | TopKState::Source(_) | TopKState::Done => Vec::new(),
+        }
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
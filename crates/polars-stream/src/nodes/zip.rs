@@ -7,7 +7,7 @@ use polars_core::prelude::{Column, IntoColumn};
 use polars_core::schema::Schema;
 use polars_core::series::Series;
 use polars_error::polars_ensure;
-use polars_ooc::{MostRecentSpillContext, SpillFrame};
+use polars_ooc::{MostRecentSpillContext, SpillContext, SpillFrame};
 use polars_utils::itertools::Itertools;
 
 use super::compute_node_prelude::*;
@@ -81,12 +81,12 @@ impl InputHead {
         self.is_broadcast.is_some() && (self.total_len > 0 || self.stream_exhausted)
     }
 
-    async fn take(&mut self, len: usize) -> DataFrame {
+    async fn take(&mut self, len: usize) -> PolarsResult<DataFrame> {
         let columns: Vec<Column> = if self.is_broadcast.unwrap() && self.shape() != (0, 0) {
             self.morsels[0]
                 .0
                 .get()
-                .await
+                .await?
                 .columns()
                 .iter()
                 .map(|s| s.new_from_index(0, len))
@@ -97,10 +97,10 @@ impl InputHead {
             return if self.morsels[0].0.height() == len {
                 self.morsels.pop_front().unwrap().0.into_df().await
             } else {
-                let mut df = self.morsels[0].0.get_mut().await;
+                let mut df = self.morsels[0].0.get_mut().await?;
                 let (head, tail) = df.split_at(len as i64);
                 *df = tail;
-                head
+                Ok(head)
             };
         } else {
             self.schema
@@ -109,14 +109,14 @@ impl InputHead {
                 .collect()
         };
 
-        unsafe { DataFrame::new_unchecked(len, columns) }
+        Ok(unsafe { DataFrame::new_unchecked(len, columns) })
     }
 
-    async fn consume_broadcast(&mut self) -> DataFrame {
+    async fn consume_broadcast(&mut self) -> PolarsResult<DataFrame> {
         assert!(self.is_broadcast == Some(true) && self.total_len == 1);
-        let out = self.morsels.pop_front().unwrap().0.into_df().await;
+        let out = self.morsels.pop_front().unwrap().0.into_df().await?;
         self.clear();
-        out
+        Ok(out)
     }
 
     fn shape(&self) -> (usize, usize) {
@@ -227,6 +227,10 @@ impl ComputeNode for ZipNode {
         Ok(())
     }
 
+    fn spill_contexts(&self) -> Vec<Arc<dyn SpillContext>> {
+        vec![self.spill_ctx.clone()]
+    }
+
     fn spawn<'env, 's>(
         &'env mut self,
         scope: &'s TaskScope<'s, 'env>,
@@ -325,7 +329,7 @@ impl ComputeNode for ZipNode {
                 }
 
                 for input_head in &mut self.input_heads {
-                    out.push(input_head.take(common_size).await);
+                    out.push(input_head.take(common_size).await?);
                 }
                 let out_df = concat_df_horizontal(&out, false, true, false)?;
                 out.clear();
@@ -372,7 +376,7 @@ impl ComputeNode for ZipNode {
                 .all(|h| h.is_broadcast == Some(true));
             if all_broadcast {
                 for input_head in &mut self.input_heads {
-                    out.push(input_head.consume_broadcast().await);
+                    out.push(input_head.consume_broadcast().await?);
                 }
                 let out_df = concat_df_horizontal(&out, false, true, false)?;
                 out.clear();
@@ -1,7 +1,7 @@
 use std::fmt::Write;
 
 use polars_ops::frame::JoinArgs;
-use polars_plan::dsl::PartitionStrategyIR;
+use polars_plan::dsl::{PartitionStrategyIR, SampleMethod, SampleOptions};
 use polars_plan::plans::expr_ir::ExprIR;
 use polars_plan::plans::{AExpr, EscapeLabel};
 use polars_plan::prelude::FileWriteFormat;
@@ -37,6 +37,14 @@ impl NodeStyle {
             K::InMemorySource { .. }
             | K::InputIndependentSelect { .. }
             | K::NegativeSlice { .. }
+            | K::Sample {
+                options:
+                    SampleOptions {
+                        method: SampleMethod::Reservoir { .. },
+                        ..
+                    },
+                ..
+            }
             | K::InMemorySink { .. }
             | K::Sort { .. }
             | K::GroupBy { .. }
@@ -270,6 +278,9 @@ fn visualize_plan_rec(
             offset,
             length,
         } => ("slice".to_owned(), &[*input, *offset, *length][..]),
+        PhysNodeKind::Sample { input, options } => {
+            (options.to_string().to_lowercase(), from_ref(input))
+        },
         PhysNodeKind::Shift {
             input,
             offset,
@@ -314,6 +325,8 @@ fn visualize_plan_rec(
             FileWriteFormat::Csv(_) => ("csv-sink".to_string(), from_ref(input)),
             #[cfg(feature = "json")]
             FileWriteFormat::NDJson(_) => ("ndjson-sink".to_string(), from_ref(input)),
+            #[cfg(feature = "avro")]
+            FileWriteFormat::Avro(_) => ("avro-sink".to_string(), from_ref(input)),
         },
         PhysNodeKind::PartitionedSink { input, options } => {
             let variant = match options.partition_strategy {
@@ -330,6 +343,8 @@ fn visualize_plan_rec(
                 FileWriteFormat::Csv(_) => (format!("{variant}[csv]"), from_ref(input)),
                 #[cfg(feature = "json")]
                 FileWriteFormat::NDJson(_) => (format!("{variant}[ndjson]"), from_ref(input)),
+                #[cfg(feature = "avro")]
+                FileWriteFormat::Avro(_) => (format!("{variant}[avro]"), from_ref(input)),
             }
         },
         PhysNodeKind::InMemoryMap {
@@ -401,6 +416,20 @@ fn visualize_plan_rec(
 
             (s, from_ref(input))
         },
+        PhysNodeKind::SortedOver { input, keys, exprs } => {
+            let mut s = String::new();
+            s.push_str("sorted-over\\n");
+            let f = &mut s;
+            write!(f, "keys: {}\\n", keys.iter().join(", ")).unwrap();
+            write!(
+                f,
+                "exprs:\\n{}",
+                fmt_exprs_to_label(exprs, expr_arena, FormatExprStyle::Select)
+            )
+            .unwrap();
+
+            (s, from_ref(input))
+        },
         PhysNodeKind::Sort {
             input,
             by_column,
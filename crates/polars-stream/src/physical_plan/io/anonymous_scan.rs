@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use polars_core::config;
+use polars_plan::plans::{AnonymousScan, AnonymousScanArgs, AnonymousScanBatches};
+use polars_utils::format_pl_smallstr;
+
+use crate::execute::StreamingExecutionState;
+use crate::nodes::io_sources::batch::builder::BatchFnReaderBuilder;
+use crate::nodes::io_sources::batch::{BatchFnReader, GetBatchFn, GetBatchState};
+use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
+
+/// Reads an `AnonymousScan` as a single source of the multi-scan. The scan is only started when
+/// the first batch is requested.
+pub fn anonymous_scan_to_reader_builder(
+    function: Arc<dyn AnonymousScan>,
+    fmt_str: &'static str,
+    args: AnonymousScanArgs,
+) -> Arc<dyn FileReaderBuilder> {
+    let name = format_pl_smallstr!("anonymous[{}]", fmt_str);
+    let file_schema = args.schema.clone();
+
+    let args = Mutex::new(Some(args));
+    let batches: Mutex<Option<AnonymousScanBatches>> = Mutex::new(None);
+    let get_batch_fn = Box::new(move |_state: &StreamingExecutionState| {
+        let mut batches = batches.lock();
+        if let Some(args) = args.lock().take() {
+            *batches = Some(function.scan_batched(args)?);
+        }
+        batches.as_mut().unwrap().next().transpose()
+    }) as GetBatchFn;
+
+    let reader = BatchFnReader {
+        name: name.clone(),
+        output_schema: Some(file_schema),
+        get_batch_state: Some(GetBatchState::from(get_batch_fn)),
+        execution_state: None,
+        verbose: config::verbose(),
+    };
+
+    Arc::new(BatchFnReaderBuilder {
+        name,
+        reader: std::sync::Mutex::new(Some(reader)),
+        execution_state: Default::default(),
+    }) as Arc<dyn FileReaderBuilder>
+}
@@ -32,7 +32,9 @@ use crate::physical_plan::ZipBehavior;
 use crate::physical_plan::lower_group_by::{
     GroupByLowerKind, build_group_by_stream, try_build_streaming_group_by,
 };
-use crate::physical_plan::lower_ir::{build_filter_stream, build_row_idx_stream};
+use crate::physical_plan::lower_ir::{
+    build_filter_stream, build_row_idx_stream, build_stable_sort_keys,
+};
 
 type ExprNodeKey = Node;
 
@@ -55,6 +57,7 @@ impl ExprCache {
 struct LowerExprContext<'a> {
     prepare_visualization: bool,
     sortedness: &'a IRPlanSorted,
+    sorted_over: Option<&'a PlHashSet<Node>>,
     expr_arena: &'a mut Arena<AExpr>,
     phys_sm: &'a mut SlotMap<PhysNodeKey, PhysNode>,
     cache: &'a mut ExprCache,
@@ -67,6 +70,7 @@ impl<'a> From<LowerExprContext<'a>> for StreamingLowerIRContext<'a> {
         Self {
             prepare_visualization: value.prepare_visualization,
             sortedness: value.sortedness,
+            sorted_over: value.sorted_over,
         }
     }
 }
@@ -75,6 +79,7 @@ impl<'a> From<&LowerExprContext<'a>> for StreamingLowerIRContext<'a> {
         Self {
             prepare_visualization: value.prepare_visualization,
             sortedness: value.sortedness,
+            sorted_over: value.sorted_over,
         }
     }
 }
@@ -385,6 +390,110 @@ fn is_length_preserving_ctx(expr_key: ExprNodeKey, ctx: &mut LowerExprContext) -
     matches!(height, ExprProjectionHeight::Column)
 }
 
+/// Collects the window expressions in `exprs` whose partitions are contiguous in the output of
+/// the IR node `input`, because it is sorted by the partition keys.
+pub fn sorted_over_exprs(
+    input: Node,
+    input_schema: &Schema,
+    exprs: &[ExprIR],
+    expr_arena: &Arena<AExpr>,
+    sortedness: &IRPlanSorted,
+) -> PlHashSet<Node> {
+    let mut sorted_over = PlHashSet::new();
+    for expr in exprs {
+        for (node, ae) in expr_arena.iter(expr.node()) {
+            let AExpr::Over {
+                partition_by,
+                mapping: WindowMapping::GroupsToRows | WindowMapping::Join,
+                ..
+            } = ae
+            else {
+                continue;
+            };
+
+            if partition_by.is_empty()
+                || !partition_by
+                    .iter()
+                    .all(|n| matches!(expr_arena.get(*n), AExpr::Column(_)))
+            {
+                continue;
+            }
+
+            let keys = partition_by
+                .iter()
+                .map(|n| ExprIR::from_node(*n, expr_arena))
+                .collect_vec();
+            if sortedness
+                .are_keys_sorted_any(input, &keys, expr_arena, input_schema)
+                .is_some()
+            {
+                sorted_over.insert(node);
+            }
+        }
+    }
+    sorted_over
+}
+
+/// Whether the window expression `expr` can be evaluated on `input` with a sorted-over node.
+fn is_sorted_over_ctx(input: PhysStream, expr: Node, ctx: &LowerExprContext) -> bool {
+    if !ctx.sorted_over.is_some_and(|s| s.contains(&expr)) {
+        return false;
+    }
+
+    // The partition keys must be columns of this input, not of e.g. an exploded list.
+    let AExpr::Over { partition_by, .. } = ctx.expr_arena.get(expr) else {
+        unreachable!()
+    };
+    let input_schema = input.output_schema(ctx.phys_sm);
+    partition_by.iter().all(|n| match ctx.expr_arena.get(*n) {
+        AExpr::Column(name) => input_schema.contains(name),
+        _ => false,
+    })
+}
+
+/// Builds a sorted-over node that evaluates the window expression `expr` partition by partition
+/// as the partition keys change. Outputs a single column named `out_name`.
+fn build_sorted_over_stream(
+    input: PhysStream,
+    expr: Node,
+    out_name: PlSmallStr,
+    ctx: &mut LowerExprContext,
+) -> PolarsResult<PhysStream> {
+    let AExpr::Over { partition_by, .. } = ctx.expr_arena.get(expr) else {
+        unreachable!()
+    };
+    let keys = partition_by
+        .iter()
+        .map(|n| match ctx.expr_arena.get(*n) {
+            AExpr::Column(name) => name.clone(),
+            _ => unreachable!(),
+        })
+        .collect_vec();
+
+    // Only pass on the columns needed by the window expression.
+    let mut select_names: PlIndexSet<PlSmallStr> =
+        polars_plan::utils::aexpr_to_leaf_names_iter(expr, ctx.expr_arena)
+            .cloned()
+            .collect();
+    select_names.extend(keys.iter().cloned());
+    let select_exprs = select_names
+        .into_iter()
+        .map(|name| {
+            ExprIR::new(
+                ctx.expr_arena.add(AExpr::Column(name.clone())),
+                OutputName::ColumnLhs(name),
+            )
+        })
+        .collect_vec();
+    let input = build_select_stream_with_ctx(input, &select_exprs, ctx)?;
+
+    let exprs = vec![ExprIR::new(expr, OutputName::Alias(out_name))];
+    let output_schema = schema_for_select(input, &exprs, ctx)?;
+    let kind = PhysNodeKind::SortedOver { input, keys, exprs };
+    let node_key = ctx.phys_sm.insert(PhysNode::new(output_schema, kind));
+    Ok(PhysStream::first(node_key))
+}
+
 fn build_fallback_node_with_ctx(
     input: PhysStream,
     exprs: &[ExprIR],
@@ -854,6 +963,7 @@ fn lower_exprs_with_ctx(
                     StreamingLowerIRContext {
                         prepare_visualization: ctx.prepare_visualization,
                         sortedness: ctx.sortedness,
+                        sorted_over: None,
                     },
                     false,
                 )?;
@@ -918,6 +1028,7 @@ fn lower_exprs_with_ctx(
                     StreamingLowerIRContext {
                         prepare_visualization: ctx.prepare_visualization,
                         sortedness: ctx.sortedness,
+                        sorted_over: None,
                     },
                     false,
                 )?;
@@ -1893,15 +2004,31 @@ fn lower_exprs_with_ctx(
                 let select_stream =
                     build_select_stream_with_ctx(input, std::slice::from_ref(&inner_expr_ir), ctx)?;
                 let col_expr = ctx.expr_arena.add(AExpr::Column(sorted_name.clone()));
+                let sorted_col_expr = ExprIR::new(col_expr, OutputName::Alias(sorted_name));
+                let mut by_column = vec![sorted_col_expr.clone()];
+                let mut sort_options = (&options).into();
+                let select_stream = build_stable_sort_keys(
+                    select_stream,
+                    &mut by_column,
+                    &mut sort_options,
+                    ctx.expr_arena,
+                    ctx.phys_sm,
+                );
                 let kind = PhysNodeKind::Sort {
                     input: select_stream,
-                    by_column: vec![ExprIR::new(col_expr, OutputName::Alias(sorted_name))],
+                    by_column,
                     slice: None,
-                    sort_options: (&options).into(),
+                    sort_options,
                 };
                 let output_schema = select_stream.output_schema(ctx.phys_sm).clone();
                 let node_key = ctx.phys_sm.insert(PhysNode::new(output_schema, kind));
-                input_streams.insert(PhysStream::first(node_key));
+                // Drop the row index we may have added.
+                let sort_stream = build_select_stream_with_ctx(
+                    PhysStream::first(node_key),
+                    std::slice::from_ref(&sorted_col_expr),
+                    ctx,
+                )?;
+                input_streams.insert(sort_stream);
                 transformed_exprs.push(col_expr);
             },
 
@@ -1921,17 +2048,26 @@ fn lower_exprs_with_ctx(
                 let select_stream = build_select_stream_with_ctx(input, &all_inner_expr_irs, ctx)?;
 
                 // Sort the inputs.
+                let mut by_column = by_names
+                    .into_iter()
+                    .map(|name| {
+                        ExprIR::new(
+                            ctx.expr_arena.add(AExpr::Column(name.clone())),
+                            OutputName::Alias(name),
+                        )
+                    })
+                    .collect();
+                let mut sort_options = sort_options;
+                let select_stream = build_stable_sort_keys(
+                    select_stream,
+                    &mut by_column,
+                    &mut sort_options,
+                    ctx.expr_arena,
+                    ctx.phys_sm,
+                );
                 let kind = PhysNodeKind::Sort {
                     input: select_stream,
-                    by_column: by_names
-                        .into_iter()
-                        .map(|name| {
-                            ExprIR::new(
-                                ctx.expr_arena.add(AExpr::Column(name.clone())),
-                                OutputName::Alias(name),
-                            )
-                        })
-                        .collect(),
+                    by_column,
                     slice: None,
                     sort_options,
                 };
@@ -1939,7 +2075,16 @@ fn lower_exprs_with_ctx(
                 let sort_node_key = ctx.phys_sm.insert(PhysNode::new(output_schema, kind));
 
                 let sorted_col_expr = ctx.expr_arena.add(AExpr::Column(sorted_name.clone()));
-                input_streams.insert(PhysStream::first(sort_node_key));
+                // Drop the row index we may have added.
+                let sort_stream = build_select_stream_with_ctx(
+                    PhysStream::first(sort_node_key),
+                    &[ExprIR::new(
+                        sorted_col_expr,
+                        OutputName::Alias(sorted_name.clone()),
+                    )],
+                    ctx,
+                )?;
+                input_streams.insert(sort_stream);
                 transformed_exprs.push(sorted_col_expr);
             },
 
@@ -2172,6 +2317,16 @@ fn lower_exprs_with_ctx(
                 transformed_exprs.push(trans_expr);
             },
 
+            #[cfg(feature = "approx_quantile")]
+            AExpr::Function {
+                function: IRFunctionExpr::ApproxQuantile { .. },
+                ..
+            } => {
+                let (trans_stream, trans_expr) = lower_reduce_node(input, expr, ctx)?;
+                input_streams.insert(trans_stream);
+                transformed_exprs.push(trans_expr);
+            },
+
             AExpr::Function {
                 function:
                     IRFunctionExpr::Boolean(
@@ -2575,6 +2730,13 @@ fn lower_exprs_with_ctx(
                 transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
             },
 
+            AExpr::Over { .. } if is_sorted_over_ctx(input, expr, ctx) => {
+                let out_name = unique_column_name();
+                let stream = build_sorted_over_stream(input, expr, out_name.clone(), ctx)?;
+                input_streams.insert(stream);
+                transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
+            },
+
             AExpr::Over {
                 function,
                 partition_by,
@@ -2811,6 +2973,7 @@ pub fn lower_exprs(
         cache: expr_cache,
         prepare_visualization: ctx.prepare_visualization,
         sortedness: ctx.sortedness,
+        sorted_over: ctx.sorted_over,
         node_scratch: &mut Default::default(),
         ae_height_scratch: &mut Default::default(),
     };
@@ -2841,6 +3004,7 @@ pub fn build_select_stream(
         cache: expr_cache,
         prepare_visualization: ctx.prepare_visualization,
         sortedness: ctx.sortedness,
+        sorted_over: ctx.sorted_over,
         node_scratch: &mut Default::default(),
         ae_height_scratch: &mut Default::default(),
     };
@@ -2919,6 +3083,7 @@ pub fn build_length_preserving_select_stream(
         cache: expr_cache,
         prepare_visualization: ctx.prepare_visualization,
         sortedness: ctx.sortedness,
+        sorted_over: ctx.sorted_over,
         node_scratch: &mut Default::default(),
         ae_height_scratch: &mut Default::default(),
     };
@@ -4,8 +4,9 @@ use arrow::array::{MutableBinaryViewArray, Utf8ViewArray};
 use arrow::datatypes::ArrowDataType;
 use parking_lot::Mutex;
 use polars_async::executor::ALLOW_RAYON_THREADS;
+use polars_buffer::Buffer;
 use polars_core::frame::{DataFrame, UniqueKeepStrategy};
-use polars_core::prelude::{DataType, IntoColumn, PlHashMap, PlHashSet};
+use polars_core::prelude::{DataType, IntoColumn, PlHashMap, PlHashSet, SortMultipleOptions};
 use polars_core::scalar::Scalar;
 use polars_core::schema::Schema;
 use polars_core::series::Series;
@@ -25,6 +26,7 @@ use polars_plan::prelude::*;
 use polars_utils::aliases::PlIndexMap;
 use polars_utils::arena::{Arena, Node};
 use polars_utils::itertools::Itertools;
+use polars_utils::pl_path::PlRefPath;
 use polars_utils::pl_str::PlSmallStr;
 #[cfg(any(feature = "parquet", feature = "csv", feature = "json"))]
 use polars_utils::relaxed_cell::RelaxedCell;
@@ -41,7 +43,9 @@ use crate::nodes::io_sources::multi_scan::components::forbid_extra_columns::Forb
 use crate::nodes::io_sources::multi_scan::components::projection::builder::ProjectionBuilder;
 use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
 use crate::physical_plan::ZipBehavior;
-use crate::physical_plan::lower_expr::{ExprCache, build_select_stream, lower_exprs};
+use crate::physical_plan::lower_expr::{
+    ExprCache, build_select_stream, lower_exprs, sorted_over_exprs,
+};
 use crate::physical_plan::lower_group_by::build_group_by_stream;
 use crate::utils::late_materialized_df::LateMaterializedDataFrame;
 
@@ -144,10 +148,49 @@ pub fn build_row_idx_stream(
     PhysStream::first(with_row_idx_node_key)
 }
 
+/// Replaces `maintain_order` of a sort by an extra sort key on a row index of
+/// the input, so ties are broken on the position of the rows in the input. The
+/// row index is added as a column to the stream, the caller is responsible for
+/// dropping it again.
+pub fn build_stable_sort_keys(
+    input: PhysStream,
+    by_column: &mut Vec<ExprIR>,
+    sort_options: &mut SortMultipleOptions,
+    expr_arena: &mut Arena<AExpr>,
+    phys_sm: &mut SlotMap<PhysNodeKey, PhysNode>,
+) -> PhysStream {
+    if !sort_options.maintain_order {
+        return input;
+    }
+
+    let row_idx_name = unique_column_name();
+    let stream = build_row_idx_stream(input, row_idx_name.clone(), None, phys_sm);
+
+    // Broadcast the options before extending them with the row index.
+    for opts in [&mut sort_options.descending, &mut sort_options.nulls_last] {
+        if opts.len() == 1 && by_column.len() > 1 {
+            *opts = vec![opts[0]; by_column.len()];
+        }
+    }
+    let row_idx_node = expr_arena.add(AExpr::Column(row_idx_name.clone()));
+    by_column.push(ExprIR::new(
+        row_idx_node,
+        OutputName::ColumnLhs(row_idx_name),
+    ));
+    sort_options.descending.push(false);
+    sort_options.nulls_last.push(true);
+
+    // No longer needed for the actual sort itself, handled by row index.
+    sort_options.maintain_order = false;
+    stream
+}
+
 #[derive(Clone, Copy)]
 pub struct StreamingLowerIRContext<'a> {
     pub prepare_visualization: bool,
     pub sortedness: &'a IRPlanSorted,
+    /// Window expressions of the IR node being lowered that may use a sorted-over node.
+    pub sorted_over: Option<&'a PlHashSet<Node>>,
 }
 
 #[recursive::recursive]
@@ -206,6 +249,7 @@ pub fn lower_ir(
         },
 
         IR::Select { input, expr, .. } => {
+            let input = *input;
             let selectors = expr.clone();
 
             if selectors.iter().all(|e| {
@@ -217,15 +261,30 @@ pub fn lower_ir(
                 disable_morsel_split.get_or_insert(true);
             }
 
-            let phys_input = lower_ir!(*input)?;
+            let phys_input = lower_ir!(input)?;
+            let input_schema = IR::schema_with_cache(input, ir_arena, schema_cache);
+            let sorted_over =
+                sorted_over_exprs(input, &input_schema, &selectors, expr_arena, ctx.sortedness);
+            let ctx = StreamingLowerIRContext {
+                sorted_over: Some(&sorted_over),
+                ..ctx
+            };
             return build_select_stream(
                 phys_input, &selectors, expr_arena, phys_sm, expr_cache, ctx,
             );
         },
 
         IR::HStack { input, exprs, .. } => {
+            let input = *input;
             let exprs = exprs.to_vec();
-            let phys_input = lower_ir!(*input)?;
+            let phys_input = lower_ir!(input)?;
+            let input_schema = IR::schema_with_cache(input, ir_arena, schema_cache);
+            let sorted_over =
+                sorted_over_exprs(input, &input_schema, &exprs, expr_arena, ctx.sortedness);
+            let ctx = StreamingLowerIRContext {
+                sorted_over: Some(&sorted_over),
+                ..ctx
+            };
             return build_hstack_stream(phys_input, &exprs, expr_arena, phys_sm, expr_cache, ctx);
         },
 
@@ -405,6 +464,11 @@ pub fn lower_ir(
                     offset,
                 },
 
+                FunctionIR::Sample(options) => PhysNodeKind::Sample {
+                    input: phys_input,
+                    options,
+                },
+
                 function if function.is_streamable() => {
                     let map = Arc::new(move |df| function.evaluate(df));
                     let format_str = ctx.prepare_visualization.then(|| {
@@ -492,25 +556,14 @@ pub fn lower_ir(
 
             let mut stream = phys_input;
 
-            // If we need to maintain order augment with row index. This is
-            // not yet necessary for the non-limiting case as that one
-            // dispatches to in-memory.
-            if sort_options.maintain_order && limit < u64::MAX {
-                let row_idx_name = unique_column_name();
-                stream = build_row_idx_stream(stream, row_idx_name.clone(), None, phys_sm);
-
-                // Add row index to sort columns.
-                let row_idx_node = expr_arena.add(AExpr::Column(row_idx_name.clone()));
-                by_column.push(ExprIR::new(
-                    row_idx_node,
-                    OutputName::ColumnLhs(row_idx_name),
-                ));
-                sort_options.descending.push(false);
-                sort_options.nulls_last.push(true);
-
-                // No longer needed for the actual sort itself, handled by row index.
-                sort_options.maintain_order = false;
-            }
+            // If we need to maintain order augment with row index.
+            stream = build_stable_sort_keys(
+                stream,
+                &mut by_column,
+                &mut sort_options,
+                expr_arena,
+                phys_sm,
+            );
 
             let mut output_exprs: Vec<_> = output_schema
                 .iter_names()
@@ -628,12 +681,12 @@ pub fn lower_ir(
 
         v @ IR::Scan { .. } => {
             let IR::Scan {
-                sources: scan_sources,
+                sources: mut scan_sources,
                 file_info,
                 mut hive_parts,
-                output_schema: _,
+                output_schema: ir_output_schema,
                 scan_type,
-                predicate,
+                mut predicate,
                 predicate_file_skip_applied,
                 unified_scan_args,
             } = v.clone()
@@ -775,6 +828,7 @@ pub fn lower_ir(
                             prefetch_semaphore: std::sync::OnceLock::new(),
                             shared_prefetch_wait_group_slot: Default::default(),
                             io_metrics: std::sync::OnceLock::new(),
+                            rejects: unified_scan_args.rejects.clone(),
                         }) as _
                     },
                     #[cfg(feature = "json")]
@@ -785,8 +839,22 @@ pub fn lower_ir(
                             prefetch_semaphore: std::sync::OnceLock::new(),
                             shared_prefetch_wait_group_slot: Default::default(),
                             io_metrics: std::sync::OnceLock::new(),
+                            rejects: unified_scan_args.rejects.clone().map(|callback| {
+                                let file_schema = file_info
+                                    .reader_schema
+                                    .as_ref()
+                                    .and_then(|schema| schema.as_ref().right().cloned())
+                                    .unwrap_or_else(|| file_info.schema.clone());
+                                (callback, file_schema)
+                            }),
                         },
                     ) as _,
+
+                    #[cfg(feature = "avro")]
+                    FileScanIR::Avro => {
+                        Arc::new(crate::nodes::io_sources::avro::AvroReaderBuilder::default()) as _
+                    },
+
                     #[cfg(feature = "python")]
                     FileScanIR::PythonDataset {
                         dataset_object: _,
@@ -814,9 +882,55 @@ pub fn lower_ir(
                         }) as _
                     },
 
+                    #[cfg(feature = "fwf")]
+                    FileScanIR::Fwf { options } => {
+                        Arc::new(crate::nodes::io_sources::fwf::FwfReaderBuilder {
+                            options: options.clone(),
+                            prefetch_limit: RelaxedCell::new_usize(0),
+                            prefetch_semaphore: std::sync::OnceLock::new(),
+                            shared_prefetch_wait_group_slot: Default::default(),
+                            io_metrics: std::sync::OnceLock::new(),
+                        }) as _
+                    },
+
                     FileScanIR::ExpandedPaths { name: _ } => unreachable!(),
 
-                    FileScanIR::Anonymous { .. } => todo!("unimplemented: AnonymousScan"),
+                    FileScanIR::Anonymous { options, function } => {
+                        // The slice is applied again by the multiscan, so this only limits the
+                        // number of rows the scan function has to produce.
+                        let n_rows = match &unified_scan_args.pre_slice {
+                            Some(slice @ Slice::Positive { .. }) => Some(slice.end_position()),
+                            _ => None,
+                        };
+                        let with_columns = function
+                            .allows_projection_pushdown()
+                            .then(|| unified_scan_args.projection.clone())
+                            .flatten();
+                        // A scan that allows predicate pushdown is trusted to fully apply the
+                        // predicate, as is done by the in-memory engine.
+                        let scan_predicate = if function.allows_predicate_pushdown() {
+                            predicate.take().map(|p| p.to_expr(expr_arena))
+                        } else {
+                            None
+                        };
+
+                        // Give multiscan a single scan source. (It doesn't actually read from this).
+                        scan_sources = ScanSources::Paths(Buffer::from_iter([PlRefPath::new(
+                            options.fmt_str,
+                        )]));
+
+                        crate::physical_plan::io::anonymous_scan::anonymous_scan_to_reader_builder(
+                            function.clone(),
+                            options.fmt_str,
+                            AnonymousScanArgs {
+                                n_rows,
+                                with_columns,
+                                schema: file_info.schema.clone(),
+                                output_schema: ir_output_schema,
+                                predicate: scan_predicate,
+                            },
+                        )
+                    },
                 };
 
                 {
@@ -1781,8 +1895,6 @@ fn insert_sort_node_if_not_sorted(
     schema_cache: &mut PlHashMap<Node, Arc<Schema>>,
     sortedness: &IRPlanSorted,
 ) -> Node {
-    use polars_core::prelude::SortMultipleOptions;
-
     let input_schema = IR::schema_with_cache(input, ir_arena, schema_cache);
     if sortedness
         .is_expr_sorted(input, on, expr_arena, &input_schema)
@@ -23,7 +23,7 @@ use polars_plan::dsl::StrptimeOptions;
 use polars_plan::dsl::deletion::DeletionFilesList;
 use polars_plan::dsl::{
     CastColumnsPolicy, ColumnsUdf, FileSinkOptions, JoinTypeOptionsIR, MissingColumnsPolicy,
-    PartitionedSinkOptionsIR, PredicateFileSkip, ScanSources, TableStatistics,
+    PartitionedSinkOptionsIR, PredicateFileSkip, SampleOptions, ScanSources, TableStatistics,
 };
 use polars_plan::plans::expr_ir::ExprIR;
 use polars_plan::plans::hive::HivePartitionsDf;
@@ -198,6 +198,11 @@ pub enum PhysNodeKind {
         length: PhysStream,
     },
 
+    Sample {
+        input: PhysStream,
+        options: SampleOptions,
+    },
+
     Shift {
         input: PhysStream,
         offset: PhysStream,
@@ -292,6 +297,13 @@ pub enum PhysNodeKind {
         slice: Option<(IdxSize, IdxSize)>,
     },
 
+    /// Window expressions on an input whose partitions are contiguous.
+    SortedOver {
+        input: PhysStream,
+        keys: Vec<PlSmallStr>,
+        exprs: Vec<ExprIR>,
+    },
+
     Sort {
         input: PhysStream,
         by_column: Vec<ExprIR>,
@@ -575,6 +587,7 @@ fn visit_node_inputs_mut(
             | PhysNodeKind::Reduce { input, .. }
             | PhysNodeKind::StreamingSlice { input, .. }
             | PhysNodeKind::NegativeSlice { input, .. }
+            | PhysNodeKind::Sample { input, .. }
             | PhysNodeKind::Filter { input, .. }
             | PhysNodeKind::SimpleProjection { input, .. }
             | PhysNodeKind::InMemorySink { input }
@@ -583,6 +596,7 @@ fn visit_node_inputs_mut(
             | PhysNodeKind::PartitionedSink { input, .. }
             | PhysNodeKind::InMemoryMap { input, .. }
             | PhysNodeKind::SortedGroupBy { input, .. }
+            | PhysNodeKind::SortedOver { input, .. }
             | PhysNodeKind::Map { input, .. }
             | PhysNodeKind::Sort { input, .. }
             | PhysNodeKind::Multiplexer { input }
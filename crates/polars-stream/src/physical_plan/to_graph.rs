@@ -13,7 +13,8 @@ use polars_expr::state::ExecutionState;
 use polars_mem_engine::create_physical_plan;
 use polars_mem_engine::scan_predicate::create_scan_predicate;
 use polars_plan::dsl::{
-    FileSinkOptions, JoinOptionsIR, PartitionStrategyIR, PartitionedSinkOptionsIR, ScanSources,
+    FileSinkOptions, JoinOptionsIR, PartitionStrategyIR, PartitionedSinkOptionsIR, SampleMethod,
+    ScanSources,
 };
 use polars_plan::plans::expr_ir::ExprIR;
 use polars_plan::plans::{AExpr, ArenaExprIter, IR, IRAggExpr};
@@ -154,6 +155,20 @@ fn to_graph_rec<'a>(
             )
         },
 
+        Sample { input, options } => {
+            let input_key = to_graph_rec(input.node, ctx)?;
+            match options.method {
+                SampleMethod::Reservoir { n } => ctx.graph.add_node(
+                    nodes::sample::ReservoirSampleNode::new(n, options.seed),
+                    [(input_key, input.port)],
+                ),
+                SampleMethod::Bernoulli { .. } | SampleMethod::System { .. } => ctx.graph.add_node(
+                    nodes::sample::SampleFilterNode::new(*options),
+                    [(input_key, input.port)],
+                ),
+            }
+        },
+
         DynamicSlice {
             input,
             offset,
@@ -586,6 +601,29 @@ fn to_graph_rec<'a>(
             )
         },
 
+        SortedOver { input, keys, exprs } => {
+            let input_schema = input.output_schema(ctx.phys_sm).clone();
+            let input_key = to_graph_rec(input.node, ctx)?;
+            let exprs = exprs
+                .iter()
+                .map(|e| {
+                    Ok((
+                        e.output_name().clone(),
+                        create_stream_expr(e, ctx, &input_schema)?,
+                    ))
+                })
+                .collect::<PolarsResult<Arc<[_]>>>()?;
+
+            ctx.graph.add_node(
+                nodes::sorted_over::SortedOver::new(
+                    keys.iter().cloned().collect(),
+                    exprs,
+                    input_schema,
+                ),
+                [(input_key, input.port)],
+            )
+        },
+
         Sort {
             input,
             by_column,
@@ -593,31 +631,24 @@ fn to_graph_rec<'a>(
             sort_options,
         } => {
             let input_schema = input.output_schema(ctx.phys_sm).clone();
-            let lmdf = Arc::new(LateMaterializedDataFrame::default());
-            let mut lp_arena = Arena::default();
-            let df_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema.clone()));
-            let sort_node = lp_arena.add(IR::Sort {
-                input: df_node,
-                by_column: by_column.clone(),
-                slice: slice.map(|t| (t.0, t.1, None)),
-                sort_options: sort_options.clone(),
-            });
-            let executor = Mutex::new(create_physical_plan(
-                sort_node,
-                &mut lp_arena,
-                ctx.expr_arena,
-                Some(crate::dispatch::build_streaming_query_executor),
-            )?);
+            let key_selectors = by_column
+                .iter()
+                .map(|e| create_stream_expr(e, ctx, &input_schema))
+                .try_collect_vec()?;
+
+            let first_key_column = match ctx.expr_arena.get(by_column[0].node()) {
+                AExpr::Column(name) => input_schema.index_of(name),
+                _ => None,
+            };
 
             let input_key = to_graph_rec(input.node, ctx)?;
             ctx.graph.add_node(
-                nodes::in_memory_map::InMemoryMapNode::new(
+                nodes::sort::SortNode::new(
                     input_schema,
-                    Arc::new(move |df| {
-                        lmdf.set_materialized_dataframe(df);
-                        let mut state = ExecutionState::new();
-                        executor.lock().execute(&mut state)
-                    }),
+                    key_selectors,
+                    sort_options,
+                    *slice,
+                    first_key_column,
                 ),
                 [(input_key, input.port)],
             )
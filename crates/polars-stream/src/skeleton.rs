@@ -14,6 +14,7 @@ use polars_utils::arena::{Arena, Node};
 use polars_utils::relaxed_cell::RelaxedCell;
 use slotmap::{SecondaryMap, SlotMap};
 
+use crate::execute::StreamingExecutionOptions;
 use crate::graph::{Graph, GraphNodeKey};
 use crate::metrics::GraphMetrics;
 use crate::physical_plan::{PhysNode, PhysNodeKey, PhysNodeKind, StreamingLowerIRContext};
@@ -34,7 +35,26 @@ pub fn run_query(
     ir_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
 ) -> PolarsResult<QueryResult> {
-    StreamingQuery::build(node, ir_arena, expr_arena)?.execute()
+    run_query_with_options(
+        node,
+        ir_arena,
+        expr_arena,
+        StreamingExecutionOptions::default(),
+    )
+}
+
+/// Executes the IR with the streaming engine using the given execution options.
+///
+/// See [`run_query`].
+pub fn run_query_with_options(
+    node: Node,
+    ir_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+    execution_options: StreamingExecutionOptions,
+) -> PolarsResult<QueryResult> {
+    let mut query = StreamingQuery::build(node, ir_arena, expr_arena)?;
+    query.execution_options = execution_options;
+    query.execute()
 }
 
 /// Visualizes the physical plan as a dot graph.
@@ -49,6 +69,7 @@ pub fn visualize_physical_plan(
     let ctx = StreamingLowerIRContext {
         prepare_visualization: true,
         sortedness: &sortedness,
+        sorted_over: None,
     };
     let root_phys_node =
         crate::physical_plan::build_physical_plan(node, ir_arena, expr_arena, &mut phys_sm, ctx)?;
@@ -65,6 +86,7 @@ pub struct StreamingQuery {
     pub phys_sm: SlotMap<PhysNodeKey, PhysNode>,
     pub phys_to_graph: SecondaryMap<PhysNodeKey, GraphNodeKey>,
     pub metrics: Option<Arc<Mutex<GraphMetrics>>>,
+    pub execution_options: StreamingExecutionOptions,
 }
 
 /// Configures if IR lowering creates the `format_str` for `InMemoryMap`.
@@ -105,6 +127,7 @@ impl StreamingQuery {
         let ctx = StreamingLowerIRContext {
             prepare_visualization: cfg_prepare_visualization_data(),
             sortedness: &sortedness,
+            sorted_over: None,
         };
         let root_phys_node = crate::physical_plan::build_physical_plan(
             node,
@@ -140,6 +163,7 @@ impl StreamingQuery {
             phys_sm,
             phys_to_graph,
             metrics,
+            execution_options: StreamingExecutionOptions::default(),
         };
 
         Ok(out)
@@ -153,10 +177,12 @@ impl StreamingQuery {
             phys_sm,
             phys_to_graph,
             metrics,
+            execution_options,
         } = self;
 
         let query_start = Instant::now();
-        let mut results = crate::execute::execute_graph(&mut graph, metrics.clone())?;
+        let mut results =
+            crate::execute::execute_graph(&mut graph, metrics.clone(), execution_options)?;
         let query_elapsed = query_start.elapsed();
 
         // Print metrics.
@@ -198,6 +224,9 @@ impl StreamingQuery {
                 let io_total_bytes_requested = node_metrics.io_total_bytes_requested;
                 let io_total_bytes_received = node_metrics.io_total_bytes_received;
                 let io_total_bytes_sent = node_metrics.io_total_bytes_sent;
+                let io_total_row_groups_skipped = node_metrics.io_total_row_groups_skipped;
+                let memory_peak_resident_bytes = node_metrics.memory_peak_resident_bytes;
+                let memory_spilled_bytes = node_metrics.memory_spilled_bytes;
 
                 lines.push(
                     (total_time, format!(
@@ -210,7 +239,9 @@ impl StreamingQuery {
                                     total_active_time={io_total_active_time:.2?}, \
                                     total_bytes_requested={io_total_bytes_requested}, \
                                     total_bytes_received={io_total_bytes_received}, \
-                                    total_bytes_sent={io_total_bytes_sent})"))
+                                    total_bytes_sent={io_total_bytes_sent}, \
+                                    total_row_groups_skipped={io_total_row_groups_skipped}), \
+                                 memory(peak_resident_bytes={memory_peak_resident_bytes}, spilled_bytes={memory_spilled_bytes})"))
                 );
 
                 total_query_ns += total_ns;
@@ -39,6 +39,7 @@ pub mod pl_path;
 mod pl_ref_str;
 pub mod pl_str;
 pub mod priority;
+pub mod quantile_sketch;
 pub mod range;
 pub mod regex_cache;
 pub mod relaxed_cell;
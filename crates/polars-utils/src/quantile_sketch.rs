@@ -0,0 +1,216 @@
+use std::f64::consts::PI;
+
+/// The compression parameter δ, bounding the number of centroids to roughly
+/// δ / 2 after compression. With δ = 200 quantiles away from the median are
+/// typically accurate to well within 1% of the rank.
+const COMPRESSION: f64 = 200.0;
+
+/// The number of unmerged values after which the sketch is compressed.
+const BUFFER_SIZE: usize = 5 * COMPRESSION as usize;
+
+#[derive(Clone, Copy, Debug)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// Computing Extremely Accurate Quantiles Using t-Digests
+/// Ted Dunning, Otmar Ertl
+///
+/// A merging t-digest using the k1 scale function `k(q) = δ / 2π * asin(2q - 1)`,
+/// which keeps the centroids near the tails small so extreme quantiles stay
+/// accurate. Sketches can be combined in any order, making them suitable for
+/// parallel and streaming aggregation; the memory usage of a sketch is bounded
+/// independent of the number of inserted values.
+#[derive(Clone, Debug)]
+pub struct QuantileSketch {
+    /// Compressed centroids, sorted by mean.
+    centroids: Vec<Centroid>,
+    /// Values and centroids not yet merged into `centroids`.
+    unmerged: Vec<Centroid>,
+    min: f64,
+    max: f64,
+}
+
+impl Default for QuantileSketch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn k_scale(q: f64) -> f64 {
+    COMPRESSION / (2.0 * PI) * (2.0 * q - 1.0).asin()
+}
+
+fn k_scale_inv(k: f64) -> f64 {
+    if k >= COMPRESSION / 4.0 {
+        1.0
+    } else {
+        ((k * 2.0 * PI / COMPRESSION).sin() + 1.0) / 2.0
+    }
+}
+
+impl QuantileSketch {
+    pub fn new() -> Self {
+        Self {
+            centroids: Vec::new(),
+            unmerged: Vec::new(),
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.centroids.is_empty() && self.unmerged.is_empty()
+    }
+
+    /// Add a value to the sketch, NaN values are ignored.
+    pub fn insert(&mut self, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        self.unmerged.push(Centroid {
+            mean: value,
+            weight: 1.0,
+        });
+        if self.unmerged.len() >= BUFFER_SIZE {
+            self.compress();
+        }
+    }
+
+    pub fn combine(&mut self, other: &QuantileSketch) {
+        if other.is_empty() {
+            return;
+        }
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+        self.unmerged.extend_from_slice(&other.centroids);
+        self.unmerged.extend_from_slice(&other.unmerged);
+        if self.unmerged.len() >= BUFFER_SIZE {
+            self.compress();
+        }
+    }
+
+    /// Merge all unmerged values into the centroids.
+    fn compress(&mut self) {
+        if self.unmerged.is_empty() {
+            return;
+        }
+
+        let mut all = std::mem::take(&mut self.unmerged);
+        all.append(&mut self.centroids);
+        all.sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+        let total: f64 = all.iter().map(|c| c.weight).sum();
+
+        let mut out = Vec::with_capacity(COMPRESSION as usize);
+        let mut weight_before = 0.0;
+        let mut q_limit = k_scale_inv(k_scale(0.0) + 1.0);
+        let mut iter = all.into_iter();
+        let mut cur = iter.next().unwrap();
+        for next in iter {
+            let q = (weight_before + cur.weight + next.weight) / total;
+            if q <= q_limit {
+                cur.weight += next.weight;
+                cur.mean += (next.mean - cur.mean) * next.weight / cur.weight;
+            } else {
+                weight_before += cur.weight;
+                out.push(cur);
+                q_limit = k_scale_inv(k_scale(weight_before / total) + 1.0);
+                cur = next;
+            }
+        }
+        out.push(cur);
+
+        self.centroids = out;
+        self.unmerged = Vec::new();
+    }
+
+    /// Estimate the value at quantile `q`, which must be between 0 and 1.
+    ///
+    /// Returns `None` if no values were inserted.
+    pub fn quantile(&mut self, q: f64) -> Option<f64> {
+        debug_assert!((0.0..=1.0).contains(&q));
+        self.compress();
+        let centroids = self.centroids.as_slice();
+        let (first, last) = (centroids.first()?, centroids.last()?);
+        if centroids.len() == 1 {
+            return Some(first.mean);
+        }
+
+        let total: f64 = centroids.iter().map(|c| c.weight).sum();
+        let target = q * total;
+
+        // Interpolate between the extremes and the centers of the outer centroids.
+        if target < first.weight / 2.0 {
+            let frac = target / (first.weight / 2.0);
+            return Some(self.min + (first.mean - self.min) * frac);
+        }
+        if target > total - last.weight / 2.0 {
+            let frac = (total - target) / (last.weight / 2.0);
+            return Some(self.max - (self.max - last.mean) * frac);
+        }
+
+        // Otherwise interpolate between the centers of the two surrounding centroids.
+        let mut center = first.weight / 2.0;
+        for w in centroids.windows(2) {
+            let next_center = center + (w[0].weight + w[1].weight) / 2.0;
+            if target <= next_center {
+                let frac = (target - center) / (next_center - center);
+                let out = w[0].mean + (w[1].mean - w[0].mean) * frac;
+                return Some(out.clamp(self.min, self.max));
+            }
+            center = next_center;
+        }
+        Some(last.mean)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rank_error(sorted: &[f64], q: f64, estimate: f64) -> f64 {
+        let rank = sorted.partition_point(|v| *v < estimate) as f64;
+        (rank / sorted.len() as f64 - q).abs()
+    }
+
+    #[test]
+    fn test_small_is_exact_at_values() {
+        let mut sketch = QuantileSketch::new();
+        for v in [3.0, 1.0, 2.0, f64::NAN] {
+            sketch.insert(v);
+        }
+        assert_eq!(sketch.quantile(0.0), Some(1.0));
+        assert_eq!(sketch.quantile(0.5), Some(2.0));
+        assert_eq!(sketch.quantile(1.0), Some(3.0));
+        assert_eq!(QuantileSketch::new().quantile(0.5), None);
+    }
+
+    #[test]
+    fn test_combine_bounded() {
+        let n = 100_000;
+        let values: Vec<f64> = (0..n).map(|i| ((i * 7919) % n) as f64).collect();
+
+        let mut sketches: Vec<QuantileSketch> = (0..8).map(|_| QuantileSketch::new()).collect();
+        for (i, v) in values.iter().enumerate() {
+            sketches[i % 8].insert(*v);
+        }
+        let mut sketch = QuantileSketch::new();
+        for s in &sketches {
+            sketch.combine(s);
+        }
+        sketch.compress();
+        assert!(sketch.centroids.len() <= COMPRESSION as usize);
+
+        let mut sorted = values;
+        sorted.sort_by(f64::total_cmp);
+        for q in [0.001, 0.01, 0.25, 0.5, 0.75, 0.99, 0.999] {
+            let estimate = sketch.quantile(q).unwrap();
+            assert!(rank_error(&sorted, q, estimate) < 0.005, "q: {q}");
+        }
+        assert_eq!(sketch.quantile(0.0), Some(0.0));
+        assert_eq!(sketch.quantile(1.0), Some((n - 1) as f64));
+    }
+}
@@ -0,0 +1,74 @@
+use polars::io::SerWriter;
+use polars::io::avro::AvroWriter;
+use polars::prelude::*;
+
+#[test]
+fn test_scan_avro() -> PolarsResult<()> {
+    let path =
+        std::env::temp_dir().join(format!("polars-test-scan-avro-{}.avro", std::process::id()));
+
+    let mut df = df!(
+        "a" => (0..100i64).collect::<Vec<_>>(),
+        "b" => (0..100).map(|i| format!("{i}")).collect::<Vec<_>>(),
+        "c" => (0..100).map(|i| i % 2 == 0).collect::<Vec<_>>(),
+    )?;
+    let mut file = std::fs::File::create(&path)?;
+    AvroWriter::new(&mut file).finish(&mut df)?;
+    drop(file);
+
+    let scan = || LazyFrame::scan_avro(path.to_str().unwrap().into(), Default::default());
+
+    let out = scan()?.collect()?;
+    assert!(out.equals(&df));
+
+    let out = scan()?
+        .select([col("c"), col("a")])
+        .slice(10, 5)
+        .collect()?;
+    assert!(out.equals(&df.select(["c", "a"])?.slice(10, 5)));
+
+    let out = scan()?.filter(col("a").gt(lit(97i64))).collect()?;
+    assert_eq!(out.column("b")?.str()?.get(0), Some("98"));
+    assert_eq!(out.height(), 2);
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+fn test_scan_avro_many_blocks() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join(format!(
+        "polars-test-scan-avro-blocks-{}.avro",
+        std::process::id()
+    ));
+
+    let mut df = df!(
+        "a" => (0..1000i64).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| format!("{i}")).collect::<Vec<_>>(),
+    )?;
+    let mut file = std::fs::File::create(&path)?;
+    AvroWriter::new(&mut file)
+        .with_block_size(Some(64))
+        .finish(&mut df)?;
+    drop(file);
+
+    let scan = || LazyFrame::scan_avro(path.to_str().unwrap().into(), Default::default());
+
+    let out = scan()?.slice(100, 200).collect()?;
+    assert!(out.equals(&df.slice(100, 200)));
+
+    let out = scan()?.slice(950, 100).collect()?;
+    assert!(out.equals(&df.slice(950, 100)));
+
+    let out = scan()?.select([len()]).collect()?;
+    assert_eq!(out.column("len")?.idx()?.get(0), Some(1000));
+
+    let out = scan()?
+        .with_row_index("idx", None)
+        .slice(130, 3)
+        .collect()?;
+    assert_eq!(out.column("idx")?.idx()?.get(0), Some(130));
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
@@ -8,7 +8,7 @@ use arrow::io::avro::avro_schema::write::{compress, write_block, write_metadata}
 use arrow::io::avro::write;
 use arrow::record_batch::RecordBatchT;
 use avro_schema::schema::{Field as AvroField, Record, Schema as AvroSchema};
-use polars::io::avro::{AvroReader, AvroWriter};
+use polars::io::avro::{AvroCodec, AvroReader, AvroWriter};
 use polars::io::{SerReader, SerWriter};
 use polars::prelude::df;
 use polars_error::PolarsResult;
@@ -485,3 +485,68 @@ fn test_with_columns() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_write_multiple_blocks() -> PolarsResult<()> {
+    let mut df = df!("i64" => &[1, 2], "string" => &["a", "b"])?;
+    df.vstack_mut(&df!("i64" => &[3, 4, 5], "string" => &["c", "d", "e"])?)?;
+
+    for block_size in [None, Some(2)] {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+
+        AvroWriter::new(&mut buf)
+            .with_name("test".to_string())
+            .with_namespace(Some("polars.test".to_string()))
+            .with_block_size(block_size)
+            .finish(&mut df)?;
+        buf.set_position(0);
+
+        let read_df = AvroReader::new(buf).finish()?;
+        assert!(df.equals(&read_df));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_write_and_read_zstd() -> PolarsResult<()> {
+    let mut df = df!("i64" => &[1, 2, 3, 4, 5], "string" => &["a", "b", "c", "d", "e"])?;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    AvroWriter::new(&mut buf)
+        .with_codec(Some(AvroCodec::Zstd))
+        .with_block_size(Some(2))
+        .finish(&mut df)?;
+
+    buf.set_position(0);
+    let schema = AvroReader::new(&mut buf).schema()?;
+    assert_eq!(&schema, df.schema().as_ref());
+
+    buf.set_position(0);
+    let read_df = AvroReader::new(&mut buf).finish()?;
+    assert!(df.equals(&read_df));
+
+    buf.set_position(0);
+    let read_df = AvroReader::new(&mut buf)
+        .with_n_rows(Some(3))
+        .with_columns(Some(vec!["string".to_string()]))
+        .finish()?;
+    assert!(df.select(["string"])?.head(Some(3)).equals(&read_df));
+
+    Ok(())
+}
+
+#[test]
+fn test_write_random_sync_marker() -> PolarsResult<()> {
+    let mut df = df!("i64" => &[1, 2])?;
+
+    let mut files = [Vec::new(), Vec::new()];
+    for file in files.iter_mut() {
+        AvroWriter::new(Cursor::new(file)).finish(&mut df)?;
+    }
+    // The files only differ in their sync markers.
+    assert_eq!(files[0].len(), files[1].len());
+    assert_ne!(files[0], files[1]);
+
+    Ok(())
+}
@@ -311,3 +311,172 @@ fn test_ext_store_sink_and_scan_parquet() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_scan_parquet_bloom_filter_predicate() -> PolarsResult<()> {
+    use polars::io::parquet::write::BloomFilterOptions;
+
+    let path = std::env::temp_dir().join(format!(
+        "polars-test-scan-parquet-bloom-filter-{}.parquet",
+        std::process::id()
+    ));
+
+    // Even ids spread across row groups so that min/max statistics can't skip anything.
+    let ids = (0..1000i64)
+        .map(|i| (i * 7919 % 1000) * 2)
+        .collect::<Vec<_>>();
+    let names = ids.iter().map(|i| format!("name-{i}")).collect::<Vec<_>>();
+    let row_group_246 = ids.iter().position(|&i| i == 246).unwrap() / 100;
+    let mut df = df!("id" => ids, "name" => names)?;
+
+    // A low false positive rate, so that the bloom filters rule out every other row group.
+    let options = BloomFilterOptions {
+        fpp: 1e-6,
+        ..Default::default()
+    };
+    let mut file = std::fs::File::create(&path)?;
+    ParquetWriter::new(&mut file)
+        .with_row_group_size(Some(100))
+        .with_bloom_filters(vec![("id".into(), options), ("name".into(), options)])
+        .finish(&mut df)?;
+    drop(file);
+
+    let scan = || LazyFrame::scan_parquet(path.to_str().unwrap().into(), Default::default());
+
+    let out = scan()?.filter(col("id").eq(lit(246i64))).collect()?;
+    assert_eq!(out.column("id")?.i64()?.cont_slice()?, &[246]);
+
+    let out = scan()?.filter(col("id").eq(lit(501i64))).collect()?;
+    assert_eq!(out.height(), 0);
+
+    let out = scan()?
+        .filter(col("name").is_in(
+            lit(Series::new("x".into(), ["name-2", "name-3", "name-1998"])),
+            false,
+        ))
+        .sort(["id"], Default::default())
+        .collect()?;
+    assert_eq!(out.column("id")?.i64()?.cont_slice()?, &[2, 1998]);
+
+    // Zero the data of every row group except the one holding 246. The bloom filters and the
+    // footer are written after the row groups, so the scan only succeeds if the bloom filters
+    // skip the zeroed row groups without reading them.
+    let mut bytes = std::fs::read(&path)?;
+    let metadata = polars_parquet::read::read_metadata(&mut Cursor::new(&bytes))?;
+    for (i, rg) in metadata.row_groups.iter().enumerate() {
+        if i != row_group_246 {
+            let range = rg.full_byte_range();
+            bytes[range.start as usize..range.end as usize].fill(0);
+        }
+    }
+    std::fs::write(&path, &bytes)?;
+
+    let out = scan()?
+        .filter(col("id").eq(lit(246i64)))
+        .collect_with_engine(Engine::Streaming)?
+        .unwrap_single();
+    assert_eq!(out.column("id")?.i64()?.cont_slice()?, &[246]);
+    assert_eq!(
+        out.column("name")?.str()?.iter().collect::<Vec<_>>(),
+        vec![Some("name-246")]
+    );
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+fn test_scan_parquet_page_index_predicate() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join(format!(
+        "polars-test-scan-parquet-page-index-{}.parquet",
+        std::process::id()
+    ));
+
+    let mut df = df!(
+        "id" => (0..10_000i64).collect::<Vec<_>>(),
+        "name" => (0..10_000).map(|i| format!("name-{i}")).collect::<Vec<_>>(),
+    )?;
+
+    let mut file = std::fs::File::create(&path)?;
+    ParquetWriter::new(&mut file)
+        .with_data_page_size(Some(4096))
+        .finish(&mut df)?;
+    drop(file);
+
+    let scan = || LazyFrame::scan_parquet(path.to_str().unwrap().into(), Default::default());
+
+    let out = scan()?.filter(col("id").eq(lit(1234i64))).collect()?;
+    assert_eq!(out.column("id")?.i64()?.cont_slice()?, &[1234]);
+    assert_eq!(
+        out.column("name")?.str()?.iter().collect::<Vec<_>>(),
+        vec![Some("name-1234")]
+    );
+
+    let out = scan()?
+        .with_row_index("index", None)
+        .filter(
+            col("id")
+                .gt_eq(lit(5000i64))
+                .and(col("id").lt(lit(5100i64))),
+        )
+        .collect()?;
+    assert_eq!(
+        out.column("id")?.i64()?.cont_slice()?,
+        (5000..5100i64).collect::<Vec<_>>().as_slice()
+    );
+    assert_eq!(
+        out.column("index")?
+            .idx()?
+            .into_no_null_iter()
+            .collect::<Vec<_>>(),
+        (5000..5100).collect::<Vec<IdxSize>>()
+    );
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+fn test_scan_parquet_encrypted() -> PolarsResult<()> {
+    use polars::io::parquet::encryption::ParquetEncryptionOptions;
+
+    let path = std::env::temp_dir().join(format!(
+        "polars-test-scan-parquet-encrypted-{}.parquet",
+        std::process::id()
+    ));
+
+    let mut df = df!(
+        "id" => (0..1000i64).collect::<Vec<_>>(),
+        "name" => (0..1000).map(|i| format!("name-{i}")).collect::<Vec<_>>(),
+    )?;
+
+    let keys = write::test_key_retriever();
+    let mut file = std::fs::File::create(&path)?;
+    ParquetWriter::new(&mut file)
+        .with_row_group_size(Some(100))
+        .with_encryption(Some(ParquetEncryptionOptions {
+            column_key_metadata: vec![("id".into(), b"col-a".to_vec())],
+            ..ParquetEncryptionOptions::new(keys.clone(), b"footer".to_vec())
+        }))
+        .finish(&mut df)?;
+    drop(file);
+
+    let args = ScanArgsParquet {
+        decryption: Some(keys),
+        ..Default::default()
+    };
+    let out = LazyFrame::scan_parquet(path.to_str().unwrap().into(), args)?
+        .filter(col("id").gt_eq(lit(500i64)))
+        .collect()?;
+    assert!(out.equals(&df.slice(500, 500)));
+
+    // The footer can't be read without the keys.
+    assert!(
+        LazyFrame::scan_parquet(path.to_str().unwrap().into(), Default::default())
+            .and_then(|lf| lf.collect())
+            .is_err()
+    );
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
@@ -299,3 +299,359 @@ fn test_read_parquet_with_columns() {
     assert_eq!(df_read.shape(), (3, 2));
     df_read.equals(&expected);
 }
+
+#[test]
+fn test_write_parquet_bloom_filters() -> PolarsResult<()> {
+    use polars::io::parquet::write::BloomFilterOptions;
+    use polars_parquet::parquet::bloom_filter;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = df!(
+        "id" => (0..1000i64).collect::<Vec<_>>(),
+        "name" => (0..1000).map(|i| format!("name-{i}")).collect::<Vec<_>>(),
+        "x" => (0..1000i32).collect::<Vec<_>>(),
+    )?;
+
+    ParquetWriter::new(&mut buf)
+        .with_bloom_filters(vec![
+            ("id".into(), BloomFilterOptions::default()),
+            (
+                "name".into(),
+                BloomFilterOptions {
+                    ndv: Some(2000),
+                    fpp: 0.01,
+                },
+            ),
+        ])
+        .finish(&mut df)?;
+
+    let metadata = read_metadata(&mut buf)?;
+    let columns = metadata.row_groups[0].parquet_columns();
+    assert!(columns[2].bloom_filter_offset().is_none());
+
+    let mut bitset = vec![];
+    bloom_filter::read(&columns[0], &mut buf, &mut bitset)?;
+    assert!(!bitset.is_empty());
+    for i in 0..1000i64 {
+        assert!(bloom_filter::is_in_set(
+            &bitset,
+            bloom_filter::hash_native(i)
+        ));
+    }
+
+    bloom_filter::read(&columns[1], &mut buf, &mut bitset)?;
+    assert!(!bitset.is_empty());
+    for i in 0..1000 {
+        let value = format!("name-{i}");
+        assert!(bloom_filter::is_in_set(
+            &bitset,
+            bloom_filter::hash_byte(value)
+        ));
+    }
+
+    // The file itself is still readable.
+    buf.set_position(0);
+    let df_read = ParquetReader::new(buf).finish()?;
+    assert!(df_read.equals(&df));
+    Ok(())
+}
+
+#[test]
+fn test_write_parquet_bloom_filters_unknown_column() {
+    use polars::io::parquet::write::BloomFilterOptions;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = df!("a" => [1, 2, 3]).unwrap();
+
+    let result = ParquetWriter::new(&mut buf)
+        .with_bloom_filters(vec![("b".into(), BloomFilterOptions::default())])
+        .finish(&mut df);
+    assert!(result.is_err());
+}
+
+/// Resolves keys from an in-memory table of `(key metadata, key)`.
+struct TestKeyRetriever(Vec<(&'static [u8], Vec<u8>)>);
+
+impl polars::io::parquet::encryption::KeyRetriever for TestKeyRetriever {
+    fn retrieve_key(&self, key_metadata: &[u8]) -> ParquetResult<Vec<u8>> {
+        self.0
+            .iter()
+            .find(|(m, _)| *m == key_metadata)
+            .map(|(_, key)| key.clone())
+            .ok_or_else(|| {
+                polars_parquet::parquet::error::ParquetError::InvalidParameter(
+                    "unknown key".to_string(),
+                )
+            })
+    }
+}
+
+pub(super) fn test_key_retriever() -> polars::io::parquet::encryption::ParquetKeyRetriever {
+    polars::io::parquet::encryption::ParquetKeyRetriever::new(TestKeyRetriever(vec![
+        (b"footer", (0..16).collect()),
+        (b"col-a", (16..48).collect()),
+        (b"col-b", (48..72).collect()),
+    ]))
+}
+
+#[test]
+fn test_write_parquet_encrypted_footer() -> PolarsResult<()> {
+    use polars::io::parquet::encryption::ParquetEncryptionOptions;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = df!(
+        "a" => (0..1000i64).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| format!("value-{i}")).collect::<Vec<_>>(),
+    )?;
+
+    let keys = test_key_retriever();
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(300))
+        .with_encryption(Some(ParquetEncryptionOptions::new(
+            keys.clone(),
+            b"footer".to_vec(),
+        )))
+        .finish(&mut df)?;
+
+    assert_eq!(&buf.get_ref()[..4], b"PARE");
+    assert_eq!(&buf.get_ref()[buf.get_ref().len() - 4..], b"PARE");
+
+    buf.set_position(0);
+    let df_read = ParquetReader::new(&mut buf)
+        .with_decryption(Some(keys))
+        .finish()?;
+    assert!(df_read.equals(&df));
+
+    // Without the keys nothing can be read.
+    buf.set_position(0);
+    assert!(ParquetReader::new(&mut buf).finish().is_err());
+
+    // A wrong footer key fails to decrypt the footer.
+    let wrong_keys = polars::io::parquet::encryption::ParquetKeyRetriever::new(TestKeyRetriever(
+        vec![(b"footer", (1..17).collect())],
+    ));
+    buf.set_position(0);
+    assert!(
+        ParquetReader::new(&mut buf)
+            .with_decryption(Some(wrong_keys))
+            .finish()
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_write_parquet_encrypted_columns_plaintext_footer() -> PolarsResult<()> {
+    use polars::io::parquet::encryption::ParquetEncryptionOptions;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = df!(
+        "a" => (0..1000i64).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| format!("value-{i}")).collect::<Vec<_>>(),
+        "c" => (0..1000i32).collect::<Vec<_>>(),
+    )?;
+
+    let keys = test_key_retriever();
+    ParquetWriter::new(&mut buf)
+        .with_encryption(Some(ParquetEncryptionOptions {
+            column_key_metadata: vec![
+                ("a".into(), b"col-a".to_vec()),
+                ("b".into(), b"col-b".to_vec()),
+            ],
+            plaintext_footer: true,
+            ..ParquetEncryptionOptions::new(keys.clone(), b"footer".to_vec())
+        }))
+        .finish(&mut df)?;
+
+    assert_eq!(&buf.get_ref()[..4], b"PAR1");
+
+    // The plaintext footer can be read without the keys, but the encrypted columns can't.
+    let metadata = read_metadata(&mut buf)?;
+    let columns = metadata.row_groups[0].parquet_columns();
+    assert!(columns[0].is_encrypted());
+    assert!(columns[1].is_encrypted());
+    assert!(!columns[2].is_encrypted());
+
+    // The page indexes of the encrypted columns are written as encrypted modules.
+    for column in columns {
+        assert!(column.column_index_offset().is_some());
+        let offset = column.offset_index_offset().unwrap() as usize;
+        let length = column.offset_index_length().unwrap() as usize;
+        let offset_index = &buf.get_ref()[offset..offset + length];
+        assert_eq!(
+            polars_parquet::parquet::read::deserialize_offset_index(offset_index).is_ok(),
+            !column.is_encrypted()
+        );
+    }
+
+    buf.set_position(0);
+    let df_read = ParquetReader::new(&mut buf)
+        .with_columns(Some(vec!["c".to_string()]))
+        .finish()?;
+    assert!(df_read.equals(&df.select(["c"])?));
+
+    buf.set_position(0);
+    assert!(
+        ParquetReader::new(&mut buf)
+            .with_columns(Some(vec!["a".to_string()]))
+            .finish()
+            .is_err()
+    );
+
+    buf.set_position(0);
+    let df_read = ParquetReader::new(&mut buf)
+        .with_decryption(Some(keys.clone()))
+        .finish()?;
+    assert!(df_read.equals(&df));
+
+    // Bloom filters can't be written for encrypted columns.
+    let result = ParquetWriter::new(Cursor::new(Vec::new()))
+        .with_bloom_filters(vec![(
+            "a".into(),
+            polars::io::parquet::write::BloomFilterOptions::default(),
+        )])
+        .with_encryption(Some(ParquetEncryptionOptions {
+            column_key_metadata: vec![("a".into(), b"col-a".to_vec())],
+            ..ParquetEncryptionOptions::new(keys, b"footer".to_vec())
+        }))
+        .finish(&mut df);
+    assert!(result.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_read_parquet_encrypted_inaccessible_column_key() -> PolarsResult<()> {
+    use polars::io::parquet::encryption::ParquetEncryptionOptions;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = df!(
+        "a" => (0..1000i64).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| format!("value-{i}")).collect::<Vec<_>>(),
+    )?;
+
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(300))
+        .with_encryption(Some(ParquetEncryptionOptions {
+            column_key_metadata: vec![
+                ("a".into(), b"col-a".to_vec()),
+                ("b".into(), b"col-b".to_vec()),
+            ],
+            ..ParquetEncryptionOptions::new(test_key_retriever(), b"footer".to_vec())
+        }))
+        .finish(&mut df)?;
+
+    // Only the key of the projected column is needed.
+    let keys = polars::io::parquet::encryption::ParquetKeyRetriever::new(TestKeyRetriever(vec![
+        (b"footer", (0..16).collect()),
+        (b"col-a", (16..48).collect()),
+    ]));
+    buf.set_position(0);
+    let df_read = ParquetReader::new(&mut buf)
+        .with_decryption(Some(keys.clone()))
+        .with_columns(Some(vec!["a".to_string()]))
+        .finish()?;
+    assert!(df_read.equals(&df.select(["a"])?));
+
+    buf.set_position(0);
+    assert!(
+        ParquetReader::new(&mut buf)
+            .with_decryption(Some(keys))
+            .with_columns(Some(vec!["b".to_string()]))
+            .finish()
+            .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_write_parquet_footer_key_plaintext_footer() -> PolarsResult<()> {
+    use polars::io::parquet::encryption::ParquetEncryptionOptions;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = df!(
+        "a" => (0..1000i64).collect::<Vec<_>>(),
+        "b" => (0..1000).map(|i| format!("value-{i}")).collect::<Vec<_>>(),
+    )?;
+
+    let keys = test_key_retriever();
+    ParquetWriter::new(&mut buf)
+        .with_encryption(Some(ParquetEncryptionOptions {
+            plaintext_footer: true,
+            ..ParquetEncryptionOptions::new(keys.clone(), b"footer".to_vec())
+        }))
+        .finish(&mut df)?;
+
+    // The statistics of columns encrypted with the footer key aren't in the plaintext footer.
+    let metadata = read_metadata(&mut buf)?;
+    for column in metadata.row_groups[0].parquet_columns() {
+        assert!(column.is_encrypted());
+        assert!(column.statistics(&metadata.footer_buf).is_none());
+    }
+
+    buf.set_position(0);
+    let df_read = ParquetReader::new(&mut buf)
+        .with_decryption(Some(keys))
+        .finish()?;
+    assert!(df_read.equals(&df));
+    Ok(())
+}
+
+#[test]
+fn test_write_parquet_page_index() -> PolarsResult<()> {
+    use arrow::array::PrimitiveArray;
+    use polars_parquet::parquet::read::deserialize_page_index;
+    use polars_parquet::read::statistics::deserialize_page_statistics;
+
+    let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+    let mut df = df!("id" => (0..10_000i64).collect::<Vec<_>>())?;
+
+    ParquetWriter::new(&mut buf)
+        .with_data_page_size(Some(4096))
+        .finish(&mut df)?;
+
+    let metadata = read_metadata(&mut buf)?;
+    let rg = &metadata.row_groups[0];
+    let column = &rg.parquet_columns()[0];
+
+    let read_range = |offset: Option<i64>, length: Option<i32>| {
+        let offset = offset.unwrap() as usize;
+        buf.get_ref()[offset..offset + length.unwrap() as usize].to_vec()
+    };
+    let column_index = read_range(column.column_index_offset(), column.column_index_length());
+    let offset_index = read_range(column.offset_index_offset(), column.offset_index_length());
+
+    let page_index = deserialize_page_index(&column_index, &offset_index)?;
+    assert!(page_index.num_pages() > 1);
+
+    let page_row_ranges = page_index
+        .page_row_ranges(rg.num_rows())
+        .collect::<Vec<_>>();
+    assert_eq!(page_row_ranges.first().unwrap().start, 0);
+    assert_eq!(page_row_ranges.last().unwrap().end, rg.num_rows());
+
+    let field = ArrowField::new("id".into(), ArrowDataType::Int64, true);
+    let statistics =
+        deserialize_page_statistics(&field, column, &page_index, rg.num_rows())?.unwrap();
+    let min = statistics
+        .min_value
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i64>>()
+        .unwrap();
+    let max = statistics
+        .max_value
+        .as_any()
+        .downcast_ref::<PrimitiveArray<i64>>()
+        .unwrap();
+
+    for (i, range) in page_row_ranges.iter().enumerate() {
+        assert_eq!(min.value(i), range.start as i64);
+        assert_eq!(max.value(i), range.end as i64 - 1);
+    }
+
+    // Corrupt indexes are rejected.
+    assert!(
+        deserialize_page_index(&column_index[..column_index.len() / 2], &offset_index).is_err()
+    );
+
+    Ok(())
+}
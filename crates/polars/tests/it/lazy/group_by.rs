@@ -192,3 +192,108 @@ fn test_filter_aggregated_expression() -> PolarsResult<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_group_by_grouping_sets() -> PolarsResult<()> {
+    let df = df![
+        "a" => ["x", "x", "y", "y"],
+        "b" => [1, 2, 1, 1],
+        "v" => [1, 2, 3, 4],
+    ]?;
+    let sort_options = SortMultipleOptions::default().with_nulls_last(true);
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by([col("a"), col("b")])
+        .rollup()?
+        .with_grouping_id("gid")
+        .agg([col("v").sum()])
+        .sort(["gid", "a", "b"], sort_options.clone())
+        .collect()?;
+    let expected = df![
+        "a" => [Some("x"), Some("x"), Some("y"), Some("x"), Some("y"), None],
+        "b" => [Some(1), Some(2), Some(1), None, None, None],
+        "v" => [1, 2, 7, 3, 7, 10],
+        "gid" => [0u32, 0, 0, 1, 1, 3],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by([col("a"), col("b")])
+        .cube()?
+        .agg([col("v").sum()])
+        .collect()?;
+    assert_eq!(out.height(), 3 + 2 + 2 + 1);
+
+    // Sums, counts, minima and maxima are rolled up from the finest set; a mean is not, so it
+    // is aggregated per set.
+    for mean in [false, true] {
+        let mut aggs = vec![
+            col("v").min().alias("min"),
+            col("v").max().alias("max"),
+            len().alias("len"),
+        ];
+        if mean {
+            aggs.push(col("v").mean().alias("mean"));
+        }
+        let out = df
+            .clone()
+            .lazy()
+            .group_by([col("a"), col("b")])
+            .rollup()?
+            .with_grouping_id("gid")
+            .agg(aggs)
+            .sort(["gid", "a", "b"], sort_options.clone())
+            .collect()?;
+        assert_eq!(
+            out.column("min")?
+                .i32()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            [1, 2, 3, 1, 3, 1]
+        );
+        assert_eq!(
+            out.column("max")?
+                .i32()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            [1, 2, 4, 2, 4, 4]
+        );
+        assert_eq!(
+            out.column("len")?
+                .idx()?
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            [1, 1, 2, 2, 2, 4]
+        );
+        if mean {
+            assert_eq!(
+                out.column("mean")?
+                    .f64()?
+                    .into_no_null_iter()
+                    .collect::<Vec<_>>(),
+                [1.0, 2.0, 3.5, 1.5, 3.5, 2.5]
+            );
+        }
+    }
+
+    let out = df
+        .lazy()
+        .group_by([col("a"), col("b")])
+        .grouping_sets(vec![vec![1], vec![]])?
+        .having(col("v").sum().gt(lit(3)))
+        .agg([col("v").sum()])
+        .sort(["b"], sort_options)
+        .collect()?;
+    let expected = df![
+        "b" => [Some(1), None],
+        "v" => [8, 10],
+    ]?;
+    assert_eq!(out.column("a")?.null_count(), 2);
+    assert!(out.select(["b", "v"])?.equals_missing(&expected));
+
+    Ok(())
+}
@@ -277,3 +277,137 @@ fn test_group_by_on_lists() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_sample() -> PolarsResult<()> {
+    let df = df![
+        "a" => (0..1000).collect::<Vec<i32>>(),
+    ]?;
+
+    let engines = [
+        Engine::InMemory,
+        #[cfg(feature = "streaming")]
+        Engine::Streaming,
+    ];
+    for engine in engines {
+        let sample = |options: SampleOptions| {
+            df.clone()
+                .lazy()
+                .sample(options)
+                .collect_with_engine(engine)
+                .map(|r| r.unwrap_single())
+        };
+
+        let out = sample(SampleOptions::reservoir(10).with_seed(Some(42)))?;
+        assert_eq!(out.height(), 10);
+        // The order of the input is maintained and rows are sampled without replacement.
+        let a = out.column("a")?.i32()?;
+        assert!(a.into_no_null_iter().is_sorted_by(|l, r| l < r));
+        assert!(out.equals(&sample(SampleOptions::reservoir(10).with_seed(Some(42)))?));
+
+        assert_eq!(sample(SampleOptions::reservoir(2000))?.height(), 1000);
+        assert_eq!(sample(SampleOptions::reservoir(0))?.height(), 0);
+
+        assert_eq!(sample(SampleOptions::bernoulli(0.0))?.height(), 0);
+        assert_eq!(sample(SampleOptions::bernoulli(1.0))?.height(), 1000);
+        let height = sample(SampleOptions::bernoulli(0.5).with_seed(Some(0)))?.height();
+        assert!((300..700).contains(&height), "{height}");
+
+        assert_eq!(sample(SampleOptions::system(0.0))?.height(), 0);
+        assert!(sample(SampleOptions::system(1.0))?.equals(&df));
+
+        assert!(sample(SampleOptions::bernoulli(1.5)).is_err());
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "streaming")]
+fn test_anonymous_scan_streaming() -> PolarsResult<()> {
+    struct BatchedScan {
+        allows_pushdown: bool,
+    }
+
+    impl AnonymousScan for BatchedScan {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn allows_predicate_pushdown(&self) -> bool {
+            self.allows_pushdown
+        }
+
+        fn allows_projection_pushdown(&self) -> bool {
+            self.allows_pushdown
+        }
+
+        fn scan(&self, _scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+            unreachable!()
+        }
+
+        fn scan_batched(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<AnonymousScanBatches> {
+            if !self.allows_pushdown {
+                assert!(scan_opts.with_columns.is_none() && scan_opts.predicate.is_none());
+            }
+
+            let predicate = scan_opts.predicate;
+            let with_columns = scan_opts.with_columns;
+            Ok(Box::new((0..4).map(move |i| {
+                let mut df = df![
+                    "a" => (i * 10..(i + 1) * 10).collect::<Vec<i32>>(),
+                    "b" => vec![i; 10],
+                ]?;
+                // The only predicate in this test is `a > 5`.
+                if predicate.is_some() {
+                    df = df.filter(&df.column("a")?.i32()?.gt(5))?;
+                }
+                if let Some(columns) = &with_columns {
+                    df = df.select(columns.iter().cloned())?;
+                }
+                Ok(df)
+            })))
+        }
+    }
+
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("a".into(), DataType::Int32),
+        Field::new("b".into(), DataType::Int32),
+    ]));
+
+    for allows_pushdown in [false, true] {
+        let scan = || {
+            LazyFrame::anonymous_scan(
+                Arc::new(BatchedScan { allows_pushdown }),
+                ScanArgsAnonymous {
+                    schema: Some(schema.clone()),
+                    ..Default::default()
+                },
+            )
+        };
+
+        let out = scan()?
+            .filter(col("a").gt(lit(5)))
+            .select([col("a")])
+            .with_row_index("idx", None)
+            .slice(3, 30)
+            .collect_with_engine(Engine::Streaming)?
+            .unwrap_single();
+
+        assert_eq!(out.height(), 30);
+        assert_eq!(
+            out.column("idx")?.idx()?.first(),
+            Some(3),
+            "allows_pushdown: {allows_pushdown}"
+        );
+        assert_eq!(out.column("a")?.i32()?.first(), Some(9));
+        assert_eq!(out.column("a")?.i32()?.last(), Some(38));
+
+        let len = scan()?
+            .select([len()])
+            .collect_with_engine(Engine::Streaming)?
+            .unwrap_single();
+        assert_eq!(len.column("len")?.idx()?.get(0), Some(40));
+    }
+
+    Ok(())
+}
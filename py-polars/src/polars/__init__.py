@@ -237,8 +237,10 @@ from polars.io import (
     read_parquet_schema,
     scan_csv,
     scan_delta,
+    scan_fwf,
     scan_iceberg,
     scan_ipc,
+    scan_json,
     scan_lines,
     scan_ndjson,
     scan_parquet,
@@ -252,7 +254,7 @@ from polars.io.cloud import (
     CredentialProviderFunctionReturn,
     CredentialProviderGCP,
 )
-from polars.lazyframe import GPUEngine, LazyFrame, QueryOptFlags
+from polars.lazyframe import GPUEngine, LazyFrame, QueryOptFlags, StreamingEngine
 from polars.meta import (
     build_info,
     get_index_type,
@@ -286,6 +288,7 @@ __all__ = [
     "Series",
     # Engine configuration
     "GPUEngine",
+    "StreamingEngine",
     # schema
     "Schema",
     # datatype_expr
@@ -355,8 +358,10 @@ __all__ = [
     "read_parquet_schema",
     "scan_csv",
     "scan_delta",
+    "scan_fwf",
     "scan_iceberg",
     "scan_ipc",
+    "scan_json",
     "scan_lines",
     "scan_ndjson",
     "scan_parquet",
@@ -3,6 +3,7 @@ from typing import Any, Literal, TypeAlias, overload
 
 from numpy.typing import NDArray
 
+from polars import DataFrame
 from polars._typing import ArrowSchemaExportable
 from polars.io.iceberg._sink import IcebergSinkState
 from polars.io.scan_options._options import ScanOptions
@@ -37,7 +38,7 @@ SinkOptions: TypeAlias = dict[str, Any]
 SinkTarget: TypeAlias = Any
 AsofStrategy: TypeAlias = Literal["backward", "forward", "nearest"]
 InterpolationMethod: TypeAlias = Literal["linear", "nearest"]
-AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate"]
+AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate", "zstd"]
 CategoricalOrdering: TypeAlias = Literal["physical", "lexical"]
 StartBy: TypeAlias = Literal[
     "window",
@@ -771,6 +772,7 @@ class PyDataFrame:
         truncate_ragged_lines: bool,
         decimal_comma: bool,
         schema: Any | None,
+        json_columns: Sequence[str] | None = None,
     ) -> PyDataFrame: ...
     @staticmethod
     def read_json(
@@ -843,6 +845,8 @@ class PyLazyFrame:
         include_file_paths: str | None,
         cloud_options: dict[str, Any] | None,
         credential_provider: Any | None,
+        rejects: Callable[[DataFrame], None] | None = None,
+        record_pointer: str | None = None,
     ) -> PyLazyFrame: ...
     @staticmethod
     def new_from_csv(
@@ -878,6 +882,8 @@ class PyLazyFrame:
         credential_provider: Any | None,
         include_file_paths: str | None,
         missing_columns: str | None,
+        rejects: Callable[[DataFrame], None] | None = None,
+        json_columns: Sequence[str] | None = None,
     ) -> PyLazyFrame: ...
     @staticmethod
     def new_from_parquet(
@@ -902,6 +908,17 @@ class PyLazyFrame:
         scan_options: ScanOptions,
     ) -> PyLazyFrame: ...
     @staticmethod
+    def new_from_scan_fwf(
+        sources: Any,
+        *,
+        scan_options: ScanOptions,
+        schema: Schema,
+        spans: Sequence[tuple[int, int]],
+        span_unit: str,
+        trim: str,
+        skip_lines: int,
+    ) -> PyLazyFrame: ...
+    @staticmethod
     def new_from_expand_paths(
         sources: Any,
         *,
@@ -962,7 +979,13 @@ class PyLazyFrame:
     def profile(
         self, lambda_post_opt: Any | None
     ) -> tuple[PyDataFrame, PyDataFrame]: ...
-    def collect(self, engine: Any, lambda_post_opt: Any | None) -> PyDataFrame: ...
+    def collect(
+        self,
+        engine: Any,
+        lambda_post_opt: Any | None,
+        memory_budget: int | None = None,
+        spill_policy: str | None = None,
+    ) -> PyDataFrame: ...
     def collect_with_callback(self, engine: Any, lambda_func: Any) -> None: ...
     def collect_batches(
         self, engine: Any, maintain_order: bool, chunk_size: int | None, lazy: bool
@@ -978,6 +1001,7 @@ class PyLazyFrame:
         data_page_size: int | None,
         metadata: KeyValueMetadata | None,
         arrow_schema: ArrowSchemaExportable | None = None,
+        bloom_filters: Sequence[tuple[str, int | None, float]] | None = None,
     ) -> PyLazyFrame: ...
     def sink_ipc(
         self,
@@ -1009,6 +1033,7 @@ class PyLazyFrame:
         decimal_comma: bool,
         null_value: str | None,
         quote_style: QuoteStyle | None,
+        nested_as_json: bool = False,
     ) -> PyLazyFrame: ...
     def sink_ndjson(
         self,
@@ -1139,6 +1164,17 @@ class PyLazyFrame:
         separator: str,
         column_naming: Literal["auto", "combine"],
     ) -> PyLazyFrame: ...
+    def pivot_deferred(
+        self,
+        on: PySelector,
+        index: PySelector,
+        values: PySelector,
+        agg: PyExpr,
+        maintain_order: bool,
+        separator: str,
+        column_naming: Literal["auto", "combine"],
+        max_on_columns: int | None,
+    ) -> PyLazyFrame: ...
     def unpivot(
         self,
         on: PySelector | None,
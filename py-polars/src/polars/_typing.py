@@ -31,7 +31,7 @@ if TYPE_CHECKING:
     from polars import DataFrame, Expr, LazyFrame, Series
     from polars._dependencies import numpy as np
     from polars.datatypes import DataType, DataTypeClass, IntegerType, TemporalType
-    from polars.lazyframe.engine_config import GPUEngine
+    from polars.lazyframe.engine_config import GPUEngine, StreamingEngine
     from polars.selectors import Selector
 
 
@@ -196,11 +196,19 @@ ColumnNameOrSelector: TypeAlias = Union["str", SelectorType]
 # User-facing string literal types
 # The following all have an equivalent Rust enum with the same name
 Ambiguous: TypeAlias = Literal["earliest", "latest", "raise", "null"]
-AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate"]
+AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate", "zstd"]
 CsvQuoteStyle: TypeAlias = Literal["necessary", "always", "non_numeric", "never"]
 CategoricalOrdering: TypeAlias = Literal["physical", "lexical"]
 CsvCompression: TypeAlias = Literal["uncompressed", "gzip", "zstd"]
-CsvEncoding: TypeAlias = Literal["utf8", "utf8-lossy"]
+CsvEncoding: TypeAlias = Literal[
+    "utf8",
+    "utf8-lossy",
+    "latin1",
+    "windows-1252",
+    "utf16",
+    "utf16-le",
+    "utf16-be",
+]
 ColumnMapping: TypeAlias = tuple[
     Literal["iceberg-column-mapping"],
     # This is "pa.Schema". Not typed as that causes pyright strict type checking
@@ -435,7 +443,7 @@ MultiColSelector: TypeAlias = MultiIndexSelector | MultiNameSelector | BooleanMa
 
 # LazyFrame engine selection
 EngineType: TypeAlias = Union[
-    Literal["auto", "in-memory", "streaming", "gpu"], "GPUEngine"
+    Literal["auto", "in-memory", "streaming", "gpu"], "GPUEngine", "StreamingEngine"
 ]
 
 PlanStage: TypeAlias = Literal["ir", "physical"]
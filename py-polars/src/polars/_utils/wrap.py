@@ -1,10 +1,12 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Any
 
 import polars._reexport as pl
 
 if TYPE_CHECKING:
+    from collections.abc import Callable
+
     from polars import DataFrame, Expr, LazyFrame, Series
     from polars._plr import PyDataFrame, PyExpr, PyLazyFrame, PySeries
 
@@ -23,3 +25,7 @@ def wrap_s(s: PySeries) -> Series:
 
 def wrap_expr(pyexpr: PyExpr) -> Expr:
     return pl.Expr._from_pyexpr(pyexpr)
+
+
+def call_with_wrapped_df(function: Callable[[DataFrame], Any], df: PyDataFrame) -> Any:
+    return function(wrap_df(df))
@@ -3029,6 +3029,7 @@ class DataFrame:
         decimal_comma: bool = ...,
         null_value: str | None = ...,
         quote_style: CsvQuoteStyle | None = ...,
+        nested_as_json: bool = ...,
         storage_options: StorageOptionsDict | None = ...,
         credential_provider: CredentialProviderFunction | Literal["auto"] | None = ...,
         retries: int | None = ...,
@@ -3056,6 +3057,7 @@ class DataFrame:
         decimal_comma: bool = ...,
         null_value: str | None = ...,
         quote_style: CsvQuoteStyle | None = ...,
+        nested_as_json: bool = ...,
         storage_options: StorageOptionsDict | None = ...,
         credential_provider: CredentialProviderFunction | Literal["auto"] | None = ...,
         retries: int | None = ...,
@@ -3082,6 +3084,7 @@ class DataFrame:
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        nested_as_json: bool = False,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: (
             CredentialProviderFunction | Literal["auto"] | None
@@ -3173,6 +3176,10 @@ class DataFrame:
               Namely, when writing a field that does not parse as a valid float
               or integer, then quotes will be used even if they aren`t strictly
               necessary.
+        nested_as_json
+            Write List, Array and Struct values as compact JSON text instead of
+            raising an error. Such columns can be read back with the `json_columns`
+            parameter of :func:`read_csv` and :func:`scan_csv`.
         storage_options
             Options that indicate how to connect to a cloud provider.
 
@@ -3255,6 +3262,7 @@ class DataFrame:
             decimal_comma=decimal_comma,
             null_value=null_value,
             quote_style=quote_style,
+            nested_as_json=nested_as_json,
             storage_options=storage_options,
             credential_provider=credential_provider,
             retries=retries,
@@ -3301,7 +3309,7 @@ class DataFrame:
         ----------
         file
             File path or writable file-like object to which the data will be written.
-        compression : {'uncompressed', 'snappy', 'deflate'}
+        compression : {'uncompressed', 'snappy', 'deflate', 'zstd'}
             Compression method. Defaults to "uncompressed".
         name
             Schema name. Defaults to empty string.
@@ -4140,6 +4148,7 @@ class DataFrame:
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        bloom_filters: str | Sequence[str] | Mapping[str, dict[str, Any]] | None = None,
         use_pyarrow: bool = False,
         pyarrow_options: dict[str, Any] | None = None,
         partition_by: str | Sequence[str] | None = None,
@@ -4195,6 +4204,16 @@ class DataFrame:
             Size of the row groups in number of rows. Defaults to 512^2 rows.
         data_page_size
             Size of the data page in bytes. Defaults to 1024^2 bytes.
+        bloom_filters
+            Write split-block bloom filters for these columns, which allow readers to
+            skip row groups on equality and `is_in` predicates. Pass a dictionary to
+            set per-column options:
+
+            - "fpp": the false positive probability (default: `0.05`)
+            - "ndv": the expected number of distinct values per row group
+              (default: the number of non-null values)
+
+            Cannot be combined with `use_pyarrow`.
         use_pyarrow
             Use PyArrow's C++ parquet implementation instead of Polars' native
             Rust implementation. This may be useful when specific PyArrow features
@@ -4308,6 +4327,9 @@ class DataFrame:
             if metadata is not None:
                 msg = "write_parquet with `use_pyarrow=True` cannot be combined with `metadata`"
                 raise ValueError(msg)
+            if bloom_filters is not None:
+                msg = "write_parquet with `use_pyarrow=True` cannot be combined with `bloom_filters`"
+                raise ValueError(msg)
             if mkdir:
                 msg = "write_parquet with `use_pyarrow=True` cannot be combined with `mkdir`"
                 raise ValueError(msg)
@@ -4376,6 +4398,7 @@ class DataFrame:
             statistics=statistics,
             row_group_size=row_group_size,
             data_page_size=data_page_size,
+            bloom_filters=bloom_filters,
             storage_options=storage_options,
             credential_provider=credential_provider,
             retries=retries,
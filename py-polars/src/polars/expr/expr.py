@@ -3708,6 +3708,43 @@ class Expr:
         """
         return wrap_expr(self._pyexpr.approx_n_unique())
 
+    @unstable()
+    def approx_quantile(self, quantile: float) -> Expr:
+        """
+        Approximate quantile value.
+
+        The quantile is estimated using a t-digest sketch instead of sorting the
+        values, so the memory needed per group is bounded. This makes it well
+        suited for large (streaming) group-bys. Null and NaN values are ignored.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        quantile
+            Quantile between 0.0 and 1.0.
+
+        See Also
+        --------
+        quantile
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3, 4, 5]})
+        >>> df.select(pl.col("a").approx_quantile(0.5))
+        shape: (1, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ f64 │
+        ╞═════╡
+        │ 3.0 │
+        └─────┘
+        """
+        return wrap_expr(self._pyexpr.approx_quantile(quantile))
+
     def null_count(self) -> Expr:
         """
         Count null values.
@@ -5,9 +5,10 @@ from polars.io.clipboard import read_clipboard
 from polars.io.csv import read_csv, read_csv_batched, scan_csv
 from polars.io.database import read_database, read_database_uri
 from polars.io.delta import read_delta, scan_delta
+from polars.io.fwf import scan_fwf
 from polars.io.iceberg import scan_iceberg
 from polars.io.ipc import read_ipc, read_ipc_schema, read_ipc_stream, scan_ipc
-from polars.io.json import read_json
+from polars.io.json import read_json, scan_json
 from polars.io.lines import read_lines, scan_lines
 from polars.io.ndjson import read_ndjson, scan_ndjson
 from polars.io.parquet import (
@@ -49,8 +50,10 @@ __all__ = [
     "read_parquet_schema",
     "scan_csv",
     "scan_delta",
+    "scan_fwf",
     "scan_iceberg",
     "scan_ipc",
+    "scan_json",
     "scan_lines",
     "scan_ndjson",
     "scan_parquet",
@@ -52,6 +52,22 @@ if TYPE_CHECKING:
     from polars.io.cloud import CredentialProviderFunction
     from polars.io.cloud.credential_provider._builder import CredentialProviderBuilder
 
+# Encodings that the CSV reader transcodes itself.
+_NATIVE_ENCODINGS = {
+    "utf8",
+    "utf8-lossy",
+    "latin1",
+    "iso-8859-1",
+    "windows-1252",
+    "cp1252",
+    "utf16",
+    "utf-16",
+    "utf16-le",
+    "utf-16-le",
+    "utf16-be",
+    "utf-16-be",
+}
+
 
 @deprecate_renamed_parameter("dtypes", "schema_overrides", version="0.20.31")
 @deprecate_renamed_parameter("row_count_name", "row_index_name", version="0.20.4")
@@ -93,6 +109,7 @@ def read_csv(
     raise_if_empty: bool = True,
     truncate_ragged_lines: bool = False,
     decimal_comma: bool = False,
+    json_columns: Sequence[str] | None = None,
     glob: bool = True,
 ) -> DataFrame:
     r"""
@@ -194,11 +211,13 @@ def read_csv(
         Stop reading from CSV file after reading `n_rows`.
         During multi-threaded parsing, an upper bound of `n_rows`
         rows cannot be guaranteed.
-    encoding : {'utf8', 'utf8-lossy', 'windows-1252', 'windows-1252-lossy', ...}
+    encoding : {'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'utf16', 'utf16-le', 'utf16-be', 'windows-1252-lossy', ...}
         Lossy means that invalid utf8 values are replaced with `�`
-        characters. When using other encodings than `utf8` or
-        `utf8-lossy`, the input is first decoded in memory with
-        python. Defaults to `utf8`.
+        characters. `latin1`, `windows-1252` and the `utf16` encodings are
+        transcoded before parsing, which keeps a transcoded copy of the input in
+        memory (use :func:`scan_csv` to transcode chunk-wise); `utf16` detects
+        the byte order from the byte order mark. When using other encodings,
+        the input is first decoded in memory with python. Defaults to `utf8`.
     low_memory
         Reduce memory pressure at the expense of performance.
     rechunk
@@ -240,6 +259,10 @@ def read_csv(
         Truncate lines that are longer than the schema.
     decimal_comma
         Parse floats using a comma as the decimal separator instead of a period.
+    json_columns
+        Columns that hold JSON text, e.g. as written with `nested_as_json=True` in
+        :meth:`DataFrame.write_csv`. They are decoded into the List, Array or Struct
+        data type that must be given for them in `schema` or `schema_overrides`.
     glob
         Expand path given via globbing rules.
 
@@ -489,7 +512,7 @@ def read_csv(
 
     # TODO: scan_csv doesn't support a "dtype slice" (i.e. list[DataType])
     schema_overrides_is_list = isinstance(schema_overrides, Sequence)
-    encoding_supported_in_lazy = encoding in {"utf8", "utf8-lossy"}
+    encoding_supported_in_lazy = encoding in _NATIVE_ENCODINGS
 
     streaming = (
         os.getenv("POLARS_FORCE_STREAMING") == "1"
@@ -560,6 +583,7 @@ def read_csv(
             raise_if_empty=raise_if_empty,
             truncate_ragged_lines=truncate_ragged_lines,
             decimal_comma=decimal_comma,
+            json_columns=json_columns,
             glob=glob,
         )
 
@@ -573,7 +597,7 @@ def read_csv(
     else:
         with prepare_file_arg(
             source,
-            encoding=encoding,
+            encoding=None if encoding_supported_in_lazy else encoding,
             use_pyarrow=False,
             raise_if_empty=raise_if_empty,
             storage_options=storage_options,
@@ -597,7 +621,7 @@ def read_csv(
                 infer_schema_length=infer_schema_length,
                 batch_size=batch_size,
                 n_rows=n_rows,
-                encoding=encoding if encoding == "utf8-lossy" else "utf8",
+                encoding=encoding if encoding_supported_in_lazy else "utf8",  # type: ignore[arg-type]
                 low_memory=low_memory,
                 rechunk=rechunk,
                 skip_rows_after_header=skip_rows_after_header,
@@ -607,6 +631,7 @@ def read_csv(
                 raise_if_empty=raise_if_empty,
                 truncate_ragged_lines=truncate_ragged_lines,
                 decimal_comma=decimal_comma,
+                json_columns=json_columns,
                 glob=glob,
             )
 
@@ -646,6 +671,7 @@ def _read_csv_impl(
     raise_if_empty: bool = True,
     truncate_ragged_lines: bool = False,
     decimal_comma: bool = False,
+    json_columns: Sequence[str] | None = None,
     glob: bool = True,
 ) -> DataFrame:
     if sample_size != 1024:
@@ -715,6 +741,7 @@ def _read_csv_impl(
             raise_if_empty=raise_if_empty,
             truncate_ragged_lines=truncate_ragged_lines,
             decimal_comma=decimal_comma,
+            json_columns=json_columns,
             glob=glob,
         )
         if columns is None:
@@ -760,6 +787,7 @@ def _read_csv_impl(
         raise_if_empty=raise_if_empty,
         truncate_ragged_lines=truncate_ragged_lines,
         decimal_comma=decimal_comma,
+        json_columns=json_columns,
         schema=schema,
     )
     return wrap_df(pydf)
@@ -1141,6 +1169,7 @@ def scan_csv(
     raise_if_empty: bool = True,
     truncate_ragged_lines: bool = False,
     decimal_comma: bool = False,
+    json_columns: Sequence[str] | None = None,
     glob: bool = True,
     storage_options: StorageOptionsDict | None = None,
     credential_provider: CredentialProviderFunction | Literal["auto"] | None = "auto",
@@ -1148,6 +1177,7 @@ def scan_csv(
     file_cache_ttl: int | None = None,
     include_file_paths: str | None = None,
     missing_columns: Literal["insert", "raise"] | None = None,
+    rejects: Callable[[DataFrame], None] | None = None,
 ) -> LazyFrame:
     r"""
     Lazily read from a CSV file or multiple files via glob patterns.
@@ -1233,9 +1263,11 @@ def scan_csv(
         `pl.String`.
     n_rows
         Stop reading from CSV file after reading `n_rows`.
-    encoding : {'utf8', 'utf8-lossy'}
+    encoding : {'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'utf16', 'utf16-le', 'utf16-be'}
         Lossy means that invalid utf8 values are replaced with `�`
-        characters. Defaults to "utf8".
+        characters. The other encodings are transcoded to utf8 while
+        parsing; `utf16` detects the byte order from the byte order mark.
+        Defaults to "utf8".
     low_memory
         Reduce memory pressure at the expense of performance.
     rechunk
@@ -1266,6 +1298,10 @@ def scan_csv(
         Truncate lines that are longer than the schema.
     decimal_comma
         Parse floats using a comma as the decimal separator instead of a period.
+    json_columns
+        Columns that hold JSON text, e.g. as written with `nested_as_json=True` in
+        :meth:`DataFrame.write_csv`. They are decoded into the List, Array or Struct
+        data type that must be given for them in `schema` or `schema_overrides`.
     glob
         Expand path given via globbing rules.
     storage_options
@@ -1311,6 +1347,19 @@ def scan_csv(
         * ``"insert"``: Insert the missing columns with NULL values.
         * ``"raise"``: Raise an error.
 
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    rejects
+        Function that receives the rows that could not be read, instead of failing
+        the scan. It is called with a DataFrame with the columns `path`, `line` (the
+        1-based line number in the file), `raw` (the text of the line) and `error`.
+        Every row is checked to have a field for each column, but only the values
+        of the selected columns are parsed. Cannot be combined with `ignore_errors`.
+
+        The function can be called concurrently for different parts of a file, so
+        the order of calls is not guaranteed.
+
         .. warning::
             This functionality is considered **unstable**. It may be changed
             at any point without it being considered a breaking change.
@@ -1433,6 +1482,10 @@ def scan_csv(
         msg = "The `missing_columns` parameter of `scan_csv` is considered unstable."
         issue_unstable_warning(msg)
 
+    if rejects is not None:
+        msg = "The `rejects` parameter of `scan_csv` is considered unstable."
+        issue_unstable_warning(msg)
+
     credential_provider_builder = _init_credential_provider_builder(
         credential_provider, source, storage_options, "scan_csv"
     )
@@ -1466,11 +1519,13 @@ def scan_csv(
         raise_if_empty=raise_if_empty,
         truncate_ragged_lines=truncate_ragged_lines,
         decimal_comma=decimal_comma,
+        json_columns=json_columns,
         glob=glob,
         storage_options=storage_options,
         credential_provider=credential_provider_builder,
         include_file_paths=include_file_paths,
         missing_columns=missing_columns,
+        rejects=rejects,
     )
 
 
@@ -1511,11 +1566,13 @@ def _scan_csv_impl(
     raise_if_empty: bool = True,
     truncate_ragged_lines: bool = True,
     decimal_comma: bool = False,
+    json_columns: Sequence[str] | None = None,
     glob: bool = True,
     storage_options: StorageOptionsDict | None = None,
     credential_provider: CredentialProviderBuilder | None = None,
     include_file_paths: str | None = None,
     missing_columns: Literal["insert", "raise"] | None = None,
+    rejects: Callable[[DataFrame], None] | None = None,
 ) -> LazyFrame:
     dtype_list: list[tuple[str, PolarsDataType]] | None = None
     if schema_overrides is not None:
@@ -1566,11 +1623,13 @@ def _scan_csv_impl(
         raise_if_empty=raise_if_empty,
         truncate_ragged_lines=truncate_ragged_lines,
         decimal_comma=decimal_comma,
+        json_columns=json_columns,
         glob=glob,
         schema=schema,
         cloud_options=storage_options,
         credential_provider=credential_provider,
         include_file_paths=include_file_paths,
         missing_columns=missing_columns,
+        rejects=rejects,
     )
     return wrap_ldf(pylf)
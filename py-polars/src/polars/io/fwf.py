@@ -0,0 +1,182 @@
+from __future__ import annotations
+
+import contextlib
+from itertools import accumulate
+from typing import IO, TYPE_CHECKING, Literal
+
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_ldf
+from polars.io._utils import get_sources
+from polars.io.cloud.credential_provider._builder import (
+    _init_credential_provider_builder,
+)
+from polars.io.scan_options._options import ScanOptions
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    from polars._plr import PyLazyFrame
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+    from pathlib import Path
+
+    from polars._typing import SchemaDict, StorageOptionsDict
+    from polars.io.cloud import CredentialProviderFunction
+    from polars.lazyframe.frame import LazyFrame
+
+
+@unstable()
+def scan_fwf(
+    source: (
+        str
+        | Path
+        | IO[str]
+        | IO[bytes]
+        | bytes
+        | list[str]
+        | list[Path]
+        | list[IO[str]]
+        | list[IO[bytes]]
+    ),
+    *,
+    schema: SchemaDict,
+    spans: Sequence[tuple[int, int]] | None = None,
+    widths: Sequence[int] | None = None,
+    span_unit: Literal["bytes", "chars"] = "bytes",
+    trim: Literal["none", "start", "end", "both"] = "both",
+    skip_lines: int = 0,
+    n_rows: int | None = None,
+    row_index_name: str | None = None,
+    row_index_offset: int = 0,
+    glob: bool = True,
+    storage_options: StorageOptionsDict | None = None,
+    credential_provider: CredentialProviderFunction | Literal["auto"] | None = "auto",
+    include_file_paths: str | None = None,
+) -> LazyFrame:
+    r"""
+    Lazily read from a fixed-width text file.
+
+    Every line of the file is a record, and every column occupies the same span of
+    each line.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    source
+        Path(s) to a file or directory
+        When needing to authenticate for scanning cloud locations, see the
+        `storage_options` parameter.
+    schema
+        Names and data types of the columns, in the order of `spans` / `widths`.
+        Values are read as strings and cast to these data types.
+    spans
+        Half-open `(start, end)` span of every column, counted in `span_unit`
+        from the start of the line. Spans may overlap or leave gaps.
+        Mutually exclusive with `widths`.
+    widths
+        Width of every column, for columns that directly follow each other starting
+        at the beginning of the line. Mutually exclusive with `spans`.
+    span_unit : {'bytes', 'chars'}
+        Whether spans are counted in bytes or in UTF-8 characters.
+    trim : {'none', 'start', 'end', 'both'}
+        Which side of the values to strip of spaces and tabs. Values that are
+        empty after trimming, or that lie past the end of a line, are read as null.
+    skip_lines
+        Number of lines to skip at the start of every file, e.g. headers.
+    n_rows
+        Stop reading from the file after reading `n_rows`.
+    row_index_name
+        If not None, this will insert a row index column with the given name into the
+        DataFrame
+    row_index_offset
+        Offset to start the row index column (only used if the name is set)
+    glob
+        Expand path given via globbing rules.
+    storage_options
+        Options that indicate how to connect to a cloud provider.
+
+        The cloud providers currently supported are AWS, GCP, and Azure.
+        See supported keys here:
+
+        * `aws <https://docs.rs/object_store/latest/object_store/aws/enum.AmazonS3ConfigKey.html>`_
+        * `gcp <https://docs.rs/object_store/latest/object_store/gcp/enum.GoogleConfigKey.html>`_
+        * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
+        * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
+          `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+
+        If `storage_options` is not provided, Polars will try to infer the information
+        from environment variables.
+    credential_provider
+        Provide a function that can be called to provide cloud storage
+        credentials. The function is expected to return a dictionary of
+        credential keys along with an optional credential expiry time.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    include_file_paths
+        Include the path of the source file(s) as a column with this name.
+
+    See Also
+    --------
+    scan_lines
+
+    Examples
+    --------
+    >>> data = b"id  name    amount\n   1alice      1.5\n   2bob         -2\n"
+    >>> pl.scan_fwf(
+    ...     data,
+    ...     schema={"id": pl.Int64, "name": pl.String, "amount": pl.Float64},
+    ...     widths=[4, 8, 6],
+    ...     skip_lines=1,
+    ... ).collect()
+    shape: (2, 3)
+    ┌─────┬───────┬────────┐
+    │ id  ┆ name  ┆ amount │
+    │ --- ┆ ---   ┆ ---    │
+    │ i64 ┆ str   ┆ f64    │
+    ╞═════╪═══════╪════════╡
+    │ 1   ┆ alice ┆ 1.5    │
+    │ 2   ┆ bob   ┆ -2.0   │
+    └─────┴───────┴────────┘
+    """
+    if spans is not None and widths is None:
+        spans = list(spans)
+    elif widths is not None and spans is None:
+        ends = list(accumulate(widths))
+        spans = list(zip([0, *ends[:-1]], ends))
+    else:
+        msg = "exactly one of `spans` and `widths` must be given"
+        raise ValueError(msg)
+
+    sources = get_sources(source)
+
+    credential_provider_builder = _init_credential_provider_builder(
+        credential_provider, sources, storage_options, "scan_fwf"
+    )
+    del credential_provider
+
+    pylf = PyLazyFrame.new_from_scan_fwf(
+        sources=sources,
+        scan_options=ScanOptions(
+            row_index=(
+                (row_index_name, row_index_offset)
+                if row_index_name is not None
+                else None
+            ),
+            pre_slice=(0, n_rows) if n_rows is not None else None,
+            include_file_paths=include_file_paths,
+            glob=glob,
+            storage_options=storage_options,
+            credential_provider=credential_provider_builder,
+        ),
+        schema=dict(schema),
+        spans=spans,
+        span_unit=span_unit,
+        trim=trim,
+        skip_lines=skip_lines,
+    )
+
+    return wrap_ldf(pylf)
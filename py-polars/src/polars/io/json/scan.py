@@ -0,0 +1,181 @@
+from __future__ import annotations
+
+import contextlib
+from pathlib import Path
+from typing import IO, TYPE_CHECKING, Literal
+
+from polars._utils.unstable import unstable
+from polars._utils.various import is_path_or_str_sequence, normalize_filepath
+from polars._utils.wrap import wrap_ldf
+from polars.datatypes import N_INFER_DEFAULT
+from polars.io._utils import parse_row_index_args
+from polars.io.cloud.credential_provider._builder import (
+    _init_credential_provider_builder,
+)
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    from polars._plr import PyLazyFrame
+
+if TYPE_CHECKING:
+    from polars import LazyFrame
+    from polars._typing import SchemaDefinition, StorageOptionsDict
+    from polars.io.cloud import CredentialProviderFunction
+
+
+@unstable()
+def scan_json(
+    source: (
+        str
+        | Path
+        | IO[str]
+        | IO[bytes]
+        | bytes
+        | list[str]
+        | list[Path]
+        | list[IO[str]]
+        | list[IO[bytes]]
+    ),
+    *,
+    record_pointer: str = "",
+    schema: SchemaDefinition | None = None,
+    schema_overrides: SchemaDefinition | None = None,
+    infer_schema_length: int | None = N_INFER_DEFAULT,
+    n_rows: int | None = None,
+    row_index_name: str | None = None,
+    row_index_offset: int = 0,
+    ignore_errors: bool = False,
+    storage_options: StorageOptionsDict | None = None,
+    credential_provider: CredentialProviderFunction | Literal["auto"] | None = "auto",
+    include_file_paths: str | None = None,
+) -> LazyFrame:
+    """
+    Lazily read the records of an array in a JSON document.
+
+    Unlike :func:`read_json`, the document is read in a streaming fashion, so it does
+    not have to fit in memory. Every file is a single JSON document, and every element
+    of the array at `record_pointer` is a row.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    source
+        Path to a file.
+    record_pointer
+        JSON pointer (RFC 6901) to the array of records, e.g. `""` for a top-level
+        array or `"/data/items"` for `{"data": {"items": [...]}}`. Array elements
+        can be addressed by their index. The records must be JSON objects. The rest
+        of the document after the array is not read.
+    schema : Sequence of str, (str,DataType) pairs, or a {str:DataType,} dict
+        The DataFrame schema may be declared in several ways:
+
+        * As a dict of {name:type} pairs; if type is None, it will be auto-inferred.
+        * As a list of column names; in this case types are automatically inferred.
+        * As a list of (name,type) pairs; this is equivalent to the dictionary form.
+
+        If you supply a list of column names that does not match the names in the
+        underlying data, the names given here will overwrite them. The number
+        of names given in the schema should match the underlying data dimensions.
+    schema_overrides : dict, default None
+        Support type specification or override of one or more columns; note that
+        any dtypes inferred from the schema param will be overridden.
+    infer_schema_length
+        The maximum number of records to scan for schema inference.
+        If set to `None`, the full data may be scanned *(this is slow)*.
+    n_rows
+        Stop reading from JSON file after reading `n_rows`.
+    row_index_name
+        If not None, this will insert a row index column with give name into the
+        DataFrame
+    row_index_offset
+        Offset to start the row index column (only use if the name is set)
+    ignore_errors
+        Return `Null` if parsing fails because of schema mismatches.
+    storage_options
+        Options that indicate how to connect to a cloud provider.
+
+        The cloud providers currently supported are AWS, GCP, and Azure.
+        See supported keys here:
+
+        * `aws <https://docs.rs/object_store/latest/object_store/aws/enum.AmazonS3ConfigKey.html>`_
+        * `gcp <https://docs.rs/object_store/latest/object_store/gcp/enum.GoogleConfigKey.html>`_
+        * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
+        * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
+          `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+
+        If `storage_options` is not provided, Polars will try to infer the information
+        from environment variables.
+    credential_provider
+        Provide a function that can be called to provide cloud storage
+        credentials. The function is expected to return a dictionary of
+        credential keys along with an optional credential expiry time.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    include_file_paths
+        Include the path of the source file(s) as a column with this name.
+
+    See Also
+    --------
+    read_json
+    scan_ndjson
+
+    Examples
+    --------
+    >>> data = b'{"data": {"items": [{"a": 1, "b": "x"}, {"a": 2, "b": "y"}]}}'
+    >>> pl.scan_json(data, record_pointer="/data/items").collect()
+    shape: (2, 2)
+    ┌─────┬─────┐
+    │ a   ┆ b   │
+    │ --- ┆ --- │
+    │ i64 ┆ str │
+    ╞═════╪═════╡
+    │ 1   ┆ x   │
+    │ 2   ┆ y   │
+    └─────┴─────┘
+    """
+    sources: list[str] | list[Path] | list[IO[str]] | list[IO[bytes]] = []
+    if isinstance(source, (str, Path)):
+        source = normalize_filepath(source, check_not_directory=False)
+    elif isinstance(source, list):
+        if is_path_or_str_sequence(source):
+            sources = [
+                normalize_filepath(source, check_not_directory=False)
+                for source in source
+            ]
+        else:
+            sources = source
+
+        source = None  # type: ignore[assignment]
+
+    if infer_schema_length == 0:
+        msg = "'infer_schema_length' should be positive"
+        raise ValueError(msg)
+
+    credential_provider_builder = _init_credential_provider_builder(
+        credential_provider, source, storage_options, "scan_json"
+    )
+
+    del credential_provider
+
+    pylf = PyLazyFrame.new_from_ndjson(
+        source,
+        sources,
+        infer_schema_length=infer_schema_length,
+        schema=schema,
+        schema_overrides=schema_overrides,
+        batch_size=None,
+        n_rows=n_rows,
+        low_memory=False,
+        rechunk=False,
+        row_index=parse_row_index_args(row_index_name, row_index_offset),
+        ignore_errors=ignore_errors,
+        include_file_paths=include_file_paths,
+        cloud_options=storage_options,
+        credential_provider=credential_provider_builder,
+        record_pointer=record_pointer,
+    )
+    return wrap_ldf(pylf)
@@ -8,6 +8,7 @@ from polars._utils.deprecation import (
     deprecate_renamed_parameter,
     issue_deprecation_warning,
 )
+from polars._utils.unstable import issue_unstable_warning
 from polars._utils.various import is_path_or_str_sequence, normalize_filepath
 from polars._utils.wrap import wrap_ldf
 from polars.datatypes import N_INFER_DEFAULT
@@ -20,6 +21,8 @@ with contextlib.suppress(ImportError):  # Module not available when building doc
     from polars._plr import PyLazyFrame
 
 if TYPE_CHECKING:
+    from collections.abc import Callable
+
     from polars import DataFrame, LazyFrame
     from polars._typing import SchemaDefinition, StorageOptionsDict
     from polars.io.cloud import CredentialProviderFunction
@@ -217,6 +220,7 @@ def scan_ndjson(
     retries: int | None = None,
     file_cache_ttl: int | None = None,
     include_file_paths: str | None = None,
+    rejects: Callable[[DataFrame], None] | None = None,
 ) -> LazyFrame:
     """
     Lazily read from a newline delimited JSON file or multiple files via glob patterns.
@@ -299,6 +303,19 @@ def scan_ndjson(
             File cache is no longer supported.
     include_file_paths
         Include the path of the source file(s) as a column with this name.
+    rejects
+        Function that receives the rows that could not be read, instead of failing
+        the scan. It is called with a DataFrame with the columns `path`, `line` (the
+        1-based line number in the file), `raw` (the text of the line) and `error`.
+        Only the values of the selected columns are parsed, but every line must be
+        valid JSON. Cannot be combined with `ignore_errors`.
+
+        The function can be called concurrently for different parts of a file, so
+        the order of calls is not guaranteed.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
     """
     sources: list[str] | list[Path] | list[IO[str]] | list[IO[bytes]] = []
     if isinstance(source, (str, Path)):
@@ -328,6 +345,10 @@ def scan_ndjson(
         msg = "file cache is no longer supported as of 1.39.0."
         issue_deprecation_warning(msg)
 
+    if rejects is not None:
+        msg = "The `rejects` parameter of `scan_ndjson` is considered unstable."
+        issue_unstable_warning(msg)
+
     credential_provider_builder = _init_credential_provider_builder(
         credential_provider, source, storage_options, "scan_ndjson"
     )
@@ -349,5 +370,6 @@ def scan_ndjson(
         include_file_paths=include_file_paths,
         cloud_options=storage_options,
         credential_provider=credential_provider_builder,
+        rejects=rejects,
     )
     return wrap_ldf(pylf)
@@ -1,4 +1,4 @@
-from polars.lazyframe.engine_config import GPUEngine
+from polars.lazyframe.engine_config import GPUEngine, StreamingEngine
 from polars.lazyframe.frame import LazyFrame
 from polars.lazyframe.opt_flags import QueryOptFlags
 from polars.lazyframe.query_result import QueryResult, SingleNodeQueryResult
@@ -9,4 +9,5 @@ __all__ = [
     "QueryOptFlags",
     "QueryResult",
     "SingleNodeQueryResult",
+    "StreamingEngine",
 ]
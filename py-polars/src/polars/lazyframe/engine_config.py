@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING, Any
+from typing import TYPE_CHECKING, Any, Literal
 
 if TYPE_CHECKING:
     from collections.abc import Mapping
@@ -59,3 +59,48 @@ class GPUEngine:
         # Avoids need for changes in cudf-polars
         kwargs["raise_on_fail"] = raise_on_fail
         self.config = kwargs
+
+
+class StreamingEngine:
+    """
+    Configuration options for the streaming execution engine.
+
+    Use this if you want control over how a single query spills its data.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    memory_budget : int, default None
+        Budget in bytes for the data held by the query. Once exceeded, the
+        query spills its data to disk, regardless of the memory usage of other
+        queries. If not provided, the ``POLARS_OOC_QUERY_MEMORY_BUDGET_MB``
+        environment variable is used.
+    spill_policy : {'auto', 'most_recent', 'least_recent', 'random'}, default None
+        The order in which the query spills its data. If not provided, the
+        ``POLARS_OOC_SPILL_POLICY`` environment variable is used.
+
+    Notes
+    -----
+    These options are currently only supported by :meth:`LazyFrame.collect`.
+    """
+
+    memory_budget: int | None
+    """Budget in bytes for the data held by the query."""
+    spill_policy: Literal["auto", "most_recent", "least_recent", "random"] | None
+    """The order in which the query spills its data."""
+
+    def __init__(
+        self,
+        *,
+        memory_budget: int | None = None,
+        spill_policy: Literal["auto", "most_recent", "least_recent", "random"]
+        | None = None,
+    ) -> None:
+        if memory_budget is not None and memory_budget < 0:
+            msg = f"`memory_budget` must be non-negative, got {memory_budget}"
+            raise ValueError(msg)
+        self.memory_budget = memory_budget
+        self.spill_policy = spill_policy
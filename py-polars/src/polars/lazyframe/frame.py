@@ -98,7 +98,7 @@ from polars.datatypes import (
 from polars.datatypes.group import DataTypeGroup
 from polars.exceptions import InvalidOperationError, PerformanceWarning
 from polars.interchange.protocol import CompatLevel
-from polars.lazyframe.engine_config import GPUEngine
+from polars.lazyframe.engine_config import GPUEngine, StreamingEngine
 from polars.lazyframe.group_by import LazyGroupBy
 from polars.lazyframe.in_process import InProcessQuery
 from polars.lazyframe.opt_flags import DEFAULT_QUERY_OPT_FLAGS, forward_old_opt_flags
@@ -135,6 +135,7 @@ if TYPE_CHECKING:
         Alignment,
         ArrowSchemaExportable,
         AsofJoinStrategy,
+        AvroCompression,
         ClosedInterval,
         ColumnNameOrSelector,
         CsvQuoteStyle,
@@ -187,6 +188,9 @@ _COLLECT_BATCHES_POOL = ThreadPoolExecutor(thread_name_prefix="pl_col_batch_")
 
 
 def _select_engine(engine: EngineType) -> EngineType:
+    if isinstance(engine, StreamingEngine):
+        msg = "`StreamingEngine` options are only supported by `LazyFrame.collect`"
+        raise NotImplementedError(msg)
     return get_engine_affinity() if engine == "auto" else engine
 
 
@@ -2496,6 +2500,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
               ``cudf-polars``). Pass a :class:`~.GPUEngine` object for
               fine-grained control (e.g. device selection on multi-GPU systems).
 
+            Pass a :class:`~.StreamingEngine` object to use the streaming engine
+            with a memory budget or spill policy for this query.
+
             If the selected engine cannot run the query, Polars falls back to
             the in-memory engine.
 
@@ -2609,6 +2616,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 error_msg = f"collect() got an unexpected keyword argument '{k}'"
                 raise TypeError(error_msg)
 
+        streaming_engine = None
+        if isinstance(engine, StreamingEngine):
+            streaming_engine = engine
+            engine = "streaming"
         engine = _select_engine(engine)
 
         callback = _gpu_engine_callback(
@@ -2627,6 +2638,15 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         # Only for testing purposes
         callback = _kwargs.get("post_opt_callback", callback)
+        if streaming_engine is not None:
+            return wrap_df(
+                ldf.collect(
+                    engine,
+                    callback,
+                    streaming_engine.memory_budget,
+                    streaming_engine.spill_policy,
+                )
+            )
         return wrap_df(ldf.collect(engine, callback))
 
     @overload
@@ -2809,6 +2829,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        bloom_filters: str | Sequence[str] | Mapping[str, dict[str, Any]] | None = None,
         maintain_order: bool = True,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: CredentialProviderFunction
@@ -2835,6 +2856,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        bloom_filters: str | Sequence[str] | Mapping[str, dict[str, Any]] | None = None,
         maintain_order: bool = True,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: CredentialProviderFunction
@@ -2860,6 +2882,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
         data_page_size: int | None = None,
+        bloom_filters: str | Sequence[str] | Mapping[str, dict[str, Any]] | None = None,
         maintain_order: bool = True,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: CredentialProviderFunction
@@ -2919,6 +2942,14 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         data_page_size
             Size limit of individual data pages.
             If not set defaults to 1024 * 1024 bytes
+        bloom_filters
+            Write split-block bloom filters for these columns, which allow readers to
+            skip row groups on equality and `is_in` predicates. Pass a dictionary to
+            set per-column options:
+
+            - "fpp": the false positive probability (default: `0.05`)
+            - "ndv": the expected number of distinct values per row group
+              (default: the number of non-null values)
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -3070,6 +3101,18 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 "null_count": True,
             }
 
+        bloom_filter_options: list[tuple[str, int | None, float]] | None = None
+        if bloom_filters is not None:
+            if isinstance(bloom_filters, str):
+                bloom_filters = [bloom_filters]
+            if isinstance(bloom_filters, Mapping):
+                bloom_filter_options = [
+                    (name, opts.get("ndv"), opts.get("fpp", 0.05))
+                    for name, opts in bloom_filters.items()
+                ]
+            else:
+                bloom_filter_options = [(name, None, 0.05) for name in bloom_filters]
+
         from polars.io.cloud.credential_provider._builder import (
             _init_credential_provider_builder,
         )
@@ -3117,6 +3160,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             data_page_size=data_page_size,
             metadata=metadata,
             arrow_schema=arrow_schema,
+            bloom_filters=bloom_filter_options,
         )
 
         if not lazy:
@@ -3773,6 +3817,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        nested_as_json: bool = False,
         maintain_order: bool = True,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: CredentialProviderFunction
@@ -3808,6 +3853,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        nested_as_json: bool = False,
         maintain_order: bool = True,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: CredentialProviderFunction
@@ -3842,6 +3888,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         decimal_comma: bool = False,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        nested_as_json: bool = False,
         maintain_order: bool = True,
         storage_options: StorageOptionsDict | None = None,
         credential_provider: CredentialProviderFunction
@@ -3940,6 +3987,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
               Namely, when writing a field that does not parse as a valid float
               or integer, then quotes will be used even if they aren`t strictly
               necessary.
+        nested_as_json
+            Write List, Array and Struct values as compact JSON text instead of
+            raising an error. Such columns can be read back with the `json_columns`
+            parameter of :func:`read_csv` and :func:`scan_csv`.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -4105,6 +4156,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             decimal_comma=decimal_comma,
             null_value=null_value,
             quote_style=quote_style,
+            nested_as_json=nested_as_json,
         )
 
         if not lazy:
@@ -4361,6 +4413,185 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             return None
         return LazyFrame._from_pyldf(ldf_py)
 
+    @overload
+    def sink_avro(
+        self,
+        path: str | Path | IO[bytes] | PartitionBy,
+        *,
+        compression: AvroCompression = "uncompressed",
+        name: str = "",
+        namespace: str | None = None,
+        block_size: int | None = None,
+        maintain_order: bool = True,
+        storage_options: StorageOptionsDict | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        lazy: Literal[False] = ...,
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> None: ...
+
+    @overload
+    def sink_avro(
+        self,
+        path: str | Path | IO[bytes] | PartitionBy,
+        *,
+        compression: AvroCompression = "uncompressed",
+        name: str = "",
+        namespace: str | None = None,
+        block_size: int | None = None,
+        maintain_order: bool = True,
+        storage_options: StorageOptionsDict | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        lazy: Literal[True],
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> LazyFrame: ...
+
+    @unstable()
+    def sink_avro(
+        self,
+        path: str | Path | IO[bytes] | PartitionBy,
+        *,
+        compression: AvroCompression = "uncompressed",
+        name: str = "",
+        namespace: str | None = None,
+        block_size: int | None = None,
+        maintain_order: bool = True,
+        storage_options: StorageOptionsDict | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        lazy: bool = False,
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> LazyFrame | None:
+        """
+        Evaluate the query in streaming mode and write to an Apache Avro file.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        This allows streaming results that are larger than RAM to be written to disk.
+
+        Parameters
+        ----------
+        path
+            File path to which the file should be written.
+        compression : {'uncompressed', 'snappy', 'deflate', 'zstd'}
+            Compression codec of the blocks.
+        name
+            Name of the record schema.
+        namespace
+            Namespace of the record schema.
+        block_size
+            Maximum number of rows per block. If `None`, the block size is chosen
+            by the engine.
+        maintain_order
+            Maintain the order in which data is processed.
+            Setting this to `False` will be slightly faster.
+        storage_options
+            Options that indicate how to connect to a cloud provider.
+
+            The cloud providers currently supported are AWS, GCP, and Azure.
+            See supported keys here:
+
+            * `aws <https://docs.rs/object_store/latest/object_store/aws/enum.AmazonS3ConfigKey.html>`_
+            * `gcp <https://docs.rs/object_store/latest/object_store/gcp/enum.GoogleConfigKey.html>`_
+            * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
+            * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
+            `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+
+            If `storage_options` is not provided, Polars will try to infer the
+            information from environment variables.
+        credential_provider
+            Provide a function that can be called to provide cloud storage
+            credentials. The function is expected to return a dictionary of
+            credential keys along with an optional credential expiry time.
+        sync_on_close: { None, 'data', 'all' }
+            Sync to disk when before closing a file.
+
+            * `None` does not sync.
+            * `data` syncs the file contents.
+            * `all` syncs the file contents and metadata.
+        mkdir: bool
+            Recursively create all the directories in the path.
+        lazy: bool
+            Wait to start execution until `collect` is called.
+        engine
+            Select the engine used to process the query (default ``"auto"``).
+            See :meth:`sink_ndjson` for the available engines.
+        optimizations
+            The optimization passes done during query optimization.
+
+            This has no effect if `lazy` is set to `True`.
+
+        Examples
+        --------
+        >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
+        >>> lf.sink_avro("out.avro", compression="zstd")  # doctest: +SKIP
+
+        Write an Avro file per partition:
+
+        >>> pl.LazyFrame({"x": [1, 2, 1], "y": [3, 4, 5]}).sink_avro(
+        ...     pl.PartitionBy("./out/", key="x"),
+        ...     name="record",
+        ...     mkdir=True,
+        ... )  # doctest: +SKIP
+
+        See Also
+        --------
+        PartitionBy
+        """
+        engine = _select_engine(engine)
+
+        from polars.io.cloud.credential_provider._builder import (
+            _init_credential_provider_builder,
+        )
+
+        credential_provider_builder = _init_credential_provider_builder(
+            credential_provider, path, storage_options, "sink_avro"
+        )
+        del credential_provider
+
+        target = _to_sink_target(path)
+
+        from polars.io.partition import _SinkOptions
+
+        sink_options = _SinkOptions(
+            mkdir=mkdir,
+            maintain_order=maintain_order,
+            sync_on_close=sync_on_close,
+            storage_options=storage_options,
+            credential_provider=credential_provider_builder,
+        )
+
+        ldf_py = self._ldf.sink_avro(
+            target=target,
+            compression=compression,
+            name=name,
+            namespace=namespace,
+            block_size=block_size,
+            sink_options=sink_options,
+        )
+
+        if not lazy:
+            ldf_py = ldf_py.with_optimizations(optimizations._pyoptflags)
+            ldf = LazyFrame._from_pyldf(ldf_py)
+            ldf.collect(engine=engine)
+            return None
+        return LazyFrame._from_pyldf(ldf_py)
+
     @overload
     def sink_batches(
         self,
@@ -8505,7 +8736,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
     def pivot(
         self,
         on: ColumnNameOrSelector | Sequence[ColumnNameOrSelector],
-        on_columns: Sequence[Any] | pl.Series | pl.DataFrame,
+        on_columns: Sequence[Any] | pl.Series | pl.DataFrame | None = None,
         *,
         index: ColumnNameOrSelector | Sequence[ColumnNameOrSelector] | None = None,
         values: ColumnNameOrSelector | Sequence[ColumnNameOrSelector] | None = None,
@@ -8513,6 +8744,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         maintain_order: bool = False,
         separator: str = "_",
         column_naming: Literal["auto", "combine"] = "auto",
+        max_on_columns: int | None = None,
     ) -> LazyFrame:
         """
         Create a spreadsheet-style pivot table as a DataFrame.
@@ -8524,6 +8756,12 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             DataFrame.
         on_columns
             What value combinations will be considered for the output table.
+            If None, the distinct values of `on` are discovered from the data, in
+            order of appearance, when the query is collected. The columns used by
+            the pivot are then collected once, with the same engine and
+            optimizations. As the output columns are unknown before that,
+            requesting the schema (e.g. through :meth:`collect_schema`) or
+            explaining the query raises an error.
         index
             The column(s) that remain from the input to the output. The output DataFrame will have one row
             for each unique combination of the `index`'s values.
@@ -8558,6 +8796,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             .. warning::
                 This functionality is considered **unstable**. It may be changed
                 at any point without it being considered a breaking change.
+        max_on_columns
+            Raise an error if more than this many distinct `on` values are
+            discovered. Only valid if `on_columns` is None.
 
         Returns
         -------
@@ -8733,6 +8974,23 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         else:
             agg = aggregate_function
 
+        if on_columns is None:
+            return self._from_pyldf(
+                self._ldf.pivot_deferred(
+                    on=on_selector._pyselector,
+                    index=index_selector._pyselector,
+                    values=values_selector._pyselector,
+                    agg=agg._pyexpr,
+                    maintain_order=maintain_order,
+                    separator=separator,
+                    column_naming=column_naming,
+                    max_on_columns=max_on_columns,
+                )
+            )
+        if max_on_columns is not None:
+            msg = "`max_on_columns` can only be used if `on_columns` is None"
+            raise InvalidOperationError(msg)
+
         on_cols: pl.DataFrame
         if isinstance(on_columns, pl.DataFrame):
             on_cols = on_columns
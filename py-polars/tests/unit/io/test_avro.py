@@ -14,7 +14,7 @@ if TYPE_CHECKING:
     from polars._typing import AvroCompression
 
 
-COMPRESSIONS = ["uncompressed", "snappy", "deflate"]
+COMPRESSIONS = ["uncompressed", "snappy", "deflate", "zstd"]
 
 
 @pytest.fixture
@@ -89,3 +89,30 @@ def test_with_name() -> None:
     read_df = pl.read_json(raw[raw.find(b"{") : raw.rfind(b"}") + 1])
 
     assert_frame_equal(expected, read_df)
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("compression", COMPRESSIONS)
+def test_sink_avro(compression: AvroCompression, tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": range(1000), "b": [str(i) for i in range(1000)]})
+    path = tmp_path / "out.avro"
+
+    df.lazy().sink_avro(path, compression=compression, block_size=100)
+
+    assert_frame_equal(pl.read_avro(path), df)
+
+
+@pytest.mark.write_disk
+def test_sink_avro_partitioned(tmp_path: Path) -> None:
+    df = pl.DataFrame({"key": [1, 2, 1, 2], "value": ["a", "b", "c", "d"]})
+
+    df.lazy().sink_avro(
+        pl.PartitionBy(tmp_path, key="key", include_key=False),
+        name="record",
+        mkdir=True,
+    )
+
+    paths = sorted(tmp_path.rglob("*.avro"))
+    assert len(paths) == 2
+    out = pl.concat([pl.read_avro(p) for p in paths])
+    assert_frame_equal(out, df.select("value"), check_row_order=False)
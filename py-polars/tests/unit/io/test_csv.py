@@ -28,7 +28,7 @@ if TYPE_CHECKING:
     from pathlib import Path
     from typing import Any
 
-    from polars._typing import CsvQuoteStyle, TimeUnit
+    from polars._typing import CsvEncoding, CsvQuoteStyle, TimeUnit
 
 
 @pytest.fixture
@@ -562,6 +562,39 @@ def test_read_csv_encoding_lossy(chunk_override: None, tmp_path: Path) -> None:
         )
 
 
+@pytest.mark.write_disk
+@pytest.mark.parametrize(
+    ("encoding", "python_encoding", "bom"),
+    [
+        ("latin1", "latin1", b""),
+        ("windows-1252", "cp1252", b""),
+        ("utf16", "utf-16-le", b"\xff\xfe"),
+        ("utf16", "utf-16-be", b"\xfe\xff"),
+        ("utf16-le", "utf-16-le", b""),
+        ("utf16-be", "utf-16-be", b""),
+    ],
+)
+def test_read_csv_native_encoding(
+    encoding: CsvEncoding, python_encoding: str, bom: bytes, tmp_path: Path
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+
+    text = "name,price\nCafé,1.5\nCrème brûlée,2\n" + "Ærø,3\n" * 1000
+    if python_encoding != "latin1":
+        text += "€uro,4\n"
+    expected = pl.read_csv(text.encode())
+
+    file_path = tmp_path / "encoding.csv"
+    file_path.write_bytes(bom + text.encode(python_encoding))
+
+    assert_frame_equal(pl.read_csv(file_path, encoding=encoding), expected)
+
+    lf = pl.scan_csv(file_path, encoding=encoding)
+    assert_frame_equal(lf.collect(), expected)
+    assert_frame_equal(lf.head(2).collect(), expected.head(2))
+    assert lf.select(pl.len()).item() == expected.height
+
+
 @pytest.mark.may_fail_auto_streaming  # read->scan_csv dispatch
 def test_column_rename_and_schema_overrides(chunk_override: None) -> None:
     csv = textwrap.dedent(
@@ -3207,3 +3240,57 @@ def test_read_csv_mixed_i128_float_infers_float64_27654(chunk_override: None) ->
     csv_data = b"value\n12345678901234567890\n1.5"
     df = pl.read_csv(csv_data)
     assert df.schema["value"] == pl.Float64
+
+
+def test_write_csv_nested_as_json(chunk_override: None) -> None:
+    df = pl.DataFrame(
+        {
+            "i": [1, 2],
+            "l": [[1, 2], None],
+            "s": [{"x": 1, "y": "a"}, None],
+        }
+    )
+
+    with pytest.raises(ComputeError, match="does not support nested data"):
+        df.write_csv()
+
+    assert df.write_csv(nested_as_json=True) == (
+        'i,l,s\n1,"[1,2]","{""x"":1,""y"":""a""}"\n2,,\n'
+    )
+
+
+def test_read_csv_json_columns_round_trip(chunk_override: None) -> None:
+    df = pl.DataFrame(
+        {
+            "i": [1, 2, 3],
+            "l": [[1, None], None, []],
+            "a": [["a", "b"], ["c", None], None],
+            "s": [{"x": 1, "y": [1.5]}, None, {"x": None, "y": None}],
+        },
+        schema_overrides={"a": pl.Array(pl.String, 2)},
+    )
+    csv = df.write_csv(nested_as_json=True).encode()
+    json_columns = ["l", "a", "s"]
+    nested = {name: df.schema[name] for name in json_columns}
+
+    assert_frame_equal(
+        pl.read_csv(csv, schema_overrides=nested, json_columns=json_columns), df
+    )
+    assert_frame_equal(
+        pl.scan_csv(csv, schema_overrides=nested, json_columns=json_columns)
+        .select("s", "i")
+        .collect(),
+        df.select("s", "i"),
+    )
+
+
+def test_read_csv_json_columns_errors(chunk_override: None) -> None:
+    csv = b'a\n"[1,2"\n'
+
+    with pytest.raises(InvalidOperationError, match="must have a nested dtype"):
+        pl.read_csv(csv, json_columns=["a"])
+
+    with pytest.raises(ComputeError, match="error deserializing JSON"):
+        pl.read_csv(
+            csv, schema_overrides={"a": pl.List(pl.Int64)}, json_columns=["a"]
+        )
@@ -420,6 +420,39 @@ def test_scan_csv_missing_columns_insert() -> None:
     assert_frame_equal(result, expected)
 
 
+def test_scan_csv_rejects() -> None:
+    data = b"a,b\n1,x\n2\n3,y\nfoo,z\n4,w\n"
+    expected_rejects = pl.DataFrame(
+        {
+            "path": ["in-mem", "in-mem"],
+            "line": pl.Series([3, 5], dtype=pl.UInt64),
+            "raw": ["2", "foo,z"],
+        }
+    )
+
+    rejected: list[pl.DataFrame] = []
+    out = pl.scan_csv(
+        data, schema_overrides={"a": pl.Int64}, rejects=rejected.append
+    ).collect()
+    assert_frame_equal(out, pl.DataFrame({"a": [1, 3, 4], "b": ["x", "y", "w"]}))
+
+    rejects = pl.concat(rejected).sort("line")
+    assert_frame_equal(rejects.drop("error"), expected_rejects)
+    assert rejects["error"][0] == "expected 2 fields, found 1"
+
+    # The number of fields is validated for every row, the values only for the
+    # projected columns.
+    rejected.clear()
+    out = pl.scan_csv(
+        data, schema_overrides={"a": pl.Int64}, rejects=rejected.append
+    ).select("b")
+    assert_frame_equal(out.collect(), pl.DataFrame({"b": ["x", "y", "z", "w"]}))
+    assert_frame_equal(pl.concat(rejected).drop("error"), expected_rejects.head(1))
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="ignore_errors"):
+        pl.scan_csv(data, ignore_errors=True, rejects=rejected.append).collect()
+
+
 @pytest.mark.may_fail_auto_streaming
 @pytest.mark.parametrize("streaming", [True, False])
 def test_file_list_schema_supertype(tmp_path: Path, streaming: bool) -> None:
@@ -366,6 +366,76 @@ def test_scan_ndjson_schema_overwrite_22514() -> None:
     assert_frame_equal(q.collect(), pl.DataFrame({"a": "1"}))
 
 
+def test_scan_ndjson_rejects() -> None:
+    data = b'{"a":1,"b":"x"}\n{"a":\n\n{"a":2,"b":"y"}\n'
+
+    rejected: list[pl.DataFrame] = []
+    out = pl.scan_ndjson(
+        data, schema={"a": pl.Int64, "b": pl.String}, rejects=rejected.append
+    ).select("b")
+    assert_frame_equal(out.collect(), pl.DataFrame({"b": ["x", "y"]}))
+
+    rejects = pl.concat(rejected)
+    assert rejects.columns == ["path", "line", "raw", "error"]
+    assert rejects.select("path", "line", "raw").rows() == [("in-mem", 2, '{"a":')]
+
+
+@pytest.mark.parametrize("force_unit_read_size", [True, False])
+def test_scan_json_record_pointer(
+    force_unit_read_size: bool, plmonkeypatch: PlMonkeyPatch
+) -> None:
+    if force_unit_read_size:
+        plmonkeypatch.setenv("POLARS_FORCE_NDJSON_READ_SIZE", "1")
+
+    data = b"""{
+        "meta": {"items": [{"a": "not this"}]},
+        "data": {
+            "items": [
+                {"a": 1, "b": {"c": "}]"}},
+                {
+                    "a": 2,
+                    "b": {"c": "\\n"}
+                },
+                {"a": 3}
+            ]
+        },
+        "ignored": [
+    """
+
+    lf = pl.scan_json(data, record_pointer="/data/items")
+    expect = pl.DataFrame(
+        {"a": [1, 2, 3], "b": [{"c": "}]"}, {"c": "\n"}, None]},
+    )
+
+    assert_frame_equal(lf.collect(), expect)
+    assert_frame_equal(lf.select("a").collect(), expect.select("a"))
+    assert_frame_equal(lf.slice(1, 1).collect(), expect.slice(1, 1))
+    assert_frame_equal(lf.tail(2).collect(), expect.tail(2))
+    assert lf.select(pl.len()).collect().item() == 3
+    assert_frame_equal(
+        pl.scan_json(data, record_pointer="/data/items", row_index_name="i")
+        .select("i")
+        .collect(),
+        pl.DataFrame({"i": [0, 1, 2]}, schema={"i": pl.get_index_type()}),
+    )
+
+    assert_frame_equal(
+        pl.scan_json(b'[{"a": 1}, {"a": 2}]').collect(),
+        pl.DataFrame({"a": [1, 2]}),
+    )
+
+
+def test_scan_json_record_pointer_errors() -> None:
+    with pytest.raises(pl.exceptions.ComputeError, match="no JSON array found"):
+        pl.scan_json(b'{"a": []}', record_pointer="/b").collect()
+
+    with pytest.raises(pl.exceptions.ComputeError, match="expected a JSON array"):
+        pl.scan_json(b'{"a": {}}', record_pointer="/a").collect()
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="JSON pointer"):
+        pl.scan_json(b"[]", record_pointer="a").collect()
+
+
 @pytest.mark.slow
 @pytest.mark.write_disk
 @pytest.mark.parametrize("compression", ["uncompressed", "zstd", "gzip"])
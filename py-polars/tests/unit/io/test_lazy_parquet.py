@@ -1891,3 +1891,33 @@ def test_sink_parquet_lazy_and_collect() -> None:
     assert q.collect().shape == (0, 0)
 
     assert_frame_equal(pl.scan_parquet(f).collect(), df)
+
+
+@pytest.mark.write_disk
+def test_scan_parquet_page_index_fetches_pages_async(
+    plmonkeypatch: PlMonkeyPatch, tmp_path: Path
+) -> None:
+    plmonkeypatch.setenv("POLARS_FORCE_ASYNC", "1")
+    path = tmp_path / "data.parquet"
+
+    n = 10_000
+    df = pl.DataFrame(
+        {
+            "id": range(n),
+            "name": [f"name-{i}" for i in range(n)],
+            "category": [f"c{i % 7}" for i in range(n)],
+        }
+    )
+    df.write_parquet(path, data_page_size=4096, row_group_size=n)
+
+    q = pl.scan_parquet(path).filter(pl.col("id").is_between(5000, 5099))
+    assert_frame_equal(q.collect(), df.slice(5000, 100))
+
+    q = (
+        pl.scan_parquet(path)
+        .with_row_index()
+        .filter((pl.col("id") == 12) | (pl.col("id") == 9_990))
+    )
+    assert_frame_equal(
+        q.collect(), df.with_row_index().filter(pl.col("id").is_in([12, 9_990]))
+    )
@@ -4375,3 +4375,28 @@ def test_multi_file_resolve_metadata_level(
     lf = pl.scan_parquet(tmp_path / "part_*.parquet")
     assert lf.collect().height == 8
     assert f"ESTIMATED ROWS: {expected_est}" in lf.explain(optimized=True)
+
+
+def test_write_parquet_bloom_filters(tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": range(1000), "b": [f"s{i}" for i in range(1000)]})
+
+    plain = tmp_path / "plain.parquet"
+    with_filters = tmp_path / "bloom.parquet"
+    df.write_parquet(plain, row_group_size=100)
+    df.write_parquet(
+        with_filters, row_group_size=100, bloom_filters={"a": {}, "b": {"fpp": 0.01}}
+    )
+    assert with_filters.stat().st_size > plain.stat().st_size
+
+    assert_frame_equal(pl.read_parquet(with_filters), df)
+    assert_frame_equal(
+        pl.scan_parquet(with_filters).filter(pl.col("b") == "s123").collect(),
+        df.filter(pl.col("b") == "s123"),
+    )
+    assert_frame_equal(
+        pl.scan_parquet(with_filters).filter(pl.col("a").is_in([5, 2000])).collect(),
+        df.filter(pl.col("a") == 5),
+    )
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="false positive"):
+        df.write_parquet(plain, bloom_filters={"a": {"fpp": 1.5}})
@@ -0,0 +1,92 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing.asserts.frame import assert_frame_equal
+
+if TYPE_CHECKING:
+    from tests.conftest import PlMonkeyPatch
+
+DATA = b"""\
+ID  NAME    AMOUNT
+----------------
+   1alice      1.5
+   2          -2
+   3bob
+"""
+
+SCHEMA = {"id": pl.Int64, "name": pl.String, "amount": pl.Float64}
+
+
+@pytest.mark.parametrize("force_unit_chunk_size", [True, False])
+@pytest.mark.parametrize("carriage_return", [True, False])
+def test_scan_fwf(
+    force_unit_chunk_size: bool,
+    carriage_return: bool,
+    plmonkeypatch: PlMonkeyPatch,
+) -> None:
+    if force_unit_chunk_size:
+        plmonkeypatch.setenv("POLARS_FORCE_NDJSON_READ_SIZE", "1")
+
+    data = DATA.replace(b"\n", b"\r\n") if carriage_return else DATA
+
+    lf = pl.scan_fwf(data, schema=SCHEMA, widths=[4, 8, 6], skip_lines=2)
+
+    expect = pl.DataFrame(
+        {
+            "id": [1, 2, 3],
+            "name": ["alice", None, "bob"],
+            "amount": [1.5, -2.0, None],
+        }
+    )
+
+    assert_frame_equal(lf.collect(), expect)
+    assert_frame_equal(lf.select("amount").collect(), expect.select("amount"))
+    assert_frame_equal(lf.head(2).collect(), expect.head(2))
+    assert_frame_equal(lf.slice(1, 1).collect(), expect.slice(1, 1))
+    assert_frame_equal(lf.tail(1).collect(), expect.tail(1))
+    assert lf.select(pl.len()).collect().item() == 3
+
+    assert_frame_equal(
+        pl.scan_fwf(
+            data, schema=SCHEMA, widths=[4, 8, 6], skip_lines=2, row_index_name="idx"
+        ).collect(),
+        expect.with_row_index("idx"),
+    )
+
+
+def test_scan_fwf_spans() -> None:
+    assert_frame_equal(
+        pl.scan_fwf(
+            b"ab|cd|ef\n",
+            schema={"c": pl.String, "a": pl.String},
+            spans=[(6, 8), (0, 2)],
+        ).collect(),
+        pl.DataFrame({"c": ["ef"], "a": ["ab"]}),
+    )
+
+    assert_frame_equal(
+        pl.scan_fwf(
+            "äö ü\n".encode(),
+            schema={"a": pl.String, "b": pl.String},
+            spans=[(0, 3), (3, 4)],
+            span_unit="chars",
+            trim="none",
+        ).collect(),
+        pl.DataFrame({"a": ["äö "], "b": ["ü"]}),
+    )
+
+
+def test_scan_fwf_errors() -> None:
+    with pytest.raises(ValueError, match="exactly one of"):
+        pl.scan_fwf(b"", schema=SCHEMA)
+
+    with pytest.raises(InvalidOperationError, match="expected 3 column spans"):
+        pl.scan_fwf(b"", schema=SCHEMA, widths=[1, 2])
+
+    with pytest.raises(InvalidOperationError):
+        pl.scan_fwf(b"abc\n", schema={"a": pl.Int64}, widths=[3]).collect()
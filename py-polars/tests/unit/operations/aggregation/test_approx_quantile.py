@@ -0,0 +1,71 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_frame_equal
+
+
+def test_approx_quantile_small_exact() -> None:
+    df = pl.DataFrame({"a": [3, 1, None, 2], "b": [1.0, float("nan"), 2.0, 3.0]})
+    result = df.select(
+        pl.col("a").approx_quantile(0.0).alias("a_min"),
+        pl.col("a").approx_quantile(0.5).alias("a_median"),
+        pl.col("a").approx_quantile(1.0).alias("a_max"),
+        pl.col("b").cast(pl.Float32).approx_quantile(0.5).alias("b_median"),
+    )
+    expected = pl.DataFrame(
+        {"a_min": [1.0], "a_median": [2.0], "a_max": [3.0], "b_median": [2.0]},
+        schema_overrides={"b_median": pl.Float32},
+    )
+    assert_frame_equal(result, expected)
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_approx_quantile_group_by(engine: str) -> None:
+    n = 30_000
+    df = pl.LazyFrame(
+        {
+            "g": [i % 3 for i in range(n)],
+            "x": [(i * 7919) % n for i in range(n)],
+        }
+    )
+    result = (
+        df.group_by("g")
+        .agg(
+            approx=pl.col("x").approx_quantile(0.9),
+            exact=pl.col("x").quantile(0.9),
+        )
+        .collect(engine=engine)  # type: ignore[arg-type]
+    )
+    assert result.height == 3
+    max_error = (result["approx"] - result["exact"]).abs().max()
+    assert max_error < 0.01 * n  # type: ignore[operator]
+
+    q = df.select(pl.col("x").approx_quantile(0.5))
+    median = q.collect(engine=engine).item()  # type: ignore[arg-type]
+    assert abs(median - (n - 1) / 2) < 0.01 * n
+
+
+def test_approx_quantile_empty_and_null() -> None:
+    df = pl.LazyFrame(
+        {"g": [1, 1, 2], "x": [None, None, 1]},
+        schema={"g": pl.Int64, "x": pl.Int64},
+    )
+    expected = pl.DataFrame({"g": [1, 2], "x": [None, 1.0]})
+    for engine in ["in-memory", "streaming"]:
+        result = (
+            df.group_by("g", maintain_order=True)
+            .agg(pl.col("x").approx_quantile(0.5))
+            .collect(engine=engine)  # type: ignore[arg-type]
+        )
+        assert_frame_equal(result, expected)
+
+
+def test_approx_quantile_invalid() -> None:
+    df = pl.DataFrame({"s": ["a"], "x": [1]})
+    with pytest.raises(ComputeError, match="between 0.0 and 1.0"):
+        df.select(pl.col("x").approx_quantile(1.5))
+    with pytest.raises(InvalidOperationError, match="not supported for dtype"):
+        df.select(pl.col("s").approx_quantile(0.5))
@@ -769,3 +769,67 @@ def test_pivot_null_on_values_27272() -> None:
         {"id": ["a", "b"], '{"X","p"}': [1, 0], "null": [2, 3]},
     )
     assert_frame_equal(result2, expected2)
+
+
+def test_lazy_pivot_deferred_on_columns() -> None:
+    df = pl.DataFrame(
+        {
+            "id": ["a", "a", "b", "c"],
+            "cat": ["X", "Y", "X", "Z"],
+            "val": [1, 2, 3, 4],
+        }
+    )
+
+    result = (
+        df.lazy()
+        .pivot(on="cat", index="id", values="val", aggregate_function="sum")
+        .collect()
+    )
+    expected = df.pivot(on="cat", index="id", values="val", aggregate_function="sum")
+    assert_frame_equal(result, expected)
+
+
+def test_lazy_pivot_deferred_executes_once() -> None:
+    calls = 0
+
+    def count_calls(df: pl.DataFrame) -> pl.DataFrame:
+        nonlocal calls
+        calls += 1
+        return df
+
+    lf = (
+        pl.LazyFrame({"id": ["a", "a", "b"], "cat": ["X", "Y", "X"], "val": [1, 2, 3]})
+        .map_batches(count_calls)
+        .pivot(on="cat", index="id", values="val")
+    )
+
+    with pytest.raises(
+        pl.exceptions.InvalidOperationError,
+        match="only known once the query is collected",
+    ):
+        lf.collect_schema()
+    with pytest.raises(
+        pl.exceptions.InvalidOperationError,
+        match="only known once the query is collected",
+    ):
+        lf.explain()
+    assert calls == 0
+
+    result = lf.collect()
+    assert calls == 1
+    expected = pl.DataFrame({"id": ["a", "b"], "X": [1, 3], "Y": [2, None]})
+    assert_frame_equal(result, expected)
+
+    result = lf.filter(pl.col("Y").is_null()).select("X").collect()
+    assert calls == 2
+    assert_frame_equal(result, pl.DataFrame({"X": [3]}))
+
+
+def test_lazy_pivot_max_on_columns() -> None:
+    lf = pl.LazyFrame({"id": [1, 2, 3], "cat": ["X", "Y", "Z"], "val": [1, 2, 3]})
+
+    with pytest.raises(ComputeError, match="max_on_columns"):
+        lf.pivot(on="cat", index="id", values="val", max_on_columns=2).collect()
+
+    result = lf.pivot(on="cat", index="id", values="val", max_on_columns=3).collect()
+    assert result.columns == ["id", "X", "Y", "Z"]
@@ -475,3 +475,32 @@ def test_streaming_hconcat_strict_27372() -> None:
     result = lf.collect(engine="streaming")
     expected = lf.collect(engine="in-memory")
     assert_frame_equal(result, expected)
+
+
+@pytest.mark.parametrize("morsel_size", [1, 3, 100_000])
+def test_streaming_sorted_over(morsel_size: int, plmonkeypatch: PlMonkeyPatch) -> None:
+    plmonkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", str(morsel_size))
+
+    df = pl.DataFrame(
+        {
+            "g": [1, 1, 1, 2, 3, 3, 4, 4, 4, 4],
+            "h": ["a", "a", "b", "b", "a", "a", "a", "b", "b", "b"],
+            "x": [5, 3, None, 2, 1, 8, 4, 4, 0, 7],
+        }
+    )
+    lf = df.lazy().set_sorted("g", "h")
+    q = lf.with_columns(
+        cum=pl.col.x.cum_sum().over("g"),
+        rank=pl.col.x.rank("dense").over("g", "h"),
+        total=pl.col.x.sum().over("g") * 2,
+        head=pl.col.x.head(2).over("g", mapping_strategy="join"),
+    )
+
+    dot = q.show_graph(engine="streaming", plan_stage="physical", raw_output=True)
+    assert "sorted-over" in dot
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+    # Not sorted by the partition key.
+    q = lf.with_columns(cum=pl.col.x.cum_sum().over("h"))
+    dot = q.show_graph(engine="streaming", plan_stage="physical", raw_output=True)
+    assert "sorted-over" not in dot
@@ -530,3 +530,45 @@ def test_streaming_group_by_nested_agg_fallback() -> None:
     )
     expected = {("aaa", n // 3), ("bbb", n - n // 3)}
     assert expected == set(res.rows())
+
+
+@pytest.mark.parametrize("keys", [["a"], ["s"], ["a", "s"]])
+@pytest.mark.parametrize("with_first", [False, True])
+def test_streaming_group_by_spill_pre_aggs_and_repartition(
+    plmonkeypatch: PlMonkeyPatch, keys: list[str], with_first: bool
+) -> None:
+    plmonkeypatch.setenv("POLARS_FORCE_GROUP_BY_SPILL", "1")
+    plmonkeypatch.setenv("POLARS_HOT_TABLE_SIZE", "4")
+    plmonkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "100")
+
+    rng = np.random.default_rng(0)
+    n = 10_000
+    lf = pl.LazyFrame(
+        {
+            "a": rng.integers(0, 1_000, n),
+            "s": pl.Series(rng.integers(0, 50, n)).cast(pl.String),
+            "x": rng.integers(-100, 100, n),
+            "b": rng.integers(0, 2, n).astype(bool),
+        }
+    ).with_columns(
+        pl.when(pl.col("x") % 7 == 0).then(None).otherwise("a").alias("a"),
+        pl.when(pl.col("x") % 5 == 0).then(None).otherwise("x").alias("x"),
+    )
+
+    aggs = [
+        pl.col("x").sum().alias("sum"),
+        pl.col("x").mean().alias("mean"),
+        pl.col("x").min().alias("min"),
+        pl.col("x").max().alias("max"),
+        pl.col("x").count().alias("count"),
+        pl.col("b").sum().alias("b_sum"),
+        pl.col("b").mean().alias("b_mean"),
+        pl.len(),
+    ]
+    if with_first:
+        # `first` can't have its state spilled, so its pre-aggregates are kept
+        # in memory.
+        aggs.append(pl.col("x").first().alias("first"))
+
+    q = lf.group_by(keys).agg(aggs).sort(keys, nulls_last=True)
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+import re
 from datetime import datetime, timedelta
 from typing import TYPE_CHECKING, Any, Literal
 
@@ -22,6 +23,7 @@ if TYPE_CHECKING:
     from pathlib import Path
 
     from polars._typing import AsofJoinStrategy, JoinStrategy, MaintainOrderJoin
+    from tests.conftest import PlMonkeyPatch
 
 pytestmark = pytest.mark.xdist_group("streaming")
 
@@ -732,3 +734,143 @@ def test_streaming_merge_join_send_port_done_27547() -> None:
     expected = q.collect(engine="in-memory")
     actual = q.collect(engine="streaming")
     assert_frame_equal(actual, expected)
+
+
+@pytest.mark.parametrize("how", ["inner", "left", "right", "full"])
+@pytest.mark.parametrize("sample_limit", ["0", "1000"])
+def test_streaming_grace_join(
+    plmonkeypatch: PlMonkeyPatch, how: JoinStrategy, sample_limit: str
+) -> None:
+    plmonkeypatch.setenv("POLARS_FORCE_GRACE_JOIN", "1")
+    plmonkeypatch.setenv("POLARS_JOIN_GRACE_PARTITIONS", "7")
+    plmonkeypatch.setenv("POLARS_JOIN_SAMPLE_LIMIT", sample_limit)
+    plmonkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "100")
+
+    rng = np.random.default_rng(0)
+    n = 5_000
+    left = pl.LazyFrame(
+        {
+            "a": rng.integers(0, 1_000, n),
+            "b": pl.Series(rng.integers(0, 5, n)).cast(pl.String),
+            "x": np.arange(n),
+        }
+    ).with_columns(pl.when(pl.col("x") % 11 == 0).then(None).otherwise("a").alias("a"))
+    right = pl.LazyFrame(
+        {
+            "a": rng.integers(500, 1_500, n),
+            "b": pl.Series(rng.integers(0, 5, n)).cast(pl.String),
+            "y": np.arange(n),
+        }
+    )
+
+    q = left.join(right, on=["a", "b"], how=how).sort("x", "y", nulls_last=True)
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+def test_streaming_grace_join_resplit(
+    plmonkeypatch: PlMonkeyPatch, capfd: pytest.CaptureFixture[str]
+) -> None:
+    # Without any memory budget every grace partition is split again until the
+    # maximum depth is reached.
+    plmonkeypatch.setenv("POLARS_OOC_MEMORY_BUDGET_MB", "0")
+    plmonkeypatch.setenv("POLARS_JOIN_GRACE_PARTITIONS", "2")
+    plmonkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "100")
+    plmonkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    rng = np.random.default_rng(0)
+    n = 5_000
+    left = pl.LazyFrame({"a": rng.integers(0, 1_000, n), "x": np.arange(n)})
+    right = pl.LazyFrame({"a": rng.integers(500, 1_500, n), "y": np.arange(n)})
+
+    q = left.join(right, on="a", how="full").sort("x", "y", nulls_last=True)
+    actual = q.collect(engine="streaming")
+    assert "splitting grace partition at depth 0 again" in capfd.readouterr().err
+    assert_frame_equal(actual, q.collect(engine="in-memory"))
+
+
+@pytest.mark.parametrize("policy", ["auto", "most_recent", "least_recent", "random"])
+@pytest.mark.parametrize("via_engine", [False, True])
+def test_streaming_query_memory_budget(
+    plmonkeypatch: PlMonkeyPatch,
+    capfd: pytest.CaptureFixture[str],
+    policy: Literal["auto", "most_recent", "least_recent", "random"],
+    via_engine: bool,
+) -> None:
+    # A zero budget makes the query spill its buffers as soon as they are registered.
+    engine: pl.StreamingEngine | Literal["streaming"]
+    if via_engine:
+        engine = pl.StreamingEngine(memory_budget=0, spill_policy=policy)
+    else:
+        plmonkeypatch.setenv("POLARS_OOC_QUERY_MEMORY_BUDGET_MB", "0")
+        plmonkeypatch.setenv("POLARS_OOC_SPILL_POLICY", policy)
+        engine = "streaming"
+    plmonkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "100")
+    plmonkeypatch.setenv("POLARS_LOG_METRICS", "1")
+    plmonkeypatch.setenv("POLARS_OOC_LOG_METRICS", "1")
+
+    rng = np.random.default_rng(0)
+    n = 5_000
+    left = pl.LazyFrame({"a": rng.integers(0, 1_000, n), "x": np.arange(n)})
+    right = pl.LazyFrame({"a": rng.integers(500, 1_500, n), "y": np.arange(n)})
+
+    q = (
+        left.join(right, on="a", how="left")
+        .group_by("a")
+        .agg(pl.col("x").sum(), pl.col("y").max())
+        .sort("a", nulls_last=True)
+    )
+    capfd.readouterr()
+    actual = q.collect(engine=engine)
+    capture = capfd.readouterr().err
+    assert_frame_equal(actual, q.collect(engine="in-memory"))
+
+    num_spills = [
+        int(n) for n in re.findall(r"spill\(succ=[^,]*, n=(\d+)\)", capture)
+    ]
+    assert sum(num_spills) > 0
+    peak_resident = [
+        int(n) for n in re.findall(r"memory\(peak_resident_bytes=(\d+)", capture)
+    ]
+    assert peak_resident
+    assert max(peak_resident) > 0
+
+
+def test_streaming_engine_invalid_options() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3]})
+    with pytest.raises(ValueError, match="spill_policy"):
+        lf.collect(engine=pl.StreamingEngine(spill_policy="lifo"))  # type: ignore[arg-type]
+    with pytest.raises(ValueError, match="memory_budget"):
+        pl.StreamingEngine(memory_budget=-1)
+    with pytest.raises(NotImplementedError, match="only supported by"):
+        lf.profile(engine=pl.StreamingEngine())
+
+
+@pytest.mark.parametrize("spill_format", ["ipc", "ipc_lz4", "row"])
+def test_streaming_spill_formats(
+    plmonkeypatch: PlMonkeyPatch, spill_format: str
+) -> None:
+    plmonkeypatch.setenv("POLARS_OOC_QUERY_MEMORY_BUDGET_MB", "0")
+    plmonkeypatch.setenv("POLARS_OOC_SPILL_FORMAT", spill_format)
+    plmonkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "100")
+
+    rng = np.random.default_rng(0)
+    n = 5_000
+    left = pl.LazyFrame(
+        {
+            "a": rng.integers(0, 1_000, n),
+            "s": pl.Series(rng.integers(0, 50, n)).cast(pl.String),
+            "l": [[i, None] for i in range(n)],
+        }
+    )
+    right = pl.LazyFrame(
+        {
+            "a": rng.integers(500, 1_500, n),
+            # The row encoding doesn't preserve -0.0, these frames must use IPC.
+            "f": np.where(np.arange(n) % 2 == 0, -0.0, 1.5),
+        }
+    )
+
+    q = left.join(right, on="a").sort("a", "s", "f", maintain_order=True)
+    out = q.collect(engine="streaming")
+    assert_frame_equal(out, q.collect(engine="in-memory"))
+    assert np.signbit(out.filter(pl.col("f") == 0.0)["f"].to_numpy()).all()
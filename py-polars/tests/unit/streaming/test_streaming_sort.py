@@ -13,6 +13,8 @@ from polars.testing import assert_frame_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
+    from tests.conftest import PlMonkeyPatch
+
 pytestmark = pytest.mark.xdist_group("streaming")
 
 
@@ -183,3 +185,57 @@ def test_sort_descending_nulls_last(descending: bool, nulls_last: bool) -> None:
         .collect(engine="streaming"),
         pl.DataFrame({"x": ref_x, "y": ref_y}),
     )
+
+
+@pytest.mark.parametrize("spill", [False, True])
+@pytest.mark.parametrize("maintain_order", [False, True])
+@pytest.mark.parametrize("slice", [None, (0, 10), (1234, 500), (-300, 100)])
+def test_streaming_sort_many_runs(
+    plmonkeypatch: PlMonkeyPatch,
+    spill: bool,
+    maintain_order: bool,
+    slice: tuple[int, int] | None,
+) -> None:
+    # A small morsel size forces the sort to produce and merge many runs.
+    plmonkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "10")
+    if spill:
+        # Spill the runs so they are merged block by block.
+        plmonkeypatch.setenv("POLARS_OOC_QUERY_MEMORY_BUDGET_MB", "0")
+    rng = np.random.default_rng(0)
+    n = 20_000
+    df = pl.DataFrame(
+        {
+            "a": rng.integers(0, 100, n),
+            "b": pl.Series(rng.integers(0, 1000, n)).cast(pl.String),
+            "c": np.arange(n),
+        }
+    ).with_columns(pl.when(pl.col("c") % 7 == 0).then(None).otherwise("a").alias("a"))
+
+    q = df.lazy().sort(
+        ["a", "b"], descending=[True, False], maintain_order=maintain_order
+    )
+    if slice is not None:
+        q = q.slice(*slice)
+
+    expected = q.collect(engine="in-memory")
+    out = q.collect(engine="streaming")
+    if maintain_order:
+        assert_frame_equal(out, expected)
+    else:
+        assert_frame_equal(out.drop("c"), expected.drop("c"))
+
+
+def test_streaming_sort_by_maintain_order_many_sources(
+    plmonkeypatch: PlMonkeyPatch,
+) -> None:
+    plmonkeypatch.setenv("POLARS_IDEAL_MORSEL_SIZE", "10")
+    rng = np.random.default_rng(0)
+    parts = [
+        pl.LazyFrame({"a": rng.integers(0, 5, 1_000), "c": np.arange(1_000) + i})
+        for i in range(0, 4_000, 1_000)
+    ]
+    q = pl.concat(parts).select(
+        pl.col("c").sort_by("a", maintain_order=True),
+        pl.col("c").sort_by("a", descending=True, maintain_order=True).alias("desc"),
+    )
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))